@@ -1,7 +1,7 @@
 //! Benchmarks for rich_rust rendering.
 
 use criterion::{Criterion, criterion_group, criterion_main};
-use rich_rust::cells::cell_len;
+use rich_rust::cells::{cell_len, cell_len_uncached};
 use rich_rust::color::Color;
 use rich_rust::prelude::*;
 use rich_rust::style::Style;
@@ -137,6 +137,18 @@ fn benchmark_cell_len(c: &mut Criterion) {
     c.bench_function("cell_len_long_ascii", |b| {
         b.iter(|| black_box(cell_len(&long_ascii)));
     });
+
+    // ~1MB inputs, uncached: the cost of measuring large logs for wrapping.
+    let log_ascii = "2024-01-01T00:00:00Z INFO request served in 12ms\n".repeat(20_000);
+    let log_mixed = "2024-01-01 INFO 用户登录成功 ✓ naïve café\n".repeat(20_000);
+
+    c.bench_function("cell_len_1mb_ascii", |b| {
+        b.iter(|| black_box(cell_len_uncached(&log_ascii)));
+    });
+
+    c.bench_function("cell_len_1mb_mixed", |b| {
+        b.iter(|| black_box(cell_len_uncached(&log_mixed)));
+    });
 }
 
 fn benchmark_table_render(c: &mut Criterion) {
@@ -444,6 +456,43 @@ fn benchmark_stress_large_table(c: &mut Criterion) {
     });
 }
 
+fn benchmark_stress_table_10k_rows(c: &mut Criterion) {
+    // Large enough that body rows are rendered on several threads
+    let mut table = Table::new()
+        .with_column(Column::new("Id"))
+        .with_column(Column::new("Message").max_width(40));
+    for row in 0..10_000 {
+        table.add_row_cells([
+            row.to_string(),
+            format!("request {row} completed with status 200 after a long wait"),
+        ]);
+    }
+
+    c.bench_function("stress_table_10k_rows", |b| {
+        b.iter(|| black_box(table.render(100)));
+    });
+}
+
+// =============================================================================
+// Recorded Output
+// =============================================================================
+
+fn benchmark_recorded_export(c: &mut Criterion) {
+    // Exporting without clearing keeps the whole recording, so it is copied each time.
+    let console = Console::builder()
+        .width(100)
+        .file(Box::new(std::io::sink()))
+        .build();
+    console.begin_capture();
+    for row in 0..10_000 {
+        console.print_plain(&format!("request {row} completed with status 200"));
+    }
+
+    c.bench_function("console_export_text_10k_lines", |b| {
+        b.iter(|| black_box(console.export_text_with_ansi(None, false)));
+    });
+}
+
 criterion_group!(
     benches,
     benchmark_text_render,
@@ -461,5 +510,7 @@ criterion_group!(
     benchmark_color_downgrade,
     benchmark_stress_large_text,
     benchmark_stress_large_table,
+    benchmark_stress_table_10k_rows,
+    benchmark_recorded_export,
 );
 criterion_main!(benches);
@@ -140,6 +140,10 @@ fn run_live_dashboard(
         redirect_stdout: false,
         redirect_stderr: false,
         vertical_overflow: VerticalOverflowMethod::Ellipsis,
+        partial_refresh: true, // Only repaint the panes that changed
+        max_fps: Some(30.0),
+        adaptive_refresh: true, // Idle down to ~1 Hz between updates
+        ..LiveOptions::default()
     };
 
     let live = Live::with_options(Arc::clone(console), options).get_renderable(move || {
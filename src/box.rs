@@ -394,6 +394,58 @@ pub const MINIMAL_HEAVY_HEAD: BoxChars = BoxChars::new(
     false,
 );
 
+/// Minimal with a double header separator.
+pub const MINIMAL_DOUBLE_HEAD: BoxChars = BoxChars::new(
+    [' ', ' ', ' ', ' '],
+    [' ', ' ', '\u{2502}', ' '],        //   │
+    [' ', '\u{2550}', '\u{256A}', ' '], //  ═╪
+    [' ', ' ', ' ', ' '],
+    [' ', '\u{2500}', '\u{253C}', ' '], //  ─┼
+    [' ', '\u{2500}', '\u{253C}', ' '], //  ─┼
+    [' ', ' ', '\u{2502}', ' '],        //   │
+    [' ', ' ', ' ', ' '],
+    false,
+);
+
+/// Square with a double header separator.
+pub const SQUARE_DOUBLE_HEAD: BoxChars = BoxChars::new(
+    ['\u{250C}', '\u{2500}', '\u{252C}', '\u{2510}'], // ┌─┬┐
+    ['\u{2502}', ' ', '\u{2502}', '\u{2502}'],        // │ ││
+    ['\u{255E}', '\u{2550}', '\u{256A}', '\u{2561}'], // ╞═╪╡
+    ['\u{2502}', ' ', '\u{2502}', '\u{2502}'],        // │ ││
+    ['\u{251C}', '\u{2500}', '\u{253C}', '\u{2524}'], // ├─┼┤
+    ['\u{251C}', '\u{2500}', '\u{253C}', '\u{2524}'], // ├─┼┤
+    ['\u{2502}', ' ', '\u{2502}', '\u{2502}'],        // │ ││
+    ['\u{2514}', '\u{2500}', '\u{2534}', '\u{2518}'], // └─┴┘
+    false,
+);
+
+/// Heavy outer edge with light inner lines.
+pub const HEAVY_EDGE: BoxChars = BoxChars::new(
+    ['\u{250F}', '\u{2501}', '\u{252F}', '\u{2513}'], // ┏━┯┓
+    ['\u{2503}', ' ', '\u{2502}', '\u{2503}'],        // ┃ │┃
+    ['\u{2520}', '\u{2500}', '\u{253C}', '\u{2528}'], // ┠─┼┨
+    ['\u{2503}', ' ', '\u{2502}', '\u{2503}'],        // ┃ │┃
+    ['\u{2520}', '\u{2500}', '\u{253C}', '\u{2528}'], // ┠─┼┨
+    ['\u{2520}', '\u{2500}', '\u{253C}', '\u{2528}'], // ┠─┼┨
+    ['\u{2503}', ' ', '\u{2502}', '\u{2503}'],        // ┃ │┃
+    ['\u{2517}', '\u{2501}', '\u{2537}', '\u{251B}'], // ┗━┷┛
+    false,
+);
+
+/// Double outer edge with light inner lines.
+pub const DOUBLE_EDGE: BoxChars = BoxChars::new(
+    ['\u{2554}', '\u{2550}', '\u{2564}', '\u{2557}'], // ╔═╤╗
+    ['\u{2551}', ' ', '\u{2502}', '\u{2551}'],        // ║ │║
+    ['\u{255F}', '\u{2500}', '\u{253C}', '\u{2562}'], // ╟─┼╢
+    ['\u{2551}', ' ', '\u{2502}', '\u{2551}'],        // ║ │║
+    ['\u{255F}', '\u{2500}', '\u{253C}', '\u{2562}'], // ╟─┼╢
+    ['\u{255F}', '\u{2500}', '\u{253C}', '\u{2562}'], // ╟─┼╢
+    ['\u{2551}', ' ', '\u{2502}', '\u{2551}'],        // ║ │║
+    ['\u{255A}', '\u{2550}', '\u{2567}', '\u{255D}'], // ╚═╧╝
+    false,
+);
+
 /// Get a box style by name.
 #[must_use]
 pub fn get_box(name: &str) -> Option<&'static BoxChars> {
@@ -408,6 +460,10 @@ pub fn get_box(name: &str) -> Option<&'static BoxChars> {
         "heavy_head" => Some(&HEAVY_HEAD),
         "minimal" => Some(&MINIMAL),
         "minimal_heavy_head" => Some(&MINIMAL_HEAVY_HEAD),
+        "minimal_double_head" => Some(&MINIMAL_DOUBLE_HEAD),
+        "square_double_head" => Some(&SQUARE_DOUBLE_HEAD),
+        "heavy_edge" => Some(&HEAVY_EDGE),
+        "double_edge" => Some(&DOUBLE_EDGE),
         "simple" => Some(&SIMPLE),
         "simple_heavy" => Some(&SIMPLE_HEAVY),
         "horizontals" => Some(&HORIZONTALS),
@@ -433,6 +489,32 @@ mod tests {
         assert_eq!(ASCII.top[0], '+');
     }
 
+    #[test]
+    fn test_edge_and_double_head_boxes() {
+        let widths = [2, 2];
+        assert_eq!(
+            HEAVY_EDGE.get_top(&widths),
+            "\u{250F}\u{2501}\u{2501}\u{252F}\u{2501}\u{2501}\u{2513}"
+        );
+        assert_eq!(
+            DOUBLE_EDGE.get_bottom(&widths),
+            "\u{255A}\u{2550}\u{2550}\u{2567}\u{2550}\u{2550}\u{255D}"
+        );
+        assert_eq!(
+            SQUARE_DOUBLE_HEAD.get_head_row(&widths),
+            "\u{255E}\u{2550}\u{2550}\u{256A}\u{2550}\u{2550}\u{2561}"
+        );
+        for name in [
+            "heavy_edge",
+            "double_edge",
+            "square_double_head",
+            "minimal_double_head",
+        ] {
+            let box_style = get_box(name).expect(name);
+            assert!(box_style.substitute(true).ascii);
+        }
+    }
+
     #[test]
     fn test_get_top() {
         let widths = [5, 3, 7];
@@ -16,24 +16,59 @@ const CACHE_MIN_LEN: usize = 8;
 
 /// LRU cache for `cell_len` calculations.
 /// Per `RICH_SPEC.md` Section 12.4, string widths should be cached.
-static CELL_LEN_CACHE: LazyLock<Mutex<LruCache<String, usize>>> =
+///
+/// Keyed by the string itself; lookups borrow the `&str` and only a miss
+/// copies it into the cache.
+static CELL_LEN_CACHE: LazyLock<Mutex<LruCache<Box<str>, usize>>> =
     LazyLock::new(|| Mutex::new(LruCache::new(NonZeroUsize::new(1024).expect("non-zero"))));
 
+/// Widths of every Basic Multilingual Plane code point, packed 2 bits each.
+///
+/// Built once from `unicode-width`, so lookups agree with it exactly while
+/// costing a single indexed load instead of a multi-level table walk.
+static BMP_WIDTHS: LazyLock<Box<[u8]>> = LazyLock::new(|| {
+    let mut table = vec![0u8; 0x1_0000 / 4];
+    for cp in 0..0x1_0000u32 {
+        let width = char::from_u32(cp).map_or(0, |c| c.width().unwrap_or(0));
+        // Single characters are at most 2 cells wide.
+        let bits = u8::try_from(width.min(3)).unwrap_or(0);
+        table[(cp / 4) as usize] |= bits << ((cp % 4) * 2);
+    }
+    table.into_boxed_slice()
+});
+
 /// Get the cell width of a single character.
 ///
 /// Most characters are 1 cell wide, but CJK characters and some emoji
 /// are 2 cells wide. Control characters have 0 width.
 #[must_use]
 pub fn get_character_cell_size(c: char) -> usize {
+    let cp = u32::from(c);
+    if cp < 0x7F {
+        // Printable ASCII is one cell; C0 controls are zero.
+        return usize::from(cp >= 0x20);
+    }
+    if cp < 0x1_0000 {
+        let packed = BMP_WIDTHS[(cp / 4) as usize];
+        return usize::from((packed >> ((cp % 4) * 2)) & 0b11);
+    }
     c.width().unwrap_or(0)
 }
 
 /// Compute cell width by summing character widths.
 ///
 /// This ensures consistent handling of control characters (width 0)
-/// using the same logic as `get_character_cell_size`.
+/// using the same logic as `get_character_cell_size`. Pure ASCII input,
+/// the common case for logs, is counted byte-wise without UTF-8 decoding,
+/// which the compiler vectorizes.
 #[inline]
 fn compute_cell_width(text: &str) -> usize {
+    if text.is_ascii() {
+        return text
+            .bytes()
+            .filter(|byte| (0x20..0x7F).contains(byte))
+            .count();
+    }
     text.chars().map(get_character_cell_size).sum()
 }
 
@@ -43,27 +78,32 @@ fn compute_cell_width(text: &str) -> usize {
 /// wide characters that take 2 cells. Control characters have 0 width.
 ///
 /// Per `RICH_SPEC.md` Section 12.4, results are cached using an LRU cache
-/// for strings of 8+ characters to avoid repeated calculations.
+/// for strings of 8+ bytes to avoid repeated calculations.
 #[must_use]
 pub fn cell_len(text: &str) -> usize {
     // Short strings: compute directly (cache overhead not worth it)
     if text.len() < CACHE_MIN_LEN {
         return compute_cell_width(text);
     }
+    cached_cell_len(text)
+}
 
+/// Get the total cell width of a string, always going through the LRU cache.
+///
+/// Use this for strings that are measured over and over regardless of their
+/// length, such as table headers re-measured during column negotiation.
+#[must_use]
+pub fn cached_cell_len(text: &str) -> usize {
     // Check cache first
-    {
-        let mut cache = lock_recover(&CELL_LEN_CACHE);
-        if let Some(&cached) = cache.get(text) {
-            return cached;
-        }
+    if let Some(&cached) = lock_recover(&CELL_LEN_CACHE).get(text) {
+        return cached;
     }
 
     // Compute width using character-level function for consistency
     let width = compute_cell_width(text);
 
     // Store in cache
-    lock_recover(&CELL_LEN_CACHE).put(text.to_string(), width);
+    lock_recover(&CELL_LEN_CACHE).put(Box::from(text), width);
 
     width
 }
@@ -500,6 +540,46 @@ mod tests {
         assert_eq!(cell_len(cjk_long), cjk_width); // Cache hit
     }
 
+    #[test]
+    fn test_lookup_table_matches_unicode_width() {
+        for cp in (0..0x1_0000u32).chain([0x1_F600, 0x2_0000, 0xE_0001]) {
+            if let Some(c) = char::from_u32(cp) {
+                assert_eq!(
+                    get_character_cell_size(c),
+                    c.width().unwrap_or(0),
+                    "U+{cp:04X}"
+                );
+            }
+        }
+        assert_eq!(cell_len_uncached("tab\there\x7f"), 7);
+        assert_eq!(cell_len_uncached("naïve 日本"), 10);
+    }
+
+    #[test]
+    fn test_cached_cell_len_any_length() {
+        // Short strings are cached too when asked explicitly
+        assert_eq!(cached_cell_len("日本"), 4);
+        assert_eq!(cached_cell_len("日本"), 4);
+        assert_eq!(cached_cell_len(""), 0);
+
+        // Same key space as `cell_len`
+        let long = "a header measured during column negotiation";
+        assert_eq!(cached_cell_len(long), cell_len(long));
+        assert_eq!(cell_len(long), cell_len_uncached(long));
+    }
+
+    #[test]
+    fn test_cached_cell_len_distinguishes_same_length_strings() {
+        // Equal byte lengths, different widths: a hit must match the text itself.
+        let ascii = "abcdefghijkl";
+        let wide = "日本語abc";
+        assert_eq!(wide.len(), ascii.len());
+
+        assert_eq!(cached_cell_len(ascii), 12);
+        assert_eq!(cached_cell_len(wide), 9);
+        assert_eq!(cached_cell_len(ascii), 12);
+    }
+
     // ========================================================================
     // Unicode Edge Cases (bd-1ghg)
     // ========================================================================
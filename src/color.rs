@@ -77,6 +77,7 @@ use lru::LruCache;
 use regex::Regex;
 use std::fmt;
 use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::LazyLock;
 use std::sync::Mutex;
@@ -244,8 +245,196 @@ impl TerminalTheme {
             ansi_colors,
         }
     }
+
+    /// Import an iTerm2 color preset (`.itermcolors` file).
+    ///
+    /// Colors the preset doesn't define keep their [`DEFAULT_TERMINAL_THEME`] values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or defines no usable colors.
+    pub fn from_iterm(path: impl AsRef<Path>) -> Result<Self, TerminalThemeError> {
+        let contents = std::fs::read_to_string(&path).map_err(|err| TerminalThemeError::Io {
+            path: path.as_ref().to_path_buf(),
+            err,
+        })?;
+        Self::from_iterm_str(&contents)
+    }
+
+    /// Import an iTerm2 color preset from its XML property list contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a color component isn't a number or no colors are defined.
+    pub fn from_iterm_str(contents: &str) -> Result<Self, TerminalThemeError> {
+        static ENTRY: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"(?s)<key>\s*([^<]+?)\s*</key>\s*<dict>(.*?)</dict>").expect("valid regex")
+        });
+        static COMPONENT: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"<key>\s*(Red|Green|Blue) Component\s*</key>\s*<real>\s*([^<]*?)\s*</real>")
+                .expect("valid regex")
+        });
+
+        let mut theme = DEFAULT_TERMINAL_THEME;
+        let mut found = false;
+        for entry in ENTRY.captures_iter(contents) {
+            let name = &entry[1];
+            let mut rgb = [0u8; 3];
+            for component in COMPONENT.captures_iter(&entry[2]) {
+                let value: f64 =
+                    component[2]
+                        .parse()
+                        .map_err(|_| TerminalThemeError::InvalidValue {
+                            name: name.to_string(),
+                            value: component[2].to_string(),
+                        })?;
+                let index = match &component[1] {
+                    "Red" => 0,
+                    "Green" => 1,
+                    _ => 2,
+                };
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "value is clamped to 0..255"
+                )]
+                let scaled = (value * 255.0).round().clamp(0.0, 255.0) as u8;
+                rgb[index] = scaled;
+            }
+            found |= theme.set_named_color(name, ColorTriplet::new(rgb[0], rgb[1], rgb[2]));
+        }
+        if found {
+            Ok(theme)
+        } else {
+            Err(TerminalThemeError::NoColors)
+        }
+    }
+
+    /// Import colors from X resources (`.Xresources` / `xrdb -query` output).
+    ///
+    /// Reads the `background`, `foreground`, and `color0`-`color15` resources
+    /// for any class (`*.color1`, `URxvt.background`, ...) as `#rrggbb` or
+    /// `rgb:rr/gg/bb`. Colors not defined keep their [`DEFAULT_TERMINAL_THEME`]
+    /// values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a color value is invalid or no colors are defined.
+    pub fn from_xresources(contents: &str) -> Result<Self, TerminalThemeError> {
+        let mut theme = DEFAULT_TERMINAL_THEME;
+        let mut found = false;
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.starts_with('!') || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let name = key.trim().rsplit(['.', '*']).next().unwrap_or_default();
+            let name = match name {
+                "background" => "Background Color".to_string(),
+                "foreground" => "Foreground Color".to_string(),
+                _ => match name.strip_prefix("color") {
+                    Some(number) if number.parse::<u8>().is_ok_and(|n| n < 16) => {
+                        format!("Ansi {number} Color")
+                    }
+                    _ => continue,
+                },
+            };
+            let value = value.trim();
+            let color =
+                parse_xresources_color(value).ok_or_else(|| TerminalThemeError::InvalidValue {
+                    name: key.trim().to_string(),
+                    value: value.to_string(),
+                })?;
+            found |= theme.set_named_color(&name, color);
+        }
+        if found {
+            Ok(theme)
+        } else {
+            Err(TerminalThemeError::NoColors)
+        }
+    }
+
+    /// Set a color by its iTerm2 name; returns false for other names.
+    fn set_named_color(&mut self, name: &str, color: ColorTriplet) -> bool {
+        match name {
+            "Background Color" => self.background_color = color,
+            "Foreground Color" => self.foreground_color = color,
+            _ => {
+                let index = name
+                    .strip_prefix("Ansi ")
+                    .and_then(|rest| rest.strip_suffix(" Color"))
+                    .and_then(|number| number.parse::<usize>().ok())
+                    .filter(|&index| index < 16);
+                match index {
+                    Some(index) => self.ansi_colors[index] = color,
+                    None => return false,
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Parse an X resources color: `#rrggbb`, `#rgb`, or `rgb:r/g/b` with 1-4 hex digits each.
+fn parse_xresources_color(value: &str) -> Option<ColorTriplet> {
+    if let Some(spec) = value.strip_prefix("rgb:") {
+        let mut rgb = [0u8; 3];
+        let mut parts = spec.split('/');
+        for channel in &mut rgb {
+            let part = parts.next()?;
+            if part.is_empty() || part.len() > 4 {
+                return None;
+            }
+            let max = (1u32 << (4 * part.len())) - 1;
+            let component = u32::from_str_radix(part, 16).ok()?;
+            *channel = u8::try_from(component * 255 / max).ok()?;
+        }
+        return parts
+            .next()
+            .is_none()
+            .then(|| ColorTriplet::new(rgb[0], rgb[1], rgb[2]));
+    }
+    let hex = value.strip_prefix('#').filter(|hex| hex.is_ascii())?;
+    let hex = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
+    let component = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+    Some(ColorTriplet::new(
+        component(0..2)?,
+        component(2..4)?,
+        component(4..6)?,
+    ))
 }
 
+/// Errors returned when importing a [`TerminalTheme`].
+#[derive(Debug)]
+pub enum TerminalThemeError {
+    Io { path: PathBuf, err: std::io::Error },
+    InvalidValue { name: String, value: String },
+    NoColors,
+}
+
+impl fmt::Display for TerminalThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, err } => {
+                write!(f, "failed to read terminal theme {}: {err}", path.display())
+            }
+            Self::InvalidValue { name, value } => {
+                write!(f, "invalid color value for {name:?}: {value:?}")
+            }
+            Self::NoColors => write!(f, "terminal theme defines no colors"),
+        }
+    }
+}
+
+impl std::error::Error for TerminalThemeError {}
+
 /// Blend two RGB colors.
 ///
 /// `factor` is the weight of `background` in the blend:
@@ -503,6 +692,43 @@ impl Color {
         }
     }
 
+    /// The nearest named color and its distance from this color.
+    ///
+    /// Compares this color's RGB value against every name [`Color::parse`]
+    /// accepts, using the same weighted distance as color downgrading; a
+    /// distance of 0.0 is an exact match. When several names are equally
+    /// close the standard color (or shortest name) wins. The default color
+    /// is named `"default"`.
+    ///
+    /// ```
+    /// use rich_rust::color::Color;
+    ///
+    /// let (name, distance) = Color::parse("#ff0101").unwrap().name_approx();
+    /// assert_eq!(name, "red1");
+    /// assert!(distance < 3.0);
+    /// ```
+    #[must_use]
+    pub fn name_approx(&self) -> (&'static str, f64) {
+        if self.is_default() {
+            return ("default", 0.0);
+        }
+        let target = self.get_truecolor();
+        NAMED_COLORS_BY_NUMBER
+            .iter()
+            .map(|&(name, number)| {
+                let triplet = if number < 16 {
+                    STANDARD_PALETTE[usize::from(number)]
+                } else {
+                    EIGHT_BIT_PALETTE[usize::from(number)]
+                };
+                (name, color_distance(target, triplet))
+            })
+            .min_by_key(|&(_, distance)| distance)
+            .map_or(("default", 0.0), |(name, distance)| {
+                (name, f64::from(distance).sqrt())
+            })
+    }
+
     /// Get the RGB triplet for this color within a given export [`TerminalTheme`].
     ///
     /// This mirrors Python Rich's `Color.get_truecolor(theme, foreground=...)` behavior
@@ -1130,6 +1356,16 @@ fn color_distance(c1: ColorTriplet, c2: ColorTriplet) -> u32 {
 
 use std::collections::HashMap;
 
+/// Named colors ordered by color number, then name length and name.
+static NAMED_COLORS_BY_NUMBER: LazyLock<Vec<(&'static str, u8)>> = LazyLock::new(|| {
+    let mut names: Vec<_> = NAMED_COLORS
+        .iter()
+        .map(|(&name, &number)| (name, number))
+        .collect();
+    names.sort_by_key(|&(name, number)| (number, name.len(), name));
+    names
+});
+
 /// Map of named colors to their 8-bit color numbers.
 static NAMED_COLORS: LazyLock<HashMap<&'static str, u8>> = LazyLock::new(|| {
     let mut m = HashMap::new();
@@ -1353,10 +1589,108 @@ static NAMED_COLORS: LazyLock<HashMap<&'static str, u8>> = LazyLock::new(|| {
     m
 });
 
+/// Names accepted by [`Color::parse`] for the 256 standard colors.
+pub(crate) fn named_colors() -> impl Iterator<Item = &'static str> {
+    NAMED_COLORS.keys().copied()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_color_name_approx() {
+        let (name, distance) = Color::parse("red").unwrap().name_approx();
+        assert_eq!((name, distance), ("red", 0.0));
+        assert_eq!(
+            Color::parse("color(196)").unwrap().name_approx(),
+            ("red1", 0.0)
+        );
+        assert_eq!(
+            Color::parse("#5f0000").unwrap().name_approx(),
+            ("dark_red", 0.0)
+        );
+
+        let (name, distance) = Color::parse("#fe0102").unwrap().name_approx();
+        assert_eq!(name, "red1");
+        assert!(distance > 0.0 && distance < 5.0);
+
+        assert_eq!(Color::default().name_approx(), ("default", 0.0));
+    }
+
+    #[test]
+    fn test_terminal_theme_from_xresources() {
+        let theme = TerminalTheme::from_xresources(
+            "! comment\n\
+             *.background: #1d1f21\n\
+             URxvt*foreground: rgb:c5/c8/c6\n\
+             *color1:  #c66\n\
+             *.color15: #ffffff\n\
+             *.font: monospace:size=10\n",
+        )
+        .unwrap();
+        assert_eq!(theme.background_color, ColorTriplet::new(0x1d, 0x1f, 0x21));
+        assert_eq!(theme.foreground_color, ColorTriplet::new(0xc5, 0xc8, 0xc6));
+        assert_eq!(theme.ansi_colors[1], ColorTriplet::new(0xcc, 0x66, 0x66));
+        assert_eq!(theme.ansi_colors[15], ColorTriplet::new(255, 255, 255));
+        assert_eq!(theme.ansi_colors[2], DEFAULT_TERMINAL_THEME.ansi_colors[2]);
+
+        assert!(matches!(
+            TerminalTheme::from_xresources("*.color0: nope"),
+            Err(TerminalThemeError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            TerminalTheme::from_xresources("*.font: mono"),
+            Err(TerminalThemeError::NoColors)
+        ));
+    }
+
+    #[test]
+    fn test_terminal_theme_from_iterm() {
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+    <key>Ansi 4 Color</key>
+    <dict>
+        <key>Alpha Component</key>
+        <real>1</real>
+        <key>Blue Component</key>
+        <real>1</real>
+        <key>Green Component</key>
+        <real>0.5</real>
+        <key>Red Component</key>
+        <real>0.0</real>
+    </dict>
+    <key>Background Color</key>
+    <dict>
+        <key>Blue Component</key>
+        <real>0.1</real>
+        <key>Green Component</key>
+        <real>0.1</real>
+        <key>Red Component</key>
+        <real>0.1</real>
+    </dict>
+    <key>Cursor Color</key>
+    <dict>
+        <key>Red Component</key>
+        <real>1</real>
+    </dict>
+</dict>
+</plist>"#;
+        let theme = TerminalTheme::from_iterm_str(plist).unwrap();
+        assert_eq!(theme.ansi_colors[4], ColorTriplet::new(0, 128, 255));
+        assert_eq!(theme.background_color, ColorTriplet::new(26, 26, 26));
+        assert_eq!(
+            theme.foreground_color,
+            DEFAULT_TERMINAL_THEME.foreground_color
+        );
+
+        assert!(matches!(
+            TerminalTheme::from_iterm("/nonexistent/theme.itermcolors"),
+            Err(TerminalThemeError::Io { .. })
+        ));
+    }
+
     #[test]
     fn test_color_triplet_hex() {
         let c = ColorTriplet::new(255, 0, 128);
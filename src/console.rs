@@ -78,13 +78,15 @@
 //!
 //! You can override these with the builder pattern or by setting explicit values.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 use std::io::{self, Write};
 use std::sync::{
-    Arc, Mutex, Weak,
+    Arc, Mutex, MutexGuard, Weak,
     atomic::{AtomicBool, Ordering},
 };
+use std::time::{Duration, Instant};
 use time::OffsetDateTime;
 
 use crate::color::{ColorSystem, DEFAULT_TERMINAL_THEME, SVG_EXPORT_THEME, TerminalTheme};
@@ -120,6 +122,46 @@ impl Default for ConsoleDimensions {
     }
 }
 
+/// When the console flushes its output stream (see [`ConsoleBuilder::flush_policy`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlushPolicy {
+    /// Flush after every write.
+    #[default]
+    Immediate,
+    /// Flush after writes that end a line or carry control codes.
+    ///
+    /// Partial lines (e.g. a print with `end = ""`) stay buffered until the
+    /// line is finished.
+    LineBuffered,
+    /// Only flush on [`Console::flush`].
+    Manual,
+}
+
+/// Process-wide lock held by consoles built with [`ConsoleBuilder::serialize_output`].
+static OUTPUT_LOCK: Mutex<()> = Mutex::new(());
+
+/// A console's locked output stream, plus the process-wide output lock when
+/// the console serializes its output.
+struct OutputGuard<'a> {
+    // Declared first so the stream is released before the global lock.
+    file: MutexGuard<'a, Box<dyn Write + Send>>,
+    _global: Option<MutexGuard<'static, ()>>,
+}
+
+impl std::ops::Deref for OutputGuard<'_> {
+    type Target = Box<dyn Write + Send>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.file
+    }
+}
+
+impl std::ops::DerefMut for OutputGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.file
+    }
+}
+
 /// Options for rendering.
 #[derive(Debug, Clone)]
 pub struct ConsoleOptions {
@@ -222,6 +264,8 @@ pub struct PrintOptions {
     pub no_newline: bool,
     /// Parse markup.
     pub markup: Option<bool>,
+    /// Replace `:emoji:` codes (None = inherit Console setting).
+    pub emoji: Option<bool>,
     /// Enable/disable highlighting (None = inherit Console setting).
     pub highlight: Option<bool>,
     /// Override the highlighter used when highlighting is enabled.
@@ -252,6 +296,16 @@ impl PrintOptions {
         self
     }
 
+    /// Enable/disable `:emoji:` code replacement for this print call.
+    ///
+    /// Useful for text that must keep literal colons (cron specs, `host:port`)
+    /// while the console default stays on.
+    #[must_use]
+    pub fn with_emoji(mut self, emoji: bool) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
     /// Set style.
     #[must_use]
     pub fn with_style(mut self, style: Style) -> Self {
@@ -348,6 +402,7 @@ impl std::fmt::Debug for PrintOptions {
             .field("no_wrap", &self.no_wrap)
             .field("no_newline", &self.no_newline)
             .field("markup", &self.markup)
+            .field("emoji", &self.emoji)
             .field("highlight", &self.highlight)
             .field(
                 "highlighter",
@@ -378,8 +433,9 @@ pub trait RenderHook: Send + Sync {
 /// recovery (see the [`sync`](crate::sync) module).
 ///
 /// When multiple threads print concurrently, their output may interleave at
-/// the line level. For strictly ordered output, synchronize at the application
-/// level or use a single printing thread.
+/// the line level. For strictly ordered output, group related prints with
+/// [`Console::batch`], enable [`ConsoleBuilder::serialize_output`] when several
+/// consoles share a stream, or use a single printing thread.
 ///
 /// # Example
 ///
@@ -426,10 +482,14 @@ pub struct Console {
     height: Option<usize>,
     /// Use ASCII-safe box characters.
     safe_box: bool,
+    /// Default justification when a print doesn't set one.
+    justify: Option<JustifyMethod>,
+    /// Default overflow handling when a print doesn't set one.
+    overflow: Option<OverflowMethod>,
     /// Output stream (defaults to stdout).
     file: Mutex<Box<dyn Write + Send>>,
     /// Recording buffer.
-    buffer: Mutex<Vec<Segment<'static>>>,
+    buffer: Mutex<Vec<RecordedSegment>>,
     /// Cached terminal detection.
     is_terminal: bool,
     /// Detected/configured color system.
@@ -438,6 +498,202 @@ pub struct Console {
     render_hooks: Mutex<Vec<Arc<dyn RenderHook>>>,
     /// Active Live stack for nested Live handling.
     live_stack: Mutex<Vec<Weak<LiveInner>>>,
+    /// Emit OSC 8 hyperlinks (links are stripped from styles otherwise).
+    hyperlinks: bool,
+    /// Emit blink attributes (dropped from styles otherwise).
+    blink: bool,
+    /// Emit the conceal attribute (concealed text is blanked otherwise).
+    conceal: bool,
+    /// Show blinking text as bold instead (accessibility).
+    no_blink: bool,
+    /// Screen-reader friendly output (see [`ConsoleBuilder::accessible`]).
+    accessible: bool,
+    /// Colors used by [`Console::export_html`] and [`Console::export_svg`].
+    export_theme: Option<TerminalTheme>,
+    /// Titles set through this console (terminals can't be queried for the current one).
+    title_stack: Mutex<TitleStack>,
+    /// Print rate limiter (`None` = unlimited).
+    throttle: Option<Mutex<PrintThrottle>>,
+    /// Extra outputs receiving a copy of everything written (see [`ConsoleBuilder::tee`]).
+    tees: Mutex<Vec<TeeSink>>,
+    /// When writes are flushed to the output and tees.
+    flush_policy: FlushPolicy,
+    /// Hold the process-wide output lock for every write (see [`ConsoleBuilder::serialize_output`]).
+    serialize_output: bool,
+}
+
+/// Output collected by [`Console::batch`], written in one piece when the batch ends.
+///
+/// The methods mirror the console's own print methods but only render; nothing
+/// reaches the output until the batch closure returns.
+pub struct PrintBatch<'c> {
+    console: &'c Console,
+    segments: Vec<Segment<'static>>,
+}
+
+impl PrintBatch<'_> {
+    /// Add a string, parsing markup when the console does (see [`Console::print`]).
+    pub fn print(&mut self, content: &str) {
+        let options = PrintOptions::new().with_markup(self.console.markup);
+        self.print_with_options(content, &options);
+    }
+
+    /// Add a string without markup parsing (see [`Console::print_plain`]).
+    pub fn print_plain(&mut self, content: &str) {
+        self.print_with_options(content, &PrintOptions::new().with_markup(false));
+    }
+
+    /// Add text exactly as given, plus a newline (see [`Console::out`]).
+    pub fn out(&mut self, text: &str) {
+        self.segments.push(Segment::new(text.to_string(), None));
+        self.segments.push(Segment::line());
+    }
+
+    /// Add a string with custom options (see [`Console::print_with_options`]).
+    pub fn print_with_options(&mut self, content: &str, options: &PrintOptions) {
+        let segments = self.console.render_str_segments(content, options);
+        self.segments.extend(segments);
+    }
+
+    /// Add a prepared Text object (see [`Console::print_text`]).
+    pub fn print_text(&mut self, text: &Text) {
+        let text = text.resolve_styles(self.console);
+        self.segments
+            .extend(text.render(&text.end).into_iter().map(Segment::into_owned));
+    }
+
+    /// Add a renderable (see [`Console::print_renderable`]).
+    pub fn print_renderable(&mut self, renderable: &impl Renderable) {
+        let options = self.console.options();
+        self.segments.extend(
+            renderable
+                .render(self.console, &options)
+                .into_iter()
+                .map(Segment::into_owned),
+        );
+    }
+
+    /// Add prepared segments (see [`Console::print_segments`]).
+    pub fn print_segments(&mut self, segments: &[Segment<'_>]) {
+        self.segments
+            .extend(segments.iter().cloned().map(Segment::into_owned));
+    }
+}
+
+/// A recorded segment and the [origin](Renderable::origin) of the renderable that printed it.
+///
+/// The text is shared, so exports that keep the recording don't copy it.
+#[derive(Debug, Clone)]
+struct RecordedSegment {
+    text: Arc<str>,
+    style: Option<Style>,
+    control: Option<Vec<ControlCode>>,
+    origin: Option<&'static str>,
+}
+
+impl RecordedSegment {
+    fn new(segment: &Segment<'_>, origin: Option<&'static str>) -> Self {
+        Self {
+            text: Arc::from(segment.text.as_ref()),
+            style: segment.style.clone(),
+            control: segment.control.clone(),
+            origin,
+        }
+    }
+
+    /// The recorded segment, borrowing its text.
+    fn segment(&self) -> Segment<'_> {
+        Segment {
+            text: Cow::Borrowed(&self.text),
+            style: self.style.clone(),
+            control: self.control.clone(),
+        }
+    }
+
+    fn into_segment(self) -> Segment<'static> {
+        Segment {
+            text: Cow::Owned(self.text.as_ref().to_owned()),
+            style: self.style,
+            control: self.control,
+        }
+    }
+
+    fn is_control(&self) -> bool {
+        self.control.is_some()
+    }
+}
+
+/// An extra output with its own color system, added by [`ConsoleBuilder::tee`].
+struct TeeSink {
+    writer: Box<dyn Write + Send>,
+    /// Color system for this sink (`None` = plain text without control codes).
+    color_system: Option<ColorSystem>,
+}
+
+/// The dim "… suppressed N lines" notice for output dropped by the print rate limit.
+fn suppressed_notice(lines: usize) -> Vec<Segment<'static>> {
+    let noun = if lines == 1 { "line" } else { "lines" };
+    vec![
+        Segment::new(
+            format!("\u{2026} suppressed {lines} {noun}"),
+            Some(Style::new().dim()),
+        ),
+        Segment::line(),
+    ]
+}
+
+/// Internally tracked window titles for [`Console::push_title`] / [`Console::pop_title`].
+#[derive(Debug, Default)]
+struct TitleStack {
+    /// The last title set through the console, if any.
+    current: Option<String>,
+    /// Titles to restore, most recent last.
+    saved: Vec<Option<String>>,
+}
+
+/// Per-second print budget for [`ConsoleBuilder::max_prints_per_second`].
+#[derive(Debug)]
+struct PrintThrottle {
+    /// Prints allowed per one-second window.
+    limit: u32,
+    /// Start of the current window.
+    window_start: Option<Instant>,
+    /// Prints written in the current window.
+    printed: u32,
+    /// Lines dropped since the last notice.
+    suppressed: usize,
+}
+
+impl PrintThrottle {
+    fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            window_start: None,
+            printed: 0,
+            suppressed: 0,
+        }
+    }
+
+    /// Decide whether a print of `lines` lines may be written at `now`.
+    ///
+    /// Returns `None` to drop the print, or `Some(n)` to write it after a notice
+    /// for `n` suppressed lines (when `n > 0`).
+    fn admit(&mut self, now: Instant, lines: usize) -> Option<usize> {
+        let expired = self
+            .window_start
+            .is_none_or(|start| now.duration_since(start) >= Duration::from_secs(1));
+        if expired {
+            self.window_start = Some(now);
+            self.printed = 0;
+        }
+        if self.printed < self.limit {
+            self.printed += 1;
+            Some(std::mem::take(&mut self.suppressed))
+        } else {
+            self.suppressed += lines.max(1);
+            None
+        }
+    }
 }
 
 impl std::fmt::Debug for Console {
@@ -453,7 +709,17 @@ impl std::fmt::Debug for Console {
             .field("width", &self.width)
             .field("height", &self.height)
             .field("safe_box", &self.safe_box)
+            .field("justify", &self.justify)
+            .field("overflow", &self.overflow)
+            .field("blink", &self.blink)
+            .field("conceal", &self.conceal)
+            .field("no_blink", &self.no_blink)
+            .field("accessible", &self.accessible)
+            .field("export_theme", &self.export_theme)
             .field("file", &"<dyn Write>")
+            .field("tees", &lock_recover(&self.tees).len())
+            .field("flush_policy", &self.flush_policy)
+            .field("serialize_output", &self.serialize_output)
             .field("buffer_len", &lock_recover(&self.buffer).len())
             .field("is_terminal", &self.is_terminal)
             .field("detected_color_system", &self.detected_color_system)
@@ -491,15 +757,36 @@ impl Console {
             width: None,
             height: None,
             safe_box: false,
+            justify: None,
+            overflow: None,
             file: Mutex::new(Box::new(io::stdout())),
             buffer: Mutex::new(Vec::new()),
             is_terminal,
             detected_color_system,
             render_hooks: Mutex::new(Vec::new()),
             live_stack: Mutex::new(Vec::new()),
+            hyperlinks: true,
+            blink: true,
+            conceal: true,
+            no_blink: false,
+            accessible: false,
+            export_theme: None,
+            title_stack: Mutex::new(TitleStack::default()),
+            throttle: None,
+            tees: Mutex::new(Vec::new()),
+            flush_policy: FlushPolicy::default(),
+            serialize_output: false,
         }
     }
 
+    /// Create a console configured from environment variables.
+    ///
+    /// See [`ConsoleBuilder::from_env`] for the variables read.
+    #[must_use]
+    pub fn from_env() -> Self {
+        ConsoleBuilder::from_env().build()
+    }
+
     /// Create a console builder for custom configuration.
     #[must_use]
     pub fn builder() -> ConsoleBuilder {
@@ -560,12 +847,40 @@ impl Console {
         self.emoji
     }
 
+    /// Check if OSC 8 hyperlinks are emitted.
+    #[must_use]
+    pub const fn supports_hyperlinks(&self) -> bool {
+        self.hyperlinks
+    }
+
+    /// Check if blinking text is emitted as blinking.
+    ///
+    /// False when the terminal can't blink or [`ConsoleBuilder::no_blink`] is set.
+    #[must_use]
+    pub const fn supports_blink(&self) -> bool {
+        self.blink && !self.no_blink
+    }
+
+    /// Check if the conceal attribute is emitted (concealed text is blanked otherwise).
+    #[must_use]
+    pub const fn supports_conceal(&self) -> bool {
+        self.conceal
+    }
+
     /// Check if ASCII-safe box drawing is enabled.
     #[must_use]
     pub const fn safe_box(&self) -> bool {
         self.safe_box
     }
 
+    /// Check if screen-reader friendly output is enabled.
+    ///
+    /// See [`ConsoleBuilder::accessible`].
+    #[must_use]
+    pub const fn is_accessible(&self) -> bool {
+        self.accessible
+    }
+
     /// Get a style by theme name or parse a style definition.
     ///
     /// Mirrors Python Rich `Console.get_style()`:
@@ -579,14 +894,33 @@ impl Console {
     }
 
     /// Like [`Self::get_style`], but returns an error if the style can't be parsed.
+    ///
+    /// Unknown words carry a did-you-mean [suggestion](StyleParseError::suggestion)
+    /// drawn from attribute and color names. A word close to one of the active
+    /// theme's style names is reported as [`StyleParseError::UnknownStyle`].
     pub fn try_get_style(&self, name: &str) -> Result<Style, StyleParseError> {
-        {
-            let stack = lock_recover(&self.theme_stack);
-            if let Some(style) = stack.get(name) {
-                return Ok(style.clone());
+        let stack = lock_recover(&self.theme_stack);
+        if let Some(style) = stack.get(name) {
+            return Ok(style.clone());
+        }
+        match Style::parse(name) {
+            Err(StyleParseError::UnknownToken(token)) => {
+                let theme_match =
+                    if token.contains('.') || crate::style::suggest_token(&token).is_none() {
+                        crate::style::closest_match(&token, stack.names())
+                    } else {
+                        None
+                    };
+                Err(match theme_match {
+                    Some(suggestion) => StyleParseError::UnknownStyle {
+                        suggestion: suggestion.to_string(),
+                        token,
+                    },
+                    None => StyleParseError::UnknownToken(token),
+                })
             }
+            result => result,
         }
-        Style::parse(name)
     }
 
     /// Push a theme on to the theme stack.
@@ -599,6 +933,76 @@ impl Console {
         lock_recover(&self.theme_stack).pop_theme()
     }
 
+    /// Load a theme file and reload it whenever the file changes.
+    ///
+    /// The theme is pushed on the theme stack, inheriting from the current theme.
+    /// This polls rather than subscribing to file system events: a background
+    /// thread reads the file every 250 ms, and when its contents change the theme
+    /// is replaced in place and any active [`Live`](crate::live::Live) display is
+    /// refreshed. Edits that fail to parse are ignored, keeping the last good theme.
+    ///
+    /// Watching stops when the returned [`ThemeWatcher`] is dropped, or once the
+    /// watched theme has been popped off the stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed initially.
+    #[cfg(feature = "watch")]
+    pub fn watch_theme(
+        self: &Arc<Self>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<ThemeWatcher, crate::theme::ThemeError> {
+        let path = path.as_ref().to_path_buf();
+        let mut contents =
+            std::fs::read_to_string(&path).map_err(|err| crate::theme::ThemeError::Io {
+                path: path.clone(),
+                err,
+            })?;
+        let theme = Theme::from_ini_str(&contents, true)?;
+        let id = {
+            let mut stack = lock_recover(&self.theme_stack);
+            stack.push_theme(theme, true);
+            stack.top_id()
+        };
+
+        let console = Arc::downgrade(self);
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let polls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let thread_polls = Arc::clone(&polls);
+        let handle = std::thread::spawn(move || {
+            loop {
+                std::thread::park_timeout(THEME_POLL_INTERVAL);
+                if thread_stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                let Some(console) = console.upgrade() else {
+                    break;
+                };
+                if let Ok(current) = std::fs::read_to_string(&path)
+                    && current != contents
+                {
+                    if let Ok(theme) = Theme::from_ini_str(&current, true) {
+                        if !lock_recover(&console.theme_stack).replace_theme(id, theme, true) {
+                            break;
+                        }
+                        if let Some(live) = console.live_stack_snapshot().first() {
+                            let _ = live.refresh_display();
+                        }
+                    }
+                    contents = current;
+                }
+                thread_polls.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        Ok(ThemeWatcher {
+            stop,
+            polls,
+            handle: Some(handle),
+        })
+    }
+
     /// Use a theme for the duration of the returned guard.
     #[must_use]
     pub fn use_theme(&self, theme: Theme, inherit: bool) -> ThemeGuard<'_> {
@@ -629,8 +1033,8 @@ impl Console {
             is_terminal: self.is_terminal(),
             encoding: String::from("utf-8"),
             max_height: self.height(),
-            justify: None,
-            overflow: None,
+            justify: self.justify,
+            overflow: self.overflow,
             no_wrap: None,
             highlight: Some(self.highlight),
             markup: Some(self.markup),
@@ -662,10 +1066,10 @@ impl Console {
         terminal::is_dumb_terminal()
     }
 
-    /// Check if the console is interactive (TTY and not dumb).
+    /// Check if the console is interactive (TTY, not dumb, and not accessible).
     #[must_use]
     pub fn is_interactive(&self) -> bool {
-        self.is_terminal() && !self.is_dumb_terminal()
+        !self.accessible && self.is_terminal() && !self.is_dumb_terminal()
     }
 
     pub(crate) fn push_render_hook(&self, hook: Arc<dyn RenderHook>) {
@@ -676,16 +1080,25 @@ impl Console {
         lock_recover(&self.render_hooks).pop()
     }
 
-    pub(crate) fn set_live(&self, live: &Arc<LiveInner>) -> bool {
+    /// Register an active Live; `Some(true)` if it owns the live region.
+    ///
+    /// Returns `None`, without registering, when another Live is active and
+    /// `nested` is off.
+    pub(crate) fn set_live(&self, live: &Arc<LiveInner>, nested: bool) -> Option<bool> {
         let mut stack = lock_recover(&self.live_stack);
+        stack.retain(|entry| entry.strong_count() > 0);
+        if !nested && !stack.is_empty() {
+            return None;
+        }
         stack.push(Arc::downgrade(live));
-        stack.len() == 1
+        Some(stack.len() == 1)
     }
 
-    pub(crate) fn clear_live(&self) {
+    pub(crate) fn clear_live(&self, live: &Arc<LiveInner>) {
         let mut stack = lock_recover(&self.live_stack);
-        if !stack.is_empty() {
-            stack.pop();
+        let target = Arc::downgrade(live);
+        if let Some(index) = stack.iter().rposition(|entry| entry.ptr_eq(&target)) {
+            stack.remove(index);
         }
     }
 
@@ -706,8 +1119,8 @@ impl Console {
             return Ok(());
         }
         let segment = Segment::control(control_codes);
-        let mut file = lock_recover(&self.file);
-        self.write_segments_raw(&mut *file, &[segment])
+        let mut file = self.lock_output();
+        self.write_output(&mut file, &[segment], None)
     }
 
     pub(crate) fn swap_file(&self, writer: Box<dyn Write + Send>) -> Box<dyn Write + Send> {
@@ -734,6 +1147,31 @@ impl Console {
         self.write_control_codes(vec![control])
     }
 
+    /// Switch to the alternate screen for the duration of the returned guard.
+    ///
+    /// The cursor is hidden when `hide_cursor` is set. Dropping the [`Screen`]
+    /// (including while unwinding from a panic) leaves the alternate screen and
+    /// shows the cursor again. Non-interactive consoles are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the control codes cannot be written.
+    pub fn screen(&self, hide_cursor: bool) -> io::Result<Screen<'_>> {
+        let active = self.is_interactive();
+        if active {
+            self.set_alt_screen(true)?;
+            if hide_cursor {
+                self.show_cursor(false)?;
+            }
+        }
+        Ok(Screen {
+            console: self,
+            renderable: None,
+            hide_cursor: active && hide_cursor,
+            active,
+        })
+    }
+
     /// Enable recording mode.
     ///
     /// All subsequent console output will be captured to an internal buffer
@@ -750,6 +1188,9 @@ impl Console {
     pub fn end_capture(&self) -> Vec<Segment<'static>> {
         self.record.store(false, Ordering::Relaxed);
         std::mem::take(&mut *lock_recover(&self.buffer))
+            .into_iter()
+            .map(RecordedSegment::into_segment)
+            .collect()
     }
 
     /// Print styled text to the console.
@@ -768,25 +1209,84 @@ impl Console {
 
     /// Print a prepared Text object.
     pub fn print_text(&self, text: &Text) {
-        let mut file = lock_recover(&self.file);
-        let _ = self.print_text_to(&mut *file, text);
+        let mut file = self.lock_output();
+        let segments = self.text_segments(text);
+        let _ = self.write_output(&mut file, &segments, None);
     }
 
     /// Print a prepared Text object to a specific writer.
     pub fn print_text_to<W: Write>(&self, writer: &mut W, text: &Text) -> io::Result<()> {
+        let segments = self.text_segments(text);
+        self.write_segments_raw(writer, &segments, None)
+    }
+
+    /// Render a prepared Text object and run the render hooks over it.
+    fn text_segments(&self, text: &Text) -> Vec<Segment<'static>> {
+        let text = text.resolve_styles(self);
         let segments: Vec<Segment<'static>> = text
             .render(&text.end)
             .into_iter()
             .map(Segment::into_owned)
             .collect();
-        let segments = self.apply_render_hooks(segments);
-        self.write_segments_raw(writer, &segments)
+        self.apply_render_hooks(segments)
+    }
+
+    /// Print `text` as a hyperlink to `url`.
+    ///
+    /// When the console doesn't emit hyperlinks (see
+    /// [`supports_hyperlinks`](Self::supports_hyperlinks)) or colors are disabled, the
+    /// URL is shown in parentheses after the text so it isn't lost.
+    pub fn print_link(&self, text: &str, url: &str) {
+        let mut line = Text::new("");
+        if self.hyperlinks && self.is_color_enabled() {
+            line.append_link(text, url);
+        } else if text == url {
+            line.append(text);
+        } else {
+            line.append(&format!("{text} ({url})"));
+        }
+        self.print_text(&line);
+    }
+
+    /// Print several items as a single write.
+    ///
+    /// Everything printed through the [`PrintBatch`] is rendered into one
+    /// buffer, then written (and flushed, per the [`FlushPolicy`]) once under
+    /// the output lock, so other threads' output can't interleave with it.
+    /// Returns the closure's result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rich_rust::Console;
+    /// use rich_rust::renderables::Rule;
+    ///
+    /// let console = Console::new();
+    /// console.batch(|batch| {
+    ///     batch.print("[bold]Report[/]");
+    ///     batch.print_renderable(&Rule::new());
+    ///     batch.print("all checks passed");
+    /// });
+    /// ```
+    pub fn batch<R>(&self, f: impl FnOnce(&mut PrintBatch<'_>) -> R) -> R {
+        let mut batch = PrintBatch {
+            console: self,
+            segments: Vec::new(),
+        };
+        let result = f(&mut batch);
+        if !batch.segments.is_empty() {
+            let segments = self.apply_render_hooks(batch.segments);
+            let mut file = self.lock_output();
+            let _ = self.write_output(&mut file, &segments, None);
+        }
+        result
     }
 
     /// Print prepared segments.
     pub fn print_segments(&self, segments: &[Segment<'_>]) {
-        let mut file = lock_recover(&self.file);
-        let _ = self.print_segments_to(&mut *file, segments);
+        let mut file = self.lock_output();
+        let segments = self.hooked_segments(segments);
+        let _ = self.write_output(&mut file, &segments, None);
     }
 
     /// Print prepared segments to a specific writer.
@@ -795,17 +1295,24 @@ impl Console {
         writer: &mut W,
         segments: &[Segment<'_>],
     ) -> io::Result<()> {
+        let segments = self.hooked_segments(segments);
+        self.write_segments_raw(writer, &segments, None)
+    }
+
+    /// Copy prepared segments and run the render hooks over them.
+    fn hooked_segments(&self, segments: &[Segment<'_>]) -> Vec<Segment<'static>> {
         let owned: Vec<Segment<'static>> =
             segments.iter().cloned().map(Segment::into_owned).collect();
-        let processed = self.apply_render_hooks(owned);
-        self.write_segments_raw(writer, &processed)
+        self.apply_render_hooks(owned)
     }
 
     /// Print any object implementing the Renderable trait.
     pub fn print_renderable(&self, renderable: &impl Renderable) {
         let options = self.options();
         let segments = renderable.render(self, &options);
-        self.print_segments(&segments);
+        let mut file = self.lock_output();
+        let segments = self.hooked_segments(&segments);
+        let _ = self.write_output(&mut file, &segments, renderable.origin());
     }
 
     fn render_rich_cast_segments(
@@ -841,8 +1348,10 @@ impl Console {
 
     /// Print a castable value with custom options (string options apply when the cast yields a string).
     pub fn print_cast_with_options(&self, value: &dyn RichCast, options: &PrintOptions) {
-        let mut file = lock_recover(&self.file);
-        let _ = self.print_cast_to(&mut *file, value, options);
+        let mut file = self.lock_output();
+        let segments = self.render_rich_cast_segments(value, options);
+        let segments = self.apply_render_hooks(segments);
+        let _ = self.write_output(&mut file, &segments, None);
     }
 
     /// Print a castable value to a specific writer.
@@ -854,7 +1363,7 @@ impl Console {
     ) -> io::Result<()> {
         let segments = self.render_rich_cast_segments(value, options);
         let segments = self.apply_render_hooks(segments);
-        self.write_segments_raw(writer, &segments)
+        self.write_segments_raw(writer, &segments, None)
     }
 
     /// Print an exception / traceback renderable.
@@ -866,10 +1375,12 @@ impl Console {
 
     /// Print with custom options.
     pub fn print_with_options(&self, content: &str, options: &PrintOptions) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_output();
+        let segments = self.render_str_segments(content, options);
+        let segments = self.apply_render_hooks(segments);
         // Keep `Console::print_*` infallible (matches Rich's ergonomics). If callers need
         // I/O error handling they can use `Console::print_to(...)` directly.
-        let _ = self.print_to(&mut *file, content, options);
+        let _ = self.write_output(&mut file, &segments, None);
     }
 
     /// Export rendered text (no ANSI) using default print options.
@@ -910,21 +1421,97 @@ impl Console {
         Self::segments_to_plain(&segments)
     }
 
+    /// Export recorded output as ANSI text rendered for an explicit color system.
+    ///
+    /// The detected color system is ignored, so a single capture can produce truecolor,
+    /// 256-color, 16-color, and (with `None`) uncolored artifacts.
+    ///
+    /// ```rust,ignore
+    /// console.begin_capture();
+    /// console.print("[bold #ff8800]Report[/]");
+    /// let full = console.export_text_with_ansi(Some(ColorSystem::TrueColor), false);
+    /// let basic = console.export_text_with_ansi(Some(ColorSystem::Standard), false);
+    /// let plain = console.export_text_with_ansi(None, true);
+    /// ```
+    #[must_use]
+    pub fn export_text_with_ansi(&self, color_system: Option<ColorSystem>, clear: bool) -> String {
+        self.export_text_with_ansi_options(&ExportTextOptions {
+            color_system,
+            clear,
+            ..ExportTextOptions::default()
+        })
+    }
+
+    /// Export recorded output as text with explicit options.
+    ///
+    /// With [`ExportTextOptions::apply_control_codes`], a recorded progress bar
+    /// or Live session exports as its final screen instead of every frame.
+    #[must_use]
+    pub fn export_text_with_ansi_options(&self, options: &ExportTextOptions) -> String {
+        assert!(
+            self.record.load(Ordering::Relaxed),
+            "To export console contents call Console::begin_capture() first"
+        );
+        let recorded = self.recorded(options.clear);
+        let segments = Self::recorded_export_segments(&recorded, options.apply_control_codes);
+        match options.color_system {
+            Some(color_system) => self.segments_to_ansi(&segments, Some(color_system)),
+            // Uncolored text drops control codes, like `export_text`.
+            None => Self::segments_to_plain(&segments),
+        }
+    }
+
+    /// Export a renderable as ANSI text rendered for an explicit color system.
+    #[must_use]
+    pub fn export_renderable_with_ansi(
+        &self,
+        renderable: &impl Renderable,
+        color_system: Option<ColorSystem>,
+    ) -> String {
+        let options = self.options();
+        let segments = renderable.render(self, &options);
+        self.segments_to_ansi(&segments, color_system)
+    }
+
+    fn segments_to_ansi(
+        &self,
+        segments: &[Segment<'_>],
+        color_system: Option<ColorSystem>,
+    ) -> String {
+        let mut output = Vec::new();
+        // Writing to a Vec<u8> is infallible.
+        let _ = self.write_segments_styled(&mut output, segments, color_system);
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// The theme set with [`ConsoleBuilder::export_theme`], if any.
+    ///
+    /// [`Console::export_html`] and [`Console::export_svg`] use it in place of
+    /// their defaults ([`DEFAULT_TERMINAL_THEME`] and [`SVG_EXPORT_THEME`]).
+    #[must_use]
+    pub const fn export_theme(&self) -> Option<TerminalTheme> {
+        self.export_theme
+    }
+
     /// Export recorded output to HTML.
     #[must_use]
     pub fn export_html(&self, clear: bool) -> String {
+        let defaults = ExportHtmlOptions::default();
         self.export_html_with_options(&ExportHtmlOptions {
             clear,
-            ..ExportHtmlOptions::default()
+            theme: self.export_theme.unwrap_or(defaults.theme),
+            ..defaults
         })
     }
 
     /// Export recorded output to SVG.
     #[must_use]
     pub fn export_svg(&self, clear: bool) -> String {
+        let defaults = ExportSvgOptions::default();
         self.export_svg_with_options(&ExportSvgOptions {
             clear,
-            ..ExportSvgOptions::default()
+            theme: self.export_theme.unwrap_or(defaults.theme),
+            ..defaults
         })
     }
 
@@ -937,7 +1524,8 @@ impl Console {
             self.record.load(Ordering::Relaxed),
             "To export console contents call Console::begin_capture() first"
         );
-        let segments = self.recorded_segments(options.clear);
+        let recorded = self.recorded(options.clear);
+        let segments = Self::recorded_export_segments(&recorded, options.apply_control_codes);
         export_segments_to_html_rich(&segments, options)
     }
 
@@ -950,10 +1538,29 @@ impl Console {
             self.record.load(Ordering::Relaxed),
             "To export console contents call Console::begin_capture() first"
         );
-        let segments = self.recorded_segments(options.clear);
+        let recorded = self.recorded(options.clear);
+        let segments = Self::recorded_export_segments(&recorded, options.apply_control_codes);
         export_segments_to_svg_rich(&segments, self.width(), options)
     }
 
+    /// Export recorded output as Markdown.
+    ///
+    /// Bold, italic, strikethrough, inline code (the theme's `markdown.code`
+    /// style) and hyperlinks are kept as Markdown formatting; colors are dropped.
+    /// Output of a [`Table`](crate::renderables::Table) printed with
+    /// [`print_renderable`](Self::print_renderable) is converted to a pipe table
+    /// (see [`Renderable::origin`]). Line breaks follow the
+    /// terminal output, which suits renderers that keep them (GitHub comments).
+    #[must_use]
+    pub fn export_markdown(&self, clear: bool) -> String {
+        assert!(
+            self.record.load(Ordering::Relaxed),
+            "To export console contents call Console::begin_capture() first"
+        );
+        let recorded = self.recorded(clear);
+        export_segments_to_markdown(&recorded, &self.get_style("markdown.code"))
+    }
+
     /// Print to a specific writer.
     pub fn print_to<W: Write>(
         &self,
@@ -963,11 +1570,11 @@ impl Console {
     ) -> io::Result<()> {
         let segments = self.render_str_segments(content, options);
         let segments = self.apply_render_hooks(segments);
-        self.write_segments_raw(writer, &segments)
+        self.write_segments_raw(writer, &segments, None)
     }
 
     fn render_str_segments(&self, content: &str, options: &PrintOptions) -> Vec<Segment<'static>> {
-        let content = if self.emoji {
+        let content = if options.emoji.unwrap_or(self.emoji) {
             emoji::replace(content, None)
         } else {
             std::borrow::Cow::Borrowed(content)
@@ -989,10 +1596,12 @@ impl Console {
             highlighter.highlight(self, &mut text);
         }
 
-        if let Some(justify) = options.justify {
+        let justify = options.justify.or(self.justify);
+        let overflow = options.overflow.or(self.overflow);
+        if let Some(justify) = justify {
             text.justify = justify;
         }
-        if let Some(overflow) = options.overflow {
+        if let Some(overflow) = overflow {
             text.overflow = overflow;
         }
         if let Some(no_wrap) = options.no_wrap {
@@ -1007,8 +1616,8 @@ impl Console {
         }
 
         let width = options.width.or_else(|| {
-            if options.justify.is_some()
-                || options.overflow.is_some()
+            if justify.is_some()
+                || overflow.is_some()
                 || options.no_wrap.is_some()
                 || options.crop
                 || options.soft_wrap
@@ -1088,13 +1697,27 @@ impl Console {
         output
     }
 
-    fn recorded_segments(&self, clear: bool) -> Vec<Segment<'static>> {
+    fn recorded(&self, clear: bool) -> Vec<RecordedSegment> {
         let mut buffer = lock_recover(&self.buffer);
-        let segments = buffer.clone();
         if clear {
-            buffer.clear();
+            std::mem::take(&mut *buffer)
+        } else {
+            // Only bumps the text's reference counts.
+            buffer.clone()
+        }
+    }
+
+    /// Recorded segments, replayed onto a virtual screen when `apply_control_codes` is set.
+    fn recorded_export_segments(
+        recorded: &[RecordedSegment],
+        apply_control_codes: bool,
+    ) -> Vec<Segment<'_>> {
+        let segments = recorded.iter().map(RecordedSegment::segment);
+        if apply_control_codes {
+            crate::segment::apply_control_codes(segments)
+        } else {
+            segments.collect()
         }
-        segments
     }
 
     fn apply_render_hooks(&self, segments: Vec<Segment<'static>>) -> Vec<Segment<'static>> {
@@ -1110,43 +1733,267 @@ impl Console {
     }
 
     /// Write segments to a writer without invoking render hooks.
+    ///
+    /// For explicit writers (`print_to` and friends): the print rate limit and
+    /// tees only apply to the console's own output, see [`write_output`](Self::write_output).
+    /// `origin` is recorded with the segments; see [`Renderable::origin`].
     fn write_segments_raw<W: Write>(
         &self,
         writer: &mut W,
         segments: &[Segment<'_>],
+        origin: Option<&'static str>,
     ) -> io::Result<()> {
-        if self.record.load(Ordering::Relaxed) {
-            lock_recover(&self.buffer).extend(segments.iter().cloned().map(Segment::into_owned));
+        self.record_segments(segments, origin);
+        self.write_segments_styled(writer, segments, self.color_system())?;
+        if self.should_flush(segments) {
+            writer.flush()?;
         }
+        Ok(())
+    }
 
-        let color_system = self.color_system();
+    /// Write segments to the console's own output and copy them to every tee.
+    ///
+    /// The tees get the same output even when writing the file fails, and their
+    /// errors are ignored: a broken log file shouldn't fail the console's output,
+    /// so only the file's result is returned.
+    fn write_output(
+        &self,
+        file: &mut OutputGuard<'_>,
+        segments: &[Segment<'_>],
+        origin: Option<&'static str>,
+    ) -> io::Result<()> {
+        self.record_segments(segments, origin);
 
-        for segment in segments {
-            if segment.is_control() {
-                self.write_control_segment(writer, segment)?;
-                continue;
+        // Output carrying control codes (cursor movement, Live redraws) is never throttled.
+        let mut notice = Vec::new();
+        if let Some(throttle) = &self.throttle
+            && !segments.iter().any(Segment::is_control)
+        {
+            let lines = segments
+                .iter()
+                .map(|segment| segment.text.matches('\n').count())
+                .sum();
+            match lock_recover(throttle).admit(Instant::now(), lines) {
+                None => return Ok(()),
+                Some(0) => {}
+                Some(suppressed) => notice = suppressed_notice(suppressed),
             }
+        }
 
-            // Get ANSI codes for style
-            let ansi_codes;
-            let (prefix, suffix) = if let Some(ref style) = segment.style {
-                if let Some(cs) = color_system {
-                    ansi_codes = style.render_ansi(cs);
-                    (&ansi_codes.0, &ansi_codes.1)
-                } else {
-                    static EMPTY: (String, String) = (String::new(), String::new());
-                    (&EMPTY.0, &EMPTY.1)
-                }
+        let flush = self.should_flush(segments);
+        let result = self
+            .write_segments_styled(&mut **file, &notice, self.color_system())
+            .and_then(|()| self.write_segments_styled(&mut **file, segments, self.color_system()))
+            .and_then(|()| if flush { file.flush() } else { Ok(()) });
+        self.write_tees(&notice, false);
+        self.write_tees(segments, flush);
+        result
+    }
+
+    fn record_segments(&self, segments: &[Segment<'_>], origin: Option<&'static str>) {
+        if self.record.load(Ordering::Relaxed) {
+            lock_recover(&self.buffer).extend(
+                segments
+                    .iter()
+                    .map(|segment| RecordedSegment::new(segment, origin)),
+            );
+        }
+    }
+
+    /// Copy segments to every tee, ignoring write errors.
+    fn write_tees(&self, segments: &[Segment<'_>], flush: bool) {
+        if segments.is_empty() {
+            return;
+        }
+        for tee in lock_recover(&self.tees).iter_mut() {
+            if tee.color_system.is_some() {
+                let _ = self.write_segments_styled(&mut tee.writer, segments, tee.color_system);
             } else {
-                static EMPTY: (String, String) = (String::new(), String::new());
-                (&EMPTY.0, &EMPTY.1)
-            };
+                // Plain sinks are files and logs: cursor movement would only garble them.
+                let text: Vec<Segment<'_>> = segments
+                    .iter()
+                    .filter(|segment| !segment.is_control())
+                    .cloned()
+                    .collect();
+                let _ = self.write_segments_styled(&mut tee.writer, &text, None);
+            }
+            if flush {
+                let _ = tee.writer.flush();
+            }
+        }
+    }
 
-            // Write styled text
-            write!(writer, "{prefix}{}{suffix}", segment.text)?;
+    /// Whether a write of `segments` is flushed under the console's [`FlushPolicy`].
+    fn should_flush(&self, segments: &[Segment<'_>]) -> bool {
+        match self.flush_policy {
+            FlushPolicy::Immediate => true,
+            FlushPolicy::LineBuffered => segments
+                .iter()
+                .any(|segment| segment.is_control() || segment.text.contains('\n')),
+            FlushPolicy::Manual => false,
         }
+    }
 
-        writer.flush()
+    /// Lock the output stream for a write.
+    ///
+    /// With [`ConsoleBuilder::serialize_output`], the process-wide output lock
+    /// is taken first, so writes from every serializing console are ordered.
+    fn lock_output(&self) -> OutputGuard<'_> {
+        let global = self.serialize_output.then(|| lock_recover(&OUTPUT_LOCK));
+        OutputGuard {
+            file: lock_recover(&self.file),
+            _global: global,
+        }
+    }
+
+    /// Check whether writes hold the process-wide output lock.
+    #[must_use]
+    pub fn serialize_output(&self) -> bool {
+        self.serialize_output
+    }
+
+    /// Get the flush policy set with [`ConsoleBuilder::flush_policy`].
+    #[must_use]
+    pub fn flush_policy(&self) -> FlushPolicy {
+        self.flush_policy
+    }
+
+    /// Flush the output stream and every tee.
+    ///
+    /// Needed with [`FlushPolicy::Manual`] (and for partial lines with
+    /// [`FlushPolicy::LineBuffered`]) to make buffered output visible.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first I/O error from flushing; the remaining outputs are
+    /// still flushed.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut result = self.lock_output().flush();
+        for tee in lock_recover(&self.tees).iter_mut() {
+            let flushed = tee.writer.flush();
+            if result.is_ok() {
+                result = flushed;
+            }
+        }
+        result
+    }
+
+    /// Get the print rate limit set with [`ConsoleBuilder::max_prints_per_second`].
+    #[must_use]
+    pub fn max_prints_per_second(&self) -> Option<u32> {
+        self.throttle
+            .as_ref()
+            .map(|throttle| lock_recover(throttle).limit)
+    }
+
+    /// Write the "suppressed N lines" notice for output dropped by the rate limiter.
+    ///
+    /// The notice is otherwise written before the next print that gets through, so
+    /// call this before exiting to report output dropped at the very end.
+    pub fn flush_suppressed(&self) {
+        let Some(throttle) = &self.throttle else {
+            return;
+        };
+        let suppressed = std::mem::take(&mut lock_recover(throttle).suppressed);
+        if suppressed > 0 {
+            let notice = suppressed_notice(suppressed);
+            let mut file = self.lock_output();
+            let _ = self.write_segments_styled(&mut *file, &notice, self.color_system());
+            let _ = file.flush();
+            self.write_tees(&notice, true);
+        }
+    }
+
+    /// Write segments with ANSI styling for an explicit color system (`None` = no color).
+    fn write_segments_styled<W: Write>(
+        &self,
+        writer: &mut W,
+        segments: &[Segment<'_>],
+        color_system: Option<ColorSystem>,
+    ) -> io::Result<()> {
+        for segment in segments {
+            if segment.is_control() {
+                self.write_control_segment(writer, segment)?;
+                continue;
+            }
+
+            // Drop hyperlinks and attributes the console isn't configured to emit.
+            let adjusted;
+            let style = match segment.style {
+                Some(ref style) if self.needs_style_gating(style) => {
+                    adjusted = self.gate_style(style);
+                    Some(&adjusted)
+                }
+                ref style => style.as_ref(),
+            };
+
+            // Blank concealed text the terminal would otherwise show.
+            let blanked;
+            let text = if color_system.is_some()
+                && !self.conceal
+                && segment
+                    .style
+                    .as_ref()
+                    .is_some_and(|style| style.attributes.contains(Attributes::CONCEAL))
+            {
+                blanked = " ".repeat(segment.cell_length());
+                blanked.as_str()
+            } else {
+                segment.text.as_ref()
+            };
+
+            // Get ANSI codes for style
+            let ansi_codes;
+            let (prefix, suffix) = if let Some(style) = style {
+                if let Some(cs) = color_system {
+                    ansi_codes = style.render_ansi(cs);
+                    (&ansi_codes.0, &ansi_codes.1)
+                } else {
+                    static EMPTY: (String, String) = (String::new(), String::new());
+                    (&EMPTY.0, &EMPTY.1)
+                }
+            } else {
+                static EMPTY: (String, String) = (String::new(), String::new());
+                (&EMPTY.0, &EMPTY.1)
+            };
+
+            // Write styled text
+            write!(writer, "{prefix}{text}{suffix}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether `style` uses anything [`gate_style`](Self::gate_style) changes.
+    fn needs_style_gating(&self, style: &Style) -> bool {
+        (style.link.is_some() && !self.hyperlinks)
+            || (style
+                .attributes
+                .intersects(Attributes::BLINK | Attributes::BLINK2)
+                && (self.no_blink || !self.blink))
+            || (style.attributes.contains(Attributes::CONCEAL) && !self.conceal)
+    }
+
+    /// Adapt a style to what the console emits: links and unsupported blink
+    /// or conceal are dropped, and blink becomes bold with `no_blink`.
+    fn gate_style(&self, style: &Style) -> Style {
+        let mut style = style.clone();
+        if !self.hyperlinks {
+            style.link = None;
+            style.link_id = None;
+        }
+        let blink = Attributes::BLINK | Attributes::BLINK2;
+        if style.attributes.intersects(blink) && (self.no_blink || !self.blink) {
+            style.attributes.remove(blink);
+            if self.no_blink {
+                style.attributes.insert(Attributes::BOLD);
+                style.set_attributes.insert(Attributes::BOLD);
+            }
+        }
+        if !self.conceal {
+            style.attributes.remove(Attributes::CONCEAL);
+        }
+        style
     }
 
     fn write_control_segment<W: Write>(
@@ -1227,63 +2074,145 @@ impl Console {
     }
 
     /// Print a blank line.
+    ///
+    /// Like other output, it goes above an active [`Live`](crate::live::Live)
+    /// display.
     pub fn line(&self) {
-        let mut file = lock_recover(&self.file);
-        let _ = writeln!(file);
+        self.print_segments(&[Segment::line()]);
     }
 
     /// Print a rule (horizontal line).
+    ///
+    /// Builds a [`Rule`](crate::renderables::Rule) styled with the theme's
+    /// `rule.line` and `rule.text` styles and prints it like any other
+    /// renderable, so captures, recordings and exports include it. The title
+    /// is parsed as markup when markup is enabled.
     pub fn rule(&self, title: Option<&str>) {
-        let width = self.width();
-        let line_char = if self.safe_box { '-' } else { '\u{2500}' };
-
-        let mut file = lock_recover(&self.file);
-        if let Some(title) = title {
-            // Ensure title fits within width, accounting for 2 spaces padding
-            let max_title_width = width.saturating_sub(2);
-            let title_len = crate::cells::cell_len(title);
-
-            let display_title = if title_len > max_title_width {
-                let mut t = Text::new(title);
-                t.truncate(max_title_width, OverflowMethod::Ellipsis, false);
-                t.plain().to_string()
-            } else {
-                title.to_string()
-            };
+        let rule = match title {
+            Some(title) => {
+                let text = if self.markup {
+                    markup::render_or_plain_with_style_resolver(title, |definition| {
+                        self.get_style(definition)
+                    })
+                } else {
+                    Text::new(title)
+                };
+                crate::renderables::Rule::with_title(text)
+            }
+            None => crate::renderables::Rule::new(),
+        };
+        let rule = rule
+            .style(self.get_style("rule.line"))
+            .title_style(self.get_style("rule.text"));
+        self.print_renderable(&rule);
+    }
 
-            let display_len = crate::cells::cell_len(&display_title);
-            let available = width.saturating_sub(display_len + 2);
-            let left_pad = available / 2;
-            let right_pad = available - left_pad;
-            let left = line_char.to_string().repeat(left_pad);
-            let right = line_char.to_string().repeat(right_pad);
-            let _ = writeln!(file, "{left} {display_title} {right}");
-        } else {
-            let _ = writeln!(file, "{}", line_char.to_string().repeat(width));
-        }
+    /// Read a line of input from stdin after printing `prompt`.
+    ///
+    /// Shorthand for `Prompt::new(prompt).allow_empty(true).ask(self)`; build a
+    /// [`Prompt`](crate::interactive::Prompt) directly for defaults,
+    /// validation, or [password](crate::interactive::Prompt::password) input.
+    ///
+    /// ```rust,ignore
+    /// let name = console.input("[bold]Name[/]")?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PromptError::NotInteractive`](crate::interactive::PromptError::NotInteractive)
+    /// when the console is not a terminal, or an error if reading stdin fails.
+    pub fn input(
+        &self,
+        prompt: impl Into<String>,
+    ) -> Result<String, crate::interactive::PromptError> {
+        crate::interactive::Prompt::new(prompt)
+            .allow_empty(true)
+            .ask(self)
+    }
+
+    /// Show a spinner with a message until the returned guard is dropped.
+    ///
+    /// Returns a started [`Status`](crate::interactive::Status); chain
+    /// [`spinner`](crate::interactive::Status::spinner) or
+    /// [`spinner_style`](crate::interactive::Status::spinner_style) to change
+    /// its look. When the console is not interactive, or the live display cannot
+    /// start, the message is printed once instead.
+    ///
+    /// ```rust,ignore
+    /// let console = Console::new().shared();
+    /// let _status = console.status("Loading").spinner("dots");
+    /// ```
+    pub fn status(self: &Arc<Self>, message: impl Into<String>) -> crate::interactive::Status {
+        let message = message.into();
+        crate::interactive::Status::new(self, message.clone())
+            .unwrap_or_else(|_| crate::interactive::Status::printed(self, message))
     }
 
     /// Clear the screen.
     pub fn clear(&self) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_output();
         let _ = terminal::control::clear_screen(&mut *file);
     }
 
     /// Clear the current line.
     pub fn clear_line(&self) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_output();
         let _ = terminal::control::clear_line(&mut *file);
     }
 
     /// Set the terminal title.
     pub fn set_title(&self, title: &str) {
-        let mut file = lock_recover(&self.file);
+        let mut titles = lock_recover(&self.title_stack);
+        titles.current = Some(title.to_string());
+        self.write_title(title);
+    }
+
+    /// The last title set through this console, if any.
+    ///
+    /// Terminals can't be queried reliably, so this only reflects titles set via
+    /// [`set_title`](Self::set_title) and [`push_title`](Self::push_title).
+    #[must_use]
+    pub fn title(&self) -> Option<String> {
+        lock_recover(&self.title_stack).current.clone()
+    }
+
+    /// Set the terminal title, saving the current one for [`pop_title`](Self::pop_title).
+    ///
+    /// ```rust,ignore
+    /// console.push_title("Building...");
+    /// run_build();
+    /// console.pop_title(); // back to whatever the title was before
+    /// ```
+    pub fn push_title(&self, title: &str) {
+        let mut titles = lock_recover(&self.title_stack);
+        let previous = titles.current.replace(title.to_string());
+        titles.saved.push(previous);
+        self.write_title(title);
+    }
+
+    /// Restore the title that was current before the matching [`push_title`](Self::push_title).
+    ///
+    /// If no title had been set through the console before the push, the title is
+    /// cleared (most terminals then fall back to their default). Returns `false`
+    /// without writing anything when there is nothing to pop.
+    pub fn pop_title(&self) -> bool {
+        let mut titles = lock_recover(&self.title_stack);
+        let Some(previous) = titles.saved.pop() else {
+            return false;
+        };
+        self.write_title(previous.as_deref().unwrap_or(""));
+        titles.current = previous;
+        true
+    }
+
+    fn write_title(&self, title: &str) {
+        let mut file = self.lock_output();
         let _ = terminal::control::set_title(&mut *file, title);
     }
 
     /// Ring the terminal bell.
     pub fn bell(&self) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_output();
         let _ = terminal::control::bell(&mut *file);
     }
 
@@ -1292,6 +2221,20 @@ impl Console {
         self.print_with_options(content, &PrintOptions::new().with_markup(false));
     }
 
+    /// Write text exactly as given, followed by a newline.
+    ///
+    /// Unlike [`print`](Self::print), no markup, emoji codes, highlighting,
+    /// wrapping, or justification is applied, for output the application has
+    /// already formatted. Like Python Rich's `Console.out`, it is still
+    /// recorded by [`begin_capture`](Self::begin_capture) and passes through
+    /// render hooks, so it stays above an active [`Live`](crate::live::Live) display.
+    pub fn out(&self, text: &str) {
+        let segments =
+            self.apply_render_hooks(vec![Segment::new(text.to_string(), None), Segment::line()]);
+        let mut file = self.lock_output();
+        let _ = self.write_output(&mut file, &segments, None);
+    }
+
     /// Print a styled message.
     pub fn print_styled(&self, content: &str, style: Style) {
         self.print_with_options(
@@ -1349,60 +2292,51 @@ impl Console {
             LogLevel::Error => ("[ERROR]", Style::parse("bold red").unwrap_or_default()),
         };
 
-        {
-            let mut file = lock_recover(&self.file);
-            // Print timestamp if enabled
-            if options.show_timestamp {
-                let timestamp = Self::format_timestamp(options.timestamp_format.as_deref());
-                let ts_style = Style::parse("dim").unwrap_or_default();
-                let _ = self.print_to(
-                    &mut *file,
-                    &timestamp,
-                    &PrintOptions::new().with_markup(false).with_style(ts_style),
-                );
-                let _ = write!(file, " ");
-            }
-
-            // Print file/line info if provided
-            if options.file_path.is_some() || options.line_number.is_some() {
-                let path_style = Style::parse("magenta").unwrap_or_default();
-                let path_info = match (&options.file_path, options.line_number) {
-                    (Some(path), Some(line)) => format!("{path}:{line}"),
-                    (Some(path), None) => path.clone(),
-                    (None, Some(line)) => format!(":{line}"),
-                    (None, None) => String::new(),
-                };
-                if !path_info.is_empty() {
-                    let _ = self.print_to(
-                        &mut *file,
-                        &path_info,
-                        &PrintOptions::new()
-                            .with_markup(false)
-                            .with_style(path_style),
-                    );
-                    let _ = write!(file, " ");
-                }
-            }
+        // Render the whole entry first so it reaches render hooks (e.g. an
+        // active Live display) as a single line.
+        let prefix = PrintOptions::new().with_markup(false).with_no_newline(true);
+        let separator = Segment::new(" ", None);
+        let mut segments = Vec::new();
+
+        // Timestamp if enabled
+        if options.show_timestamp {
+            let timestamp = Self::format_timestamp(options.timestamp_format.as_deref());
+            let ts_style = Style::parse("dim").unwrap_or_default();
+            segments
+                .extend(self.render_str_segments(&timestamp, &prefix.clone().with_style(ts_style)));
+            segments.push(separator.clone());
+        }
 
-            // Print level prefix if enabled
-            if options.show_level {
-                let _ = self.print_to(
-                    &mut *file,
-                    level_prefix,
-                    &PrintOptions::new()
-                        .with_markup(false)
-                        .with_style(level_style),
+        // File/line info if provided
+        if options.file_path.is_some() || options.line_number.is_some() {
+            let path_style = Style::parse("magenta").unwrap_or_default();
+            let path_info = match (&options.file_path, options.line_number) {
+                (Some(path), Some(line)) => format!("{path}:{line}"),
+                (Some(path), None) => path.clone(),
+                (None, Some(line)) => format!(":{line}"),
+                (None, None) => String::new(),
+            };
+            if !path_info.is_empty() {
+                segments.extend(
+                    self.render_str_segments(&path_info, &prefix.clone().with_style(path_style)),
                 );
-                let _ = write!(file, " ");
+                segments.push(separator.clone());
             }
+        }
 
-            // Print the message
-            let _ = self.print_to(
-                &mut *file,
-                message,
-                &PrintOptions::new().with_markup(self.markup),
+        // Level prefix if enabled
+        if options.show_level {
+            segments.extend(
+                self.render_str_segments(level_prefix, &prefix.clone().with_style(level_style)),
             );
+            segments.push(separator);
         }
+
+        // The message
+        segments.extend(
+            self.render_str_segments(message, &PrintOptions::new().with_markup(self.markup)),
+        );
+        self.print_segments(&segments);
     }
 
     /// Format the current time as a timestamp string.
@@ -1526,6 +2460,27 @@ pub const CONSOLE_HTML_FORMAT: &str = "<!DOCTYPE html>\n<html>\n<head>\n<meta ch
 /// Default SVG export template (Rich 13.9.4).
 pub const CONSOLE_SVG_FORMAT: &str = "<svg class=\"rich-terminal\" viewBox=\"0 0 {width} {height}\" xmlns=\"http://www.w3.org/2000/svg\">\n    <!-- Generated with Rich https://www.textualize.io -->\n    <style>\n\n    @font-face {\n        font-family: \"Fira Code\";\n        src: local(\"FiraCode-Regular\"),\n                url(\"https://cdnjs.cloudflare.com/ajax/libs/firacode/6.2.0/woff2/FiraCode-Regular.woff2\") format(\"woff2\"),\n                url(\"https://cdnjs.cloudflare.com/ajax/libs/firacode/6.2.0/woff/FiraCode-Regular.woff\") format(\"woff\");\n        font-style: normal;\n        font-weight: 400;\n    }\n    @font-face {\n        font-family: \"Fira Code\";\n        src: local(\"FiraCode-Bold\"),\n                url(\"https://cdnjs.cloudflare.com/ajax/libs/firacode/6.2.0/woff2/FiraCode-Bold.woff2\") format(\"woff2\"),\n                url(\"https://cdnjs.cloudflare.com/ajax/libs/firacode/6.2.0/woff/FiraCode-Bold.woff\") format(\"woff\");\n        font-style: bold;\n        font-weight: 700;\n    }\n\n    .{unique_id}-matrix {\n        font-family: Fira Code, monospace;\n        font-size: {char_height}px;\n        line-height: {line_height}px;\n        font-variant-east-asian: full-width;\n    }\n\n    .{unique_id}-title {\n        font-size: 18px;\n        font-weight: bold;\n        font-family: arial;\n    }\n\n    {styles}\n    </style>\n\n    <defs>\n    <clipPath id=\"{unique_id}-clip-terminal\">\n      <rect x=\"0\" y=\"0\" width=\"{terminal_width}\" height=\"{terminal_height}\" />\n    </clipPath>\n    {lines}\n    </defs>\n\n    {chrome}\n    <g transform=\"translate({terminal_x}, {terminal_y})\" clip-path=\"url(#{unique_id}-clip-terminal)\">\n    {backgrounds}\n    <g class=\"{unique_id}-matrix\">\n    {matrix}\n    </g>\n    </g>\n</svg>\n";
 
+/// Options for controlling text export of recorded output.
+#[derive(Debug, Clone)]
+pub struct ExportTextOptions {
+    /// Color system for ANSI styling (`None` = plain text).
+    pub color_system: Option<ColorSystem>,
+    pub clear: bool,
+    /// Replay carriage returns, cursor movement and erase codes so overwritten
+    /// output is dropped (see [`crate::segment::apply_control_codes`]).
+    pub apply_control_codes: bool,
+}
+
+impl Default for ExportTextOptions {
+    fn default() -> Self {
+        Self {
+            color_system: None,
+            clear: true,
+            apply_control_codes: false,
+        }
+    }
+}
+
 /// Options for controlling HTML export.
 #[derive(Debug, Clone)]
 pub struct ExportHtmlOptions {
@@ -1534,6 +2489,9 @@ pub struct ExportHtmlOptions {
     /// Optional template override. If `None`, uses [`CONSOLE_HTML_FORMAT`].
     pub code_format: Option<String>,
     pub inline_styles: bool,
+    /// Replay carriage returns, cursor movement and erase codes so overwritten
+    /// output is dropped (see [`crate::segment::apply_control_codes`]).
+    pub apply_control_codes: bool,
 }
 
 impl Default for ExportHtmlOptions {
@@ -1543,6 +2501,7 @@ impl Default for ExportHtmlOptions {
             clear: true,
             code_format: None,
             inline_styles: false,
+            apply_control_codes: false,
         }
     }
 }
@@ -1557,6 +2516,9 @@ pub struct ExportSvgOptions {
     pub code_format: Option<String>,
     pub font_aspect_ratio: f64,
     pub unique_id: Option<String>,
+    /// Replay carriage returns, cursor movement and erase codes so overwritten
+    /// output is dropped (see [`crate::segment::apply_control_codes`]).
+    pub apply_control_codes: bool,
 }
 
 impl Default for ExportSvgOptions {
@@ -1568,6 +2530,7 @@ impl Default for ExportSvgOptions {
             code_format: None,
             font_aspect_ratio: 0.61,
             unique_id: None,
+            apply_control_codes: false,
         }
     }
 }
@@ -1906,6 +2869,276 @@ fn apply_template(template: &str, vars: &[(&str, &str)]) -> String {
     out
 }
 
+/// Markdown formatting carried over from a segment style.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct MarkdownFormat<'a> {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+    link: Option<&'a str>,
+}
+
+impl<'a> MarkdownFormat<'a> {
+    fn from_style(style: Option<&'a Style>, code_style: &Style) -> Self {
+        let Some(style) = style else {
+            return Self::default();
+        };
+        let code = !code_style.is_null()
+            && style.color == code_style.color
+            && style.bgcolor == code_style.bgcolor
+            && style.attributes == code_style.attributes;
+        Self {
+            bold: !code && style.attributes.contains(Attributes::BOLD),
+            italic: !code && style.attributes.contains(Attributes::ITALIC),
+            strike: !code && style.attributes.contains(Attributes::STRIKE),
+            code,
+            link: style.link.as_deref(),
+        }
+    }
+
+    /// Wrap `text` in this format's Markdown syntax.
+    fn apply(self, text: &str) -> String {
+        // Emphasis markers must hug the text, so surrounding spaces stay outside.
+        let core = text.trim();
+        if core.is_empty() {
+            return text.to_string();
+        }
+        let leading = &text[..text.len() - text.trim_start().len()];
+        let trailing = &text[text.trim_end().len()..];
+
+        let mut out = if self.code {
+            let fence = if core.contains('`') { "``" } else { "`" };
+            format!("{fence}{core}{fence}")
+        } else {
+            escape_markdown(core)
+        };
+        if self.strike {
+            out = format!("~~{out}~~");
+        }
+        if self.italic {
+            out = format!("*{out}*");
+        }
+        if self.bold {
+            out = format!("**{out}**");
+        }
+        if let Some(link) = self.link {
+            out = format!("[{out}]({link})");
+        }
+        format!("{leading}{out}{trailing}")
+    }
+}
+
+fn export_segments_to_markdown(recorded: &[RecordedSegment], code_style: &Style) -> String {
+    // Only lines printed by a `Table` are converted to pipe tables.
+    let mut from_table = vec![false];
+    for recorded in recorded.iter().filter(|r| !r.is_control()) {
+        for (index, part) in recorded.text.split('\n').enumerate() {
+            if index > 0 {
+                from_table.push(false);
+            }
+            if !part.is_empty() && recorded.origin == Some("table") {
+                *from_table.last_mut().expect("at least one line") = true;
+            }
+        }
+    }
+
+    let mut lines = crate::segment::split_lines(recorded.iter().map(RecordedSegment::segment));
+    if lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+    let table_lines: Vec<String> = lines
+        .iter()
+        .zip(&from_table)
+        .map(|(line, &from_table)| {
+            if from_table {
+                Console::segments_to_plain(line).trim_end().to_string()
+            } else {
+                String::new()
+            }
+        })
+        .collect();
+
+    let mut out = String::new();
+    let mut index = 0;
+    while index < lines.len() {
+        if let Some(end) = markdown_table_end(&table_lines, index) {
+            markdown_pipe_table(&mut out, &table_lines[index..end]);
+            index = end;
+            continue;
+        }
+
+        // Merge runs with the same formatting so markers aren't split mid-word.
+        let mut runs: Vec<(MarkdownFormat<'_>, String)> = Vec::new();
+        for segment in lines[index].iter().filter(|s| !s.is_control()) {
+            let format = MarkdownFormat::from_style(segment.style.as_ref(), code_style);
+            match runs.last_mut() {
+                Some((last, text)) if *last == format => text.push_str(&segment.text),
+                _ => runs.push((format, segment.text.to_string())),
+            }
+        }
+        let line: String = runs.iter().map(|(f, text)| f.apply(text)).collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+        index += 1;
+    }
+    out
+}
+
+/// Characters used for vertical table rules.
+const MARKDOWN_TABLE_VERTICALS: &[char] = &['\u{2502}', '\u{2503}', '\u{2551}', '|'];
+
+fn is_table_border(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty()
+        && line
+            .chars()
+            .all(|c| ('\u{2500}'..='\u{257f}').contains(&c) || "+-=| ".contains(c))
+        && line
+            .chars()
+            .any(|c| matches!(c, '\u{2500}' | '\u{2501}' | '\u{2550}' | '-' | '='))
+}
+
+fn is_table_row(line: &str) -> bool {
+    let line = line.trim();
+    line.chars().count() > 1
+        && line.starts_with(MARKDOWN_TABLE_VERTICALS)
+        && line.ends_with(MARKDOWN_TABLE_VERTICALS)
+}
+
+/// Cell offsets of the column rules in a table border line.
+fn table_rule_offsets(border: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+    for c in border.chars() {
+        if !matches!(c, '\u{2500}' | '\u{2501}' | '\u{2550}' | '-' | '=' | ' ') {
+            offsets.push(offset);
+        }
+        offset += crate::cells::get_character_cell_size(c);
+    }
+    offsets
+}
+
+/// Split a table row into cells at the border's rule offsets.
+fn table_cells(line: &str, rules: &[usize]) -> Vec<String> {
+    let mut cells = vec![String::new(); rules.len().saturating_sub(1)];
+    let mut offset = 0;
+    for c in line.chars() {
+        let column = rules.iter().take_while(|&&rule| rule < offset).count();
+        if !rules.contains(&offset) && column > 0 && column <= cells.len() {
+            cells[column - 1].push(c);
+        }
+        offset += crate::cells::get_character_cell_size(c);
+    }
+    cells
+        .iter()
+        .map(|cell| escape_markdown(cell.trim()).replace('|', "\\|"))
+        .collect()
+}
+
+/// If a box-drawn table starts at `start`, return the index one past its last line.
+fn markdown_table_end(lines: &[String], start: usize) -> Option<usize> {
+    if !is_table_border(&lines[start]) {
+        return None;
+    }
+    let rules = table_rule_offsets(&lines[start]);
+    if rules.len() < 2 {
+        return None;
+    }
+    // Rows and inner borders must line up with the top border's column rules.
+    let fits = |line: &str| {
+        if is_table_border(line) {
+            return table_rule_offsets(line) == rules;
+        }
+        if !is_table_row(line) {
+            return false;
+        }
+        let mut offset = 0;
+        let mut verticals = Vec::new();
+        for c in line.chars() {
+            if MARKDOWN_TABLE_VERTICALS.contains(&c) {
+                verticals.push(offset);
+            }
+            offset += crate::cells::get_character_cell_size(c);
+        }
+        rules.iter().all(|rule| verticals.contains(rule))
+    };
+    let mut end = start + 1;
+    while end < lines.len() && fits(&lines[end]) {
+        end += 1;
+    }
+    lines[start + 1..end]
+        .iter()
+        .any(|line| is_table_row(line))
+        .then_some(end)
+}
+
+fn markdown_pipe_table(out: &mut String, lines: &[String]) {
+    let rules = table_rule_offsets(&lines[0]);
+    // Header rows are the ones above the first rule between rows.
+    let mut header: Vec<Vec<String>> = Vec::new();
+    let mut body: Vec<Vec<String>> = Vec::new();
+    let mut seen_row = false;
+    let mut in_header = true;
+    for line in lines {
+        if is_table_row(line) {
+            let cells = table_cells(line, &rules);
+            if cells.iter().all(String::is_empty) {
+                continue;
+            }
+            if in_header {
+                header.push(cells);
+            } else {
+                body.push(cells);
+            }
+            seen_row = true;
+        } else if seen_row {
+            in_header = false;
+        }
+    }
+    if body.is_empty() && header.len() > 1 {
+        body = header.split_off(1);
+    }
+
+    let columns = rules.len() - 1;
+    // Markdown headers are a single row; join wrapped header lines per column.
+    let header_row: Vec<String> = (0..columns)
+        .map(|col| {
+            header
+                .iter()
+                .map(|row| row[col].as_str())
+                .filter(|cell| !cell.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    let write_row = |out: &mut String, cells: &[String]| {
+        out.push('|');
+        for cell in cells {
+            out.push(' ');
+            out.push_str(cell);
+            out.push_str(" |");
+        }
+        out.push('\n');
+    };
+    write_row(out, &header_row);
+    write_row(out, &vec!["---".to_string(); columns]);
+    for row in &body {
+        write_row(out, row);
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '*' | '_' | '`' | '[' | ']' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
 fn escape_html_rich(text: &str) -> String {
     let mut escaped = String::with_capacity(text.len());
     for ch in text.chars() {
@@ -2065,46 +3298,222 @@ impl Drop for ThemeGuard<'_> {
     }
 }
 
-/// Builder for creating a Console with custom settings.
-#[derive(Default)]
-pub struct ConsoleBuilder {
-    color_system: Option<ColorSystem>,
-    force_terminal: Option<bool>,
-    tab_size: Option<usize>,
-    markup: Option<bool>,
-    emoji: Option<bool>,
-    highlight: Option<bool>,
-    highlighter: Option<Arc<dyn Highlighter>>,
-    width: Option<usize>,
-    height: Option<usize>,
-    safe_box: Option<bool>,
-    theme: Option<Theme>,
-    file: Option<Box<dyn Write + Send>>,
+/// RAII guard returned by [`Console::screen`].
+///
+/// Holds an optional renderable that fills the alternate screen; replace it
+/// with [`update`](Self::update).
+pub struct Screen<'a> {
+    console: &'a Console,
+    renderable: Option<Box<dyn Renderable + 'a>>,
+    hide_cursor: bool,
+    active: bool,
 }
 
-impl std::fmt::Debug for ConsoleBuilder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConsoleBuilder")
-            .field("color_system", &self.color_system)
-            .field("force_terminal", &self.force_terminal)
-            .field("tab_size", &self.tab_size)
-            .field("markup", &self.markup)
-            .field("emoji", &self.emoji)
-            .field("highlight", &self.highlight)
-            .field(
-                "highlighter",
-                &self.highlighter.as_ref().map(|_| "<Highlighter>"),
-            )
-            .field("width", &self.width)
-            .field("height", &self.height)
-            .field("safe_box", &self.safe_box)
-            .field("theme", &self.theme.as_ref().map(|_| "<Theme>"))
-            .field("file", &self.file.as_ref().map(|_| "<dyn Write>"))
-            .finish()
+impl<'a> Screen<'a> {
+    /// Replace the renderable shown on the screen and repaint.
+    pub fn update(&mut self, renderable: impl Renderable + 'a) {
+        self.renderable = Some(Box::new(renderable));
+        self.refresh();
     }
-}
+
+    /// Repaint the current renderable, e.g. after the terminal is resized.
+    pub fn refresh(&self) {
+        let Some(renderable) = &self.renderable else {
+            return;
+        };
+        if !self.active {
+            // Printed in sequence instead, so each update ends its line.
+            let options = self.console.options();
+            let mut segments = renderable.render(self.console, &options);
+            let last_text = segments
+                .iter()
+                .rev()
+                .find(|segment| !segment.is_control() && !segment.text.is_empty());
+            if last_text.is_some_and(|segment| !segment.text.ends_with('\n')) {
+                segments.push(Segment::line());
+            }
+            self.console.print_segments(&segments);
+            return;
+        }
+        let size = self.console.size();
+        let options = self.console.options().update_height(size.height);
+        let lines =
+            crate::segment::split_lines(renderable.render(self.console, &options).into_iter());
+
+        let mut segments = vec![Segment::control(vec![ControlCode::new(ControlType::Home)])];
+        let blank = Vec::new();
+        for row in 0..size.height {
+            let line = lines.get(row).unwrap_or(&blank).clone();
+            segments.extend(crate::segment::adjust_line_length(
+                line, size.width, None, true,
+            ));
+            // No newline after the last row, so the screen never scrolls.
+            if row + 1 < size.height {
+                segments.push(Segment::line());
+            }
+        }
+        self.console.print_segments(&segments);
+    }
+}
+
+impl std::fmt::Debug for Screen<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Screen")
+            .field("has_renderable", &self.renderable.is_some())
+            .field("hide_cursor", &self.hide_cursor)
+            .field("active", &self.active)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Screen<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            if self.hide_cursor {
+                let _ = self.console.show_cursor(true);
+            }
+            let _ = self.console.set_alt_screen(false);
+        }
+    }
+}
+
+/// Handle returned by [`Console::watch_theme`]; watching stops when it is dropped.
+///
+/// The watched theme stays on the console's theme stack afterwards.
+#[cfg(feature = "watch")]
+pub struct ThemeWatcher {
+    stop: Arc<AtomicBool>,
+    /// Completed polls of the theme file.
+    polls: Arc<std::sync::atomic::AtomicUsize>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(feature = "watch")]
+impl std::fmt::Debug for ThemeWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ThemeWatcher")
+            .field("stopped", &self.stop.load(Ordering::Relaxed))
+            .field("polls", &self.polls.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "watch")]
+impl Drop for ThemeWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+/// How often [`Console::watch_theme`] checks the theme file for changes.
+#[cfg(feature = "watch")]
+const THEME_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Builder for creating a Console with custom settings.
+#[derive(Default)]
+pub struct ConsoleBuilder {
+    color_system: Option<ColorSystem>,
+    force_terminal: Option<bool>,
+    tab_size: Option<usize>,
+    markup: Option<bool>,
+    emoji: Option<bool>,
+    highlight: Option<bool>,
+    highlighter: Option<Arc<dyn Highlighter>>,
+    width: Option<usize>,
+    height: Option<usize>,
+    safe_box: Option<bool>,
+    justify: Option<JustifyMethod>,
+    overflow: Option<OverflowMethod>,
+    hyperlinks: Option<bool>,
+    blink: Option<bool>,
+    conceal: Option<bool>,
+    no_blink: Option<bool>,
+    accessible: Option<bool>,
+    export_theme: Option<TerminalTheme>,
+    theme: Option<Theme>,
+    file: Option<Box<dyn Write + Send>>,
+    tees: Vec<TeeSink>,
+    max_prints_per_second: Option<u32>,
+    flush_policy: Option<FlushPolicy>,
+    serialize_output: Option<bool>,
+}
+
+impl std::fmt::Debug for ConsoleBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsoleBuilder")
+            .field("color_system", &self.color_system)
+            .field("force_terminal", &self.force_terminal)
+            .field("tab_size", &self.tab_size)
+            .field("markup", &self.markup)
+            .field("emoji", &self.emoji)
+            .field("highlight", &self.highlight)
+            .field(
+                "highlighter",
+                &self.highlighter.as_ref().map(|_| "<Highlighter>"),
+            )
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("safe_box", &self.safe_box)
+            .field("justify", &self.justify)
+            .field("overflow", &self.overflow)
+            .field("hyperlinks", &self.hyperlinks)
+            .field("blink", &self.blink)
+            .field("conceal", &self.conceal)
+            .field("no_blink", &self.no_blink)
+            .field("accessible", &self.accessible)
+            .field("export_theme", &self.export_theme)
+            .field("theme", &self.theme.as_ref().map(|_| "<Theme>"))
+            .field("file", &self.file.as_ref().map(|_| "<dyn Write>"))
+            .field("tees", &self.tees.len())
+            .field("max_prints_per_second", &self.max_prints_per_second)
+            .field("flush_policy", &self.flush_policy)
+            .field("serialize_output", &self.serialize_output)
+            .finish()
+    }
+}
 
 impl ConsoleBuilder {
+    /// Create a builder configured from environment variables.
+    ///
+    /// - `RICH_WIDTH`, then `COLUMNS`: console width
+    /// - `LINES`: console height
+    /// - `RICH_FORCE_TERMINAL`: `1`/`true`/`yes` forces terminal output,
+    ///   `0`/`false`/`no` disables it
+    /// - `NO_COLOR` and `TERM` (`dumb`, `*-256color`, ...) are honored by color
+    ///   detection as for every console
+    ///
+    /// Unset or unparsable values are ignored, and later builder calls override them.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default().with_env(|name| std::env::var(name).ok())
+    }
+
+    fn with_env(mut self, lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let number = |name: &str| {
+            lookup(name)
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .filter(|&value| value > 0)
+        };
+        if let Some(width) = number("RICH_WIDTH").or_else(|| number("COLUMNS")) {
+            self.width = Some(width);
+        }
+        if let Some(height) = number("LINES") {
+            self.height = Some(height);
+        }
+        if let Some(force) = lookup("RICH_FORCE_TERMINAL") {
+            match force.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" => self.force_terminal = Some(true),
+                "0" | "false" | "no" => self.force_terminal = Some(false),
+                _ => {}
+            }
+        }
+        self
+    }
+
     /// Set the color system.
     #[must_use]
     pub fn color_system(mut self, system: ColorSystem) -> Self {
@@ -2182,6 +3591,87 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Set the default justification for printed text.
+    ///
+    /// Applies to every print that doesn't set its own
+    /// [`PrintOptions::justify`], and flows into [`Console::options`].
+    #[must_use]
+    pub fn justify(mut self, justify: JustifyMethod) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    /// Set the default overflow handling for printed text.
+    ///
+    /// Applies to every print that doesn't set its own
+    /// [`PrintOptions::overflow`], and flows into [`Console::options`].
+    #[must_use]
+    pub fn overflow(mut self, overflow: OverflowMethod) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Enable or disable OSC 8 hyperlinks (default `true`).
+    ///
+    /// Pass [`terminal::supports_hyperlinks()`] to follow `FORCE_HYPERLINK` and
+    /// `TERM` instead.
+    #[must_use]
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = Some(enabled);
+        self
+    }
+
+    /// Enable or disable blinking text (SGR 5/6, default `true`).
+    ///
+    /// Pass [`terminal::supports_blink()`] to drop blink on terminals that
+    /// can't show it.
+    #[must_use]
+    pub fn blink(mut self, enabled: bool) -> Self {
+        self.blink = Some(enabled);
+        self
+    }
+
+    /// Enable or disable the conceal attribute (SGR 8, default `true`).
+    ///
+    /// When disabled, concealed text is written as spaces so it stays hidden.
+    /// Pass [`terminal::supports_conceal()`] to blank it only on terminals that
+    /// would show it in the clear.
+    #[must_use]
+    pub fn conceal(mut self, enabled: bool) -> Self {
+        self.conceal = Some(enabled);
+        self
+    }
+
+    /// Show blinking text as bold instead (default `false`).
+    ///
+    /// An accessibility option for users sensitive to flashing content.
+    #[must_use]
+    pub fn no_blink(mut self, no_blink: bool) -> Self {
+        self.no_blink = Some(no_blink);
+        self
+    }
+
+    /// Produce screen-reader friendly output (default `false`).
+    ///
+    /// Box drawing uses ASCII characters, spinners and live displays don't
+    /// animate (the console is never [interactive](Console::is_interactive)),
+    /// [`Progress`](crate::renderables::Progress) reports discrete log lines,
+    /// and rules and panels are announced in words instead of drawn. Also
+    /// implies [`no_blink`](Self::no_blink) unless that is set explicitly.
+    #[must_use]
+    pub fn accessible(mut self, accessible: bool) -> Self {
+        self.accessible = Some(accessible);
+        self
+    }
+
+    /// Set the terminal colors used by [`Console::export_html`] and
+    /// [`Console::export_svg`], e.g. from [`TerminalTheme::from_iterm`].
+    #[must_use]
+    pub fn export_theme(mut self, theme: TerminalTheme) -> Self {
+        self.export_theme = Some(theme);
+        self
+    }
+
     /// Set the initial console theme.
     #[must_use]
     pub fn theme(mut self, theme: Theme) -> Self {
@@ -2196,6 +3686,74 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Also write everything printed to `writer`, as plain text.
+    ///
+    /// Control codes are dropped for plain sinks, so a log file receives the
+    /// text of Live displays without cursor movement. May be called repeatedly.
+    ///
+    /// Only output to the console's own stream is copied, not output sent to an
+    /// explicit writer with [`Console::print_to`] and friends. Write errors on a
+    /// tee are ignored, so a full disk or closed pipe can't fail the console's output.
+    #[must_use]
+    pub fn tee(self, writer: Box<dyn Write + Send>) -> Self {
+        self.tee_with_color(writer, None)
+    }
+
+    /// Also write everything printed to `writer`, styled for `color_system`.
+    ///
+    /// The color system is independent of the console's own, e.g. a truecolor
+    /// terminal with a 16-color copy for a log viewer. `None` writes plain text.
+    #[must_use]
+    pub fn tee_with_color(
+        mut self,
+        writer: Box<dyn Write + Send>,
+        color_system: Option<ColorSystem>,
+    ) -> Self {
+        self.tees.push(TeeSink {
+            writer,
+            color_system,
+        });
+        self
+    }
+
+    /// Limit how many prints reach the output each second.
+    ///
+    /// Prints over the limit are dropped and reported with a dim
+    /// "… suppressed N lines" notice once output resumes (or on
+    /// [`Console::flush_suppressed`]). Recording still captures every print, and
+    /// output containing control codes, such as Live redraws, is never dropped.
+    /// Output sent to an explicit writer with [`Console::print_to`] isn't limited.
+    #[must_use]
+    pub fn max_prints_per_second(mut self, limit: u32) -> Self {
+        self.max_prints_per_second = Some(limit);
+        self
+    }
+
+    /// Set when output is flushed (default [`FlushPolicy::Immediate`]).
+    ///
+    /// Flushing after every print is slow for batch output to files and pipes;
+    /// [`FlushPolicy::LineBuffered`] and [`FlushPolicy::Manual`] trade latency
+    /// for throughput. Combine them with a buffered writer (see
+    /// [`ConsoleBuilder::file`]) and call [`Console::flush`] when done.
+    #[must_use]
+    pub fn flush_policy(mut self, policy: FlushPolicy) -> Self {
+        self.flush_policy = Some(policy);
+        self
+    }
+
+    /// Hold a process-wide output lock for every write (default `false`).
+    ///
+    /// A console already writes each print call in one piece, but separate
+    /// consoles sharing a stream (e.g. several consoles on stdout) can still
+    /// interleave within a print. With this enabled, whole print calls from all
+    /// serializing consoles are ordered, at the cost of contention between
+    /// threads.
+    #[must_use]
+    pub fn serialize_output(mut self, serialize: bool) -> Self {
+        self.serialize_output = Some(serialize);
+        self
+    }
+
     /// Build the console.
     #[must_use]
     pub fn build(self) -> Console {
@@ -2238,12 +3796,44 @@ impl ConsoleBuilder {
         if let Some(sb) = self.safe_box {
             console.safe_box = sb;
         }
+        console.justify = self.justify;
+        console.overflow = self.overflow;
+        if let Some(hyperlinks) = self.hyperlinks {
+            console.hyperlinks = hyperlinks;
+        }
+        if let Some(blink) = self.blink {
+            console.blink = blink;
+        }
+        if let Some(conceal) = self.conceal {
+            console.conceal = conceal;
+        }
+        if let Some(accessible) = self.accessible {
+            console.accessible = accessible;
+            if accessible {
+                console.safe_box = self.safe_box.unwrap_or(true);
+                console.no_blink = self.no_blink.unwrap_or(true);
+            }
+        }
+        if let Some(no_blink) = self.no_blink {
+            console.no_blink = no_blink;
+        }
+        console.export_theme = self.export_theme;
         if let Some(theme) = self.theme {
             console.theme_stack = Mutex::new(ThemeStack::new(theme));
         }
         if let Some(f) = self.file {
             console.file = Mutex::new(f);
         }
+        if let Some(limit) = self.max_prints_per_second {
+            console.throttle = Some(Mutex::new(PrintThrottle::new(limit)));
+        }
+        console.tees = Mutex::new(self.tees);
+        if let Some(policy) = self.flush_policy {
+            console.flush_policy = policy;
+        }
+        if let Some(serialize) = self.serialize_output {
+            console.serialize_output = serialize;
+        }
 
         console
     }
@@ -2274,6 +3864,20 @@ mod tests {
         assert!(!console.markup);
     }
 
+    #[test]
+    fn test_try_get_style_suggests_theme_names() {
+        let console = Console::new();
+        let suggestion = |name: &str| {
+            console
+                .try_get_style(name)
+                .unwrap_err()
+                .suggestion()
+                .map(str::to_string)
+        };
+        assert_eq!(suggestion("repr.nubmer").as_deref(), Some("repr.number"));
+        assert_eq!(suggestion("blod").as_deref(), Some("bold"));
+    }
+
     #[test]
     fn test_console_default_highlighter_applies_when_enabled() {
         let console = Console::builder().markup(false).build();
@@ -2321,33 +3925,564 @@ mod tests {
     }
 
     #[test]
-    fn test_console_options() {
-        let console = Console::builder().width(80).build();
-        let options = console.options();
+    fn test_console_options() {
+        let console = Console::builder().width(80).build();
+        let options = console.options();
+
+        assert_eq!(options.max_width, 80);
+        assert_eq!(options.size.width, 80);
+    }
+
+    #[test]
+    fn test_print_options() {
+        let options = PrintOptions::new()
+            .with_markup(true)
+            .with_style(Style::new().bold());
+
+        assert_eq!(options.markup, Some(true));
+        assert!(options.style.is_some());
+    }
+
+    #[test]
+    fn test_capture() {
+        let console = Console::new();
+        console.begin_capture();
+
+        console.print_plain("capture test");
+        let segments = console.end_capture();
+        let captured: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert!(captured.contains("capture test"));
+    }
+
+    #[test]
+    fn test_print_throttle_windows() {
+        let start = Instant::now();
+        let mut throttle = PrintThrottle::new(2);
+        assert_eq!(throttle.admit(start, 1), Some(0));
+        assert_eq!(throttle.admit(start, 1), Some(0));
+        assert_eq!(throttle.admit(start, 3), None);
+        assert_eq!(throttle.admit(start + Duration::from_millis(999), 0), None);
+        // A new window reports everything dropped in the previous one.
+        assert_eq!(throttle.admit(start + Duration::from_secs(1), 1), Some(4));
+        assert_eq!(throttle.admit(start + Duration::from_secs(1), 1), Some(0));
+    }
+
+    #[test]
+    fn test_builder_with_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_string())
+            }
+        };
+
+        let console = ConsoleBuilder::default()
+            .with_env(env(&[("COLUMNS", "120"), ("LINES", "40")]))
+            .build();
+        assert_eq!((console.width(), console.height()), (120, 40));
+
+        let builder = ConsoleBuilder::default().with_env(env(&[
+            ("COLUMNS", "120"),
+            ("RICH_WIDTH", "64"),
+            ("LINES", "nope"),
+            ("RICH_FORCE_TERMINAL", "yes"),
+        ]));
+        assert_eq!(builder.width, Some(64));
+        assert_eq!(builder.height, None);
+        assert_eq!(builder.force_terminal, Some(true));
+        assert!(builder.build().is_terminal());
+
+        let builder = ConsoleBuilder::default()
+            .with_env(env(&[("COLUMNS", "0"), ("RICH_FORCE_TERMINAL", "false")]));
+        assert_eq!(builder.width, None);
+        let console = builder.build();
+        assert!(!console.is_terminal());
+        assert_eq!(console.color_system(), None);
+
+        // Explicit builder calls win over the environment.
+        let console = ConsoleBuilder::default()
+            .with_env(env(&[("COLUMNS", "120")]))
+            .width(30)
+            .build();
+        assert_eq!(console.width(), 30);
+    }
+
+    #[test]
+    fn test_tee_sinks_use_their_own_color_system() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.lock().unwrap().flush()
+            }
+        }
+
+        let main = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let plain = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let colored = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(40)
+            .color_system(ColorSystem::TrueColor)
+            .force_terminal(true)
+            .file(Box::new(main.clone()))
+            .tee(Box::new(plain.clone()))
+            .tee_with_color(Box::new(colored.clone()), Some(ColorSystem::Standard))
+            .build();
+
+        console.print("[bold red]hi[/]");
+        console.print_segments(&[Segment::control(vec![ControlCode::new(
+            ControlType::HideCursor,
+        )])]);
+
+        let read = |buffer: &SharedBuffer| String::from_utf8(buffer.0.lock().unwrap().clone());
+        let main = read(&main).unwrap();
+        let colored = read(&colored).unwrap();
+        assert!(main.contains("\x1b[1;31mhi"), "{main:?}");
+        assert!(main.ends_with("\x1b[?25l"), "{main:?}");
+        assert_eq!(read(&plain).unwrap(), "hi\n");
+        assert!(colored.contains("\x1b[1;31mhi"), "{colored:?}");
+        assert!(colored.ends_with("\x1b[?25l"), "{colored:?}");
+    }
+
+    #[test]
+    fn test_tees_copy_only_the_consoles_own_output() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.lock().unwrap().flush()
+            }
+        }
+
+        struct FailingTee;
+
+        impl Write for FailingTee {
+            fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("tee gone"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Err(io::Error::other("tee gone"))
+            }
+        }
+
+        let main = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let log = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(40)
+            .markup(false)
+            .max_prints_per_second(1)
+            .file(Box::new(main.clone()))
+            .tee(Box::new(FailingTee))
+            .tee(Box::new(log.clone()))
+            .build();
+        let read = |buffer: &SharedBuffer| String::from_utf8(buffer.0.lock().unwrap().clone());
+
+        // An explicit writer is neither copied to the tees nor rate limited.
+        let mut other = Vec::new();
+        for i in 0..3 {
+            console
+                .print_to(&mut other, &format!("other {i}"), &PrintOptions::new())
+                .unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(other).unwrap(),
+            "other 0\nother 1\nother 2\n"
+        );
+        assert_eq!(read(&log).unwrap(), "");
+
+        // A failing tee doesn't stop the output or the other tees.
+        console.print_plain("kept");
+        console.print_plain("dropped");
+        console.flush_suppressed();
+        let expected = "kept\n\u{2026} suppressed 1 line\n";
+        assert_eq!(read(&main).unwrap(), expected);
+        assert_eq!(read(&log).unwrap(), expected);
+        assert!(console.flush().is_err());
+    }
+
+    #[test]
+    fn test_screen_enters_and_restores_alternate_screen() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(6)
+            .height(2)
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        let read = || String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut screen = console.screen(true).expect("screen");
+            screen.update(Text::new("hi"));
+            panic!("boom");
+        }));
+        assert!(result.is_err());
+
+        let output = read();
+        assert!(
+            output.starts_with("\x1b[?1049h\x1b[?25l\x1b[H"),
+            "{output:?}"
+        );
+        // Every row is padded to the width, without a trailing newline.
+        assert!(output.contains("hi    \n      \x1b[?25h"), "{output:?}");
+        assert!(output.ends_with("\x1b[?25h\x1b[?1049l"), "{output:?}");
+    }
+
+    #[test]
+    fn test_screen_on_non_interactive_console_prints_plainly() {
+        let console = Console::builder().width(20).build();
+        console.begin_capture();
+        {
+            let mut screen = console.screen(true).expect("screen");
+            screen.update(Text::new("hi"));
+        }
+        let output: String = console
+            .end_capture()
+            .into_iter()
+            .map(|segment| segment.text.into_owned())
+            .collect();
+        assert_eq!(output, "hi\n");
+    }
+
+    #[test]
+    fn test_max_prints_per_second_drops_and_records() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.lock().unwrap().flush()
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(40)
+            .markup(false)
+            .max_prints_per_second(2)
+            .file(Box::new(buffer.clone()))
+            .build();
+        assert_eq!(console.max_prints_per_second(), Some(2));
+
+        console.begin_capture();
+        for i in 0..5 {
+            console.print_plain(&format!("line {i}"));
+        }
+        console.flush_suppressed();
+        let captured = Console::segments_to_plain(&console.end_capture());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "line 0\nline 1\n\u{2026} suppressed 3 lines\n");
+        assert_eq!(captured.lines().count(), 5);
+
+        // Nothing left to report.
+        console.flush_suppressed();
+        assert_eq!(buffer.0.lock().unwrap().len(), output.len());
+    }
+
+    #[test]
+    fn test_batch_matches_separate_prints() {
+        use crate::renderables::Rule;
+
+        let console = Console::builder().width(20).build();
+        console.begin_capture();
+        console.print("[bold]one[/]");
+        console.print_renderable(&Rule::new());
+        console.print_plain("[two]");
+        let separate = console.end_capture();
+
+        console.begin_capture();
+        let count = console.batch(|batch| {
+            batch.print("[bold]one[/]");
+            batch.print_renderable(&Rule::new());
+            batch.print_plain("[two]");
+            3
+        });
+        assert_eq!(count, 3);
+        assert_eq!(console.end_capture(), separate);
+    }
+
+    #[test]
+    fn test_batch_is_not_interleaved() {
+        use std::sync::{Arc, Mutex};
+
+        /// Records each write call separately.
+        #[derive(Clone, Default)]
+        struct Writes(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Writes {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                // Give other threads a chance to write in between.
+                std::thread::yield_now();
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let output = Writes::default();
+        let console = Arc::new(
+            Console::builder()
+                .markup(false)
+                .file(Box::new(output.clone()))
+                .build(),
+        );
+        let handles: Vec<_> = ["a", "b", "c"]
+            .into_iter()
+            .map(|tag| {
+                let console = Arc::clone(&console);
+                std::thread::spawn(move || {
+                    for _ in 0..5 {
+                        console.batch(|batch| {
+                            for i in 0..4 {
+                                batch.print_plain(&format!("{tag}{i}"));
+                            }
+                        });
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let text = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 60);
+        for block in lines.chunks(4) {
+            let tag = &block[0][..1];
+            let expected: Vec<String> = (0..4).map(|i| format!("{tag}{i}")).collect();
+            assert_eq!(block, expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_serialize_output_orders_consoles_sharing_a_stream() {
+        use std::sync::{Arc, Mutex};
+
+        /// A stream shared by several consoles that yields between writes.
+        #[derive(Clone, Default)]
+        struct Shared(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                std::thread::yield_now();
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let output = Shared::default();
+        let handles: Vec<_> = ["a", "b", "c"]
+            .into_iter()
+            .map(|tag| {
+                let console = Console::builder()
+                    .serialize_output(true)
+                    .color_system(ColorSystem::Standard)
+                    .force_terminal(true)
+                    .highlight(false)
+                    .file(Box::new(output.clone()))
+                    .build();
+                assert!(console.serialize_output());
+                std::thread::spawn(move || {
+                    for _ in 0..20 {
+                        console.print(&format!("[red]{tag}[/] [bold]{tag}[/] [blue]{tag}[/]"));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let text = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        let mut decoder = crate::ansi::AnsiDecoder::new();
+        let lines: Vec<String> = text
+            .lines()
+            .map(|line| decoder.decode_line(line).plain().to_string())
+            .collect();
+        assert_eq!(lines.len(), 60);
+        for line in lines {
+            let tag = &line[..1];
+            assert_eq!(line, format!("{tag} {tag} {tag}"));
+        }
+        assert!(!Console::new().serialize_output());
+    }
+
+    #[test]
+    fn test_flush_policy() {
+        use std::sync::{Arc, Mutex};
 
-        assert_eq!(options.max_width, 80);
-        assert_eq!(options.size.width, 80);
+        /// Keeps written bytes pending until flushed, like a `BufWriter`.
+        #[derive(Clone, Default)]
+        struct FlushTracker {
+            pending: Arc<Mutex<Vec<u8>>>,
+            flushed: Arc<Mutex<Vec<u8>>>,
+        }
+
+        impl Write for FlushTracker {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.pending.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                let mut pending = self.pending.lock().unwrap();
+                self.flushed.lock().unwrap().append(&mut pending);
+                Ok(())
+            }
+        }
+
+        let visible = |tracker: &FlushTracker| {
+            String::from_utf8(tracker.flushed.lock().unwrap().clone()).unwrap()
+        };
+        let console_with = |policy: FlushPolicy| {
+            let (file, tee) = (FlushTracker::default(), FlushTracker::default());
+            let console = Console::builder()
+                .markup(false)
+                .file(Box::new(file.clone()))
+                .tee(Box::new(tee.clone()))
+                .flush_policy(policy)
+                .build();
+            (console, file, tee)
+        };
+
+        assert_eq!(Console::new().flush_policy(), FlushPolicy::Immediate);
+        let (console, file, tee) = console_with(FlushPolicy::Immediate);
+        console.print_segments(&[Segment::new("partial", None)]);
+        assert_eq!(visible(&file), "partial");
+        assert_eq!(visible(&tee), "partial");
+
+        let (console, file, tee) = console_with(FlushPolicy::LineBuffered);
+        console.print_segments(&[Segment::new("partial", None)]);
+        assert_eq!(visible(&file), "");
+        console.print_plain(" line");
+        assert_eq!(visible(&file), "partial line\n");
+        assert_eq!(visible(&tee), "partial line\n");
+
+        let (console, file, tee) = console_with(FlushPolicy::Manual);
+        console.print_plain("one");
+        console.print_plain("two");
+        assert_eq!(visible(&file), "");
+        console.flush().unwrap();
+        assert_eq!(visible(&file), "one\ntwo\n");
+        assert_eq!(visible(&tee), "one\ntwo\n");
+    }
+
+    /// Wait until `watcher` has finished `count` more polls of its file.
+    ///
+    /// Two polls guarantee at least one full read started after the caller's
+    /// last write.
+    #[cfg(feature = "watch")]
+    fn wait_for_theme_polls(watcher: &ThemeWatcher, count: usize) {
+        let target = watcher.polls.load(Ordering::Relaxed) + count;
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while watcher.polls.load(Ordering::Relaxed) < target {
+            assert!(Instant::now() < deadline, "theme watcher stopped polling");
+            std::thread::sleep(Duration::from_millis(20));
+        }
     }
 
+    #[cfg(feature = "watch")]
     #[test]
-    fn test_print_options() {
-        let options = PrintOptions::new()
-            .with_markup(true)
-            .with_style(Style::new().bold());
+    fn test_watch_theme_reloads_on_change() {
+        let path =
+            std::env::temp_dir().join(format!("rich_rust_watch_theme_{}.ini", std::process::id()));
+        std::fs::write(&path, "[styles]\nwatched = red\n").unwrap();
+
+        let console = Arc::new(Console::new());
+        let watcher = console.watch_theme(&path).unwrap();
+        assert_eq!(console.get_style("watched"), Style::parse("red").unwrap());
+        // Default styles are still inherited.
+        assert!(!console.get_style("repr.number").is_null());
+
+        // Broken edits keep the last good theme.
+        std::fs::write(&path, "watched = blue\n").unwrap();
+        wait_for_theme_polls(&watcher, 2);
+        assert_eq!(console.get_style("watched"), Style::parse("red").unwrap());
+
+        std::fs::write(&path, "[styles]\nwatched = blue\n").unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while console.get_style("watched") != Style::parse("blue").unwrap() {
+            assert!(Instant::now() < deadline, "theme was not reloaded");
+            std::thread::sleep(Duration::from_millis(20));
+        }
 
-        assert_eq!(options.markup, Some(true));
-        assert!(options.style.is_some());
+        // Dropping the watcher joins its thread, so later edits are never read.
+        drop(watcher);
+        std::fs::write(&path, "[styles]\nwatched = green\n").unwrap();
+        assert_eq!(console.get_style("watched"), Style::parse("blue").unwrap());
+        let _ = std::fs::remove_file(&path);
     }
 
+    #[cfg(feature = "watch")]
     #[test]
-    fn test_capture() {
-        let console = Console::new();
-        console.begin_capture();
+    fn test_watch_theme_never_replaces_another_theme() {
+        let path = std::env::temp_dir().join(format!(
+            "rich_rust_watch_theme_popped_{}.ini",
+            std::process::id()
+        ));
+        std::fs::write(&path, "[styles]\nwatched = red\n").unwrap();
+
+        let console = Arc::new(Console::new());
+        let watcher = console.watch_theme(&path).unwrap();
+        // A different theme takes the watched theme's place on the stack.
+        console.pop_theme().unwrap();
+        console.push_theme(
+            Theme::from_style_definitions([("other", "green")], true).unwrap(),
+            true,
+        );
 
-        console.print_plain("capture test");
-        let segments = console.end_capture();
-        let captured: String = segments.iter().map(|s| s.text.as_ref()).collect();
-        assert!(captured.contains("capture test"));
+        std::fs::write(&path, "[styles]\nwatched = blue\n").unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !watcher
+            .handle
+            .as_ref()
+            .is_some_and(std::thread::JoinHandle::is_finished)
+        {
+            assert!(Instant::now() < deadline, "theme watcher kept running");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(console.get_style("other"), Style::parse("green").unwrap());
+        assert!(console.try_get_style("watched").is_err());
+        let _ = std::fs::remove_file(&path);
     }
 
     #[test]
@@ -2406,6 +4541,226 @@ mod tests {
         assert!(captured.contains("ErrorType: boom"));
     }
 
+    #[test]
+    fn test_title_stack_push_pop() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.lock().unwrap().flush()
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder().file(Box::new(buffer.clone())).build();
+
+        assert!(!console.pop_title());
+        assert_eq!(console.title(), None);
+
+        console.set_title("shell");
+        console.push_title("build");
+        console.push_title("test");
+        assert_eq!(console.title().as_deref(), Some("test"));
+
+        assert!(console.pop_title());
+        assert_eq!(console.title().as_deref(), Some("build"));
+        assert!(console.pop_title());
+        assert_eq!(console.title().as_deref(), Some("shell"));
+        assert!(!console.pop_title());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let titles: Vec<&str> = output
+            .split("\x1b]0;")
+            .skip(1)
+            .map(|t| t.trim_end_matches('\x07'))
+            .collect();
+        assert_eq!(titles, ["shell", "build", "test", "build", "shell"]);
+    }
+
+    #[test]
+    fn test_pop_title_without_known_previous_clears_title() {
+        let console = Console::builder().file(Box::new(io::sink())).build();
+        console.push_title("temporary");
+        assert!(console.pop_title());
+        assert_eq!(console.title(), None);
+    }
+
+    #[test]
+    fn test_export_text_with_ansi_uses_requested_color_system() {
+        let console = Console::builder()
+            .color_system(ColorSystem::Standard)
+            .file(Box::new(io::sink()))
+            .build();
+        console.begin_capture();
+        console.print("[#ff8800]orange[/]");
+
+        let truecolor = console.export_text_with_ansi(Some(ColorSystem::TrueColor), false);
+        assert!(truecolor.contains("38;2;255;136;0"), "{truecolor:?}");
+
+        let eight_bit = console.export_text_with_ansi(Some(ColorSystem::EightBit), false);
+        assert!(eight_bit.contains("38;5;"), "{eight_bit:?}");
+
+        let plain = console.export_text_with_ansi(None, true);
+        assert_eq!(plain, "orange\n");
+        assert!(console.export_text_with_ansi(None, false).is_empty());
+    }
+
+    #[test]
+    fn test_export_renderable_with_ansi() {
+        use crate::renderables::Rule;
+
+        let console = Console::builder().width(10).build();
+        let rule = Rule::new().style(Style::parse("red").unwrap());
+        let colored = console.export_renderable_with_ansi(&rule, Some(ColorSystem::Standard));
+        assert!(colored.contains("\x1b[31m"), "{colored:?}");
+        let plain = console.export_renderable_with_ansi(&rule, None);
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_blink_and_conceal_emitted_by_default() {
+        let console = Console::builder().build();
+        assert!(console.supports_blink() && console.supports_conceal());
+        let text = Text::styled("hi", Style::parse("blink conceal").unwrap());
+        assert_eq!(
+            console.export_renderable_with_ansi(&text, Some(ColorSystem::Standard)),
+            "\x1b[5;8mhi\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_blink_and_conceal_gating() {
+        let render = |console: &Console, style: &str| {
+            let text = Text::styled("hi", Style::parse(style).unwrap());
+            console.export_renderable_with_ansi(&text, Some(ColorSystem::Standard))
+        };
+
+        let capable = Console::builder().blink(true).conceal(true).build();
+        assert_eq!(render(&capable, "blink"), "\x1b[5mhi\x1b[0m");
+        assert_eq!(render(&capable, "conceal"), "\x1b[8mhi\x1b[0m");
+        assert!(capable.supports_blink() && capable.supports_conceal());
+
+        let limited = Console::builder().blink(false).conceal(false).build();
+        assert_eq!(render(&limited, "blink red"), "\x1b[31mhi\x1b[0m");
+        assert_eq!(render(&limited, "conceal"), "  ");
+        assert!(!limited.supports_blink() && !limited.supports_conceal());
+
+        let no_blink = Console::builder().blink(true).no_blink(true).build();
+        assert_eq!(render(&no_blink, "blink2"), "\x1b[1mhi\x1b[0m");
+        assert!(!no_blink.supports_blink());
+    }
+
+    #[test]
+    fn test_accessible_console() {
+        use crate::renderables::{Panel, Rule};
+
+        let console = Console::builder()
+            .force_terminal(true)
+            .blink(true)
+            .accessible(true)
+            .width(20)
+            .build();
+        assert!(console.is_accessible());
+        assert!(console.safe_box());
+        assert!(!console.supports_blink());
+        assert!(!console.is_interactive());
+
+        console.begin_capture();
+        console.print_renderable(&Rule::with_title("Intro"));
+        console.print_renderable(&Rule::new());
+        console.print_renderable(&Panel::from_text("Body").title("Note"));
+        let output: String = console
+            .end_capture()
+            .iter()
+            .map(|segment| segment.text.as_ref())
+            .collect();
+        assert_eq!(
+            output,
+            "Section: Intro\nSection break\nPanel: Note\nBody\nEnd of panel\n"
+        );
+
+        let explicit = Console::builder().accessible(true).safe_box(false).build();
+        assert!(!explicit.safe_box());
+    }
+
+    #[test]
+    fn test_hyperlinks_enabled_by_default() {
+        let console = Console::builder()
+            .color_system(ColorSystem::TrueColor)
+            .build();
+        assert!(console.supports_hyperlinks());
+        assert!(Console::new().supports_hyperlinks());
+
+        let mut output = Vec::new();
+        console
+            .print_to(
+                &mut output,
+                "[link=https://example.com]x[/link]",
+                &PrintOptions::new(),
+            )
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("\x1b]8;"), "{output:?}");
+    }
+
+    #[test]
+    fn test_print_link_emits_osc8_with_id() {
+        let console = Console::builder()
+            .color_system(ColorSystem::TrueColor)
+            .hyperlinks(true)
+            .build();
+        console.begin_capture();
+        console.print_link("docs", "https://example.com");
+        let segments = console.end_capture();
+        let mut output = Vec::new();
+        console
+            .write_segments_raw(&mut output, &segments, None)
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(
+            output.contains("\x1b]8;id=rich-") && output.contains(";https://example.com\x1b\\"),
+            "{output:?}"
+        );
+    }
+
+    #[test]
+    fn test_print_link_falls_back_to_visible_url() {
+        let console = Console::builder()
+            .color_system(ColorSystem::TrueColor)
+            .hyperlinks(false)
+            .build();
+        console.begin_capture();
+        console.print_link("docs", "https://example.com");
+        console.print_link("https://example.com", "https://example.com");
+        let plain = Console::segments_to_plain(&console.end_capture());
+        assert_eq!(plain, "docs (https://example.com)\nhttps://example.com\n");
+    }
+
+    #[test]
+    fn test_hyperlinks_disabled_strips_markup_links() {
+        let console = Console::builder()
+            .color_system(ColorSystem::TrueColor)
+            .hyperlinks(false)
+            .build();
+        let mut output = Vec::new();
+        console
+            .print_to(
+                &mut output,
+                "[bold][link=https://example.com]x[/link][/bold]",
+                &PrintOptions::new(),
+            )
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("\x1b]8;"), "{output:?}");
+        assert!(output.contains("\x1b[1m"), "{output:?}");
+    }
+
     #[test]
     fn test_dimensions() {
         let dims = ConsoleDimensions::default();
@@ -2504,6 +4859,23 @@ mod tests {
         assert_eq!(output, "hi 😄\n");
     }
 
+    #[test]
+    fn test_print_options_emoji_override() {
+        let console = Console::builder().markup(false).build();
+        let literal = console.export_text_with_options(
+            "*/5 * * * * :smile:",
+            &PrintOptions::new().with_markup(false).with_emoji(false),
+        );
+        assert_eq!(literal, "*/5 * * * * :smile:\n");
+
+        let disabled = Console::builder().markup(false).emoji(false).build();
+        let replaced = disabled.export_text_with_options(
+            ":smile:",
+            &PrintOptions::new().with_markup(false).with_emoji(true),
+        );
+        assert_eq!(replaced, "\u{1f604}\n");
+    }
+
     #[test]
     fn test_export_text_does_not_replace_emoji_codes_when_disabled() {
         let console = Console::builder().markup(false).emoji(false).build();
@@ -2568,6 +4940,66 @@ mod tests {
         assert!(!cleared.contains("Hello"));
     }
 
+    #[test]
+    fn test_export_markdown_inline_formatting() {
+        let code_style = Style::parse("bold cyan on black").unwrap();
+        let segments = vec![
+            Segment::new("Bold", Some(Style::new().bold())),
+            Segment::new(" and ", None),
+            Segment::new("both ", Some(Style::new().bold().italic())),
+            Segment::new("gone", Some(Style::new().strike())),
+            Segment::new(", ", None),
+            Segment::new("cargo test", Some(code_style.clone())),
+            Segment::new(" ", None),
+            Segment::new("docs", Some(Style::new().link("https://example.com"))),
+            Segment::line(),
+            Segment::new("2 * 3 = [6]", Some(Style::parse("red").unwrap())),
+            Segment::line(),
+        ];
+        let recorded: Vec<RecordedSegment> = segments
+            .into_iter()
+            .map(|segment| RecordedSegment::new(&segment, None))
+            .collect();
+        assert_eq!(
+            export_segments_to_markdown(&recorded, &code_style),
+            "**Bold** and ***both*** ~~gone~~, `cargo test` [docs](https://example.com)\n\
+             2 \\* 3 = \\[6\\]\n"
+        );
+    }
+
+    #[test]
+    fn test_export_markdown_tables_and_panels() {
+        use crate::renderables::{Column, Panel, Table};
+
+        let console = Console::builder()
+            .width(40)
+            .file(Box::new(io::sink()))
+            .build();
+        console.begin_capture();
+        console.print_plain("Report");
+        let table = Table::new()
+            .with_column(Column::new("Name"))
+            .with_column(Column::new("Count"))
+            .with_row_cells(["apples", "3"])
+            .with_row_cells(["a|b", "4"]);
+        console.print_renderable(&table);
+        console.print_renderable(&Panel::from_text("boxed"));
+        console.print_plain("\u{250c}\u{2500}\u{252c}\u{2500}\u{2510}\n\u{2502}x\u{2502}y\u{2502}");
+
+        let markdown = console.export_markdown(true);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "Report");
+        assert_eq!(lines[1], "| Name | Count |");
+        assert_eq!(lines[2], "| --- | --- |");
+        assert_eq!(lines[3], "| apples | 3 |");
+        assert_eq!(lines[4], "| a\\|b | 4 |");
+        // Boxes that didn't come from a table stay as drawn.
+        assert!(lines[5].starts_with('\u{256d}'), "{markdown}");
+        assert!(lines[6].contains("boxed"), "{markdown}");
+        assert_eq!(lines[9], "\u{2502}x\u{2502}y\u{2502}");
+        assert!(console.export_markdown(false).is_empty());
+    }
+
     #[test]
     fn test_escape_html_entities() {
         let escaped = escape_html_rich("<>&\"'");
@@ -2661,6 +5093,59 @@ mod tests {
         assert!(!html.contains("Bell"));
     }
 
+    #[test]
+    fn test_export_applies_control_codes() {
+        use crate::segment::{ControlCode, ControlType};
+
+        let console = Console::builder().markup(false).highlight(false).build();
+        let record = |console: &Console| {
+            console.begin_capture();
+            console.print_segments(&[
+                Segment::plain("working 10%"),
+                Segment::control(vec![
+                    ControlCode::new(ControlType::CarriageReturn),
+                    ControlCode::with_params_vec(ControlType::EraseInLine, vec![2]),
+                ]),
+                Segment::plain("done"),
+                Segment::line(),
+            ]);
+        };
+
+        record(&console);
+        let text = console.export_text_with_ansi_options(&ExportTextOptions {
+            apply_control_codes: true,
+            ..ExportTextOptions::default()
+        });
+        assert_eq!(text, "done\n");
+
+        record(&console);
+        assert_eq!(
+            console.export_text_with_ansi(None, true),
+            "working 10%done\n"
+        );
+
+        record(&console);
+        let html = console.export_html_with_options(&ExportHtmlOptions {
+            code_format: Some("{code}".to_string()),
+            apply_control_codes: true,
+            ..ExportHtmlOptions::default()
+        });
+        assert_eq!(html, "done\n");
+    }
+
+    #[test]
+    fn test_export_theme_used_by_default_exports() {
+        let theme = TerminalTheme::from_xresources("*.background: #1d1f21").unwrap();
+        let console = Console::builder().export_theme(theme).build();
+        assert_eq!(console.export_theme(), Some(theme));
+        assert_eq!(Console::new().export_theme(), None);
+
+        console.begin_capture();
+        console.print_plain("hi");
+        assert!(console.export_html(false).contains("#1d1f21"));
+        assert!(console.export_svg(true).contains("#1d1f21"));
+    }
+
     #[test]
     fn test_export_svg_dimensions() {
         let segments = vec![Segment::plain("AB"), Segment::line(), Segment::plain("C")];
@@ -2735,6 +5220,41 @@ mod tests {
         assert_eq!(text, "    Hi    ");
     }
 
+    #[test]
+    fn test_console_default_justify_and_overflow() {
+        let console = Console::builder()
+            .width(10)
+            .markup(false)
+            .justify(JustifyMethod::Center)
+            .overflow(OverflowMethod::Ellipsis)
+            .build();
+        let options = console.options();
+        assert_eq!(options.justify, Some(JustifyMethod::Center));
+        assert_eq!(options.overflow, Some(OverflowMethod::Ellipsis));
+
+        let mut output = Vec::new();
+        let mut print_options = PrintOptions::new();
+        print_options.no_newline = true;
+        console
+            .print_to(&mut output, "Hi", &print_options)
+            .expect("failed to render");
+        assert_eq!(
+            String::from_utf8(output).expect("invalid utf8"),
+            "    Hi    "
+        );
+
+        // Per-print options still win.
+        let mut output = Vec::new();
+        let print_options = print_options.with_justify(JustifyMethod::Right);
+        console
+            .print_to(&mut output, "Hi", &print_options)
+            .expect("failed to render");
+        assert_eq!(
+            String::from_utf8(output).expect("invalid utf8"),
+            "        Hi"
+        );
+    }
+
     #[test]
     fn test_print_options_width_wraps() {
         let console = Console::builder().width(80).markup(false).build();
@@ -3151,6 +5671,44 @@ mod tests {
         assert!(text.contains("Writer test"));
     }
 
+    #[test]
+    fn test_out_writes_text_verbatim() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.lock().unwrap().flush()
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(10)
+            .markup(true)
+            .emoji(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+
+        let line = "[bold]:smile: 127.0.0.1 is wider than ten cells";
+        console.begin_capture();
+        console.out(line);
+        let captured: String = console
+            .end_capture()
+            .iter()
+            .map(|segment| segment.text.as_ref())
+            .collect();
+        assert_eq!(captured, format!("{line}\n"));
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, format!("{line}\n"));
+    }
+
     #[test]
     fn test_print_segments() {
         use std::sync::{Arc, Mutex};
@@ -3243,6 +5801,33 @@ mod tests {
         assert!(result.contains("Section"));
     }
 
+    #[test]
+    fn test_rule_is_recorded_with_theme_style() {
+        let console = Console::builder()
+            .width(20)
+            .force_terminal(true)
+            .file(Box::new(io::sink()))
+            .build();
+        console.begin_capture();
+        console.rule(Some("[bold]Hi[/]"));
+
+        let segments = console.end_capture();
+        let line_style = console.get_style("rule.line");
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(text, "──────── Hi ────────\n");
+        assert!(
+            segments
+                .iter()
+                .any(|s| s.text.starts_with('─') && s.style.as_ref() == Some(&line_style))
+        );
+        assert!(segments.iter().any(|s| {
+            s.text == "Hi"
+                && s.style
+                    .as_ref()
+                    .is_some_and(|style| style.attributes.contains(Attributes::BOLD))
+        }));
+    }
+
     // ========== Log Method Tests ==========
 
     #[test]
@@ -3667,7 +6252,7 @@ mod tests {
 
         let text = String::from_utf8(output).expect("invalid utf8");
         assert!(text.ends_with("Right"));
-        assert!(text.len() == 20);
+        assert_eq!(text.len(), 20);
     }
 
     // ========== ConsoleDimensions Tests ==========
@@ -32,6 +32,12 @@ pub struct HighlighterRegexError {
     message: String,
 }
 
+impl HighlighterRegexError {
+    pub(crate) fn new(pattern: String, message: String) -> Self {
+        Self { pattern, message }
+    }
+}
+
 impl std::fmt::Display for HighlighterRegexError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
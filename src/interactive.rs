@@ -195,17 +195,25 @@
 //! - **bd-1jm0**: Add `max_length` to `Prompt` builder
 //! - **bd-fal7**: Wire `read_line_limited` into `Prompt::ask_from`
 
+use std::fs::{self, OpenOptions};
 use std::io;
-use std::io::Write as _;
+use std::io::{IsTerminal as _, Write as _};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use crate::console::Console;
 use crate::console::PrintOptions;
+use crate::highlighter::{Highlighter, HighlighterRegexError};
 use crate::live::{Live, LiveOptions};
 use crate::markup;
+use crate::renderables::{Panel, Renderable, Spinner};
+use crate::segment::{self, ControlCode, ControlType, Segment};
 use crate::style::Style;
+use crate::sync::lock_recover;
+use crate::terminal::{self, Input, Key};
 use crate::text::Text;
 
 /// Default maximum input length for interactive prompts (64 KiB).
@@ -298,29 +306,27 @@ pub const DEFAULT_MAX_INPUT_LENGTH: usize = 64 * 1024;
 /// 3. New test: `update()` after `Live` has stopped (no-op, no crash).
 pub struct Status {
     message: Arc<Mutex<String>>,
+    spinner: Arc<Mutex<Spinner>>,
     live: Option<Live>,
 }
 
 impl Status {
     /// Start a status spinner with a message.
+    ///
+    /// See also [`Console::status`], which never fails.
     pub fn new(console: &Arc<Console>, message: impl Into<String>) -> io::Result<Self> {
-        let message = Arc::new(Mutex::new(message.into()));
-
+        let message = message.into();
         if !console.is_interactive() {
-            {
-                let message = crate::sync::lock_recover(&message);
-                console.print_plain(&message);
-            }
-            return Ok(Self {
-                message,
-                live: None,
-            });
+            return Ok(Self::printed(console, message));
         }
 
+        let message = Arc::new(Mutex::new(message));
+        let spinner = Arc::new(Mutex::new(
+            Spinner::simple().style(console.get_style("status.spinner")),
+        ));
         let start = Instant::now();
-        let frames: [&str; 4] = ["|", "/", "-", "\\"];
-        let frame_interval = Duration::from_millis(100);
         let message_for_render = Arc::clone(&message);
+        let spinner_for_render = Arc::clone(&spinner);
 
         let live_options = LiveOptions {
             refresh_per_second: 10.0,
@@ -330,22 +336,59 @@ impl Status {
 
         let live =
             Live::with_options(Arc::clone(console), live_options).get_renderable(move || {
-                let elapsed = start.elapsed();
-                let tick = elapsed.as_millis() / frame_interval.as_millis().max(1);
-                let idx = (tick as usize) % frames.len();
-                let frame = frames[idx];
-                let msg = crate::sync::lock_recover(&message_for_render).clone();
-                Box::new(Text::new(format!("{frame} {msg}")))
+                // Frame timing comes from the spinner, not the refresh rate.
+                let frame =
+                    crate::sync::lock_recover(&spinner_for_render).render_at(start.elapsed());
+                let mut text = Text::new("");
+                text.append_styled(&frame.text, frame.style.unwrap_or_default());
+                text.append(" ");
+                text.append(&crate::sync::lock_recover(&message_for_render));
+                Box::new(text)
             });
 
         live.start(true)?;
 
         Ok(Self {
             message,
+            spinner,
             live: Some(live),
         })
     }
 
+    /// A status without a live display: the message is printed once.
+    pub(crate) fn printed(console: &Console, message: String) -> Self {
+        console.print_plain(&message);
+        Self {
+            message: Arc::new(Mutex::new(message)),
+            spinner: Arc::new(Mutex::new(Spinner::simple())),
+            live: None,
+        }
+    }
+
+    /// Switch to a built-in spinner by name (see [`Spinner::named`]).
+    ///
+    /// Unknown names leave the current spinner in place. The spinner keeps
+    /// its style.
+    #[must_use]
+    pub fn spinner(self, name: &str) -> Self {
+        if let Some(spinner) = Spinner::named(name) {
+            let mut current = crate::sync::lock_recover(&self.spinner);
+            let style = current.frame_style(0);
+            *current = spinner.style(style);
+        }
+        self
+    }
+
+    /// Set the spinner style (defaults to the theme's `status.spinner`).
+    #[must_use]
+    pub fn spinner_style(self, style: Style) -> Self {
+        {
+            let mut spinner = crate::sync::lock_recover(&self.spinner);
+            *spinner = spinner.clone().style(style);
+        }
+        self
+    }
+
     /// Update the displayed message.
     ///
     /// # Design Note (RFC bd-gg33)
@@ -382,6 +425,8 @@ pub enum PromptError {
     Validation(String),
     /// I/O error while reading input.
     Io(io::Error),
+    /// No input arrived within the prompt's timeout and there is no default.
+    TimedOut,
     /// Input exceeded the maximum allowed length.
     InputTooLong {
         /// Maximum allowed input length in bytes.
@@ -396,6 +441,7 @@ impl std::fmt::Display for PromptError {
         match self {
             Self::NotInteractive => write!(f, "prompt requires an interactive console"),
             Self::Eof => write!(f, "prompt input reached EOF"),
+            Self::TimedOut => write!(f, "prompt timed out waiting for input"),
             Self::Validation(message) => write!(f, "{message}"),
             Self::Io(err) => write!(f, "{err}"),
             Self::InputTooLong { limit, received } => {
@@ -441,6 +487,308 @@ impl From<io::Error> for PromptError {
 }
 
 type PromptValidator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+type PromptSuggester = Arc<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+/// How a [`Prompt`] treats pasted text.
+///
+/// Any mode other than [`PasteMode::Off`] reads terminal input in raw mode with
+/// bracketed paste enabled, so a paste arrives as one value instead of being
+/// split at its first newline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PasteMode {
+    /// Read input line by line; a multi-line paste submits its first line.
+    #[default]
+    Off,
+    /// Keep newlines from pasted text in the value (shown as `↵` while editing).
+    Preserve,
+    /// Discard pastes that span several lines and ask again.
+    Reject,
+}
+
+/// Default number of entries kept by a [`PromptHistory`].
+pub const DEFAULT_HISTORY_SIZE: usize = 1000;
+
+/// Input history for [`Prompt`], navigable with ↑/↓ when reading from a terminal.
+///
+/// Clones share the same entries, so one history can serve many prompts. A
+/// file-backed history loads existing entries (one per line) and appends each
+/// new answer, so values survive across sessions.
+#[derive(Debug, Clone, Default)]
+pub struct PromptHistory {
+    inner: Arc<Mutex<HistoryInner>>,
+}
+
+#[derive(Debug)]
+struct HistoryInner {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+    max_entries: usize,
+}
+
+impl Default for HistoryInner {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            path: None,
+            max_entries: DEFAULT_HISTORY_SIZE,
+        }
+    }
+}
+
+impl PromptHistory {
+    /// Create an empty in-memory history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a history backed by `path`, loading any entries it already holds.
+    ///
+    /// A missing file is treated as an empty history and created on the first push.
+    pub fn with_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read_to_string(&path) {
+            Ok(content) => content
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        let history = Self::default();
+        {
+            let mut inner = lock_recover(&history.inner);
+            inner.entries = entries;
+            inner.path = Some(path);
+            inner.trim();
+        }
+        Ok(history)
+    }
+
+    /// Set the maximum number of entries kept (oldest are dropped first).
+    #[must_use]
+    pub fn max_entries(self, max_entries: usize) -> Self {
+        {
+            let mut inner = lock_recover(&self.inner);
+            inner.max_entries = max_entries.max(1);
+            inner.trim();
+        }
+        self
+    }
+
+    /// Record an entry.
+    ///
+    /// Empty entries, entries containing newlines, and repeats of the most
+    /// recent entry are ignored.
+    pub fn push(&self, entry: &str) -> io::Result<()> {
+        let mut inner = lock_recover(&self.inner);
+        if entry.is_empty()
+            || entry.contains(['\n', '\r'])
+            || inner.entries.last().is_some_and(|last| last == entry)
+        {
+            return Ok(());
+        }
+        inner.entries.push(entry.to_string());
+        let trimmed = inner.trim();
+        let Some(path) = &inner.path else {
+            return Ok(());
+        };
+        if trimmed {
+            let mut content = inner.entries.join("\n");
+            content.push('\n');
+            fs::write(path, content)
+        } else {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{entry}")
+        }
+    }
+
+    /// The recorded entries, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> Vec<String> {
+        lock_recover(&self.inner).entries.clone()
+    }
+
+    /// Number of recorded entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        lock_recover(&self.inner).entries.len()
+    }
+
+    /// Whether no entries have been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        lock_recover(&self.inner).entries.is_empty()
+    }
+}
+
+impl HistoryInner {
+    /// Drop the oldest entries beyond `max_entries`; returns whether any were dropped.
+    fn trim(&mut self) -> bool {
+        let excess = self.entries.len().saturating_sub(self.max_entries);
+        self.entries.drain(..excess);
+        excess > 0
+    }
+}
+
+/// Outcome of feeding a key to a [`LineEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditOutcome {
+    Continue,
+    Redraw,
+    Submit,
+    Cancel,
+    Eof,
+    /// Tab was pressed; the caller should offer completions.
+    Complete,
+    /// Input was discarded; the message explains why.
+    Rejected(&'static str),
+    /// No key arrived before the prompt's timeout.
+    TimedOut,
+}
+
+/// Single-line editor used by [`Prompt`] in raw mode.
+#[derive(Debug)]
+struct LineEditor {
+    buffer: String,
+    history: Vec<String>,
+    /// Index into `history` while browsing; `None` when editing the draft.
+    position: Option<usize>,
+    draft: String,
+    max_length: usize,
+}
+
+impl LineEditor {
+    fn new(history: Vec<String>, max_length: usize) -> Self {
+        Self {
+            buffer: String::new(),
+            history,
+            position: None,
+            draft: String::new(),
+            max_length,
+        }
+    }
+
+    fn handle_key(&mut self, key: Key) -> EditOutcome {
+        match key {
+            Key::Enter => EditOutcome::Submit,
+            Key::Tab => EditOutcome::Complete,
+            Key::CtrlC => EditOutcome::Cancel,
+            Key::Ctrl('d') if self.buffer.is_empty() => EditOutcome::Eof,
+            Key::Ctrl('u') => {
+                self.buffer.clear();
+                EditOutcome::Redraw
+            }
+            Key::Char(c) => {
+                if self.buffer.len() + c.len_utf8() > self.max_length {
+                    return EditOutcome::Continue;
+                }
+                self.buffer.push(c);
+                EditOutcome::Redraw
+            }
+            Key::Backspace => {
+                if self.buffer.pop().is_some() {
+                    EditOutcome::Redraw
+                } else {
+                    EditOutcome::Continue
+                }
+            }
+            Key::Up => self.history_previous(),
+            Key::Down => self.history_next(),
+            _ => EditOutcome::Continue,
+        }
+    }
+
+    fn handle_paste(&mut self, text: &str, mode: PasteMode) -> EditOutcome {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let text = match mode {
+            PasteMode::Reject if text.trim_end_matches('\n').contains('\n') => {
+                return EditOutcome::Rejected("Pasted text must be a single line.");
+            }
+            PasteMode::Preserve => text.as_str(),
+            _ => text.trim_end_matches('\n'),
+        };
+        if self.buffer.len() + text.len() > self.max_length {
+            return EditOutcome::Rejected("Pasted text is too long.");
+        }
+        self.buffer.push_str(text);
+        EditOutcome::Redraw
+    }
+
+    /// Apply completion `candidates` to the buffer.
+    ///
+    /// A single candidate replaces the buffer; several extend it to their
+    /// longest common prefix. Returns whether the buffer changed.
+    fn complete(&mut self, candidates: &[String]) -> bool {
+        let completion = match candidates {
+            [] => return false,
+            [only] => only.as_str(),
+            _ => {
+                let prefix = common_prefix(candidates);
+                if !prefix.starts_with(self.buffer.as_str()) {
+                    return false;
+                }
+                prefix
+            }
+        };
+        if completion == self.buffer
+            || completion.contains(['\n', '\r'])
+            || completion.len() > self.max_length
+        {
+            return false;
+        }
+        self.buffer = completion.to_string();
+        true
+    }
+
+    fn history_previous(&mut self) -> EditOutcome {
+        let position = match self.position {
+            None if self.history.is_empty() => return EditOutcome::Continue,
+            None => {
+                self.draft = std::mem::take(&mut self.buffer);
+                self.history.len() - 1
+            }
+            Some(0) => return EditOutcome::Continue,
+            Some(position) => position - 1,
+        };
+        self.position = Some(position);
+        self.buffer.clone_from(&self.history[position]);
+        EditOutcome::Redraw
+    }
+
+    fn history_next(&mut self) -> EditOutcome {
+        match self.position {
+            None => return EditOutcome::Continue,
+            Some(position) if position + 1 < self.history.len() => {
+                self.position = Some(position + 1);
+                self.buffer.clone_from(&self.history[position + 1]);
+            }
+            Some(_) => {
+                self.position = None;
+                self.buffer = std::mem::take(&mut self.draft);
+            }
+        }
+        EditOutcome::Redraw
+    }
+}
+
+/// Longest prefix shared by all `candidates`, cut at a character boundary.
+fn common_prefix(candidates: &[String]) -> &str {
+    let Some((first, rest)) = candidates.split_first() else {
+        return "";
+    };
+    let mut end = first.len();
+    for candidate in rest {
+        let shared = first
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or_else(|| first.len().min(candidate.len()), |((index, _), _)| index);
+        end = end.min(shared);
+    }
+    &first[..end]
+}
 
 /// Prompt configuration.
 #[derive(Clone)]
@@ -451,7 +799,12 @@ pub struct Prompt {
     show_default: bool,
     markup: bool,
     validator: Option<PromptValidator>,
+    suggester: Option<PromptSuggester>,
     max_length: usize,
+    history: Option<PromptHistory>,
+    paste_mode: PasteMode,
+    password: bool,
+    timeout: Option<Duration>,
 }
 
 impl std::fmt::Debug for Prompt {
@@ -464,6 +817,11 @@ impl std::fmt::Debug for Prompt {
             .field("markup", &self.markup)
             .field("max_length", &self.max_length)
             .field("validator", &self.validator.as_ref().map(|_| "<validator>"))
+            .field("suggester", &self.suggester.as_ref().map(|_| "<suggester>"))
+            .field("history", &self.history)
+            .field("paste_mode", &self.paste_mode)
+            .field("password", &self.password)
+            .field("timeout", &self.timeout)
             .finish()
     }
 }
@@ -479,7 +837,12 @@ impl Prompt {
             show_default: true,
             markup: true,
             validator: None,
+            suggester: None,
             max_length: DEFAULT_MAX_INPUT_LENGTH,
+            history: None,
+            paste_mode: PasteMode::Off,
+            password: false,
+            timeout: None,
         }
     }
 
@@ -531,8 +894,77 @@ impl Prompt {
         self
     }
 
+    /// Offer suggestions for partial input.
+    ///
+    /// `suggester` receives the text typed so far and returns candidate values.
+    /// When stdin is a terminal, Tab completes to the only candidate (or the
+    /// longest prefix shared by several) and lists the candidates otherwise.
+    /// When input is rejected, the candidates for it are printed before the
+    /// prompt is shown again.
+    #[must_use]
+    pub fn suggest<F>(mut self, suggester: F) -> Self
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.suggester = Some(Arc::new(suggester));
+        self
+    }
+
+    /// Record accepted answers in `history`.
+    ///
+    /// When stdin is a terminal, [`Prompt::ask`] reads input in raw mode so
+    /// ↑/↓ can recall earlier entries.
+    #[must_use]
+    pub fn history(mut self, history: PromptHistory) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Capture pastes with bracketed paste mode when stdin is a terminal.
+    #[must_use]
+    pub const fn paste_mode(mut self, mode: PasteMode) -> Self {
+        self.paste_mode = mode;
+        self
+    }
+
+    /// Hide the input, e.g. when asking for a secret.
+    ///
+    /// When stdin is a terminal, input is read in raw mode so nothing is
+    /// echoed and each character shows as `*`. Password answers are never
+    /// recorded in [history](Prompt::history) or completed with Tab.
+    #[must_use]
+    pub const fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    /// Stop waiting for input after `timeout`, for unattended scripts.
+    ///
+    /// When the time is up [`Prompt::ask`] returns the default value, or
+    /// [`PromptError::TimedOut`] when there is none. The window covers the
+    /// whole prompt, including re-prompts after invalid input; a line typed
+    /// after the prompt gave up answers the next prompt.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Ask for input using stdin.
     pub fn ask(&self, console: &Console) -> Result<String, PromptError> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        if (self.password
+            || self.history.is_some()
+            || self.suggester.is_some()
+            || self.paste_mode != PasteMode::Off)
+            && console.is_terminal()
+            && io::stdin().is_terminal()
+        {
+            return self.ask_raw(console, deadline);
+        }
+        if deadline.is_some() || stdin_read_by_thread() {
+            return self.ask_lines(console, || read_stdin_line(self.max_length, deadline));
+        }
         let stdin = io::stdin();
         let mut reader = stdin.lock();
         self.ask_from(console, &mut reader)
@@ -543,6 +975,18 @@ impl Prompt {
         &self,
         console: &Console,
         reader: &mut R,
+    ) -> Result<String, PromptError> {
+        self.ask_lines(console, || {
+            read_line_limited(reader, self.max_length).map(Some)
+        })
+    }
+
+    /// Prompt until `next_line` yields acceptable input; it returns `None`
+    /// once the timeout has passed.
+    fn ask_lines(
+        &self,
+        console: &Console,
+        mut next_line: impl FnMut() -> Result<Option<String>, PromptError>,
     ) -> Result<String, PromptError> {
         if !console.is_terminal() {
             return self.default.clone().ok_or(PromptError::NotInteractive);
@@ -551,29 +995,175 @@ impl Prompt {
         loop {
             self.print_prompt(console);
 
-            let line = read_line_limited(reader, self.max_length)?;
-            let input = trim_newline(&line);
-            let mut value = if input.is_empty() {
-                self.default.clone().unwrap_or_default()
-            } else {
-                input.to_string()
+            let Some(line) = next_line()? else {
+                print_exact(console, "\n");
+                return self.default.clone().ok_or(PromptError::TimedOut);
             };
+            let input = trim_newline(&line);
+            if let Some(value) = self.accept(console, input) {
+                return Ok(value);
+            }
+            self.print_suggestions(console, &self.suggestions(input), "\n");
+        }
+    }
 
-            if value.is_empty() && !self.allow_empty && self.default.is_none() {
-                self.print_error(console, "Input required.");
-                continue;
+    /// Read input in raw mode, recalling history entries with ↑/↓,
+    /// completing with Tab, and capturing bracketed pastes as a whole.
+    fn ask_raw(&self, console: &Console, deadline: Option<Instant>) -> Result<String, PromptError> {
+        loop {
+            self.print_prompt(console);
+            let history = self
+                .history
+                .as_ref()
+                .filter(|_| !self.password)
+                .map(PromptHistory::entries)
+                .unwrap_or_default();
+            let input = match self.read_line_raw(console, history, deadline) {
+                Ok(Some(input)) => input,
+                Ok(None) => continue,
+                Err(PromptError::TimedOut) => {
+                    return self.default.clone().ok_or(PromptError::TimedOut);
+                }
+                Err(err) => return Err(err),
+            };
+            if let Some(value) = self.accept(console, &input) {
+                return Ok(value);
             }
+            self.print_suggestions(console, &self.suggestions(&input), "\n");
+        }
+    }
 
-            if let Some(validator) = &self.validator
-                && let Err(message) = validator(&value)
-            {
-                self.print_error(console, &message);
-                continue;
+    /// Returns `None` when the input was discarded (an error has been printed).
+    fn read_line_raw(
+        &self,
+        console: &Console,
+        history: Vec<String>,
+        deadline: Option<Instant>,
+    ) -> Result<Option<String>, PromptError> {
+        let mut editor = LineEditor::new(history, self.max_length);
+        let outcome = {
+            let _raw = if self.paste_mode == PasteMode::Off {
+                terminal::RawMode::enable()?
+            } else {
+                terminal::RawMode::with_bracketed_paste()?
+            };
+            loop {
+                let input = match deadline {
+                    Some(deadline) => match terminal::read_input_timeout(
+                        deadline.saturating_duration_since(Instant::now()),
+                    )? {
+                        Some(input) => input,
+                        None => break EditOutcome::TimedOut,
+                    },
+                    None => terminal::read_input()?,
+                };
+                let outcome = match input {
+                    Input::Key(key) => editor.handle_key(key),
+                    Input::Paste(text) => editor.handle_paste(&text, self.paste_mode),
+                };
+                match outcome {
+                    EditOutcome::Continue => {}
+                    EditOutcome::Redraw => self.redraw(console, &editor),
+                    EditOutcome::Complete => {
+                        let candidates = self.suggestions(&editor.buffer);
+                        if editor.complete(&candidates) {
+                            self.redraw(console, &editor);
+                        } else if candidates.len() > 1 {
+                            // Raw mode doesn't translate `\n`, so end lines explicitly.
+                            print_exact(console, "\r\n");
+                            self.print_suggestions(console, &candidates, "\r\n");
+                            self.redraw(console, &editor);
+                        }
+                    }
+                    outcome => break outcome,
+                }
             }
+        };
+        print_exact(console, "\n");
+        match outcome {
+            EditOutcome::Cancel => Err(PromptError::Io(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "prompt interrupted",
+            ))),
+            EditOutcome::Eof => Err(PromptError::Eof),
+            EditOutcome::TimedOut => Err(PromptError::TimedOut),
+            EditOutcome::Rejected(message) => {
+                self.print_error(console, message);
+                Ok(None)
+            }
+            _ => Ok(Some(editor.buffer)),
+        }
+    }
+
+    /// Clear the current line and reprint the prompt with the editor's buffer.
+    fn redraw(&self, console: &Console, editor: &LineEditor) {
+        console.print_segments(&[Segment::control(vec![
+            ControlCode::new(ControlType::CarriageReturn),
+            ControlCode::with_params_vec(ControlType::EraseInLine, vec![2]),
+        ])]);
+        self.print_prompt(console);
+        if self.password {
+            print_exact(console, &"*".repeat(editor.buffer.chars().count()));
+        } else {
+            print_exact(console, &editor.buffer.replace('\n', "↵"));
+        }
+    }
+
+    fn suggestions(&self, partial: &str) -> Vec<String> {
+        if self.password {
+            return Vec::new();
+        }
+        self.suggester
+            .as_ref()
+            .map(|suggester| suggester(partial))
+            .unwrap_or_default()
+    }
+
+    /// Print `candidates` on one dim line terminated by `line_end`.
+    fn print_suggestions(&self, console: &Console, candidates: &[String], line_end: &str) {
+        if candidates.is_empty() {
+            return;
+        }
+        let style = Style::parse("dim").unwrap_or_default();
+        console.print_with_options(
+            &candidates.join("  "),
+            &PrintOptions::new()
+                .with_markup(false)
+                .with_no_newline(true)
+                .with_style(style),
+        );
+        print_exact(console, line_end);
+    }
+
+    /// Resolve and validate one line of input, printing any error.
+    ///
+    /// Returns `None` when the prompt should be shown again.
+    fn accept(&self, console: &Console, input: &str) -> Option<String> {
+        let value = if input.is_empty() {
+            self.default.clone().unwrap_or_default()
+        } else {
+            input.to_string()
+        };
+
+        if value.is_empty() && !self.allow_empty && self.default.is_none() {
+            self.print_error(console, "Input required.");
+            return None;
+        }
+
+        if let Some(validator) = &self.validator
+            && let Err(message) = validator(&value)
+        {
+            self.print_error(console, &message);
+            return None;
+        }
 
-            value = value.trim_end().to_string();
-            return Ok(value);
+        if let Some(history) = &self.history
+            && !input.is_empty()
+            && !self.password
+        {
+            let _ = history.push(input);
         }
+        Some(value.trim_end().to_string())
     }
 
     fn print_prompt(&self, console: &Console) {
@@ -669,6 +1259,61 @@ impl Pager {
         }
     }
 
+    /// Display a renderable through the pager, keeping its colors and styles.
+    ///
+    /// The renderable is rendered at the console's width for its color system
+    /// (uncolored when [`allow_color`](Self::allow_color) is off), and `less`
+    /// gets `-R` so the escape codes show as color. When no pager is installed,
+    /// a built-in one shows a screenful at a time: Space or Page Down for the
+    /// next page, Enter or ↓ for the next line, and `q` or Esc to stop.
+    /// Without a terminal the renderable is printed as usual.
+    pub fn show_renderable(
+        &self,
+        console: &Console,
+        renderable: &impl Renderable,
+    ) -> io::Result<()> {
+        if !console.is_terminal() {
+            console.print_renderable(renderable);
+            return Ok(());
+        }
+
+        let color_system = if self.allow_color {
+            console.color_system()
+        } else {
+            None
+        };
+        let content = console.export_renderable_with_ansi(renderable, color_system);
+        let (command, args) = self.resolve_command();
+        match spawn_pager(&command, &args, &content) {
+            Ok(()) => Ok(()),
+            // Quitting the pager early closes its input.
+            Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let segments = renderable
+                    .render(console, &console.options())
+                    .into_iter()
+                    .map(|segment| {
+                        if self.allow_color || segment.is_control() {
+                            segment
+                        } else {
+                            Segment::new(segment.text, None)
+                        }
+                    });
+                let lines = segment::split_lines(segments);
+                if io::stdin().is_terminal() {
+                    page_lines(console, &lines)
+                } else {
+                    for line in &lines {
+                        console.print_segments(line);
+                        console.print_segments(&[Segment::line()]);
+                    }
+                    Ok(())
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     fn resolve_command(&self) -> (String, Vec<String>) {
         let command = self
             .command
@@ -698,47 +1343,416 @@ impl Pager {
     }
 }
 
-fn spawn_pager(command: &str, args: &[String], content: &str) -> io::Result<()> {
-    let mut child = Command::new(command)
-        .args(args)
-        .stdin(Stdio::piped())
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin.write_all(content.as_bytes())?;
-        stdin.flush()?;
-    }
-
-    let _status = child.wait()?;
-    Ok(())
-}
-
-fn print_exact(console: &Console, content: &str) {
-    console.print_with_options(
-        content,
-        &PrintOptions::new().with_markup(false).with_no_newline(true),
-    );
+/// A single search hit inside pager content.
+///
+/// `start` and `end` are character offsets within line `line`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PagerMatch {
+    /// Zero-based line index.
+    pub line: usize,
+    /// Start character offset (inclusive).
+    pub start: usize,
+    /// End character offset (exclusive).
+    pub end: usize,
 }
 
-/// Read a line from input with a maximum byte length limit.
+/// Incremental `/pattern` search state for paged content.
 ///
-/// Unlike `BufRead::read_line`, this function enforces the limit *during* reading
-/// rather than after, preventing memory exhaustion from extremely long input.
+/// This models the search half of a `less`-style pager: the pattern is updated as the
+/// user types, matches are recomputed against the paged lines, and `n` / `N` step
+/// forwards and backwards through the hits (wrapping at either end).
 ///
-/// Returns the line as a `String` (including trailing newline if present).
-/// On EOF with no data, returns `Err(PromptError::Eof)`.
-/// On exceeding the limit, returns `Err(PromptError::InputTooLong)`.
-fn read_line_limited<R: io::BufRead>(
-    reader: &mut R,
-    max_bytes: usize,
-) -> Result<String, PromptError> {
-    let mut buf = Vec::with_capacity(max_bytes.min(1024));
-    let mut total = 0usize;
+/// `PagerSearch` implements [`Highlighter`], so a line can be passed through
+/// [`PagerSearch::highlight_line`] (or the trait directly) to style every hit, with the
+/// current hit drawn in a distinct style.
+#[derive(Debug, Clone)]
+pub struct PagerSearch {
+    pattern: String,
+    regex: Option<fancy_regex::Regex>,
+    matches: Vec<PagerMatch>,
+    current: Option<usize>,
+    match_style: Style,
+    current_style: Style,
+}
 
-    loop {
-        let available = reader.fill_buf()?;
+impl Default for PagerSearch {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            regex: None,
+            matches: Vec::new(),
+            current: None,
+            match_style: Style::new().reverse(),
+            current_style: Style::parse("bold reverse yellow").unwrap_or_default(),
+        }
+    }
+}
+
+impl PagerSearch {
+    /// Create a search for `pattern` (a regular expression, as in `less`).
+    pub fn new(pattern: &str) -> Result<Self, HighlighterRegexError> {
+        let mut search = Self::default();
+        search.set_pattern(pattern)?;
+        Ok(search)
+    }
+
+    /// Set the style applied to every match.
+    #[must_use]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Set the style applied to the current match.
+    #[must_use]
+    pub fn current_style(mut self, style: Style) -> Self {
+        self.current_style = style;
+        self
+    }
+
+    /// The active pattern.
+    #[must_use]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Replace the pattern, clearing previous matches.
+    ///
+    /// An empty pattern clears the search. On error the previous pattern is kept.
+    pub fn set_pattern(&mut self, pattern: &str) -> Result<(), HighlighterRegexError> {
+        let regex = if pattern.is_empty() {
+            None
+        } else {
+            Some(
+                fancy_regex::Regex::new(pattern)
+                    .map_err(|e| HighlighterRegexError::new(pattern.to_string(), e.to_string()))?,
+            )
+        };
+        self.pattern = pattern.to_string();
+        self.regex = regex;
+        self.matches.clear();
+        self.current = None;
+        Ok(())
+    }
+
+    /// Append a typed character to the pattern (incremental search).
+    ///
+    /// Partial patterns that don't compile yet (e.g. a trailing `(`) are kept as text
+    /// with no matches until they become valid.
+    pub fn push_char(&mut self, c: char) {
+        let mut pattern = std::mem::take(&mut self.pattern);
+        pattern.push(c);
+        self.set_pattern_lenient(pattern);
+    }
+
+    /// Remove the last character of the pattern (backspace).
+    pub fn pop_char(&mut self) {
+        let mut pattern = std::mem::take(&mut self.pattern);
+        pattern.pop();
+        self.set_pattern_lenient(pattern);
+    }
+
+    fn set_pattern_lenient(&mut self, pattern: String) {
+        if self.set_pattern(&pattern).is_err() {
+            self.pattern = pattern;
+            self.regex = None;
+            self.matches.clear();
+            self.current = None;
+        }
+    }
+
+    /// Recompute matches against `lines`.
+    ///
+    /// The current match becomes the first hit at or after line `from_line`, wrapping to
+    /// the first hit overall.
+    pub fn find<S: AsRef<str>>(&mut self, lines: &[S], from_line: usize) -> usize {
+        self.matches.clear();
+        self.current = None;
+        let Some(re) = &self.regex else {
+            return 0;
+        };
+
+        for (line_index, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+            for found in re.find_iter(line) {
+                let Ok(found) = found else {
+                    break; // runtime regex error; skip the rest of this line
+                };
+                if found.start() == found.end() {
+                    continue;
+                }
+                self.matches.push(PagerMatch {
+                    line: line_index,
+                    start: line[..found.start()].chars().count(),
+                    end: line[..found.end()].chars().count(),
+                });
+            }
+        }
+
+        if !self.matches.is_empty() {
+            let first = self
+                .matches
+                .iter()
+                .position(|m| m.line >= from_line)
+                .unwrap_or(0);
+            self.current = Some(first);
+        }
+        self.matches.len()
+    }
+
+    /// All matches found by the last [`find`](Self::find).
+    #[must_use]
+    pub fn matches(&self) -> &[PagerMatch] {
+        &self.matches
+    }
+
+    /// The current match, if any.
+    #[must_use]
+    pub fn current(&self) -> Option<PagerMatch> {
+        self.current.map(|index| self.matches[index])
+    }
+
+    /// Move to the next match (`n`), wrapping to the first.
+    pub fn next_match(&mut self) -> Option<PagerMatch> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = Some(self.current.map_or(0, |index| (index + 1) % len));
+        self.current()
+    }
+
+    /// Move to the previous match (`N`), wrapping to the last.
+    pub fn prev_match(&mut self) -> Option<PagerMatch> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = Some(
+            self.current
+                .map_or(len - 1, |index| (index + len - 1) % len),
+        );
+        self.current()
+    }
+
+    /// Style the matches on line `line_index` of the paged content.
+    pub fn highlight_line(&self, line_index: usize, text: &mut Text) {
+        let current = self.current();
+        for m in self.matches.iter().filter(|m| m.line == line_index) {
+            let style = if Some(*m) == current {
+                self.current_style.clone()
+            } else {
+                self.match_style.clone()
+            };
+            text.stylize(m.start, m.end, style);
+        }
+    }
+}
+
+impl Highlighter for PagerSearch {
+    /// Highlight every match of the pattern in `text`, independent of line position.
+    fn highlight(&self, _console: &Console, text: &mut Text) {
+        let Some(re) = &self.regex else {
+            return;
+        };
+        let ranges: Vec<(usize, usize)> = {
+            let plain = text.plain();
+            re.find_iter(plain)
+                .map_while(Result::ok)
+                .filter(|m| m.start() < m.end())
+                .map(|m| {
+                    (
+                        plain[..m.start()].chars().count(),
+                        plain[..m.end()].chars().count(),
+                    )
+                })
+                .collect()
+        };
+        for (start, end) in ranges {
+            text.stylize(start, end, self.match_style.clone());
+        }
+    }
+}
+
+/// Built-in pager: print `lines` a screenful at a time, waiting for a key
+/// between screens.
+fn page_lines(console: &Console, lines: &[Vec<Segment<'_>>]) -> io::Result<()> {
+    let page = console.height().saturating_sub(1).max(1);
+    let mut shown = 0;
+    let mut step = page;
+    loop {
+        let end = (shown + step).min(lines.len());
+        let mut screen = Vec::new();
+        for line in &lines[shown..end] {
+            screen.extend(line.iter().cloned());
+            screen.push(Segment::line());
+        }
+        console.print_segments(&screen);
+        shown = end;
+        if shown == lines.len() {
+            return Ok(());
+        }
+
+        console.print_with_options(
+            "-- More --",
+            &PrintOptions::new()
+                .with_markup(false)
+                .with_no_newline(true)
+                .with_style(Style::new().reverse()),
+        );
+        let key = {
+            let _raw = terminal::RawMode::enable()?;
+            terminal::read_key()?
+        };
+        console.print_segments(&[Segment::control(vec![
+            ControlCode::new(ControlType::CarriageReturn),
+            ControlCode::with_params_vec(ControlType::EraseInLine, vec![2]),
+        ])]);
+        match pager_step(key, page) {
+            Some(lines) => step = lines,
+            None => return Ok(()),
+        }
+    }
+}
+
+/// How many more lines the built-in pager shows after `key`; `None` to quit.
+fn pager_step(key: terminal::Key, page: usize) -> Option<usize> {
+    match key {
+        terminal::Key::Char(' ' | 'f') | terminal::Key::PageDown => Some(page),
+        terminal::Key::Enter | terminal::Key::Down | terminal::Key::Char('j') => Some(1),
+        terminal::Key::Char('q' | 'Q') | terminal::Key::Escape | terminal::Key::CtrlC => None,
+        _ => Some(0),
+    }
+}
+
+fn spawn_pager(command: &str, args: &[String], content: &str) -> io::Result<()> {
+    let mut child = Command::new(command)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(content.as_bytes())?;
+        stdin.flush()?;
+    }
+
+    let _status = child.wait()?;
+    Ok(())
+}
+
+fn print_exact(console: &Console, content: &str) {
+    console.print_with_options(
+        content,
+        &PrintOptions::new().with_markup(false).with_no_newline(true),
+    );
+}
+
+/// Reads lines on a helper thread so a prompt can stop waiting for one.
+///
+/// Lines are only read when requested. A read that timed out stays pending,
+/// and the line it returns answers the next read, timed or not.
+struct TimedLines {
+    requests: mpsc::Sender<usize>,
+    lines: mpsc::Receiver<Result<String, PromptError>>,
+    /// A line was requested but hasn't been received yet.
+    pending: bool,
+}
+
+impl TimedLines {
+    /// Start the reader thread; `read` reads one line of at most the given length.
+    fn spawn<F>(mut read: F) -> Self
+    where
+        F: FnMut(usize) -> Result<String, PromptError> + Send + 'static,
+    {
+        let (requests, requested) = mpsc::channel::<usize>();
+        let (sender, lines) = mpsc::channel();
+        std::thread::spawn(move || {
+            for max_length in requested {
+                if sender.send(read(max_length)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            requests,
+            lines,
+            pending: false,
+        }
+    }
+
+    /// The next line, or `None` if none arrives within `timeout`.
+    fn read_line(
+        &mut self,
+        max_length: usize,
+        timeout: Option<Duration>,
+    ) -> Result<Option<String>, PromptError> {
+        if !self.pending {
+            self.requests
+                .send(max_length)
+                .map_err(|_| PromptError::Eof)?;
+            self.pending = true;
+        }
+        let line = match timeout {
+            Some(timeout) => match self.lines.recv_timeout(timeout) {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => return Ok(None),
+                Err(RecvTimeoutError::Disconnected) => return Err(PromptError::Eof),
+            },
+            None => self.lines.recv().map_err(|_| PromptError::Eof)?,
+        };
+        self.pending = false;
+        line.map(Some)
+    }
+}
+
+/// Stdin, once a prompt with a timeout has read it.
+///
+/// There is one reader for the whole process because a blocked read of stdin
+/// can't be cancelled: after a prompt times out, its read stays pending. From
+/// then on every prompt reads stdin through this reader, so a line typed late
+/// answers the next prompt, timed or not. Reads of stdin outside prompts wait
+/// behind a pending read and miss the line it takes.
+static STDIN_LINES: Mutex<Option<TimedLines>> = Mutex::new(None);
+
+/// A line from stdin, or `None` once `deadline` has passed.
+fn read_stdin_line(
+    max_length: usize,
+    deadline: Option<Instant>,
+) -> Result<Option<String>, PromptError> {
+    lock_recover(&STDIN_LINES)
+        .get_or_insert_with(|| {
+            TimedLines::spawn(|max_length| read_line_limited(&mut io::stdin().lock(), max_length))
+        })
+        .read_line(
+            max_length,
+            deadline.map(|deadline| deadline.saturating_duration_since(Instant::now())),
+        )
+}
+
+/// Whether prompts read stdin through [`STDIN_LINES`] rather than directly.
+fn stdin_read_by_thread() -> bool {
+    lock_recover(&STDIN_LINES).is_some()
+}
+
+/// Read a line from input with a maximum byte length limit.
+///
+/// Unlike `BufRead::read_line`, this function enforces the limit *during* reading
+/// rather than after, preventing memory exhaustion from extremely long input.
+///
+/// Returns the line as a `String` (including trailing newline if present).
+/// On EOF with no data, returns `Err(PromptError::Eof)`.
+/// On exceeding the limit, returns `Err(PromptError::InputTooLong)`.
+fn read_line_limited<R: io::BufRead>(
+    reader: &mut R,
+    max_bytes: usize,
+) -> Result<String, PromptError> {
+    let mut buf = Vec::with_capacity(max_bytes.min(1024));
+    let mut total = 0usize;
+
+    loop {
+        let available = reader.fill_buf()?;
 
         if available.is_empty() {
             // EOF reached
@@ -829,6 +1843,14 @@ impl<S: Into<String>> From<S> for Choice {
 /// Displays numbered choices and allows selection by number or by typing
 /// the choice value directly.
 ///
+/// When there are more choices than fit on the terminal (or than
+/// [`Select::page_size`]), the list is paginated: one page is shown at a time
+/// with a `page 2/5` indicator, and entering `n` / `p` moves to the next or
+/// previous page. Numbers keep referring to the whole list.
+///
+/// With [`Select::arrow_keys`], a terminal instead shows a highlighted cursor
+/// that moves with ↑/↓ and confirms with Enter.
+///
 /// # Examples
 ///
 /// ```rust,ignore
@@ -847,6 +1869,73 @@ pub struct Select {
     show_default: bool,
     markup: bool,
     max_length: usize,
+    page_size: Option<usize>,
+    arrow_keys: bool,
+    timeout: Option<Duration>,
+}
+
+/// Lines kept free below a page of choices (page indicator, prompt, error).
+const SELECT_RESERVED_LINES: usize = 3;
+
+/// Highlighted choice of a [`Select`] navigated with the arrow keys, and the
+/// window of choices scrolled to keep it visible.
+#[derive(Debug)]
+struct ListCursor {
+    index: usize,
+    len: usize,
+    page_size: usize,
+    offset: usize,
+}
+
+impl ListCursor {
+    fn new(index: usize, len: usize, page_size: usize) -> Self {
+        let mut cursor = Self {
+            index,
+            len,
+            page_size: page_size.max(1),
+            offset: 0,
+        };
+        cursor.scroll();
+        cursor
+    }
+
+    /// Move for `key`; returns whether the cursor moved.
+    fn handle_key(&mut self, key: terminal::Key) -> bool {
+        use terminal::Key;
+
+        let last = self.len - 1;
+        let index = match key {
+            Key::Up | Key::Char('k') => self.index.checked_sub(1).unwrap_or(last),
+            Key::Down | Key::Char('j') => {
+                if self.index == last {
+                    0
+                } else {
+                    self.index + 1
+                }
+            }
+            Key::Home => 0,
+            Key::End => last,
+            Key::PageUp => self.index.saturating_sub(self.page_size),
+            Key::PageDown => (self.index + self.page_size).min(last),
+            _ => return false,
+        };
+        let moved = index != self.index;
+        self.index = index;
+        self.scroll();
+        moved
+    }
+
+    fn scroll(&mut self) {
+        if self.index < self.offset {
+            self.offset = self.index;
+        } else if self.index >= self.offset + self.page_size {
+            self.offset = self.index + 1 - self.page_size;
+        }
+    }
+
+    fn visible(&self) -> std::ops::Range<usize> {
+        self.offset..(self.offset + self.page_size).min(self.len)
+    }
 }
 
 impl Select {
@@ -860,6 +1949,9 @@ impl Select {
             show_default: true,
             markup: true,
             max_length: DEFAULT_MAX_INPUT_LENGTH,
+            page_size: None,
+            arrow_keys: false,
+            timeout: None,
         }
     }
 
@@ -912,18 +2004,182 @@ impl Select {
         self
     }
 
+    /// Set how many choices are shown per page.
+    ///
+    /// Defaults to what fits in the console height.
+    #[must_use]
+    pub const fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(if page_size == 0 { 1 } else { page_size });
+        self
+    }
+
+    /// Navigate the choices with a highlighted cursor when stdin is a terminal.
+    ///
+    /// ↑/↓ (or `k`/`j`), Home/End, and Page Up/Down move the cursor, Enter
+    /// selects, and Esc or Ctrl-C cancels. Falls back to numbered input when
+    /// raw mode is unavailable.
+    #[must_use]
+    pub const fn arrow_keys(mut self, arrow_keys: bool) -> Self {
+        self.arrow_keys = arrow_keys;
+        self
+    }
+
+    /// Stop waiting for a selection after `timeout`, returning the default
+    /// choice or [`PromptError::TimedOut`] when there is none.
+    ///
+    /// See [`Prompt::timeout`].
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Ask for selection using stdin.
     pub fn ask(&self, console: &Console) -> Result<String, PromptError> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        if self.arrow_keys
+            && !self.choices.is_empty()
+            && console.is_terminal()
+            && io::stdin().is_terminal()
+            && let Ok(raw) = terminal::RawMode::enable()
+        {
+            return self.ask_arrows(console, raw, deadline);
+        }
+        if deadline.is_some() || stdin_read_by_thread() {
+            return self.ask_lines(console, || read_stdin_line(self.max_length, deadline));
+        }
         let stdin = io::stdin();
         let mut reader = stdin.lock();
         self.ask_from(console, &mut reader)
     }
 
+    /// Move a highlighted cursor with the arrow keys until Enter.
+    fn ask_arrows(
+        &self,
+        console: &Console,
+        raw: terminal::RawMode,
+        deadline: Option<Instant>,
+    ) -> Result<String, PromptError> {
+        let start = self
+            .default
+            .as_deref()
+            .and_then(|default| self.find_choice(default))
+            .and_then(|default| self.choices.iter().position(|c| c.value == default.value))
+            .unwrap_or(0);
+        let mut cursor =
+            ListCursor::new(start, self.choices.len(), self.effective_page_size(console));
+
+        console.print_with_options(
+            &format!(
+                "{} [dim](↑/↓ move, enter select)[/]",
+                if self.markup {
+                    self.label.clone()
+                } else {
+                    markup::escape(&self.label)
+                }
+            ),
+            &PrintOptions::new()
+                .with_markup(true)
+                .with_no_newline(true)
+                .with_highlight(false),
+        );
+        // Raw mode doesn't translate `\n`, so end lines explicitly.
+        print_exact(console, "\r\n");
+        self.draw_cursor_list(console, &cursor, false);
+        let selected = loop {
+            let key = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    match terminal::read_key_timeout(remaining)? {
+                        Some(key) => key,
+                        None => break self.default.clone().ok_or(PromptError::TimedOut),
+                    }
+                }
+                None => terminal::read_key()?,
+            };
+            match key {
+                terminal::Key::Enter => break Ok(self.choices[cursor.index].value.clone()),
+                terminal::Key::Escape | terminal::Key::CtrlC => {
+                    break Err(PromptError::Io(io::Error::new(
+                        io::ErrorKind::Interrupted,
+                        "prompt interrupted",
+                    )));
+                }
+                key => {
+                    if cursor.handle_key(key) {
+                        self.draw_cursor_list(console, &cursor, true);
+                    }
+                }
+            }
+        };
+        drop(raw);
+        selected
+    }
+
+    /// Draw the visible window of choices; `redraw` first moves back over it.
+    fn draw_cursor_list(&self, console: &Console, cursor: &ListCursor, redraw: bool) {
+        let paginated = cursor.len > cursor.page_size;
+        if redraw {
+            let lines = cursor.visible().len() + usize::from(paginated);
+            console.print_segments(&[Segment::control(vec![
+                ControlCode::with_params_vec(
+                    ControlType::CursorUp,
+                    vec![i32::try_from(lines).unwrap_or(i32::MAX)],
+                ),
+                ControlCode::new(ControlType::CarriageReturn),
+            ])]);
+        }
+        let erase = Segment::control(vec![ControlCode::with_params_vec(
+            ControlType::EraseInLine,
+            vec![2],
+        )]);
+        let options = PrintOptions::new()
+            .with_markup(true)
+            .with_no_newline(true)
+            .with_highlight(false);
+        for index in cursor.visible() {
+            let display = self.choices[index].display();
+            let display = if self.markup {
+                display.to_string()
+            } else {
+                markup::escape(display)
+            };
+            let line = if index == cursor.index {
+                format!("[bold cyan]❯ {display}[/]")
+            } else {
+                format!("  {display}")
+            };
+            console.print_segments(std::slice::from_ref(&erase));
+            console.print_with_options(&line, &options);
+            print_exact(console, "\r\n");
+        }
+        if paginated {
+            console.print_segments(std::slice::from_ref(&erase));
+            console.print_with_options(
+                &format!("  [dim]{}/{}[/]", cursor.index + 1, cursor.len),
+                &options,
+            );
+            print_exact(console, "\r\n");
+        }
+    }
+
     /// Ask for selection from a provided reader (useful for tests).
     pub fn ask_from<R: io::BufRead>(
         &self,
         console: &Console,
         reader: &mut R,
+    ) -> Result<String, PromptError> {
+        self.ask_lines(console, || {
+            read_line_limited(reader, self.max_length).map(Some)
+        })
+    }
+
+    /// Prompt until a choice is made from the lines `next_line` yields; it
+    /// returns `None` once the timeout has passed.
+    fn ask_lines(
+        &self,
+        console: &Console,
+        mut next_line: impl FnMut() -> Result<Option<String>, PromptError>,
     ) -> Result<String, PromptError> {
         if self.choices.is_empty() {
             return Err(PromptError::Validation("No choices provided".to_string()));
@@ -933,11 +2189,31 @@ impl Select {
             return self.default.clone().ok_or(PromptError::NotInteractive);
         }
 
+        let page_size = self.effective_page_size(console);
+        let page_count = self.choices.len().div_ceil(page_size);
+        let mut page = self
+            .default
+            .as_deref()
+            .and_then(|default| self.choices.iter().position(|c| c.value == default))
+            .map_or(0, |index| index / page_size);
+
         loop {
-            self.print_choices(console);
+            self.print_choices(console, page, page_size);
+            if page_count > 1 {
+                console.print_with_options(
+                    &format!(
+                        "  [dim]page {}/{page_count} (n: next, p: previous)[/]",
+                        page + 1
+                    ),
+                    &PrintOptions::new().with_markup(true).with_highlight(false),
+                );
+            }
             self.print_prompt(console);
 
-            let line = read_line_limited(reader, self.max_length)?;
+            let Some(line) = next_line()? else {
+                print_exact(console, "\n");
+                return self.default.clone().ok_or(PromptError::TimedOut);
+            };
             let input = trim_newline(&line).trim();
 
             // Empty input uses default
@@ -964,6 +2240,18 @@ impl Select {
                 return Ok(choice.value.clone());
             }
 
+            // Page navigation
+            if page_count > 1 {
+                if input.eq_ignore_ascii_case("n") {
+                    page = (page + 1) % page_count;
+                    continue;
+                }
+                if input.eq_ignore_ascii_case("p") {
+                    page = (page + page_count - 1) % page_count;
+                    continue;
+                }
+            }
+
             self.print_error(console, &format!("Invalid choice: {input}"));
         }
     }
@@ -975,8 +2263,19 @@ impl Select {
         })
     }
 
-    fn print_choices(&self, console: &Console) {
-        for (i, choice) in self.choices.iter().enumerate() {
+    fn effective_page_size(&self, console: &Console) -> usize {
+        self.page_size.unwrap_or_else(|| {
+            console
+                .height()
+                .saturating_sub(SELECT_RESERVED_LINES)
+                .max(1)
+        })
+    }
+
+    fn print_choices(&self, console: &Console, page: usize, page_size: usize) {
+        let start = page * page_size;
+        let page_choices = self.choices.iter().enumerate().skip(start).take(page_size);
+        for (i, choice) in page_choices {
             let num = i + 1;
             let display = choice.display();
             let is_default = self.default.as_deref() == Some(&choice.value);
@@ -1028,45 +2327,458 @@ impl Select {
     }
 }
 
-/// Confirm prompt (yes/no question).
+/// Checkbox state for [`MultiSelect`] in raw mode.
+#[derive(Debug)]
+struct Checklist {
+    checked: Vec<bool>,
+    cursor: usize,
+}
+
+impl Checklist {
+    fn handle_key(&mut self, key: Key) -> EditOutcome {
+        let len = self.checked.len();
+        match key {
+            Key::Enter => EditOutcome::Submit,
+            Key::CtrlC | Key::Escape => EditOutcome::Cancel,
+            Key::Up | Key::Char('k') => {
+                self.cursor = (self.cursor + len - 1) % len;
+                EditOutcome::Redraw
+            }
+            Key::Down | Key::Char('j') => {
+                self.cursor = (self.cursor + 1) % len;
+                EditOutcome::Redraw
+            }
+            Key::Char(' ') => {
+                self.checked[self.cursor] = !self.checked[self.cursor];
+                EditOutcome::Redraw
+            }
+            Key::Char('a') => {
+                let all = self.checked.iter().all(|checked| *checked);
+                self.checked.fill(!all);
+                EditOutcome::Redraw
+            }
+            _ => EditOutcome::Continue,
+        }
+    }
+}
+
+/// Checkbox prompt for choosing several options from a list.
+///
+/// When stdin is a terminal, ↑/↓ move between choices, Space toggles the one
+/// under the cursor, `a` toggles all, and Enter confirms. Otherwise the
+/// numbered list is printed and a comma-separated list of numbers or values is
+/// read, e.g. `1,3`; empty input keeps the [defaults](MultiSelect::defaults).
 ///
 /// # Examples
 ///
 /// ```rust,ignore
-/// use rich_rust::interactive::Confirm;
+/// use rich_rust::interactive::MultiSelect;
 ///
-/// let proceed = Confirm::new("Continue?")
-///     .default(true)
+/// let toppings = MultiSelect::new("Toppings")
+///     .choices(["cheese", "olives", "basil"])
+///     .defaults(["cheese"])
+///     .min_selections(1)
 ///     .ask(&console)?;
 /// ```
 #[derive(Debug, Clone)]
-pub struct Confirm {
+pub struct MultiSelect {
     label: String,
-    default: Option<bool>,
+    choices: Vec<Choice>,
+    defaults: Vec<String>,
+    min_selections: usize,
+    max_selections: Option<usize>,
     markup: bool,
     max_length: usize,
 }
 
-impl Confirm {
-    /// Create a new confirmation prompt.
+impl MultiSelect {
+    /// Create a new multi-select prompt.
     #[must_use]
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
-            default: None,
+            choices: Vec::new(),
+            defaults: Vec::new(),
+            min_selections: 0,
+            max_selections: None,
             markup: true,
             max_length: DEFAULT_MAX_INPUT_LENGTH,
         }
     }
 
-    /// Set the default value.
+    /// Add choices to select from.
     #[must_use]
-    pub const fn default(mut self, default: bool) -> Self {
-        self.default = Some(default);
+    pub fn choices<I, C>(mut self, choices: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Choice>,
+    {
+        self.choices.extend(choices.into_iter().map(Into::into));
         self
     }
 
-    /// Enable/disable markup parsing for the prompt label.
+    /// Add a single choice.
+    #[must_use]
+    pub fn choice(mut self, choice: impl Into<Choice>) -> Self {
+        self.choices.push(choice.into());
+        self
+    }
+
+    /// Set the values checked initially (returned on empty input or in non-interactive mode).
+    #[must_use]
+    pub fn defaults<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.defaults = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Require at least `min` checked choices (default 0).
+    #[must_use]
+    pub const fn min_selections(mut self, min: usize) -> Self {
+        self.min_selections = min;
+        self
+    }
+
+    /// Allow at most `max` checked choices (default unlimited).
+    #[must_use]
+    pub const fn max_selections(mut self, max: usize) -> Self {
+        self.max_selections = Some(max);
+        self
+    }
+
+    /// Enable/disable markup parsing for the prompt label and choices.
+    #[must_use]
+    pub const fn markup(mut self, markup: bool) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// Set maximum input length in bytes.
+    ///
+    /// If input exceeds this limit, `ask()` returns `PromptError::InputTooLong`.
+    /// Defaults to [`DEFAULT_MAX_INPUT_LENGTH`] (64 KiB).
+    #[must_use]
+    pub const fn max_length(mut self, max_bytes: usize) -> Self {
+        self.max_length = if max_bytes == 0 { 1 } else { max_bytes };
+        self
+    }
+
+    /// Ask for a selection using stdin, with a checkbox list when stdin is a terminal.
+    pub fn ask(&self, console: &Console) -> Result<Vec<String>, PromptError> {
+        if !self.choices.is_empty() && console.is_terminal() && io::stdin().is_terminal() {
+            return self.ask_raw(console);
+        }
+        if stdin_read_by_thread() {
+            // Without a deadline a line always arrives.
+            return self.ask_lines(console, || {
+                read_stdin_line(self.max_length, None).map(Option::unwrap_or_default)
+            });
+        }
+        let stdin = io::stdin();
+        let mut reader = stdin.lock();
+        self.ask_from(console, &mut reader)
+    }
+
+    /// Ask for a selection from a provided reader (useful for tests).
+    pub fn ask_from<R: io::BufRead>(
+        &self,
+        console: &Console,
+        reader: &mut R,
+    ) -> Result<Vec<String>, PromptError> {
+        self.ask_lines(console, || read_line_limited(reader, self.max_length))
+    }
+
+    /// Prompt until a line from `next_line` is an acceptable selection.
+    fn ask_lines(
+        &self,
+        console: &Console,
+        mut next_line: impl FnMut() -> Result<String, PromptError>,
+    ) -> Result<Vec<String>, PromptError> {
+        if self.choices.is_empty() {
+            return Err(PromptError::Validation("No choices provided".to_string()));
+        }
+
+        let defaults = self.default_checked();
+        if !console.is_terminal() {
+            return match self.check_count(&defaults) {
+                Ok(()) if !self.defaults.is_empty() || self.min_selections == 0 => {
+                    Ok(self.values(&defaults))
+                }
+                _ => Err(PromptError::NotInteractive),
+            };
+        }
+
+        loop {
+            for (index, choice) in self.choices.iter().enumerate() {
+                let line = format!(
+                    "  {} [cyan]{}.[/] {}",
+                    checkbox(defaults[index]),
+                    index + 1,
+                    self.display(choice)
+                );
+                console.print_with_options(
+                    &line,
+                    &PrintOptions::new().with_markup(true).with_highlight(false),
+                );
+            }
+            console.print_with_options(
+                &format!("{} (e.g. 1,3): ", self.label_markup()),
+                &PrintOptions::new()
+                    .with_markup(true)
+                    .with_no_newline(true)
+                    .with_highlight(false),
+            );
+
+            let line = next_line()?;
+            let input = trim_newline(&line).trim();
+            let checked = if input.is_empty() {
+                Ok(defaults.clone())
+            } else {
+                self.parse_selection(input)
+            };
+            match checked.and_then(|checked| self.check_count(&checked).map(|()| checked)) {
+                Ok(checked) => return Ok(self.values(&checked)),
+                Err(message) => print_prompt_error(console, &message),
+            }
+        }
+    }
+
+    /// Toggle choices with the keyboard in raw mode.
+    fn ask_raw(&self, console: &Console) -> Result<Vec<String>, PromptError> {
+        console.print_with_options(
+            &format!(
+                "{} [dim](↑/↓ move, space toggle, a all, enter confirm)[/]",
+                self.label_markup()
+            ),
+            &PrintOptions::new().with_markup(true).with_highlight(false),
+        );
+        let mut list = Checklist {
+            checked: self.default_checked(),
+            cursor: 0,
+        };
+        let outcome = {
+            let _raw = terminal::RawMode::enable()?;
+            self.draw_checklist(console, &list, None, false);
+            loop {
+                match list.handle_key(terminal::read_key()?) {
+                    EditOutcome::Continue => {}
+                    EditOutcome::Submit => match self.check_count(&list.checked) {
+                        Ok(()) => break EditOutcome::Submit,
+                        Err(message) => self.draw_checklist(console, &list, Some(&message), true),
+                    },
+                    EditOutcome::Redraw => self.draw_checklist(console, &list, None, true),
+                    outcome => break outcome,
+                }
+            }
+        };
+        match outcome {
+            EditOutcome::Cancel => Err(PromptError::Io(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "prompt interrupted",
+            ))),
+            _ => Ok(self.values(&list.checked)),
+        }
+    }
+
+    /// Draw one line per choice plus a status line; `redraw` first moves back over them.
+    fn draw_checklist(
+        &self,
+        console: &Console,
+        list: &Checklist,
+        error: Option<&str>,
+        redraw: bool,
+    ) {
+        if redraw {
+            let lines = i32::try_from(self.choices.len() + 1).unwrap_or(i32::MAX);
+            console.print_segments(&[Segment::control(vec![
+                ControlCode::with_params_vec(ControlType::CursorUp, vec![lines]),
+                ControlCode::new(ControlType::CarriageReturn),
+            ])]);
+        }
+        let erase = Segment::control(vec![ControlCode::with_params_vec(
+            ControlType::EraseInLine,
+            vec![2],
+        )]);
+        let options = PrintOptions::new()
+            .with_markup(true)
+            .with_highlight(false)
+            .with_no_newline(true);
+        for (index, choice) in self.choices.iter().enumerate() {
+            let pointer = if index == list.cursor {
+                "[bold cyan]❯[/]"
+            } else {
+                " "
+            };
+            console.print_segments(std::slice::from_ref(&erase));
+            console.print_with_options(
+                &format!(
+                    "{pointer} {} {}",
+                    checkbox(list.checked[index]),
+                    self.display(choice)
+                ),
+                &options,
+            );
+            // Raw mode doesn't translate `\n`, so end lines explicitly.
+            print_exact(console, "\r\n");
+        }
+        console.print_segments(std::slice::from_ref(&erase));
+        if let Some(message) = error {
+            console.print_with_options(
+                message,
+                &options
+                    .clone()
+                    .with_markup(false)
+                    .with_style(Style::parse("bold red").unwrap_or_default()),
+            );
+        }
+        print_exact(console, "\r\n");
+    }
+
+    fn default_checked(&self) -> Vec<bool> {
+        self.choices
+            .iter()
+            .map(|choice| self.defaults.contains(&choice.value))
+            .collect()
+    }
+
+    fn values(&self, checked: &[bool]) -> Vec<String> {
+        self.choices
+            .iter()
+            .zip(checked)
+            .filter(|(_, checked)| **checked)
+            .map(|(choice, _)| choice.value.clone())
+            .collect()
+    }
+
+    /// Parse comma-separated numbers or values into checked flags.
+    fn parse_selection(&self, input: &str) -> Result<Vec<bool>, String> {
+        let mut checked = vec![false; self.choices.len()];
+        for part in input
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let index = match part.parse::<usize>() {
+                Ok(num) if (1..=self.choices.len()).contains(&num) => Some(num - 1),
+                _ => {
+                    let lower = part.to_lowercase();
+                    self.choices.iter().position(|c| {
+                        c.value.to_lowercase() == lower || c.display().to_lowercase() == lower
+                    })
+                }
+            };
+            match index {
+                Some(index) => checked[index] = true,
+                None => return Err(format!("Invalid choice: {part}")),
+            }
+        }
+        Ok(checked)
+    }
+
+    fn check_count(&self, checked: &[bool]) -> Result<(), String> {
+        let count = checked.iter().filter(|checked| **checked).count();
+        if count < self.min_selections {
+            return Err(format!(
+                "Please select at least {}.",
+                options_count(self.min_selections)
+            ));
+        }
+        match self.max_selections {
+            Some(max) if count > max => {
+                Err(format!("Please select at most {}.", options_count(max)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn label_markup(&self) -> String {
+        if self.markup {
+            self.label.clone()
+        } else {
+            markup::escape(&self.label)
+        }
+    }
+
+    fn display(&self, choice: &Choice) -> String {
+        if self.markup {
+            choice.display().to_string()
+        } else {
+            markup::escape(choice.display())
+        }
+    }
+}
+
+/// Markup for a checkbox, e.g. `[x]`.
+fn checkbox(checked: bool) -> String {
+    if checked {
+        format!("[green]{}[/]", markup::escape("[x]"))
+    } else {
+        markup::escape("[ ]")
+    }
+}
+
+fn options_count(n: usize) -> String {
+    if n == 1 {
+        "1 option".to_string()
+    } else {
+        format!("{n} options")
+    }
+}
+
+fn print_prompt_error(console: &Console, message: &str) {
+    let style = Style::parse("bold red").unwrap_or_default();
+    console.print_with_options(
+        message,
+        &PrintOptions::new().with_markup(false).with_style(style),
+    );
+}
+
+/// Confirm prompt (yes/no question).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use rich_rust::interactive::Confirm;
+///
+/// let proceed = Confirm::new("Continue?")
+///     .default(true)
+///     .ask(&console)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Confirm {
+    label: String,
+    default: Option<bool>,
+    markup: bool,
+    max_length: usize,
+    phrase: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl Confirm {
+    /// Create a new confirmation prompt.
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            default: None,
+            markup: true,
+            max_length: DEFAULT_MAX_INPUT_LENGTH,
+            phrase: None,
+            timeout: None,
+        }
+    }
+
+    /// Set the default value.
+    #[must_use]
+    pub const fn default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Enable/disable markup parsing for the prompt label.
     #[must_use]
     pub const fn markup(mut self, markup: bool) -> Self {
         self.markup = markup;
@@ -1083,8 +2795,34 @@ impl Confirm {
         self
     }
 
+    /// Require typing `phrase` exactly after answering yes, for destructive actions.
+    ///
+    /// The phrase is requested in a warning panel; any other input cancels and
+    /// the prompt returns `false`. When the console is not interactive, a
+    /// `true` default is ignored so the action is never confirmed implicitly.
+    #[must_use]
+    pub fn require_phrase(mut self, phrase: impl Into<String>) -> Self {
+        self.phrase = Some(phrase.into());
+        self
+    }
+
+    /// Stop waiting for an answer after `timeout`, returning the default or
+    /// [`PromptError::TimedOut`] when there is none.
+    ///
+    /// As when the console is not interactive, a `true` default never
+    /// confirms a [phrase](Confirm::require_phrase) prompt. See [`Prompt::timeout`].
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Ask for confirmation using stdin.
     pub fn ask(&self, console: &Console) -> Result<bool, PromptError> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        if deadline.is_some() || stdin_read_by_thread() {
+            return self.ask_lines(console, || read_stdin_line(self.max_length, deadline));
+        }
         let stdin = io::stdin();
         let mut reader = stdin.lock();
         self.ask_from(console, &mut reader)
@@ -1095,28 +2833,74 @@ impl Confirm {
         &self,
         console: &Console,
         reader: &mut R,
+    ) -> Result<bool, PromptError> {
+        self.ask_lines(console, || {
+            read_line_limited(reader, self.max_length).map(Some)
+        })
+    }
+
+    /// Ask using the lines `next_line` yields; it returns `None` once the
+    /// timeout has passed.
+    fn ask_lines(
+        &self,
+        console: &Console,
+        mut next_line: impl FnMut() -> Result<Option<String>, PromptError>,
     ) -> Result<bool, PromptError> {
         if !console.is_terminal() {
-            return self.default.ok_or(PromptError::NotInteractive);
+            return self.unattended_default().ok_or(PromptError::NotInteractive);
         }
 
+        match self.ask_yes_no(console, &mut next_line)? {
+            Some(true) => {}
+            Some(false) => return Ok(false),
+            None => return self.unattended_default().ok_or(PromptError::TimedOut),
+        }
+        match &self.phrase {
+            Some(phrase) => match self.ask_phrase(console, &mut next_line, phrase) {
+                Err(PromptError::TimedOut) => {
+                    self.unattended_default().ok_or(PromptError::TimedOut)
+                }
+                answer => answer,
+            },
+            None => Ok(true),
+        }
+    }
+
+    /// The answer when nobody responds: the default, except that a phrase
+    /// prompt is never confirmed implicitly.
+    fn unattended_default(&self) -> Option<bool> {
+        match self.phrase {
+            Some(_) => self.default.filter(|default| !default),
+            None => self.default,
+        }
+    }
+
+    /// `None` when the timeout passed without an answer.
+    fn ask_yes_no(
+        &self,
+        console: &Console,
+        next_line: &mut impl FnMut() -> Result<Option<String>, PromptError>,
+    ) -> Result<Option<bool>, PromptError> {
         loop {
             self.print_prompt(console);
 
-            let line = read_line_limited(reader, self.max_length)?;
+            let Some(line) = next_line()? else {
+                print_exact(console, "\n");
+                return Ok(None);
+            };
             let input = trim_newline(&line).trim().to_lowercase();
 
             if input.is_empty() {
                 if let Some(default) = self.default {
-                    return Ok(default);
+                    return Ok(Some(default));
                 }
                 self.print_error(console, "Please enter y or n.");
                 continue;
             }
 
             match input.as_str() {
-                "y" | "yes" | "true" | "1" => return Ok(true),
-                "n" | "no" | "false" | "0" => return Ok(false),
+                "y" | "yes" | "true" | "1" => return Ok(Some(true)),
+                "n" | "no" | "false" | "0" => return Ok(Some(false)),
                 _ => {
                     self.print_error(console, "Please enter y or n.");
                 }
@@ -1124,6 +2908,34 @@ impl Confirm {
         }
     }
 
+    fn ask_phrase(
+        &self,
+        console: &Console,
+        next_line: &mut impl FnMut() -> Result<Option<String>, PromptError>,
+        phrase: &str,
+    ) -> Result<bool, PromptError> {
+        let warning = Style::parse("bold red").unwrap_or_default();
+        let mut message = Text::new("This action cannot be undone. Type ");
+        message.append_styled(phrase, Style::new().bold());
+        message.append(" to confirm.");
+        let panel = Panel::from_rich_text(&message, console.width())
+            .title(Text::styled("Warning", warning.clone()))
+            .border_style(warning);
+        console.print_renderable(&panel);
+
+        let typed = Prompt::new("Confirm")
+            .markup(false)
+            .allow_empty(true)
+            .max_length(self.max_length)
+            .ask_lines(console, next_line)?;
+        if typed == phrase {
+            Ok(true)
+        } else {
+            self.print_error(console, "Confirmation phrase did not match; cancelled.");
+            Ok(false)
+        }
+    }
+
     fn print_prompt(&self, console: &Console) {
         let mut prompt = self.label.clone();
 
@@ -1154,64 +2966,320 @@ impl Confirm {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::error::Error as StdError;
-    use std::io::Write;
+/// A type a [`TypedPrompt`] can parse input into.
+pub trait PromptValue: Clone + PartialOrd + std::fmt::Display + std::fmt::Debug {
+    /// Message printed when input does not parse as this type.
+    const INVALID_MESSAGE: &'static str;
 
-    #[derive(Clone)]
-    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+    /// Parse trimmed input, returning `None` if it is not a valid value.
+    fn parse_input(input: &str) -> Option<Self>;
+}
 
-    impl Write for SharedBuffer {
-        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-            self.0.lock().unwrap().write(buf)
-        }
+macro_rules! integer_prompt_value {
+    ($($ty:ty),*) => {
+        $(impl PromptValue for $ty {
+            const INVALID_MESSAGE: &'static str = "Please enter a valid integer number.";
 
-        fn flush(&mut self) -> io::Result<()> {
-            self.0.lock().unwrap().flush()
-        }
-    }
+            fn parse_input(input: &str) -> Option<Self> {
+                input.parse().ok()
+            }
+        })*
+    };
+}
 
-    #[test]
-    fn test_status_non_interactive_prints_message_once() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
-        let console = Console::builder()
-            .force_terminal(false)
-            .markup(false)
-            .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+integer_prompt_value!(i32, i64, u32, u64, usize);
 
-        let _status = Status::new(&console, "Working...").expect("status");
+macro_rules! float_prompt_value {
+    ($($ty:ty),*) => {
+        $(impl PromptValue for $ty {
+            const INVALID_MESSAGE: &'static str = "Please enter a number.";
 
-        let out = buffer.0.lock().unwrap();
-        let text = String::from_utf8_lossy(&out);
-        assert!(text.contains("Working...\n"));
-    }
+            fn parse_input(input: &str) -> Option<Self> {
+                input.parse::<$ty>().ok().filter(|value| value.is_finite())
+            }
+        })*
+    };
+}
 
-    #[test]
-    fn test_prompt_non_interactive_uses_default() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
-        let console = Console::builder()
-            .force_terminal(false)
-            .markup(false)
-            .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+float_prompt_value!(f32, f64);
 
-        let prompt = Prompt::new("Name").default("Alice");
-        let answer = prompt.ask(&console).expect("prompt");
-        assert_eq!(answer, "Alice");
+/// Prompt for an integer, like Rich's `IntPrompt`.
+pub type IntPrompt = TypedPrompt<i64>;
 
-        // Non-interactive prompt should not print.
-        assert!(buffer.0.lock().unwrap().is_empty());
-    }
+/// Prompt for a floating point number, like Rich's `FloatPrompt`.
+pub type FloatPrompt = TypedPrompt<f64>;
 
-    #[test]
-    fn test_prompt_from_reader_validates_and_reprompts() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
-        let console = Console::builder()
+/// Choices and range a typed answer must satisfy.
+#[derive(Debug, Clone)]
+struct ValueRules<T> {
+    choices: Vec<T>,
+    min: Option<T>,
+    max: Option<T>,
+}
+
+impl<T: PromptValue> ValueRules<T> {
+    fn check(&self, input: &str) -> Result<T, String> {
+        let value = T::parse_input(input.trim()).ok_or_else(|| T::INVALID_MESSAGE.to_string())?;
+        if !self.choices.is_empty() && !self.choices.contains(&value) {
+            return Err("Please select one of the available options.".to_string());
+        }
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) if value < *min || value > *max => {
+                Err(format!("Please enter a value between {min} and {max}."))
+            }
+            (Some(min), None) if value < *min => {
+                Err(format!("Please enter a value of at least {min}."))
+            }
+            (None, Some(max)) if value > *max => {
+                Err(format!("Please enter a value of at most {max}."))
+            }
+            _ => Ok(value),
+        }
+    }
+}
+
+/// A prompt that parses its answer into `T`, re-prompting until it does.
+///
+/// Use [`IntPrompt`] and [`FloatPrompt`] for the common cases.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rich_rust::interactive::IntPrompt;
+///
+/// let workers = IntPrompt::new("Workers")
+///     .default(4)
+///     .range(1, 64)
+///     .ask(&console)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct TypedPrompt<T> {
+    label: String,
+    default: Option<T>,
+    rules: ValueRules<T>,
+    show_choices: bool,
+    show_default: bool,
+    markup: bool,
+    max_length: usize,
+}
+
+impl<T: PromptValue + Send + Sync + 'static> TypedPrompt<T> {
+    /// Create a new typed prompt.
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            default: None,
+            rules: ValueRules {
+                choices: Vec::new(),
+                min: None,
+                max: None,
+            },
+            show_choices: true,
+            show_default: true,
+            markup: true,
+            max_length: DEFAULT_MAX_INPUT_LENGTH,
+        }
+    }
+
+    /// Provide a default value (used when the user enters empty input, or when not interactive).
+    #[must_use]
+    pub fn default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Only accept one of `choices`.
+    #[must_use]
+    pub fn choices(mut self, choices: impl IntoIterator<Item = T>) -> Self {
+        self.rules.choices = choices.into_iter().collect();
+        self
+    }
+
+    /// Show the choices after the label, e.g. `Level [1/2/3]` (default `true`).
+    #[must_use]
+    pub const fn show_choices(mut self, show_choices: bool) -> Self {
+        self.show_choices = show_choices;
+        self
+    }
+
+    /// Reject values below `min`.
+    #[must_use]
+    pub fn min(mut self, min: T) -> Self {
+        self.rules.min = Some(min);
+        self
+    }
+
+    /// Reject values above `max`.
+    #[must_use]
+    pub fn max(mut self, max: T) -> Self {
+        self.rules.max = Some(max);
+        self
+    }
+
+    /// Only accept values from `min` to `max` inclusive.
+    #[must_use]
+    pub fn range(self, min: T, max: T) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Show the default value in the prompt (when present).
+    #[must_use]
+    pub const fn show_default(mut self, show_default: bool) -> Self {
+        self.show_default = show_default;
+        self
+    }
+
+    /// Enable/disable markup parsing for the prompt label.
+    #[must_use]
+    pub const fn markup(mut self, markup: bool) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// Set maximum input length in bytes.
+    #[must_use]
+    pub const fn max_length(mut self, max_bytes: usize) -> Self {
+        self.max_length = if max_bytes == 0 { 1 } else { max_bytes };
+        self
+    }
+
+    /// Ask for a value using stdin.
+    pub fn ask(&self, console: &Console) -> Result<T, PromptError> {
+        let answer = self.prompt().ask(console)?;
+        self.rules.check(&answer).map_err(PromptError::Validation)
+    }
+
+    /// Ask for a value from a provided reader (useful for tests).
+    pub fn ask_from<R: io::BufRead>(
+        &self,
+        console: &Console,
+        reader: &mut R,
+    ) -> Result<T, PromptError> {
+        let answer = self.prompt().ask_from(console, reader)?;
+        self.rules.check(&answer).map_err(PromptError::Validation)
+    }
+
+    /// The string prompt that reads and validates the answer.
+    fn prompt(&self) -> Prompt {
+        let mut label = self.label.clone();
+        if self.show_choices && !self.rules.choices.is_empty() {
+            let choices: Vec<String> = self.rules.choices.iter().map(ToString::to_string).collect();
+            let choices = format!(" [{}]", choices.join("/"));
+            if self.markup {
+                label.push_str(&markup::escape(&choices));
+            } else {
+                label.push_str(&choices);
+            }
+        }
+
+        let rules = self.rules.clone();
+        let mut prompt = Prompt::new(label)
+            .markup(self.markup)
+            .show_default(self.show_default)
+            .max_length(self.max_length)
+            .validate(move |input| rules.check(input).map(drop));
+        if let Some(default) = &self.default {
+            prompt = prompt.default(default.to_string());
+        }
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as StdError;
+    use std::io::Write;
+
+    #[derive(Clone)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.0.lock().unwrap().flush()
+        }
+    }
+
+    #[test]
+    fn test_status_non_interactive_prints_message_once() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let _status = Status::new(&console, "Working...").expect("status");
+
+        let out = buffer.0.lock().unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains("Working...\n"));
+    }
+
+    #[test]
+    fn test_console_status_non_interactive_prints_message() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let status = console
+            .status("Loading")
+            .spinner("dots")
+            .spinner_style(Style::parse("bold").unwrap());
+        status.update("Still loading");
+        drop(status);
+
+        let out = buffer.0.lock().unwrap();
+        assert_eq!(String::from_utf8_lossy(&out), "Loading\n");
+    }
+
+    #[test]
+    fn test_status_spinner_builders() {
+        let console = Console::builder().force_terminal(false).build().shared();
+        let style = Style::parse("red").unwrap();
+        let status = console
+            .status("Working")
+            .spinner_style(style.clone())
+            .spinner("line")
+            .spinner("no-such-spinner");
+
+        let spinner = status.spinner.lock().unwrap();
+        assert_eq!(spinner.current_frame(), "-");
+        assert_eq!(spinner.frame_style(0), style);
+    }
+
+    #[test]
+    fn test_prompt_non_interactive_uses_default() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let prompt = Prompt::new("Name").default("Alice");
+        let answer = prompt.ask(&console).expect("prompt");
+        assert_eq!(answer, "Alice");
+
+        // Non-interactive prompt should not print.
+        assert!(buffer.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prompt_from_reader_validates_and_reprompts() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
             .force_terminal(true)
             .markup(false)
             .file(Box::new(buffer.clone()))
@@ -1242,25 +3310,450 @@ mod tests {
     }
 
     #[test]
-    fn test_pager_non_interactive_falls_back_to_print() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
-        let console = Console::builder()
-            .force_terminal(false)
-            .markup(false)
-            .file(Box::new(buffer.clone()))
-            .build();
+    fn test_pager_non_interactive_falls_back_to_print() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+
+        Pager::new()
+            .show(&console, "hello\nworld\n")
+            .expect("pager");
+
+        let out = buffer.0.lock().unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains("hello\nworld\n"));
+    }
+
+    #[test]
+    fn test_select_by_number() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick a color").choices(["red", "green", "blue"]);
+
+        let input = b"2\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "green");
+    }
+
+    #[test]
+    fn test_select_by_value() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick a color").choices(["red", "green", "blue"]);
+
+        let input = b"blue\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "blue");
+    }
+
+    #[test]
+    fn test_select_case_insensitive() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick").choices(["Red", "Green"]);
+
+        let input = b"red\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "Red");
+    }
+
+    #[test]
+    fn test_select_default() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick").choices(["a", "b", "c"]).default("b");
+
+        let input = b"\n"; // Empty input
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "b");
+    }
+
+    #[test]
+    fn test_select_non_interactive_uses_default() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick").choices(["a", "b"]).default("b");
+        let answer = select.ask(&console).expect("select");
+        assert_eq!(answer, "b");
+    }
+
+    #[test]
+    fn test_select_with_labels() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick")
+            .choice(Choice::with_label("us-east-1", "US East (N. Virginia)"))
+            .choice(Choice::with_label("eu-west-1", "EU (Ireland)"));
+
+        let input = b"1\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "us-east-1");
+    }
+
+    #[test]
+    fn test_select_paginates_long_lists() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let choices: Vec<String> = (1..=10).map(|i| format!("item{i}")).collect();
+        let select = Select::new("Pick").choices(choices).page_size(4);
+
+        // Next twice, back once, then pick by number from page 2.
+        let input = b"n\nn\np\n6\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "item6");
+
+        let output = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(output.contains("page 1/3"), "{output}");
+        assert!(output.contains("page 3/3"), "{output}");
+        assert!(output.contains(" item9"), "{output}");
+        let first_page = &output[..output.find("page 1/3").unwrap()];
+        assert!(!first_page.contains("item5"), "{first_page}");
+    }
+
+    #[test]
+    fn test_select_page_size_follows_console_height() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .height(5)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let select = Select::new("Pick")
+            .choices(["a", "b", "c", "d", "e"])
+            .default("e");
+
+        let input = b"\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = select.ask_from(&console, &mut reader).expect("select");
+        assert_eq!(answer, "e");
+
+        // Two choices per page, starting on the page holding the default.
+        let output = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(output.contains("page 3/3"), "{output}");
+        assert!(!output.contains(" a\n"), "{output}");
+    }
+
+    #[test]
+    fn test_confirm_yes() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let confirm = Confirm::new("Continue?");
+
+        let input = b"y\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = confirm.ask_from(&console, &mut reader).expect("confirm");
+        assert!(answer);
+    }
+
+    #[test]
+    fn test_confirm_no() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let confirm = Confirm::new("Continue?");
+
+        let input = b"n\n";
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = confirm.ask_from(&console, &mut reader).expect("confirm");
+        assert!(!answer);
+    }
+
+    #[test]
+    fn test_confirm_default_yes() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let confirm = Confirm::new("Continue?").default(true);
+
+        let input = b"\n"; // Empty input
+        let mut reader = io::Cursor::new(&input[..]);
+        let answer = confirm.ask_from(&console, &mut reader).expect("confirm");
+        assert!(answer);
+    }
+
+    #[test]
+    fn test_confirm_non_interactive_uses_default() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let confirm = Confirm::new("Continue?").default(false);
+        let answer = confirm.ask(&console).expect("confirm");
+        assert!(!answer);
+    }
+
+    #[test]
+    fn test_choice_display() {
+        let simple = Choice::new("value");
+        assert_eq!(simple.display(), "value");
+
+        let labeled = Choice::with_label("value", "Display Label");
+        assert_eq!(labeled.display(), "Display Label");
+    }
+
+    // ========================================================================
+    // Comprehensive Prompt Tests (bd-1trs)
+    // ========================================================================
+
+    #[test]
+    fn test_prompt_history_push_skips_empty_and_repeats() {
+        let history = PromptHistory::new();
+        history.push("first").unwrap();
+        history.push("").unwrap();
+        history.push("first").unwrap();
+        history.push("two\nlines").unwrap();
+        history.push("second").unwrap();
+        history.push("first").unwrap();
+        assert_eq!(history.entries(), vec!["first", "second", "first"]);
+    }
+
+    #[test]
+    fn test_prompt_history_max_entries_drops_oldest() {
+        let history = PromptHistory::new().max_entries(2);
+        for entry in ["a", "b", "c"] {
+            history.push(entry).unwrap();
+        }
+        assert_eq!(history.entries(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_prompt_history_file_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "rich_rust_prompt_history_{}.txt",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let history = PromptHistory::with_file(&path).expect("history");
+        assert!(history.is_empty());
+        history.push("alpha").unwrap();
+        history.push("beta").unwrap();
+
+        let reloaded = PromptHistory::with_file(&path).expect("reload");
+        assert_eq!(reloaded.entries(), vec!["alpha", "beta"]);
+
+        let trimmed = PromptHistory::with_file(&path)
+            .expect("reload")
+            .max_entries(2);
+        trimmed.push("gamma").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "beta\ngamma\n");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_prompt_from_reader_records_history() {
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(SharedBuffer(Arc::new(Mutex::new(Vec::new())))))
+            .build();
+        let history = PromptHistory::new();
+        let prompt = Prompt::new("Path").default("/tmp").history(history.clone());
+
+        let mut input = io::Cursor::new("/srv/data\n\n");
+        assert_eq!(prompt.ask_from(&console, &mut input).unwrap(), "/srv/data");
+        assert_eq!(prompt.ask_from(&console, &mut input).unwrap(), "/tmp");
+        assert_eq!(history.entries(), vec!["/srv/data"]);
+    }
+
+    #[test]
+    fn test_password_prompt_masks_input_and_skips_history() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        let history = PromptHistory::new();
+        let prompt = Prompt::new("Token")
+            .password(true)
+            .history(history.clone())
+            .suggest(|_| vec!["secret".to_string()]);
+
+        let mut editor = LineEditor::new(Vec::new(), 64);
+        for c in "s3cr3t".chars() {
+            editor.handle_key(Key::Char(c));
+        }
+        prompt.redraw(&console, &editor);
+        let shown = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(shown.contains("Token: ******"), "{shown:?}");
+        assert!(!shown.contains("s3cr3t"), "{shown:?}");
+        assert!(prompt.suggestions("se").is_empty());
+
+        let mut input = io::Cursor::new("s3cr3t\n");
+        assert_eq!(prompt.ask_from(&console, &mut input).unwrap(), "s3cr3t");
+        assert!(history.entries().is_empty());
+    }
+
+    #[test]
+    fn test_console_input_non_interactive() {
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(SharedBuffer(Arc::new(Mutex::new(Vec::new())))))
+            .build();
+        assert!(matches!(
+            console.input("Name"),
+            Err(PromptError::NotInteractive)
+        ));
+    }
+
+    #[test]
+    fn test_line_editor_navigates_history() {
+        let mut editor = LineEditor::new(vec!["one".into(), "two".into()], 64);
+        editor.handle_key(Key::Char('x'));
+
+        assert_eq!(editor.handle_key(Key::Up), EditOutcome::Redraw);
+        assert_eq!(editor.buffer, "two");
+        editor.handle_key(Key::Up);
+        assert_eq!(editor.buffer, "one");
+        assert_eq!(editor.handle_key(Key::Up), EditOutcome::Continue);
+
+        editor.handle_key(Key::Down);
+        assert_eq!(editor.buffer, "two");
+        editor.handle_key(Key::Down);
+        assert_eq!(editor.buffer, "x");
+        assert_eq!(editor.handle_key(Key::Down), EditOutcome::Continue);
+    }
+
+    #[test]
+    fn test_line_editor_paste_modes() {
+        let mut editor = LineEditor::new(Vec::new(), 64);
+        assert_eq!(
+            editor.handle_paste("one\r\ntwo\n", PasteMode::Preserve),
+            EditOutcome::Redraw
+        );
+        assert_eq!(editor.buffer, "one\ntwo\n");
+
+        let mut editor = LineEditor::new(Vec::new(), 64);
+        assert_eq!(
+            editor.handle_paste("one\ntwo", PasteMode::Reject),
+            EditOutcome::Rejected("Pasted text must be a single line.")
+        );
+        assert!(editor.buffer.is_empty());
+        assert_eq!(
+            editor.handle_paste("token\n", PasteMode::Reject),
+            EditOutcome::Redraw
+        );
+        assert_eq!(editor.buffer, "token");
+
+        let mut editor = LineEditor::new(Vec::new(), 4);
+        assert_eq!(
+            editor.handle_paste("too long", PasteMode::Preserve),
+            EditOutcome::Rejected("Pasted text is too long.")
+        );
+    }
+
+    #[test]
+    fn test_line_editor_editing_keys() {
+        let mut editor = LineEditor::new(Vec::new(), 3);
+        assert_eq!(editor.handle_key(Key::Up), EditOutcome::Continue);
+        for c in "abcd".chars() {
+            editor.handle_key(Key::Char(c));
+        }
+        assert_eq!(editor.buffer, "abc");
+        editor.handle_key(Key::Backspace);
+        assert_eq!(editor.buffer, "ab");
+        assert_eq!(editor.handle_key(Key::Enter), EditOutcome::Submit);
+
+        assert_eq!(editor.handle_key(Key::CtrlC), EditOutcome::Cancel);
+        assert_eq!(editor.handle_key(Key::Ctrl('u')), EditOutcome::Redraw);
+        assert!(editor.buffer.is_empty());
+        assert_eq!(editor.handle_key(Key::Ctrl('d')), EditOutcome::Eof);
+    }
+
+    #[test]
+    fn test_line_editor_completes_common_prefix() {
+        let mut editor = LineEditor::new(Vec::new(), 64);
+        editor.handle_key(Key::Char('s'));
+        assert_eq!(editor.handle_key(Key::Tab), EditOutcome::Complete);
 
-        Pager::new()
-            .show(&console, "hello\nworld\n")
-            .expect("pager");
+        let candidates = vec!["src/lib.rs".to_string(), "src/live.rs".to_string()];
+        assert!(editor.complete(&candidates));
+        assert_eq!(editor.buffer, "src/li");
+        assert!(!editor.complete(&candidates));
 
-        let out = buffer.0.lock().unwrap();
-        let text = String::from_utf8_lossy(&out);
-        assert!(text.contains("hello\nworld\n"));
+        assert!(editor.complete(&["src/live.rs".to_string()]));
+        assert_eq!(editor.buffer, "src/live.rs");
+        assert!(!editor.complete(&[]));
+        assert_eq!(common_prefix(&["héllo".into(), "hélp".into()]), "hél");
     }
 
     #[test]
-    fn test_select_by_number() {
+    fn test_prompt_from_reader_prints_suggestions_on_rejected_input() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
         let console = Console::builder()
             .force_terminal(true)
@@ -1269,184 +3762,407 @@ mod tests {
             .build()
             .shared();
 
-        let select = Select::new("Pick a color").choices(["red", "green", "blue"]);
+        let known = ["alpha", "beta", "alpine"];
+        let prompt = Prompt::new("Host")
+            .validate(move |value| {
+                if known.contains(&value) {
+                    Ok(())
+                } else {
+                    Err("unknown host".to_string())
+                }
+            })
+            .suggest(move |partial| {
+                known
+                    .iter()
+                    .filter(|name| name.starts_with(partial))
+                    .map(|name| (*name).to_string())
+                    .collect()
+            });
 
-        let input = b"2\n";
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = select.ask_from(&console, &mut reader).expect("select");
-        assert_eq!(answer, "green");
+        let mut reader = io::Cursor::new(&b"al\nalpine\n"[..]);
+        let answer = prompt.ask_from(&console, &mut reader).expect("prompt");
+        assert_eq!(answer, "alpine");
+
+        let out = buffer.0.lock().unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains("unknown host"), "got: {text:?}");
+        assert!(text.contains("alpha  alpine"), "got: {text:?}");
+        assert!(!text.contains("beta"), "got: {text:?}");
     }
 
-    #[test]
-    fn test_select_by_value() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
-        let console = Console::builder()
+    fn confirm_console(buffer: &SharedBuffer) -> Console {
+        Console::builder()
             .force_terminal(true)
+            .width(60)
             .markup(false)
             .file(Box::new(buffer.clone()))
             .build()
-            .shared();
+    }
 
-        let select = Select::new("Pick a color").choices(["red", "green", "blue"]);
+    #[test]
+    fn test_confirm_require_phrase_accepts_exact_phrase() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = confirm_console(&buffer);
+        let confirm = Confirm::new("Drop database?").require_phrase("delete my data");
 
-        let input = b"blue\n";
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = select.ask_from(&console, &mut reader).expect("select");
-        assert_eq!(answer, "blue");
+        let mut input = io::Cursor::new("y\ndelete my data\n");
+        assert!(confirm.ask_from(&console, &mut input).unwrap());
+
+        let out = String::from_utf8_lossy(&buffer.0.lock().unwrap()).into_owned();
+        assert!(out.contains("Warning"), "missing panel: {out}");
+        assert!(out.contains("delete my data"), "missing phrase: {out}");
     }
 
     #[test]
-    fn test_select_case_insensitive() {
+    fn test_confirm_require_phrase_rejects_mismatch() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = confirm_console(&buffer);
+        let confirm = Confirm::new("Drop database?").require_phrase("delete my data");
+
+        let mut input = io::Cursor::new("yes\ndelete my date\n");
+        assert!(!confirm.ask_from(&console, &mut input).unwrap());
+        let out = String::from_utf8_lossy(&buffer.0.lock().unwrap()).into_owned();
+        assert!(out.contains("did not match"));
+
+        // Answering no skips the phrase entirely.
+        let mut input = io::Cursor::new("n\n");
+        assert!(!confirm.ask_from(&console, &mut input).unwrap());
+    }
+
+    #[test]
+    fn test_confirm_require_phrase_never_confirms_non_interactive() {
+        let console = Console::builder().force_terminal(false).build();
+        let mut input = io::Cursor::new("");
+
+        let confirm = Confirm::new("Drop?").default(true).require_phrase("yes");
+        assert!(matches!(
+            confirm.ask_from(&console, &mut input),
+            Err(PromptError::NotInteractive)
+        ));
+
+        let confirm = Confirm::new("Drop?").default(false).require_phrase("yes");
+        assert!(!confirm.ask_from(&console, &mut input).unwrap());
+    }
+
+    #[test]
+    fn test_int_prompt_reprompts_until_valid() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
         let console = Console::builder()
             .force_terminal(true)
             .markup(false)
+            .highlight(false)
             .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+            .build();
 
-        let select = Select::new("Pick").choices(["Red", "Green"]);
+        let prompt = IntPrompt::new("Workers").range(1, 8);
+        let mut input = io::Cursor::new("many\n12\n 4 \n");
+        assert_eq!(prompt.ask_from(&console, &mut input).unwrap(), 4);
 
-        let input = b"red\n";
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = select.ask_from(&console, &mut reader).expect("select");
-        assert_eq!(answer, "Red");
+        let text = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(
+            text.contains("Please enter a valid integer number."),
+            "{text:?}"
+        );
+        assert!(
+            text.contains("Please enter a value between 1 and 8."),
+            "{text:?}"
+        );
     }
 
     #[test]
-    fn test_select_default() {
+    fn test_float_prompt_choices_and_default() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
         let console = Console::builder()
             .force_terminal(true)
-            .markup(false)
             .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+            .build();
 
-        let select = Select::new("Pick").choices(["a", "b", "c"]).default("b");
+        let prompt = FloatPrompt::new("Scale")
+            .choices([0.5, 1.0, 2.0])
+            .default(1.0)
+            .markup(false);
+        let mut input = io::Cursor::new("3\n\n");
+        assert!((prompt.ask_from(&console, &mut input).unwrap() - 1.0).abs() < f64::EPSILON);
 
-        let input = b"\n"; // Empty input
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = select.ask_from(&console, &mut reader).expect("select");
-        assert_eq!(answer, "b");
+        let text = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(text.contains("Scale [0.5/1/2] [1]: "), "{text:?}");
+        assert!(
+            text.contains("Please select one of the available options."),
+            "{text:?}"
+        );
     }
 
     #[test]
-    fn test_select_non_interactive_uses_default() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+    fn test_typed_prompt_non_interactive_checks_default() {
         let console = Console::builder()
             .force_terminal(false)
-            .markup(false)
-            .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+            .file(Box::new(SharedBuffer(Arc::new(Mutex::new(Vec::new())))))
+            .build();
 
-        let select = Select::new("Pick").choices(["a", "b"]).default("b");
-        let answer = select.ask(&console).expect("select");
-        assert_eq!(answer, "b");
+        assert_eq!(
+            IntPrompt::new("Port").default(80).ask(&console).unwrap(),
+            80
+        );
+        assert!(matches!(
+            TypedPrompt::<u32>::new("Port")
+                .default(0)
+                .min(1)
+                .ask(&console),
+            Err(PromptError::Validation(_))
+        ));
+        assert!(matches!(
+            IntPrompt::new("Port").ask(&console),
+            Err(PromptError::NotInteractive)
+        ));
     }
 
     #[test]
-    fn test_select_with_labels() {
+    fn test_multi_select_from_reader() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
         let console = Console::builder()
             .force_terminal(true)
-            .markup(false)
+            .highlight(false)
             .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+            .build();
+        let select = MultiSelect::new("Toppings")
+            .choices(["cheese", "olives", "basil"])
+            .defaults(["olives"])
+            .min_selections(1)
+            .max_selections(2);
 
-        let select = Select::new("Pick")
-            .choice(Choice::with_label("us-east-1", "US East (N. Virginia)"))
-            .choice(Choice::with_label("eu-west-1", "EU (Ireland)"));
+        let mut input = io::Cursor::new("1,2,3\n4\n\n");
+        assert_eq!(
+            select.ask_from(&console, &mut input).unwrap(),
+            vec!["olives"]
+        );
+        let text = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(
+            text.contains("Please select at most 2 options."),
+            "{text:?}"
+        );
+        assert!(text.contains("Invalid choice: 4"), "{text:?}");
+        assert!(text.contains("[x]"), "{text:?}");
 
-        let input = b"1\n";
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = select.ask_from(&console, &mut reader).expect("select");
-        assert_eq!(answer, "us-east-1");
+        let mut input = io::Cursor::new("basil, 1\n");
+        assert_eq!(
+            select.ask_from(&console, &mut input).unwrap(),
+            vec!["cheese", "basil"]
+        );
     }
 
     #[test]
-    fn test_confirm_yes() {
+    fn test_multi_select_enforces_minimum() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
         let console = Console::builder()
             .force_terminal(true)
-            .markup(false)
+            .highlight(false)
             .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
-
-        let confirm = Confirm::new("Continue?");
+            .build();
+        let select = MultiSelect::new("Pick")
+            .choices(["a", "b"])
+            .min_selections(1);
 
-        let input = b"y\n";
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = confirm.ask_from(&console, &mut reader).expect("confirm");
-        assert!(answer);
+        let mut input = io::Cursor::new("\n2\n");
+        assert_eq!(select.ask_from(&console, &mut input).unwrap(), vec!["b"]);
+        let text = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(
+            text.contains("Please select at least 1 option."),
+            "{text:?}"
+        );
     }
 
     #[test]
-    fn test_confirm_no() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+    fn test_multi_select_non_interactive_uses_defaults() {
         let console = Console::builder()
-            .force_terminal(true)
-            .markup(false)
-            .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+            .force_terminal(false)
+            .file(Box::new(SharedBuffer(Arc::new(Mutex::new(Vec::new())))))
+            .build();
+        let select = MultiSelect::new("Pick").choices(["a", "b", "c"]);
 
-        let confirm = Confirm::new("Continue?");
+        assert_eq!(
+            select.clone().defaults(["c", "a"]).ask(&console).unwrap(),
+            vec!["a", "c"]
+        );
+        assert!(select.clone().ask(&console).unwrap().is_empty());
+        assert!(matches!(
+            select.min_selections(1).ask(&console),
+            Err(PromptError::NotInteractive)
+        ));
+    }
 
-        let input = b"n\n";
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = confirm.ask_from(&console, &mut reader).expect("confirm");
-        assert!(!answer);
+    #[test]
+    fn test_checklist_navigates_and_toggles() {
+        let mut list = Checklist {
+            checked: vec![false, true, false],
+            cursor: 0,
+        };
+        list.handle_key(Key::Up);
+        assert_eq!(list.cursor, 2);
+        list.handle_key(Key::Char(' '));
+        assert_eq!(list.checked, vec![false, true, true]);
+        list.handle_key(Key::Down);
+        list.handle_key(Key::Char(' '));
+        assert_eq!(list.checked, vec![true, true, true]);
+        list.handle_key(Key::Char('a'));
+        assert_eq!(list.checked, vec![false, false, false]);
+        assert!(matches!(list.handle_key(Key::Enter), EditOutcome::Submit));
     }
 
     #[test]
-    fn test_confirm_default_yes() {
+    fn test_list_cursor_moves_and_scrolls() {
+        let mut cursor = ListCursor::new(0, 5, 2);
+        assert_eq!(cursor.visible(), 0..2);
+        assert!(cursor.handle_key(Key::Up));
+        assert_eq!(cursor.index, 4);
+        assert_eq!(cursor.visible(), 3..5);
+        assert!(cursor.handle_key(Key::Down));
+        assert_eq!(cursor.index, 0);
+        assert_eq!(cursor.visible(), 0..2);
+        assert!(cursor.handle_key(Key::PageDown));
+        assert_eq!((cursor.index, cursor.visible()), (2, 1..3));
+        assert!(!cursor.handle_key(Key::Char('x')));
+        assert!(cursor.handle_key(Key::End));
+        assert!(!cursor.handle_key(Key::End));
+    }
+
+    #[test]
+    fn test_select_draws_highlighted_cursor() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
         let console = Console::builder()
             .force_terminal(true)
-            .markup(false)
+            .width(40)
             .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+            .build();
+        let select = Select::new("Color")
+            .choices(["red", "green", "blue"])
+            .arrow_keys(true);
 
-        let confirm = Confirm::new("Continue?").default(true);
+        let mut cursor = ListCursor::new(0, 3, 10);
+        cursor.handle_key(crate::terminal::Key::Down);
+        select.draw_cursor_list(&console, &cursor, true);
 
-        let input = b"\n"; // Empty input
-        let mut reader = io::Cursor::new(&input[..]);
-        let answer = confirm.ask_from(&console, &mut reader).expect("confirm");
-        assert!(answer);
+        let text = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(text.starts_with("\x1b[3A\r"), "{text:?}");
+        assert!(text.contains("  red\r\n"), "{text:?}");
+        assert!(text.contains("❯ green"), "{text:?}");
+        assert!(text.contains("  blue\r\n"), "{text:?}");
+    }
+
+    /// A reader fed line by line from a channel, blocking until a line is sent.
+    struct ChannelReader {
+        lines: mpsc::Receiver<&'static str>,
+        buffer: Vec<u8>,
+    }
+
+    impl io::Read for ChannelReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.buffer.is_empty() {
+                match self.lines.recv() {
+                    Ok(line) => self.buffer.extend_from_slice(line.as_bytes()),
+                    Err(_) => return Ok(0),
+                }
+            }
+            let len = buf.len().min(self.buffer.len());
+            buf[..len].copy_from_slice(&self.buffer[..len]);
+            self.buffer.drain(..len);
+            Ok(len)
+        }
     }
 
     #[test]
-    fn test_confirm_non_interactive_uses_default() {
-        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
-        let console = Console::builder()
-            .force_terminal(false)
-            .markup(false)
-            .file(Box::new(buffer.clone()))
-            .build()
-            .shared();
+    fn test_timed_lines_times_out_then_reports_eof() {
+        let (sender, lines) = mpsc::channel::<&str>();
+        let mut reader = io::BufReader::new(ChannelReader {
+            lines,
+            buffer: Vec::new(),
+        });
+        let mut timed =
+            TimedLines::spawn(move |max_length| read_line_limited(&mut reader, max_length));
 
-        let confirm = Confirm::new("Continue?").default(false);
-        let answer = confirm.ask(&console).expect("confirm");
-        assert!(!answer);
+        let waited = Duration::from_millis(20);
+        assert_eq!(timed.read_line(64, Some(waited)).unwrap(), None);
+        drop(sender);
+        assert!(matches!(
+            timed.read_line(64, Some(Duration::from_secs(5))),
+            Err(PromptError::Eof)
+        ));
     }
 
     #[test]
-    fn test_choice_display() {
-        let simple = Choice::new("value");
-        assert_eq!(simple.display(), "value");
+    fn test_timed_lines_hands_late_line_to_untimed_read() {
+        let (sender, lines) = mpsc::channel::<&str>();
+        let mut reader = io::BufReader::new(ChannelReader {
+            lines,
+            buffer: Vec::new(),
+        });
+        let mut timed =
+            TimedLines::spawn(move |max_length| read_line_limited(&mut reader, max_length));
 
-        let labeled = Choice::with_label("value", "Display Label");
-        assert_eq!(labeled.display(), "Display Label");
+        assert_eq!(
+            timed
+                .read_line(64, Some(Duration::from_millis(20)))
+                .unwrap(),
+            None
+        );
+        // Typed after the timed read gave up: the next read, without a timeout, gets it.
+        sender.send("late\n").unwrap();
+        assert_eq!(
+            timed.read_line(64, None).unwrap(),
+            Some("late\n".to_string())
+        );
+        sender.send("next\n").unwrap();
+        assert_eq!(
+            timed.read_line(64, None).unwrap(),
+            Some("next\n".to_string())
+        );
     }
 
-    // ========================================================================
-    // Comprehensive Prompt Tests (bd-1trs)
-    // ========================================================================
+    #[test]
+    fn test_prompts_fall_back_to_default_on_timeout() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        let timed_out = || Ok(None);
+
+        assert_eq!(
+            Prompt::new("Name")
+                .default("bot")
+                .ask_lines(&console, timed_out)
+                .unwrap(),
+            "bot"
+        );
+        assert!(matches!(
+            Prompt::new("Name").ask_lines(&console, timed_out),
+            Err(PromptError::TimedOut)
+        ));
+        assert_eq!(
+            Select::new("Color")
+                .choices(["red", "blue"])
+                .default("blue")
+                .ask_lines(&console, timed_out)
+                .unwrap(),
+            "blue"
+        );
+        assert!(
+            !Confirm::new("Deploy?")
+                .default(false)
+                .ask_lines(&console, timed_out)
+                .unwrap()
+        );
+
+        // A yes answer followed by silence never confirms a phrase prompt.
+        let mut answers = vec![Some("y\n".to_string())].into_iter();
+        let confirm = Confirm::new("Drop table?")
+            .default(true)
+            .require_phrase("drop");
+        assert!(matches!(
+            confirm.ask_lines(&console, || Ok(answers.next().flatten())),
+            Err(PromptError::TimedOut)
+        ));
+    }
 
     #[test]
     fn test_prompt_builder_chain() {
@@ -2322,6 +5038,111 @@ mod tests {
         assert_eq!(cloned.allow_color, pager.allow_color);
     }
 
+    #[test]
+    fn test_pager_show_renderable_non_interactive_prints() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .width(20)
+            .file(Box::new(buffer.clone()))
+            .build();
+
+        Pager::new()
+            .show_renderable(&console, &Panel::from_text("paged"))
+            .expect("pager");
+
+        let out = buffer.0.lock().unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains("paged") && text.contains('╭'), "{text:?}");
+    }
+
+    #[test]
+    fn test_builtin_pager_keys() {
+        use terminal::Key;
+
+        assert_eq!(pager_step(Key::Char(' '), 23), Some(23));
+        assert_eq!(pager_step(Key::PageDown, 23), Some(23));
+        assert_eq!(pager_step(Key::Enter, 23), Some(1));
+        assert_eq!(pager_step(Key::Down, 23), Some(1));
+        assert_eq!(pager_step(Key::Char('x'), 23), Some(0));
+        assert_eq!(pager_step(Key::Char('q'), 23), None);
+        assert_eq!(pager_step(Key::Escape, 23), None);
+    }
+
+    // --- PagerSearch tests ---
+
+    #[test]
+    fn test_pager_search_finds_matches_with_char_offsets() {
+        let lines = ["héllo error", "fine", "error error"];
+        let mut search = PagerSearch::new("error").expect("pattern");
+        assert_eq!(search.find(&lines, 0), 3);
+        assert_eq!(
+            search.matches()[0],
+            PagerMatch {
+                line: 0,
+                start: 6,
+                end: 11
+            }
+        );
+        assert_eq!(search.current().map(|m| m.line), Some(0));
+    }
+
+    #[test]
+    fn test_pager_search_starts_from_line_and_wraps() {
+        let lines = ["error", "x", "error"];
+        let mut search = PagerSearch::new("error").expect("pattern");
+        search.find(&lines, 1);
+        assert_eq!(search.current().map(|m| m.line), Some(2));
+        assert_eq!(search.next_match().map(|m| m.line), Some(0));
+        assert_eq!(search.prev_match().map(|m| m.line), Some(2));
+        assert_eq!(search.prev_match().map(|m| m.line), Some(0));
+    }
+
+    #[test]
+    fn test_pager_search_incremental_typing() {
+        let lines = ["abc", "abd"];
+        let mut search = PagerSearch::default();
+        search.push_char('a');
+        search.push_char('b');
+        assert_eq!(search.find(&lines, 0), 2);
+        search.push_char('(');
+        assert_eq!(search.pattern(), "ab(");
+        assert_eq!(search.find(&lines, 0), 0);
+        search.pop_char();
+        search.push_char('c');
+        assert_eq!(search.find(&lines, 0), 1);
+    }
+
+    #[test]
+    fn test_pager_search_invalid_pattern_errors() {
+        assert!(PagerSearch::new("(").is_err());
+    }
+
+    #[test]
+    fn test_pager_search_highlight_line_marks_current() {
+        let lines = ["a a"];
+        let mut search = PagerSearch::new("a")
+            .expect("pattern")
+            .match_style(Style::new().underline())
+            .current_style(Style::new().bold());
+        search.find(&lines, 0);
+        let mut text = Text::new(lines[0]);
+        search.highlight_line(0, &mut text);
+        let spans = text.spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].style, Style::new().bold());
+        assert_eq!(spans[1].style, Style::new().underline());
+    }
+
+    #[test]
+    fn test_pager_search_as_highlighter() {
+        let console = Console::new();
+        let search = PagerSearch::new("o").expect("pattern");
+        let mut text = Text::new("foo");
+        search.highlight(&console, &mut text);
+        assert_eq!(text.spans().len(), 2);
+    }
+
     // --- Choice tests ---
 
     #[test]
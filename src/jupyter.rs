@@ -0,0 +1,88 @@
+//! Notebook display support for evcxr (Rust Jupyter kernel).
+//!
+//! evcxr shows rich output for any value with an `evcxr_display` method: the
+//! method prints a MIME-typed block to stdout, which the kernel forwards to the
+//! notebook. [`Console::evcxr_display`] uses this to show recorded output as
+//! inline HTML, mirroring Python Rich's notebook support.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use rich_rust::prelude::*;
+//!
+//! let console = Console::builder().force_terminal(true).build();
+//! console.begin_capture();
+//! console.print("[bold green]Hello[/] from a notebook");
+//! console // the cell's value is displayed as HTML
+//! ```
+
+use std::io::{self, Write};
+
+use crate::console::{Console, ExportHtmlOptions};
+
+/// HTML template for notebook output (Python Rich's `JUPYTER_HTML_FORMAT`).
+pub const JUPYTER_HTML_FORMAT: &str = "<pre style=\"white-space:pre;overflow-x:auto;line-height:normal;font-family:Menlo,'DejaVu Sans Mono',consolas,'Courier New',monospace\">{code}</pre>\n";
+
+/// Wrap content in an evcxr display block for `mime_type`.
+#[must_use]
+pub fn evcxr_block(mime_type: &str, content: &str) -> String {
+    format!("EVCXR_BEGIN_CONTENT {mime_type}\n{content}\nEVCXR_END_CONTENT\n")
+}
+
+impl Console {
+    /// Export recorded output as an HTML fragment for notebooks.
+    ///
+    /// Styles are inlined, so the fragment renders without a stylesheet.
+    #[must_use]
+    pub fn export_jupyter_html(&self, clear: bool) -> String {
+        self.export_html_with_options(&ExportHtmlOptions {
+            clear,
+            code_format: Some(JUPYTER_HTML_FORMAT.to_string()),
+            inline_styles: true,
+            ..ExportHtmlOptions::default()
+        })
+    }
+
+    /// Display recorded output inline in an evcxr notebook.
+    ///
+    /// evcxr calls this for a cell whose value is a `Console`. The recording is
+    /// kept, so displaying again shows the same output plus anything new.
+    pub fn evcxr_display(&self) {
+        let block = evcxr_block("text/html", &self.export_jupyter_html(false));
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(block.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evcxr_block() {
+        assert_eq!(
+            evcxr_block("text/html", "<b>x</b>"),
+            "EVCXR_BEGIN_CONTENT text/html\n<b>x</b>\nEVCXR_END_CONTENT\n"
+        );
+    }
+
+    #[test]
+    fn test_export_jupyter_html_inlines_styles() {
+        let console = Console::builder()
+            .force_terminal(true)
+            .highlight(false)
+            .file(Box::new(io::sink()))
+            .build();
+        console.begin_capture();
+        console.print("[bold]Hi[/] <there>");
+
+        let html = console.export_jupyter_html(false);
+        assert!(html.starts_with("<pre style=\"white-space:pre;"), "{html}");
+        assert!(html.contains("font-weight: bold"), "{html}");
+        assert!(html.contains(" &lt;there&gt;"), "{html}");
+        assert!(!html.contains("<!DOCTYPE"), "{html}");
+        // The recording is kept for later displays.
+        assert_eq!(console.export_jupyter_html(true), html);
+    }
+}
@@ -64,6 +64,10 @@
 //! - **`markdown`**: Markdown rendering via pulldown-cmark
 //! - **`json`**: JSON formatting with syntax highlighting
 //! - **`tracing`**: Tracing integration via `RichTracingLayer`
+//! - **`diff`**: Unified and side-by-side text diffs via similar
+//! - **`bigtext`**: Block-letter banners from an embedded font
+//! - **`watch`**: Theme file hot-reload via `Console::watch_theme`, which polls the file
+//! - **`jupyter`**: Inline HTML display in evcxr notebooks
 //!
 //! ```toml
 //! [dependencies]
@@ -93,7 +97,8 @@
 //!
 //! ### Caveats
 //!
-//! - Output may interleave when printing from multiple threads simultaneously
+//! - Output may interleave when printing from multiple threads simultaneously;
+//!   use `Console::batch` or `ConsoleBuilder::serialize_output` to keep prints whole
 //! - Style/color parsing caches are thread-safe but not deterministic under contention
 //! - For deterministic output ordering, synchronize at the application level
 //!
@@ -174,6 +179,8 @@ pub mod emoji;
 pub mod filesize;
 pub mod highlighter;
 pub mod interactive;
+#[cfg(feature = "jupyter")]
+pub mod jupyter;
 pub mod live;
 pub mod logging;
 pub mod markup;
@@ -193,58 +200,77 @@ pub mod prelude {
     pub use crate::r#box::BoxChars;
     pub use crate::color::{
         Color, ColorSystem, ColorTriplet, ColorType, DEFAULT_TERMINAL_THEME, DIMMED_MONOKAI,
-        MONOKAI, NIGHT_OWLISH, SVG_EXPORT_THEME, TerminalTheme,
+        MONOKAI, NIGHT_OWLISH, SVG_EXPORT_THEME, TerminalTheme, TerminalThemeError,
     };
     pub use crate::console::{
         CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT, Console, ConsoleOptions, ExportHtmlOptions,
-        ExportSvgOptions, LogLevel, LogOptions,
+        ExportSvgOptions, ExportTextOptions, FlushPolicy, LogLevel, LogOptions,
     };
     pub use crate::emoji::EmojiVariant;
     pub use crate::filesize::{
         SizeUnit, binary, binary_speed, binary_with_precision, decimal, decimal_speed,
         decimal_with_precision, format_size, format_speed,
     };
+    pub use crate::group;
     pub use crate::highlighter::{Highlighter, NullHighlighter, RegexHighlighter, ReprHighlighter};
-    pub use crate::interactive::{Pager, Prompt, PromptError, Status};
-    pub use crate::live::{Live, LiveOptions, VerticalOverflowMethod};
+    pub use crate::interactive::{
+        FloatPrompt, IntPrompt, MultiSelect, Pager, PagerMatch, PagerSearch, PasteMode, Prompt,
+        PromptError, PromptHistory, PromptValue, Status, TypedPrompt,
+    };
+    pub use crate::live::{
+        Live, LiveError, LiveFrame, LiveHooks, LiveOptions, LivePosition, VerticalOverflowMethod,
+    };
     pub use crate::logging::RichLogger;
     #[cfg(feature = "tracing")]
     pub use crate::logging::RichTracingLayer;
     pub use crate::measure::Measurement;
     pub use crate::protocol::{RichCast, RichCastOutput, rich_cast};
     pub use crate::renderables::{
-        Align, AlignLines, AlignMethod, BarStyle, Cell, Column, Columns, Constrain, Control,
-        DownloadColumn, Emoji, FileSizeColumn, Inspect, InspectOptions, Layout, LayoutSplitter,
-        PaddingDimensions, Panel, Pretty, PrettyOptions, ProgressBar, Region, Row, Rule, Spinner,
-        Table, TotalFileSizeColumn, Traceback, TracebackFrame, TransferSpeedColumn, Tree,
-        TreeGuides, TreeNode, VerticalAlign, VerticalAlignMethod, align_text, inspect,
-        print_exception,
+        Align, AlignLines, AlignMethod, Bar, BarChart, BarStyle, Calendar, Canvas, CanvasMarker,
+        Cell, Column, Columns, Constrain, Control, DownloadColumn, Emoji, FileSizeColumn,
+        FileTreeOptions, Group, Inspect, InspectOptions, Layout, LayoutSplitter,
+        MofNCompleteColumn, Numbered, PaddingDimensions, Panel, PercentageColumn, Pretty,
+        PrettyOptions, Progress, ProgressBar, ProgressColumn, ProgressIteratorExt, ProgressTask,
+        Region, Row, Rule, Spinner, SpinnerColumn, Table, TaskColumn, TaskId, TimeElapsedColumn,
+        TimeRemainingColumn, TotalFileSizeColumn, Traceback, TracebackFrame, Track,
+        TransferSpeedColumn, Tree, TreeGuides, TreeNode, VerticalAlign, VerticalAlignMethod,
+        align_text, inspect, print_exception, track,
     };
     pub use crate::segment::{
         ControlCode, ControlType, Segment, escape_control_codes, strip_control_codes,
     };
     pub use crate::style::{Attributes, Style};
-    pub use crate::text::{JustifyMethod, OverflowMethod, Span, Text};
+    pub use crate::text::{JustifyMethod, OverflowMethod, Span, Text, WrapOptions};
     pub use crate::theme::{Theme, ThemeError, ThemeStackError};
 
     #[cfg(feature = "syntax")]
     pub use crate::renderables::{Syntax, SyntaxError};
 
     #[cfg(feature = "markdown")]
-    pub use crate::renderables::Markdown;
+    pub use crate::renderables::{HtmlPolicy, Markdown};
 
     #[cfg(feature = "json")]
-    pub use crate::renderables::{Json, JsonError, JsonTheme};
+    pub use crate::renderables::{Json, JsonDiff, JsonDiffTheme, JsonError, JsonTheme};
+
+    #[cfg(feature = "diff")]
+    pub use crate::renderables::{Diff, DiffMode, DiffTheme};
+
+    #[cfg(feature = "bigtext")]
+    pub use crate::renderables::BigText;
 }
 
 // Re-export key types at crate root
 pub use color::{
     Color, ColorSystem, ColorTriplet, ColorType, DEFAULT_TERMINAL_THEME, DIMMED_MONOKAI, MONOKAI,
-    NIGHT_OWLISH, SVG_EXPORT_THEME, TerminalTheme,
+    NIGHT_OWLISH, SVG_EXPORT_THEME, TerminalTheme, TerminalThemeError,
 };
 pub use console::Console;
-pub use console::{CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT, ExportHtmlOptions, ExportSvgOptions};
-pub use live::{Live, LiveOptions, VerticalOverflowMethod};
+pub use console::{
+    CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT, ExportHtmlOptions, ExportSvgOptions, ExportTextOptions,
+};
+pub use live::{
+    Live, LiveError, LiveFrame, LiveHooks, LiveOptions, LivePosition, VerticalOverflowMethod,
+};
 pub use logging::RichLogger;
 #[cfg(feature = "tracing")]
 pub use logging::RichTracingLayer;
@@ -2,19 +2,21 @@
 //!
 //! This module implements Rich-style Live updates with cursor control.
 
+use std::fmt::Write as FmtWrite;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io;
 use std::io::{Read, Write};
 use std::sync::{
     Arc, Mutex, RwLock,
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicU64, Ordering},
 };
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::ansi::AnsiDecoder;
-use crate::console::{Console, ConsoleOptions, RenderHook};
+use crate::console::{Console, ConsoleDimensions, ConsoleOptions, RenderHook};
 use crate::renderables::Renderable;
-use crate::segment::{ControlCode, ControlType, Segment, split_lines};
+use crate::segment::{ControlCode, ControlType, Segment, line_length, split_lines};
 use crate::style::Style;
 use crate::sync::{lock_recover, read_recover, write_recover};
 use crate::text::{JustifyMethod, OverflowMethod, Text};
@@ -31,6 +33,18 @@ pub enum VerticalOverflowMethod {
     Visible,
 }
 
+/// Where a Live area sits among other live areas on the same console.
+///
+/// A Live started while another is active on the same console becomes an
+/// extra area of the combined live region. Top areas are drawn above bottom
+/// areas; areas with the same position keep the order they were started in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LivePosition {
+    Top,
+    #[default]
+    Bottom,
+}
+
 /// Configuration for Live.
 #[derive(Debug, Clone)]
 pub struct LiveOptions {
@@ -41,6 +55,269 @@ pub struct LiveOptions {
     pub redirect_stdout: bool,
     pub redirect_stderr: bool,
     pub vertical_overflow: VerticalOverflowMethod,
+    pub position: LivePosition,
+    /// Repaint only what changed between refreshes.
+    ///
+    /// When a refresh produces as many lines as the previous one, lines that
+    /// are unchanged are skipped and changed lines are rewritten from the
+    /// first cell that differs, using cursor movement. Dashboards built from
+    /// a [`Layout`](crate::renderables::Layout) with many panes then only
+    /// repaint the panes that changed. Other refreshes redraw everything.
+    pub partial_refresh: bool,
+    /// Upper bound on frames per second.
+    ///
+    /// Clamps `refresh_per_second`, and while the refresh thread runs,
+    /// [`Live::update`] skips redraws requested within a frame of the last
+    /// one; the refresh thread draws them on its next tick instead.
+    pub max_fps: Option<f64>,
+    /// Slow the refresh thread to about once a second while idle.
+    ///
+    /// The display is idle once its rendered frame hasn't changed for a
+    /// couple of seconds; [`Live::update`] speeds it back up immediately.
+    pub adaptive_refresh: bool,
+    /// Lifecycle and per-frame callbacks.
+    pub hooks: LiveHooks,
+    /// Keep every frame drawn, with its time since the display started.
+    ///
+    /// Read them back with [`Live::frames`] or replay them with
+    /// [`Live::export_asciicast`].
+    pub record_frames: bool,
+    /// What happens when this display starts while another is active on the
+    /// same console.
+    ///
+    /// When `true` (the default), it becomes an extra area of the active
+    /// display's region, placed by [`position`](Self::position). When
+    /// `false`, [`Live::try_start`] fails with [`LiveError::AlreadyActive`].
+    pub nested: bool,
+}
+
+/// Errors from starting a [`Live`] display.
+#[derive(Debug)]
+pub enum LiveError {
+    /// Another Live is active on the console and [`LiveOptions::nested`] is off.
+    AlreadyActive,
+    /// Writing to the terminal failed.
+    Io(io::Error),
+}
+
+impl std::fmt::Display for LiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyActive => write!(f, "another live display is already active"),
+            Self::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for LiveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::AlreadyActive => None,
+        }
+    }
+}
+
+impl From<io::Error> for LiveError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<LiveError> for io::Error {
+    fn from(err: LiveError) -> Self {
+        match err {
+            LiveError::Io(err) => err,
+            LiveError::AlreadyActive => io::Error::new(io::ErrorKind::AlreadyExists, err),
+        }
+    }
+}
+
+/// A frame drawn by a [`Live`] display, passed to [`LiveHooks::on_frame`].
+#[derive(Debug, Clone, Copy)]
+pub struct LiveFrame<'a> {
+    /// Number of frames drawn before this one since the display started.
+    pub index: u64,
+    /// Time since the display started.
+    pub elapsed: Duration,
+    /// The rendered lines of the live region.
+    pub lines: &'a [Vec<Segment<'static>>],
+}
+
+type LiveCallback = Arc<dyn Fn() + Send + Sync>;
+type LiveFrameCallback = Arc<dyn Fn(&LiveFrame<'_>) + Send + Sync>;
+
+/// Callbacks invoked as a [`Live`] display starts, draws frames, and stops.
+///
+/// Callbacks run on whichever thread triggered the event (the caller of
+/// [`Live::start`], [`Live::refresh`], or the auto-refresh thread) after the
+/// console output has been written, so they may print or refresh freely.
+#[derive(Clone, Default)]
+pub struct LiveHooks {
+    start: Option<LiveCallback>,
+    stop: Option<LiveCallback>,
+    frame: Option<LiveFrameCallback>,
+}
+
+impl LiveHooks {
+    /// Create an empty set of hooks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `callback` when the display starts, before its first frame.
+    #[must_use]
+    pub fn on_start<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.start = Some(Arc::new(callback));
+        self
+    }
+
+    /// Call `callback` when the display stops, after its last frame.
+    #[must_use]
+    pub fn on_stop<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.stop = Some(Arc::new(callback));
+        self
+    }
+
+    /// Call `callback` after each frame is drawn.
+    ///
+    /// A display drawn as an extra area of another's region has no frames of
+    /// its own; the display that owns the region reports them.
+    #[must_use]
+    pub fn on_frame<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&LiveFrame<'_>) + Send + Sync + 'static,
+    {
+        self.frame = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl std::fmt::Debug for LiveHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveHooks")
+            .field("on_start", &self.start.is_some())
+            .field("on_stop", &self.stop.is_some())
+            .field("on_frame", &self.frame.is_some())
+            .finish()
+    }
+}
+
+impl LiveOptions {
+    /// Time between auto-refresh ticks, honoring `max_fps`.
+    #[must_use]
+    pub fn refresh_interval(&self) -> Duration {
+        let rate = match self.max_fps {
+            Some(max_fps) if max_fps > 0.0 => self.refresh_per_second.min(max_fps),
+            _ => self.refresh_per_second,
+        };
+        Duration::from_secs_f64(1.0 / rate)
+    }
+
+    /// Draw on the alternate screen (implies `transient`).
+    #[must_use]
+    pub fn with_screen(mut self, screen: bool) -> Self {
+        self.screen = screen;
+        self
+    }
+
+    /// Refresh from a background thread at `refresh_per_second`.
+    #[must_use]
+    pub fn with_auto_refresh(mut self, auto_refresh: bool) -> Self {
+        self.auto_refresh = auto_refresh;
+        self
+    }
+
+    /// Set the auto-refresh rate.
+    #[must_use]
+    pub fn with_refresh_per_second(mut self, refresh_per_second: f64) -> Self {
+        self.refresh_per_second = refresh_per_second;
+        self
+    }
+
+    /// Clear the display when it stops instead of leaving the last frame.
+    #[must_use]
+    pub fn with_transient(mut self, transient: bool) -> Self {
+        self.transient = transient;
+        self
+    }
+
+    /// Print process stdout above the display while it runs.
+    #[must_use]
+    pub fn with_redirect_stdout(mut self, redirect_stdout: bool) -> Self {
+        self.redirect_stdout = redirect_stdout;
+        self
+    }
+
+    /// Print process stderr above the display while it runs.
+    #[must_use]
+    pub fn with_redirect_stderr(mut self, redirect_stderr: bool) -> Self {
+        self.redirect_stderr = redirect_stderr;
+        self
+    }
+
+    /// Set how content taller than the terminal is shown.
+    #[must_use]
+    pub fn with_vertical_overflow(mut self, vertical_overflow: VerticalOverflowMethod) -> Self {
+        self.vertical_overflow = vertical_overflow;
+        self
+    }
+
+    /// Set where this display sits among other live areas on the console.
+    #[must_use]
+    pub fn with_position(mut self, position: LivePosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Repaint only what changed between refreshes.
+    #[must_use]
+    pub fn with_partial_refresh(mut self, partial_refresh: bool) -> Self {
+        self.partial_refresh = partial_refresh;
+        self
+    }
+
+    /// Cap the frame rate at `max_fps` frames per second.
+    #[must_use]
+    pub fn with_max_fps(mut self, max_fps: f64) -> Self {
+        self.max_fps = Some(max_fps);
+        self
+    }
+
+    /// Slow the refresh thread down while the display is idle.
+    #[must_use]
+    pub fn with_adaptive_refresh(mut self, adaptive_refresh: bool) -> Self {
+        self.adaptive_refresh = adaptive_refresh;
+        self
+    }
+
+    /// Set the lifecycle and per-frame callbacks.
+    #[must_use]
+    pub fn with_hooks(mut self, hooks: LiveHooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Keep every frame drawn for [`Live::frames`] and [`Live::export_asciicast`].
+    #[must_use]
+    pub fn with_record_frames(mut self, record_frames: bool) -> Self {
+        self.record_frames = record_frames;
+        self
+    }
+
+    /// Set whether this display may join another active display's region.
+    #[must_use]
+    pub fn with_nested(mut self, nested: bool) -> Self {
+        self.nested = nested;
+        self
+    }
 }
 
 impl Default for LiveOptions {
@@ -53,6 +330,13 @@ impl Default for LiveOptions {
             redirect_stdout: true,
             redirect_stderr: true,
             vertical_overflow: VerticalOverflowMethod::Ellipsis,
+            position: LivePosition::Bottom,
+            partial_refresh: false,
+            max_fps: None,
+            adaptive_refresh: false,
+            hooks: LiveHooks::default(),
+            record_frames: false,
+            nested: true,
         }
     }
 }
@@ -71,6 +355,13 @@ impl Default for LiveOptions {
 /// When using auto-refresh, an internal thread handles periodic updates.
 /// All internal state is protected by mutexes with poison recovery.
 ///
+/// # Resizing
+///
+/// The terminal size is checked on every refresh. When it changed, every
+/// area is re-rendered at the new size, and the previous frame is erased
+/// including the extra rows its lines wrapped onto if the terminal shrank.
+/// The auto-refresh thread redraws on its next tick after a resize.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -95,6 +386,30 @@ impl Default for LiveOptions {
 /// for h in handles { h.join().unwrap(); }
 /// live.stop().unwrap();
 /// ```
+///
+/// # Multiple Areas
+///
+/// Starting a second `Live` on a console that already has one adds another
+/// area to the same region instead of fighting over the cursor. Each area
+/// refreshes at its own rate and is only re-rendered when it refreshes (or
+/// is updated); the others are redrawn from their last render. Use
+/// [`LiveOptions::position`] to pin an area to the top of the region.
+///
+/// ```rust,ignore
+/// let status = Live::with_options(
+///     Arc::clone(&console),
+///     LiveOptions {
+///         position: LivePosition::Top,
+///         refresh_per_second: 1.0,
+///         ..LiveOptions::default()
+///     },
+/// )
+/// .renderable(Text::new("Connecting..."));
+/// let progress = Live::new(Arc::clone(&console)).renderable(bar);
+///
+/// progress.start(true)?;
+/// status.start(true)?; // drawn above the progress area
+/// ```
 #[derive(Clone)]
 pub struct Live {
     inner: Arc<LiveInner>,
@@ -174,9 +489,23 @@ pub(crate) struct LiveInner {
     get_renderable: Mutex<Option<RenderableFactory>>,
     started: AtomicBool,
     nested: AtomicBool,
+    /// The area must be re-rendered before it's next drawn.
+    stale: AtomicBool,
+    /// Last render of this area, reused while other areas refresh.
+    rendered: Mutex<Option<Vec<Segment<'static>>>>,
     alt_screen_active: AtomicBool,
     refresh_stop: Arc<AtomicBool>,
     refresh_thread: Mutex<Option<JoinHandle<()>>>,
+    /// The renderable was replaced since the refresh thread last drew.
+    updated: AtomicBool,
+    /// When the region was last drawn.
+    last_frame: Mutex<Option<Instant>>,
+    /// When the display started, for [`LiveFrame::elapsed`].
+    started_at: Mutex<Option<Instant>>,
+    /// Frames drawn since the display started.
+    frames: AtomicU64,
+    /// Frames kept when [`LiveOptions::record_frames`] is set.
+    recorded: Mutex<Vec<(Duration, Vec<Segment<'static>>)>>,
     live_render: Mutex<LiveRender>,
     stdio_redirect: Mutex<Option<StdioRedirect>>,
 }
@@ -207,9 +536,16 @@ impl Live {
                 get_renderable: Mutex::new(None),
                 started: AtomicBool::new(false),
                 nested: AtomicBool::new(false),
+                stale: AtomicBool::new(true),
+                rendered: Mutex::new(None),
                 alt_screen_active: AtomicBool::new(false),
                 refresh_stop: Arc::new(AtomicBool::new(false)),
                 refresh_thread: Mutex::new(None),
+                updated: AtomicBool::new(false),
+                last_frame: Mutex::new(None),
+                started_at: Mutex::new(None),
+                frames: AtomicU64::new(0),
+                recorded: Mutex::new(Vec::new()),
                 live_render: Mutex::new(LiveRender::default()),
                 stdio_redirect: Mutex::new(None),
             }),
@@ -237,13 +573,49 @@ impl Live {
     }
 
     /// Start the Live display.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the terminal fails, or one of kind
+    /// [`io::ErrorKind::AlreadyExists`] if another Live is active on the
+    /// console and [`LiveOptions::nested`] is off. Use [`try_start`](Self::try_start)
+    /// to tell the two apart.
     pub fn start(&self, refresh: bool) -> io::Result<()> {
+        self.try_start(refresh).map_err(io::Error::from)
+    }
+
+    /// Start the Live display, reporting a conflicting display as [`LiveError::AlreadyActive`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LiveError::AlreadyActive`] if another Live is active on the
+    /// console and [`LiveOptions::nested`] is off, or [`LiveError::Io`] if
+    /// writing to the terminal fails.
+    pub fn try_start(&self, refresh: bool) -> Result<(), LiveError> {
         if self.inner.started.swap(true, Ordering::SeqCst) {
             return Ok(());
         }
 
-        if !self.inner.console.set_live(&self.inner) {
+        let Some(owner) = self
+            .inner
+            .console
+            .set_live(&self.inner, self.inner.options().nested)
+        else {
+            self.inner.started.store(false, Ordering::SeqCst);
+            return Err(LiveError::AlreadyActive);
+        };
+        self.inner.notify_start();
+
+        if !owner {
+            // Another Live owns the region: draw this one as an extra area.
             self.inner.nested.store(true, Ordering::SeqCst);
+            self.inner.stale.store(true, Ordering::SeqCst);
+            if refresh {
+                self.refresh()?;
+            }
+            if self.inner.options().auto_refresh {
+                Arc::clone(&self.inner).start_refresh_thread();
+            }
             return Ok(());
         }
 
@@ -253,7 +625,10 @@ impl Live {
             self.inner.alt_screen_active.store(true, Ordering::SeqCst);
         }
 
-        self.inner.console.show_cursor(false)?;
+        // A console that can't redraw in place has no cursor to hide.
+        if self.inner.console.is_interactive() {
+            self.inner.console.show_cursor(false)?;
+        }
 
         // Redirect stdout/stderr (process-wide) so external prints can be routed through Live.
         //
@@ -283,9 +658,15 @@ impl Live {
         }
 
         self.inner.stop_refresh_thread();
-        self.inner.console.clear_live();
+        self.inner.console.clear_live(&self.inner);
 
-        if self.inner.nested.load(Ordering::SeqCst) {
+        if self.inner.nested.swap(false, Ordering::SeqCst) {
+            // Redraw the region without this area.
+            *lock_recover(&self.inner.rendered) = None;
+            if let Some(parent) = self.inner.console.live_stack_snapshot().first() {
+                let _ = parent.refresh_display();
+            }
+            self.inner.notify_stop();
             return Ok(());
         }
 
@@ -297,11 +678,14 @@ impl Live {
         if !self.inner.alt_screen_active.load(Ordering::SeqCst) && self.inner.console.is_terminal()
         {
             let _ = self.refresh();
+            self.inner.console.pop_render_hook();
             self.inner.console.line();
+        } else {
+            self.inner.console.pop_render_hook();
+        }
+        if self.inner.console.is_interactive() {
+            let _ = self.inner.console.show_cursor(true);
         }
-
-        self.inner.console.pop_render_hook();
-        let _ = self.inner.console.show_cursor(true);
 
         self.inner.stop_stdio_redirect();
 
@@ -314,6 +698,7 @@ impl Live {
             let _ = self.inner.console.write_control_codes(controls);
         }
 
+        self.inner.notify_stop();
         Ok(())
     }
 
@@ -323,7 +708,9 @@ impl Live {
         R: Renderable + Send + Sync + 'static,
     {
         *write_recover(&self.inner.renderable) = Some(Box::new(renderable));
-        if refresh {
+        self.inner.stale.store(true, Ordering::SeqCst);
+        self.inner.updated.store(true, Ordering::SeqCst);
+        if refresh && !self.inner.frame_limited() {
             let _ = self.refresh();
         }
     }
@@ -333,6 +720,55 @@ impl Live {
         self.inner.refresh_display()
     }
 
+    /// Frames recorded so far, each with its time since the display started.
+    ///
+    /// Empty unless [`LiveOptions::record_frames`] is set.
+    #[must_use]
+    pub fn frames(&self) -> Vec<(Duration, Vec<Segment<'static>>)> {
+        lock_recover(&self.inner.recorded).clone()
+    }
+
+    /// Export the recorded frames as an [asciicast v2] recording.
+    ///
+    /// Each frame clears the screen and redraws, so the recording replays
+    /// with `asciinema play` or any asciicast player. Styles use the
+    /// console's color system.
+    ///
+    /// [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+    #[must_use]
+    pub fn export_asciicast(&self) -> String {
+        let frames = self.frames();
+        let console = &self.inner.console;
+        let height = frames
+            .iter()
+            .map(|(_, frame)| split_lines(frame.iter().cloned()).len())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let mut out = format!(
+            "{{\"version\": 2, \"width\": {}, \"height\": {height}}}\n",
+            console.width()
+        );
+        for (elapsed, frame) in &frames {
+            let mut data = String::from("\x1b[H\x1b[2J");
+            for segment in frame {
+                let text = segment.text.replace('\n', "\r\n");
+                match (console.color_system(), &segment.style) {
+                    (Some(system), Some(style)) => data.push_str(&style.render(&text, system)),
+                    _ => data.push_str(&text),
+                }
+            }
+            let _ = writeln!(
+                out,
+                "[{:.6}, \"o\", {}]",
+                elapsed.as_secs_f64(),
+                asciicast_string(&data)
+            );
+        }
+        out
+    }
+
     /// Create a stdout proxy writer that routes output through the Console.
     #[must_use]
     pub fn stdout_proxy(&self) -> LiveWriter {
@@ -444,14 +880,15 @@ impl LiveInner {
         console: &Console,
         options: &ConsoleOptions,
     ) -> Vec<Segment<'static>> {
-        let lives = console.live_stack_snapshot();
-        if lives.is_empty() {
+        let mut lives = console.live_stack_snapshot();
+        if lives.len() <= 1 {
             return self.current_renderable(console, options);
         }
+        lives.sort_by_key(|live| live.options().position == LivePosition::Bottom);
 
         let mut output = Vec::new();
         for (idx, live) in lives.iter().enumerate() {
-            let segments = live.current_renderable(console, options);
+            let segments = live.area_segments(console, options);
             if idx > 0 && !segments.is_empty() {
                 output.push(Segment::line());
             }
@@ -460,8 +897,17 @@ impl LiveInner {
         output
     }
 
+    /// Segments for this area, re-rendered only when it is stale.
+    fn area_segments(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'static>> {
+        let mut rendered = lock_recover(&self.rendered);
+        if self.stale.swap(false, Ordering::SeqCst) || rendered.is_none() {
+            *rendered = Some(self.current_renderable(console, options));
+        }
+        rendered.clone().unwrap_or_default()
+    }
+
     fn live_render_controls_restore(&self) -> Vec<ControlCode> {
-        lock_recover(&self.live_render).restore_cursor_controls()
+        lock_recover(&self.live_render).restore_cursor_controls(self.console.width())
     }
 
     fn render_live_segments(
@@ -513,6 +959,8 @@ impl LiveInner {
             max_width = max_width.max(line_width);
         }
         render.shape = Some((max_width, lines.len()));
+        render.lines.clone_from(&lines);
+        render.size = Some(options.size);
 
         let mut flattened = Vec::new();
         let last_index = lines.len().saturating_sub(1);
@@ -531,17 +979,26 @@ impl LiveInner {
         }
 
         let inner = Arc::clone(self);
-        let interval = {
-            let options = self.options();
-            Duration::from_secs_f64(1.0 / options.refresh_per_second)
-        };
+        let options = self.options();
+        let interval = options.refresh_interval();
+        let mut pacer = RefreshPacer::new(options.adaptive_refresh, Instant::now());
         let stop = Arc::clone(&self.refresh_stop);
 
         let handle = thread::spawn(move || {
+            let mut size = inner.console.size();
             while !stop.load(Ordering::Relaxed) {
                 thread::sleep(interval);
-                if !stop.load(Ordering::Relaxed) {
+                if stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                let now = Instant::now();
+                let current = inner.console.size();
+                let resized = current != size;
+                size = current;
+                let updated = inner.updated.swap(false, Ordering::SeqCst);
+                if pacer.should_draw(now, updated || resized) {
                     let _ = inner.refresh_display();
+                    pacer.drew(now, inner.frame_hash());
                 }
             }
         });
@@ -556,21 +1013,143 @@ impl LiveInner {
         }
     }
 
-    fn refresh_display(&self) -> io::Result<()> {
+    pub(crate) fn refresh_display(&self) -> io::Result<()> {
+        self.stale.store(true, Ordering::SeqCst);
         if self.nested.load(Ordering::SeqCst) {
-            if let Some(parent) = self.console.live_stack_snapshot().first() {
+            // The area owning the region draws every area.
+            if let Some(parent) = self.console.live_stack_snapshot().first()
+                && !std::ptr::eq(Arc::as_ptr(parent), self)
+            {
                 return parent.refresh_display();
             }
             return Ok(());
         }
 
-        if (self.console.is_terminal() && !self.console.is_dumb_terminal())
-            || !self.options().transient
-        {
+        if self.console.is_interactive() || !self.options().transient {
             self.console.print_segments(&[]);
+            *lock_recover(&self.last_frame) = Some(Instant::now());
+            self.notify_frame();
         }
         Ok(())
     }
+
+    fn notify_start(&self) {
+        *lock_recover(&self.started_at) = Some(Instant::now());
+        self.frames.store(0, Ordering::SeqCst);
+        lock_recover(&self.recorded).clear();
+        if let Some(on_start) = self.options().hooks.start {
+            on_start();
+        }
+    }
+
+    fn notify_stop(&self) {
+        if let Some(on_stop) = self.options().hooks.stop {
+            on_stop();
+        }
+    }
+
+    fn notify_frame(&self) {
+        let index = self.frames.fetch_add(1, Ordering::SeqCst);
+        let options = self.options();
+        if options.hooks.frame.is_none() && !options.record_frames {
+            return;
+        }
+        let elapsed = lock_recover(&self.started_at).map_or(Duration::ZERO, |at| at.elapsed());
+        // Copy the lines out so the callback may refresh without deadlocking.
+        let lines = lock_recover(&self.live_render).lines.clone();
+        if options.record_frames {
+            let mut frame = Vec::new();
+            for (idx, line) in lines.iter().enumerate() {
+                if idx > 0 {
+                    frame.push(Segment::line());
+                }
+                frame.extend(line.iter().filter(|s| s.control.is_none()).cloned());
+            }
+            lock_recover(&self.recorded).push((elapsed, frame));
+        }
+        if let Some(on_frame) = options.hooks.frame {
+            on_frame(&LiveFrame {
+                index,
+                elapsed,
+                lines: &lines,
+            });
+        }
+    }
+
+    /// Whether a redraw now would exceed `max_fps`.
+    ///
+    /// Only applies while the refresh thread runs, so the skipped frame is
+    /// still drawn on its next tick.
+    fn frame_limited(&self) -> bool {
+        let Some(max_fps) = self.options().max_fps.filter(|fps| *fps > 0.0) else {
+            return false;
+        };
+        if lock_recover(&self.refresh_thread).is_none() {
+            return false;
+        }
+        lock_recover(&self.last_frame)
+            .is_some_and(|last| last.elapsed() < Duration::from_secs_f64(1.0 / max_fps))
+    }
+
+    /// Hash of the last rendered frame, used to detect an idle display.
+    fn frame_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for line in &lock_recover(&self.live_render).lines {
+            for segment in line {
+                segment.text.hash(&mut hasher);
+                segment.style.hash(&mut hasher);
+            }
+            '\n'.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+/// How long the frame must stay unchanged before adaptive refresh slows down.
+const IDLE_AFTER: Duration = Duration::from_secs(2);
+/// Time between frames while idle.
+const IDLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Decides which ticks of the refresh thread draw a frame.
+#[derive(Debug)]
+struct RefreshPacer {
+    adaptive: bool,
+    last_hash: Option<u64>,
+    last_change: Instant,
+    last_draw: Option<Instant>,
+}
+
+impl RefreshPacer {
+    fn new(adaptive: bool, now: Instant) -> Self {
+        Self {
+            adaptive,
+            last_hash: None,
+            last_change: now,
+            last_draw: None,
+        }
+    }
+
+    /// Whether the tick at `now` draws; `updated` means the renderable was replaced.
+    fn should_draw(&mut self, now: Instant, updated: bool) -> bool {
+        if updated {
+            self.last_change = now;
+            return true;
+        }
+        if !self.adaptive || now.duration_since(self.last_change) < IDLE_AFTER {
+            return true;
+        }
+        self.last_draw
+            .is_none_or(|last| now.duration_since(last) >= IDLE_INTERVAL)
+    }
+
+    /// Record a frame drawn at `now` whose content hashed to `hash`.
+    fn drew(&mut self, now: Instant, hash: u64) {
+        if self.last_hash != Some(hash) {
+            self.last_hash = Some(hash);
+            self.last_change = now;
+        }
+        self.last_draw = Some(now);
+    }
 }
 
 #[derive(Clone)]
@@ -743,20 +1322,39 @@ impl RenderHook for LiveInner {
 
         let mut output = Vec::new();
         if console.is_interactive() {
-            if self.alt_screen_active.load(Ordering::SeqCst) {
+            let alt_screen = self.alt_screen_active.load(Ordering::SeqCst);
+            let resized = render.size.is_some_and(|size| size != options.size);
+            if resized {
+                for live in console.live_stack_snapshot() {
+                    live.stale.store(true, Ordering::SeqCst);
+                }
+            }
+            // A pure refresh (nothing printed above) may only repaint changes.
+            let previous =
+                (segments.is_empty() && !alt_screen && !resized && self.options().partial_refresh)
+                    .then(|| render.lines.clone())
+                    .filter(|lines| !lines.is_empty());
+            if alt_screen {
                 output.push(Segment::control(vec![ControlCode::new(ControlType::Home)]));
             } else {
-                let controls = render.position_cursor_controls();
+                let controls = render.position_cursor_controls(options.size.width);
                 if !controls.is_empty() {
                     output.push(Segment::control(controls));
                 }
             }
             output.extend_from_slice(segments);
+            end_line(&mut output);
             let live_segments = self.render_live_segments(&mut render, console, &options, overflow);
+            if let Some(previous) = previous
+                && let Some(update) = render.changed_lines(&previous, options.size.height)
+            {
+                return update;
+            }
             output.extend(live_segments);
             output
         } else if !self.options().transient {
             output.extend_from_slice(segments);
+            end_line(&mut output);
             let live_segments = self.render_live_segments(&mut render, console, &options, overflow);
             output.extend(live_segments);
             output
@@ -766,16 +1364,143 @@ impl RenderHook for LiveInner {
     }
 }
 
+/// Finish a partial line printed above the live region, so the region starts on
+/// a line of its own and the next refresh doesn't erase the printed text.
+fn end_line(output: &mut Vec<Segment<'static>>) {
+    let last_text = output
+        .iter()
+        .rev()
+        .find(|segment| !segment.is_control() && !segment.text.is_empty());
+    if last_text.is_some_and(|segment| !segment.text.ends_with('\n')) {
+        output.push(Segment::line());
+    }
+}
+
+/// Quote `text` as a JSON string for an asciicast event.
+fn asciicast_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A cursor movement or column as a control code parameter.
+fn control_param(value: usize) -> i32 {
+    i32::try_from(value).unwrap_or(i32::MAX)
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct LiveRender {
     shape: Option<(usize, usize)>,
+    /// Lines of the last frame drawn, for partial refreshes.
+    lines: Vec<Vec<Segment<'static>>>,
+    /// Console size the last frame was drawn at.
+    size: Option<ConsoleDimensions>,
 }
 
 impl LiveRender {
-    fn position_cursor_controls(&self) -> Vec<ControlCode> {
+    /// Segments turning the `previous` frame into the current one in place.
+    ///
+    /// The cursor is expected at the end of the previous frame. Returns `None`
+    /// when the frame changed height or doesn't fit in `screen_height` lines,
+    /// as cursor movement can't reach lines scrolled off the screen.
+    fn changed_lines(
+        &self,
+        previous: &[Vec<Segment<'static>>],
+        screen_height: usize,
+    ) -> Option<Vec<Segment<'static>>> {
+        let height = self.lines.len();
+        if previous.len() != height || (screen_height > 0 && height > screen_height) {
+            return None;
+        }
+
+        let mut output = Vec::new();
+        let mut row = height - 1;
+        for (index, (old, new)) in previous.iter().zip(&self.lines).enumerate() {
+            if old == new {
+                continue;
+            }
+            let same = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+            let column: usize = new[..same].iter().map(Segment::cell_length).sum();
+
+            let mut controls = vec![ControlCode::with_params_vec(
+                ControlType::CursorMoveToColumn,
+                vec![control_param(column)],
+            )];
+            if index < row {
+                controls.push(ControlCode::with_params_vec(
+                    ControlType::CursorUp,
+                    vec![control_param(row - index)],
+                ));
+            } else if index > row {
+                controls.push(ControlCode::with_params_vec(
+                    ControlType::CursorDown,
+                    vec![control_param(index - row)],
+                ));
+            }
+            row = index;
+            output.push(Segment::control(controls));
+            output.extend(new[same..].iter().cloned());
+            output.push(Segment::control(vec![ControlCode::with_params_vec(
+                ControlType::EraseInLine,
+                vec![0],
+            )]));
+        }
+
+        // Leave the cursor at the end of the frame, as a full redraw does.
+        if !output.is_empty() {
+            let mut controls = Vec::new();
+            if row + 1 < height {
+                controls.push(ControlCode::with_params_vec(
+                    ControlType::CursorDown,
+                    vec![control_param(height - 1 - row)],
+                ));
+            }
+            let width: usize = self.lines[height - 1]
+                .iter()
+                .map(Segment::cell_length)
+                .sum();
+            controls.push(ControlCode::with_params_vec(
+                ControlType::CursorMoveToColumn,
+                vec![control_param(width)],
+            ));
+            output.push(Segment::control(controls));
+        }
+        Some(output)
+    }
+
+    /// Rows the last frame occupies on a screen `width` cells wide.
+    ///
+    /// After the terminal shrinks, lines wider than the screen have wrapped
+    /// onto extra rows that must be erased too.
+    fn screen_rows(&self, width: usize) -> usize {
         let Some((_, height)) = self.shape else {
-            return Vec::new();
+            return 0;
         };
+        if self.lines.is_empty() || width == 0 {
+            return height;
+        }
+        self.lines
+            .iter()
+            .map(|line| line_length(line).div_ceil(width).max(1))
+            .sum()
+    }
+
+    fn position_cursor_controls(&self, width: usize) -> Vec<ControlCode> {
+        let height = self.screen_rows(width);
         if height == 0 {
             return Vec::new();
         }
@@ -800,10 +1525,8 @@ impl LiveRender {
         controls
     }
 
-    fn restore_cursor_controls(&self) -> Vec<ControlCode> {
-        let Some((_, height)) = self.shape else {
-            return Vec::new();
-        };
+    fn restore_cursor_controls(&self, width: usize) -> Vec<ControlCode> {
+        let height = self.screen_rows(width);
         if height == 0 {
             return Vec::new();
         }
@@ -828,7 +1551,7 @@ mod tests {
     use std::io::Write;
     use std::sync::{Arc, Mutex};
     use std::thread;
-    use std::time::Duration;
+    use std::time::{Duration, Instant};
 
     #[derive(Clone)]
     struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
@@ -894,6 +1617,13 @@ mod tests {
             redirect_stdout: false,
             redirect_stderr: false,
             vertical_overflow: VerticalOverflowMethod::Crop,
+            position: LivePosition::Top,
+            partial_refresh: true,
+            max_fps: Some(5.0),
+            adaptive_refresh: true,
+            record_frames: true,
+            nested: false,
+            ..LiveOptions::default()
         };
         assert!(options.screen);
         assert!(!options.auto_refresh);
@@ -904,6 +1634,54 @@ mod tests {
         assert_eq!(options.vertical_overflow, VerticalOverflowMethod::Crop);
     }
 
+    #[test]
+    fn test_refresh_interval_clamped_by_max_fps() {
+        let mut options = LiveOptions {
+            refresh_per_second: 100.0,
+            ..LiveOptions::default()
+        };
+        assert_eq!(options.refresh_interval(), Duration::from_millis(10));
+        options.max_fps = Some(20.0);
+        assert_eq!(options.refresh_interval(), Duration::from_millis(50));
+        options.max_fps = Some(200.0);
+        assert_eq!(options.refresh_interval(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_refresh_pacer_slows_down_when_idle() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut pacer = RefreshPacer::new(true, start);
+
+        // Every tick draws until the frame has been unchanged for a while.
+        for millis in (250..=1750).step_by(250) {
+            assert!(pacer.should_draw(at(millis), false));
+            pacer.drew(at(millis), 1);
+        }
+
+        // Idle: about one frame per second.
+        assert!(!pacer.should_draw(at(2250), false));
+        assert!(!pacer.should_draw(at(2500), false));
+        assert!(pacer.should_draw(at(2750), false));
+        pacer.drew(at(2750), 1);
+        assert!(!pacer.should_draw(at(3500), false));
+        assert!(pacer.should_draw(at(3750), false));
+        pacer.drew(at(3750), 1);
+
+        // An update speeds it straight back up.
+        assert!(pacer.should_draw(at(4000), true));
+        pacer.drew(at(4000), 2);
+        assert!(pacer.should_draw(at(4250), false));
+    }
+
+    #[test]
+    fn test_refresh_pacer_without_adaptive_always_draws() {
+        let start = Instant::now();
+        let mut pacer = RefreshPacer::new(false, start);
+        pacer.drew(start, 1);
+        assert!(pacer.should_draw(start + Duration::from_mins(1), false));
+    }
+
     // =========================================================================
     // VerticalOverflowMethod Tests
     // =========================================================================
@@ -960,7 +1738,8 @@ mod tests {
         let console = make_test_console(buffer);
         let options = LiveOptions {
             screen: true,
-            transient: false, // Should be set to true when screen is true
+            // Should be set to true when screen is true
+            transient: false,
             ..LiveOptions::default()
         };
         let live = Live::with_options(console, options);
@@ -1422,7 +2201,7 @@ mod tests {
     #[test]
     fn test_live_render_position_cursor_no_shape() {
         let render = LiveRender::default();
-        let controls = render.position_cursor_controls();
+        let controls = render.position_cursor_controls(10);
         assert!(controls.is_empty());
     }
 
@@ -1430,8 +2209,9 @@ mod tests {
     fn test_live_render_position_cursor_zero_height() {
         let render = LiveRender {
             shape: Some((10, 0)),
+            ..LiveRender::default()
         };
-        let controls = render.position_cursor_controls();
+        let controls = render.position_cursor_controls(10);
         assert!(controls.is_empty());
     }
 
@@ -1439,8 +2219,9 @@ mod tests {
     fn test_live_render_position_cursor_single_line() {
         let render = LiveRender {
             shape: Some((10, 1)),
+            ..LiveRender::default()
         };
-        let controls = render.position_cursor_controls();
+        let controls = render.position_cursor_controls(10);
         // Should have CarriageReturn and EraseInLine
         assert!(!controls.is_empty());
         assert_eq!(controls.len(), 2);
@@ -1450,17 +2231,36 @@ mod tests {
     fn test_live_render_position_cursor_multiple_lines() {
         let render = LiveRender {
             shape: Some((10, 3)),
+            ..LiveRender::default()
         };
-        let controls = render.position_cursor_controls();
+        let controls = render.position_cursor_controls(10);
         // CR + EraseLine + (CursorUp + EraseLine) * 2
         // Total: 2 + 2*2 = 6
         assert_eq!(controls.len(), 6);
     }
 
+    #[test]
+    fn test_live_render_position_cursor_after_shrink() {
+        let render = LiveRender {
+            shape: Some((20, 2)),
+            lines: vec![
+                vec![Segment::new("x".repeat(20), None)],
+                vec![Segment::new("short", None)],
+            ],
+            ..LiveRender::default()
+        };
+        assert_eq!(render.screen_rows(20), 2);
+        // At 8 columns the first line wrapped onto 3 rows.
+        assert_eq!(render.screen_rows(8), 4);
+        // CR + EraseLine + (CursorUp + EraseLine) * 3
+        assert_eq!(render.position_cursor_controls(8).len(), 8);
+        assert_eq!(render.restore_cursor_controls(8).len(), 9);
+    }
+
     #[test]
     fn test_live_render_restore_cursor_no_shape() {
         let render = LiveRender::default();
-        let controls = render.restore_cursor_controls();
+        let controls = render.restore_cursor_controls(10);
         assert!(controls.is_empty());
     }
 
@@ -1468,8 +2268,9 @@ mod tests {
     fn test_live_render_restore_cursor_zero_height() {
         let render = LiveRender {
             shape: Some((10, 0)),
+            ..LiveRender::default()
         };
-        let controls = render.restore_cursor_controls();
+        let controls = render.restore_cursor_controls(10);
         assert!(controls.is_empty());
     }
 
@@ -1477,8 +2278,9 @@ mod tests {
     fn test_live_render_restore_cursor_with_height() {
         let render = LiveRender {
             shape: Some((10, 2)),
+            ..LiveRender::default()
         };
-        let controls = render.restore_cursor_controls();
+        let controls = render.restore_cursor_controls(10);
         // CR + (CursorUp + EraseLine) * height
         // Total: 1 + 2*2 = 5
         assert_eq!(controls.len(), 5);
@@ -1539,6 +2341,210 @@ mod tests {
         live.stop().expect("stop");
     }
 
+    #[test]
+    fn test_live_hooks_report_lifecycle_and_frames() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let record = |events: &Arc<Mutex<Vec<String>>>| {
+            let events = Arc::clone(events);
+            move |event: String| events.lock().unwrap().push(event)
+        };
+        let (on_start, on_stop, on_frame) = (record(&events), record(&events), record(&events));
+        let options = LiveOptions {
+            auto_refresh: false,
+            redirect_stdout: false,
+            redirect_stderr: false,
+            hooks: LiveHooks::new()
+                .on_start(move || on_start("start".to_string()))
+                .on_stop(move || on_stop("stop".to_string()))
+                .on_frame(move |frame| {
+                    let text: String = frame.lines[0].iter().map(|s| s.text.as_ref()).collect();
+                    on_frame(format!("frame {} {}", frame.index, text.trim_end()));
+                }),
+            ..LiveOptions::default()
+        };
+
+        let live = Live::with_options(console, options).renderable(Text::new("one"));
+        live.start(true).expect("start");
+        live.update(Text::new("two"), true);
+        live.stop().expect("stop");
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec!["start", "frame 0 one", "frame 1 two", "frame 2 two", "stop"]
+        );
+    }
+
+    #[test]
+    fn test_live_record_frames_and_export_asciicast() {
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .width(20)
+            .color_system(ColorSystem::Standard)
+            .file(Box::new(SharedBuffer::new()))
+            .build()
+            .shared();
+        let options = LiveOptions {
+            auto_refresh: false,
+            redirect_stdout: false,
+            redirect_stderr: false,
+            record_frames: true,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(console, options)
+            .renderable(Text::styled("a \"b\"", Style::new().bold()));
+        live.start(true).expect("start");
+        live.update(Text::new("one\ntwo"), true);
+        live.stop().expect("stop");
+
+        let frames = live.frames();
+        assert_eq!(frames.len(), 3);
+        assert!(frames.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        let text: String = frames[1].1.iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(text.trim_end(), "one\ntwo");
+
+        let cast = live.export_asciicast();
+        let mut lines = cast.lines();
+        assert_eq!(
+            lines.next(),
+            Some(r#"{"version": 2, "width": 20, "height": 2}"#)
+        );
+        let first = lines.next().expect("first event");
+        assert!(first.starts_with('['), "{first}");
+        assert!(
+            first.contains(r#""o", "\u001b[H\u001b[2J\u001b[1ma \"b\""#),
+            "{first}"
+        );
+        assert!(lines.next().expect("second event").contains(r"one\r\ntwo"));
+        assert_eq!(lines.count(), 1);
+    }
+
+    #[test]
+    fn test_live_without_record_frames_keeps_nothing() {
+        let buffer = SharedBuffer::new();
+        let live = Live::with_options(
+            make_test_console(buffer),
+            LiveOptions {
+                auto_refresh: false,
+                redirect_stdout: false,
+                redirect_stderr: false,
+                ..LiveOptions::default()
+            },
+        )
+        .renderable(Text::new("hi"));
+        live.start(true).expect("start");
+        live.stop().expect("stop");
+        assert!(live.frames().is_empty());
+        assert_eq!(live.export_asciicast().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_console_output_goes_above_live_region() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let options = LiveOptions {
+            auto_refresh: false,
+            redirect_stdout: false,
+            redirect_stderr: false,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(Arc::clone(&console), options).renderable(Text::new("LIVE"));
+        live.start(true).expect("start");
+
+        // Output without escape sequences and carriage returns.
+        let plain = |text: String| {
+            let mut out = String::new();
+            let mut chars = text.chars();
+            while let Some(c) = chars.next() {
+                if c == '\x1b' {
+                    chars.by_ref().find(char::is_ascii_alphabetic);
+                } else if c != '\r' {
+                    out.push(c);
+                }
+            }
+            out
+        };
+        buffer.clear();
+        console.print("first");
+        assert!(
+            plain(buffer.text()).ends_with("first\nLIVE"),
+            "{:?}",
+            buffer.text()
+        );
+
+        // A partial line still ends before the live region starts.
+        buffer.clear();
+        console.print_with_options(
+            "partial",
+            &crate::console::PrintOptions::new().with_no_newline(true),
+        );
+        assert!(
+            plain(buffer.text()).ends_with("partial\nLIVE"),
+            "{:?}",
+            buffer.text()
+        );
+
+        // Blank lines and log entries are routed the same way, each in one pass.
+        buffer.clear();
+        console.line();
+        assert!(
+            plain(buffer.text()).ends_with("\nLIVE"),
+            "{:?}",
+            buffer.text()
+        );
+        buffer.clear();
+        console.log("entry", crate::console::LogLevel::Info);
+        let text = plain(buffer.text());
+        assert_eq!(text.matches("LIVE").count(), 1, "{text:?}");
+        assert!(text.ends_with("[INFO] entry\nLIVE"), "{text:?}");
+
+        live.stop().expect("stop");
+        assert!(
+            plain(buffer.text()).ends_with("LIVE\n"),
+            "{:?}",
+            buffer.text()
+        );
+    }
+
+    #[test]
+    fn test_live_partial_refresh_repaints_changed_lines_only() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let options = LiveOptions {
+            auto_refresh: false,
+            redirect_stdout: false,
+            redirect_stderr: false,
+            partial_refresh: true,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(console, options).renderable(Text::new("alpha\nbeta\ngamma"));
+        live.start(true).expect("start");
+        assert!(buffer.text().contains("gamma"));
+
+        buffer.clear();
+        live.update(Text::new("alpha\nbexa\ngamma"), true);
+        let text = buffer.text();
+        assert!(text.contains("bexa"), "changed line missing: {text:?}");
+        assert!(
+            !text.contains("alpha") && !text.contains("gamma"),
+            "{text:?}"
+        );
+
+        buffer.clear();
+        live.refresh().expect("refresh");
+        assert_eq!(buffer.text(), "", "an unchanged frame writes nothing");
+
+        buffer.clear();
+        live.update(Text::new("one\ntwo"), true);
+        assert!(
+            buffer.text().contains("one"),
+            "a new height redraws everything"
+        );
+        live.stop().expect("stop");
+    }
+
     // =========================================================================
     // Auto-refresh Tests
     // =========================================================================
@@ -1549,7 +2555,8 @@ mod tests {
         let console = make_test_console(buffer.clone());
         let options = LiveOptions {
             auto_refresh: false,
-            refresh_per_second: 100.0, // High rate to detect if running
+            // High rate to detect if running
+            refresh_per_second: 100.0,
             ..LiveOptions::default()
         };
         let live = Live::with_options(console, options).renderable(Text::new("Static"));
@@ -1580,7 +2587,8 @@ mod tests {
 
         let options = LiveOptions {
             auto_refresh: true,
-            refresh_per_second: 20.0, // 50ms intervals
+            // 50ms intervals
+            refresh_per_second: 20.0,
             screen: false,
             transient: false,
             ..LiveOptions::default()
@@ -1604,6 +2612,181 @@ mod tests {
         );
     }
 
+    // =========================================================================
+    // Multiple Areas Tests
+    // =========================================================================
+
+    fn manual_options(position: LivePosition) -> LiveOptions {
+        LiveOptions {
+            auto_refresh: false,
+            redirect_stdout: false,
+            redirect_stderr: false,
+            position,
+            ..LiveOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_live_areas_render_in_position_order() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let progress =
+            Live::with_options(Arc::clone(&console), manual_options(LivePosition::Bottom))
+                .renderable(Text::new("progress"));
+        let status = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Top))
+            .renderable(Text::new("status"));
+
+        progress.start(true).expect("start progress");
+        status.start(true).expect("start status");
+        buffer.clear();
+        progress.refresh().expect("refresh");
+
+        let text = buffer.text();
+        let status_at = text.find("status").expect("status drawn");
+        let progress_at = text.find("progress").expect("progress drawn");
+        assert!(status_at < progress_at, "{text:?}");
+
+        status.stop().expect("stop status");
+        progress.stop().expect("stop progress");
+    }
+
+    #[test]
+    fn test_live_areas_refresh_independently() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer);
+        let renders = Arc::new(Mutex::new(0));
+        let progress =
+            Live::with_options(Arc::clone(&console), manual_options(LivePosition::Bottom))
+                .renderable(Text::new("progress"));
+        let counter = Arc::clone(&renders);
+        let status = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Top))
+            .get_renderable(move || {
+                *counter.lock().unwrap() += 1;
+                Box::new(Text::new("status"))
+            });
+
+        progress.start(true).expect("start progress");
+        status.start(true).expect("start status");
+        let after_start = *renders.lock().unwrap();
+        assert_eq!(after_start, 1);
+
+        // Refreshing the other area reuses the last render of this one.
+        progress.refresh().expect("refresh");
+        progress.refresh().expect("refresh");
+        assert_eq!(*renders.lock().unwrap(), after_start);
+
+        status.refresh().expect("refresh");
+        assert_eq!(*renders.lock().unwrap(), after_start + 1);
+
+        status.stop().expect("stop status");
+        progress.stop().expect("stop progress");
+    }
+
+    #[test]
+    fn test_live_areas_stop_in_any_order() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer);
+        let first = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Bottom));
+        let second = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Top));
+        let third = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Bottom));
+
+        first.start(false).expect("start");
+        second.start(false).expect("start");
+        third.start(false).expect("start");
+        second.stop().expect("stop");
+
+        let remaining = console.live_stack_snapshot();
+        assert_eq!(remaining.len(), 2);
+        assert!(Arc::ptr_eq(&remaining[0], &first.inner));
+        assert!(Arc::ptr_eq(&remaining[1], &third.inner));
+
+        third.stop().expect("stop");
+        first.stop().expect("stop");
+        assert!(console.live_stack_snapshot().is_empty());
+    }
+
+    #[test]
+    fn test_live_not_nested_rejects_second_display() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer);
+        let first = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Bottom));
+        let exclusive = Live::with_options(
+            Arc::clone(&console),
+            LiveOptions {
+                nested: false,
+                ..LiveOptions::default()
+            },
+        );
+
+        first.start(false).expect("start");
+        let err = exclusive.try_start(false).expect_err("already active");
+        assert!(matches!(err, LiveError::AlreadyActive));
+        let err = exclusive.start(false).expect_err("already active");
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(console.live_stack_snapshot().len(), 1);
+
+        first.stop().expect("stop");
+        exclusive
+            .start(false)
+            .expect("start once the first stopped");
+        exclusive.stop().expect("stop");
+    }
+
+    #[test]
+    fn test_nested_live_refreshes_on_accessible_console() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .accessible(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let outer = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Top))
+            .renderable(Text::new("outer"));
+        let inner = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Bottom))
+            .renderable(Text::new("inner"));
+
+        outer.start(true).expect("start outer");
+        inner.start(true).expect("start inner");
+        inner.update(Text::new("inner done"), true);
+        inner.stop().expect("stop inner");
+        outer.stop().expect("stop outer");
+
+        // No cursor control: every refresh appends the whole region, nested area included.
+        let output = buffer.text();
+        assert_eq!(output, "outerouter\ninnerouter\ninner doneouterouter\n");
+    }
+
+    #[test]
+    fn test_progress_nests_inside_active_live() {
+        use crate::renderables::progress::Progress;
+
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let status = Live::with_options(Arc::clone(&console), manual_options(LivePosition::Top))
+            .renderable(Text::new("status"));
+        status.start(true).expect("start status");
+
+        let progress = Progress::new(Arc::clone(&console));
+        let task = progress.add_task("Copying", Some(10));
+        progress.start().expect("progress nests");
+        progress.advance(task, 5);
+        buffer.clear();
+        status.refresh().expect("refresh");
+
+        let text = buffer.text();
+        let status_at = text.find("status").expect("status drawn");
+        let progress_at = text.find("Copying").expect("progress drawn");
+        assert!(status_at < progress_at, "{text:?}");
+
+        progress.stop().expect("stop progress");
+        buffer.clear();
+        status.refresh().expect("refresh");
+        assert!(!buffer.text().contains("Copying"), "{:?}", buffer.text());
+        status.stop().expect("stop status");
+    }
+
     // =========================================================================
     // Edge Cases
     // =========================================================================
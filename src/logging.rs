@@ -4,7 +4,9 @@
 //! `tracing` feature is enabled.
 
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use crate::segment::{ControlCode, ControlType, Segment};
 use crate::sync::lock_recover;
 
 use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
@@ -19,6 +21,9 @@ use crate::text::Text;
 #[cfg(not(feature = "backtrace"))]
 use crate::renderables::traceback::TracebackFrame;
 
+/// Minimum time between in-place updates of a `last message repeated` line.
+const REPEAT_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
 const DEFAULT_KEYWORDS: [&str; 8] = [
     "GET", "POST", "HEAD", "PUT", "DELETE", "OPTIONS", "TRACE", "PATCH",
 ];
@@ -52,6 +57,24 @@ pub struct RichLogger {
     keyword_style: Style,
     rich_tracebacks: bool,
     tracebacks_extra_lines: usize,
+    allowed_targets: Vec<String>,
+    suppressed_targets: Vec<String>,
+    summarize_suppressed: bool,
+    suppressed_counts: Mutex<Vec<(String, usize)>>,
+    fold_repeated: bool,
+    repeats: Mutex<RepeatState>,
+}
+
+/// The most recent shown record and how often it has repeated since.
+#[derive(Debug, Default)]
+struct RepeatState {
+    /// Level, target and message of the last shown record.
+    last: Option<(Level, String, String)>,
+    /// Repeats folded since that record was shown.
+    count: usize,
+    /// Count currently on screen (0 when no repeat line has been printed).
+    shown: usize,
+    last_refresh: Option<Instant>,
 }
 
 impl RichLogger {
@@ -76,6 +99,12 @@ impl RichLogger {
             keyword_style: Style::parse("bold yellow").unwrap_or_default(),
             rich_tracebacks: false,
             tracebacks_extra_lines: 3,
+            allowed_targets: Vec::new(),
+            suppressed_targets: Vec::new(),
+            summarize_suppressed: false,
+            suppressed_counts: Mutex::new(Vec::new()),
+            fold_repeated: false,
+            repeats: Mutex::new(RepeatState::default()),
         }
     }
 
@@ -162,6 +191,55 @@ impl RichLogger {
         self
     }
 
+    /// Only show records whose target starts with one of `targets`.
+    ///
+    /// Prefixes match whole path segments: `"app"` covers `app` and
+    /// `app::db` but not `apparatus`. An empty list (the default) allows all.
+    #[must_use]
+    pub fn allow<I, S>(mut self, targets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_targets = targets.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Hide records whose target starts with one of `targets`, e.g.
+    /// `suppress(["hyper", "h2"])`.
+    ///
+    /// Suppression wins over [`allow`](Self::allow), so a noisy submodule of
+    /// an allowed target can still be silenced.
+    #[must_use]
+    pub fn suppress<I, S>(mut self, targets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.suppressed_targets = targets.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Count hidden records and print a dim `suppressed N messages from hyper`
+    /// line before the next shown record (or on flush).
+    #[must_use]
+    pub fn summarize_suppressed(mut self, summarize: bool) -> Self {
+        self.summarize_suppressed = summarize;
+        self
+    }
+
+    /// Fold identical consecutive records into `last message repeated N times`.
+    ///
+    /// Records repeat when level, target and message all match. On a terminal
+    /// the repeat line is updated in place (above any active
+    /// [`Live`](crate::live::Live) display) at most every 100 ms; otherwise it
+    /// is printed once, before the next different record or on flush.
+    #[must_use]
+    pub fn fold_repeated(mut self, fold: bool) -> Self {
+        self.fold_repeated = fold;
+        self
+    }
+
     /// Install as the global logger.
     pub fn init(self) -> Result<(), SetLoggerError> {
         log::set_max_level(self.level);
@@ -174,6 +252,125 @@ impl RichLogger {
             .unwrap_or_else(|_| now.to_string())
     }
 
+    /// The name a hidden record is counted under, or `None` if `target` is shown.
+    fn suppressed_source(&self, target: &str) -> Option<String> {
+        if let Some(prefix) = self
+            .suppressed_targets
+            .iter()
+            .find(|prefix| target_matches(target, prefix))
+        {
+            return Some(prefix.clone());
+        }
+        if !self.allowed_targets.is_empty()
+            && !self
+                .allowed_targets
+                .iter()
+                .any(|prefix| target_matches(target, prefix))
+        {
+            return Some(target.split("::").next().unwrap_or(target).to_string());
+        }
+        None
+    }
+
+    fn count_suppressed(&self, source: String) {
+        let mut counts = lock_recover(&self.suppressed_counts);
+        match counts.iter_mut().find(|(name, _)| *name == source) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((source, 1)),
+        }
+    }
+
+    /// Print and reset the pending suppression summary, if any.
+    fn print_suppressed_summary(&self) {
+        let counts = std::mem::take(&mut *lock_recover(&self.suppressed_counts));
+        if counts.is_empty() {
+            return;
+        }
+        let parts: Vec<String> = counts
+            .iter()
+            .map(|(source, count)| {
+                let noun = if *count == 1 { "message" } else { "messages" };
+                format!("{count} {noun} from {source}")
+            })
+            .collect();
+        let summary = Text::styled(
+            format!("suppressed {}", parts.join(", ")),
+            Style::parse("dim").unwrap_or_default(),
+        );
+        self.console.print_text(&summary);
+    }
+
+    /// Fold `record` into the repeat count if it matches the last shown record.
+    ///
+    /// Returns `true` when the record was folded and must not be printed.
+    fn fold_repeat(&self, record: &Record<'_>) -> bool {
+        let message = record.args().to_string();
+        let (folded, pending) = {
+            let mut repeats = lock_recover(&self.repeats);
+            if repeats.last.as_ref().is_some_and(|(level, target, last)| {
+                *level == record.level() && target == record.target() && *last == message
+            }) {
+                repeats.count += 1;
+                (true, self.pending_repeats(&mut repeats, false))
+            } else {
+                let pending = self.pending_repeats(&mut repeats, true);
+                *repeats = RepeatState {
+                    last: Some((record.level(), record.target().to_string(), message)),
+                    ..RepeatState::default()
+                };
+                (false, pending)
+            }
+        };
+        // Print outside the lock: the console may call back into logging.
+        if let Some((count, replace)) = pending {
+            self.print_repeats(count, replace);
+        }
+        folded
+    }
+
+    /// Take the repeat count to print, if it changed, marking it as shown.
+    ///
+    /// Returns the count and whether it replaces an earlier repeat line.
+    /// Unless `force`d, terminals are refreshed at most every
+    /// [`REPEAT_REFRESH_INTERVAL`] and other outputs wait for the final count.
+    fn pending_repeats(&self, repeats: &mut RepeatState, force: bool) -> Option<(usize, bool)> {
+        if repeats.count == repeats.shown {
+            return None;
+        }
+        let terminal = self.console.is_terminal();
+        if !force
+            && (!terminal
+                || repeats
+                    .last_refresh
+                    .is_some_and(|at| at.elapsed() < REPEAT_REFRESH_INTERVAL))
+        {
+            return None;
+        }
+        let replace = terminal && repeats.shown > 0;
+        repeats.shown = repeats.count;
+        repeats.last_refresh = Some(Instant::now());
+        Some((repeats.count, replace))
+    }
+
+    /// Print the repeat line, replacing the previous one when `replace` is set.
+    fn print_repeats(&self, count: usize, replace: bool) {
+        let mut segments = Vec::new();
+        if replace {
+            segments.push(Segment::control(vec![
+                ControlCode::with_params_vec(ControlType::CursorUp, vec![1]),
+                ControlCode::with_params_vec(ControlType::EraseInLine, vec![2]),
+            ]));
+        }
+        let noun = if count == 1 { "time" } else { "times" };
+        let line = Text::styled(
+            format!("last message repeated {count} {noun}"),
+            Style::parse("dim").unwrap_or_default(),
+        );
+        let line = line.resolve_styles(&self.console);
+        segments.extend(line.render("\n").into_iter().map(Segment::into_owned));
+        self.console.print_segments(&segments);
+    }
+
     fn level_style(level: Level) -> Style {
         match level {
             Level::Trace => Style::parse("dim").unwrap_or_default(),
@@ -275,15 +472,34 @@ impl RichLogger {
     }
 }
 
+/// Whether `target` is `prefix` or one of its `::` submodules.
+fn target_matches(target: &str, prefix: &str) -> bool {
+    target
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
 impl Log for RichLogger {
     fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        // Level only: suppressed targets still reach `log` so they can be
+        // counted for `summarize_suppressed`.
         metadata.level() <= self.level
     }
 
     fn log(&self, record: &Record<'_>) {
-        if !self.enabled(record.metadata()) {
+        if record.level() > self.level {
+            return;
+        }
+        if let Some(source) = self.suppressed_source(record.target()) {
+            if self.summarize_suppressed {
+                self.count_suppressed(source);
+            }
             return;
         }
+        if self.fold_repeated && self.fold_repeat(record) {
+            return;
+        }
+        self.print_suppressed_summary();
 
         let text = self.format_record(record);
         self.console.print_text(&text);
@@ -294,7 +510,13 @@ impl Log for RichLogger {
         }
     }
 
-    fn flush(&self) {}
+    fn flush(&self) {
+        let pending = self.pending_repeats(&mut lock_recover(&self.repeats), true);
+        if let Some((count, replace)) = pending {
+            self.print_repeats(count, replace);
+        }
+        self.print_suppressed_summary();
+    }
 }
 
 #[cfg(feature = "tracing")]
@@ -328,6 +550,50 @@ mod tracing_integration {
             Self { logger }
         }
 
+        /// Only show events whose target starts with one of `targets`.
+        ///
+        /// See [`RichLogger::allow`].
+        #[must_use]
+        pub fn allow<I, S>(mut self, targets: I) -> Self
+        where
+            I: IntoIterator<Item = S>,
+            S: Into<String>,
+        {
+            self.logger = self.logger.allow(targets);
+            self
+        }
+
+        /// Hide events whose target starts with one of `targets`.
+        ///
+        /// See [`RichLogger::suppress`].
+        #[must_use]
+        pub fn suppress<I, S>(mut self, targets: I) -> Self
+        where
+            I: IntoIterator<Item = S>,
+            S: Into<String>,
+        {
+            self.logger = self.logger.suppress(targets);
+            self
+        }
+
+        /// Summarize hidden events before the next shown one.
+        ///
+        /// See [`RichLogger::summarize_suppressed`].
+        #[must_use]
+        pub fn summarize_suppressed(mut self, summarize: bool) -> Self {
+            self.logger = self.logger.summarize_suppressed(summarize);
+            self
+        }
+
+        /// Fold identical consecutive events into `last message repeated N times`.
+        ///
+        /// See [`RichLogger::fold_repeated`].
+        #[must_use]
+        pub fn fold_repeated(mut self, fold: bool) -> Self {
+            self.logger = self.logger.fold_repeated(fold);
+            self
+        }
+
         /// Install as the global tracing subscriber.
         pub fn init(self) -> Result<(), tracing::subscriber::SetGlobalDefaultError> {
             use tracing_subscriber::prelude::*;
@@ -453,6 +719,60 @@ mod tracing_integration {
             let _ = layer;
         }
 
+        #[test]
+        fn test_rich_tracing_layer_prints_above_live() {
+            use crate::live::{Live, LiveOptions};
+            use crate::text::Text;
+            use std::io::Write;
+            use std::sync::Mutex;
+            use tracing_subscriber::prelude::*;
+
+            #[derive(Clone)]
+            struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+            impl Write for SharedBuffer {
+                fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                    self.0.lock().unwrap().extend_from_slice(buf);
+                    Ok(buf.len())
+                }
+                fn flush(&mut self) -> std::io::Result<()> {
+                    Ok(())
+                }
+            }
+
+            let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+            let console = Console::builder()
+                .force_terminal(true)
+                .width(80)
+                .file(Box::new(buffer.clone()))
+                .build()
+                .shared();
+            let live = Live::with_options(
+                Arc::clone(&console),
+                LiveOptions {
+                    auto_refresh: false,
+                    redirect_stdout: false,
+                    redirect_stderr: false,
+                    ..LiveOptions::default()
+                },
+            )
+            .renderable(Text::new("LIVE-REGION"));
+            live.start(true).expect("start");
+            buffer.0.lock().unwrap().clear();
+
+            let subscriber =
+                tracing_subscriber::registry().with(RichTracingLayer::new(Arc::clone(&console)));
+            tracing::subscriber::with_default(subscriber, || {
+                tracing::info!("layer event");
+            });
+
+            let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+            let event_at = output.find("layer event").expect("event printed");
+            let live_at = output.rfind("LIVE-REGION").expect("live redrawn");
+            assert!(event_at < live_at, "{output:?}");
+            live.stop().expect("stop");
+        }
+
         #[test]
         fn test_rich_tracing_layer_with_logger() {
             let console = Arc::new(Console::builder().force_terminal(true).build());
@@ -1103,4 +1423,250 @@ mod tests {
         assert!(plain.contains("Line 2"));
         assert!(plain.contains("Line 3"));
     }
+
+    // =========================================================================
+    // Target Filtering Tests
+    // =========================================================================
+
+    fn target_record<'a>(target: &'a str, args: std::fmt::Arguments<'a>) -> Record<'a> {
+        Record::builder()
+            .args(args)
+            .level(Level::Info)
+            .target(target)
+            .build()
+    }
+
+    #[test]
+    fn test_target_matches_whole_segments() {
+        assert!(target_matches("hyper", "hyper"));
+        assert!(target_matches("hyper::client::pool", "hyper"));
+        assert!(!target_matches("hyperlocal", "hyper"));
+        assert!(!target_matches("h", "hyper"));
+    }
+
+    #[test]
+    fn test_suppress_and_allow_targets() {
+        let console = Arc::new(Console::builder().force_terminal(true).build());
+        let logger = RichLogger::new(console)
+            .allow(["app"])
+            .suppress(["app::noisy"]);
+        let meta = |target| {
+            Metadata::builder()
+                .level(Level::Info)
+                .target(target)
+                .build()
+        };
+
+        // Target filtering happens in `log`, not `enabled`.
+        assert!(logger.enabled(&meta("app::noisy::retry")));
+        assert!(logger.enabled(&meta("hyper::client")));
+
+        assert_eq!(logger.suppressed_source("app"), None);
+        assert_eq!(logger.suppressed_source("app::db"), None);
+        assert_eq!(
+            logger.suppressed_source("app::noisy::retry").as_deref(),
+            Some("app::noisy")
+        );
+        assert_eq!(
+            logger.suppressed_source("hyper::client").as_deref(),
+            Some("hyper")
+        );
+    }
+
+    #[test]
+    fn test_summarize_suppressed_prints_counts_before_next_record() {
+        let console = Arc::new(
+            Console::builder()
+                .force_terminal(false)
+                .markup(false)
+                .width(80)
+                .build(),
+        );
+        let logger = RichLogger::new(Arc::clone(&console))
+            .show_time(false)
+            .show_level(false)
+            .show_path(false)
+            .suppress(["hyper", "h2"])
+            .summarize_suppressed(true);
+
+        console.begin_capture();
+        logger.log(&target_record("hyper::proto", format_args!("read 1")));
+        logger.log(&target_record("hyper::proto", format_args!("read 2")));
+        logger.log(&target_record("h2::codec", format_args!("frame")));
+        logger.log(&target_record("app", format_args!("ready")));
+        logger.log(&target_record("h2", format_args!("frame")));
+        logger.flush();
+        logger.flush();
+        let output: String = console
+            .end_capture()
+            .iter()
+            .map(|segment| segment.text.as_ref())
+            .collect();
+
+        assert_eq!(
+            output,
+            "suppressed 2 messages from hyper, 1 message from h2\nready\n\
+             suppressed 1 message from h2\n"
+        );
+    }
+
+    #[test]
+    fn test_suppressed_records_are_dropped_silently_by_default() {
+        let console = Arc::new(Console::builder().force_terminal(false).build());
+        let logger = RichLogger::new(Arc::clone(&console)).suppress(["hyper"]);
+
+        console.begin_capture();
+        logger.log(&target_record("hyper", format_args!("noise")));
+        logger.flush();
+        assert!(console.end_capture().is_empty());
+    }
+
+    #[test]
+    fn test_suppressed_records_pass_enabled_and_are_counted() {
+        let console = Arc::new(Console::builder().force_terminal(false).width(80).build());
+        let logger = RichLogger::new(Arc::clone(&console))
+            .show_time(false)
+            .show_level(false)
+            .show_path(false)
+            .suppress(["hyper"])
+            .summarize_suppressed(true);
+
+        // Mirror the `log` macros, which check `enabled` before `log`.
+        console.begin_capture();
+        for target in ["hyper::proto", "hyper", "app"] {
+            let record = target_record(target, format_args!("event"));
+            if logger.enabled(record.metadata()) {
+                logger.log(&record);
+            }
+        }
+        let output: String = console
+            .end_capture()
+            .iter()
+            .map(|segment| segment.text.as_ref())
+            .collect();
+
+        assert_eq!(output, "suppressed 2 messages from hyper\nevent\n");
+    }
+
+    // =========================================================================
+    // Repeat Folding Tests
+    // =========================================================================
+
+    fn captured_text(segments: &[Segment<'static>]) -> String {
+        segments
+            .iter()
+            .filter(|segment| !segment.is_control())
+            .map(|segment| segment.text.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_fold_repeated_prints_count_before_next_record() {
+        let console = Arc::new(Console::builder().force_terminal(false).width(80).build());
+        let logger = RichLogger::new(Arc::clone(&console))
+            .show_time(false)
+            .show_level(false)
+            .show_path(false)
+            .fold_repeated(true);
+
+        console.begin_capture();
+        for _ in 0..3 {
+            logger.log(&target_record("app", format_args!("retrying")));
+        }
+        logger.log(&target_record("other", format_args!("retrying")));
+        logger.log(&target_record("app", format_args!("done")));
+        logger.log(&target_record("app", format_args!("done")));
+        logger.flush();
+        let segments = console.end_capture();
+
+        assert!(segments.iter().all(|segment| !segment.is_control()));
+        assert_eq!(
+            captured_text(&segments),
+            "retrying\nlast message repeated 2 times\nretrying\ndone\n\
+             last message repeated 1 time\n"
+        );
+    }
+
+    #[test]
+    fn test_fold_repeated_prints_without_holding_repeat_lock() {
+        use std::io::Write;
+        use std::sync::OnceLock;
+
+        /// Records whether the repeat lock was free during every write.
+        #[derive(Clone)]
+        struct LockProbe(Arc<OnceLock<Arc<RichLogger>>>, Arc<Mutex<bool>>);
+
+        impl Write for LockProbe {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if let Some(logger) = self.0.get()
+                    && logger.repeats.try_lock().is_err()
+                {
+                    *self.1.lock().unwrap() = true;
+                }
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let probe = LockProbe(Arc::new(OnceLock::new()), Arc::new(Mutex::new(false)));
+        let console = Console::builder()
+            .force_terminal(false)
+            .width(80)
+            .file(Box::new(probe.clone()))
+            .build()
+            .shared();
+        let logger = Arc::new(
+            RichLogger::new(console)
+                .show_time(false)
+                .show_level(false)
+                .show_path(false)
+                .fold_repeated(true),
+        );
+        let _ = probe.0.set(Arc::clone(&logger));
+
+        for _ in 0..3 {
+            logger.log(&target_record("app", format_args!("retrying")));
+        }
+        logger.log(&target_record("app", format_args!("done")));
+        logger.log(&target_record("app", format_args!("done")));
+        logger.flush();
+
+        assert!(
+            !*probe.1.lock().unwrap(),
+            "printed while holding the repeat lock"
+        );
+    }
+
+    #[test]
+    fn test_fold_repeated_updates_terminal_line_in_place() {
+        let console = Arc::new(Console::builder().force_terminal(true).width(80).build());
+        let logger = RichLogger::new(Arc::clone(&console))
+            .show_time(false)
+            .show_level(false)
+            .show_path(false)
+            .fold_repeated(true);
+
+        console.begin_capture();
+        for _ in 0..4 {
+            logger.log(&target_record("app", format_args!("retrying")));
+        }
+        logger.flush();
+        let segments = console.end_capture();
+
+        let text = captured_text(&segments);
+        assert!(text.starts_with("retrying\nlast message repeated 1 time\n"));
+        assert!(
+            text.ends_with("last message repeated 3 times\n"),
+            "{text:?}"
+        );
+        assert!(segments.iter().any(|segment| {
+            segment.control.as_ref().is_some_and(|codes| {
+                codes
+                    .iter()
+                    .any(|code| code.control_type == ControlType::CursorUp)
+            })
+        }));
+    }
 }
@@ -20,6 +20,7 @@ use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
 use crate::segment::Segment;
 use crate::style::Style;
+use crate::text::JustifyMethod;
 
 /// Horizontal alignment method.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -33,6 +34,18 @@ pub enum AlignMethod {
     Right,
 }
 
+impl From<JustifyMethod> for AlignMethod {
+    /// Map a justification to the alignment it implies; `Full` and `Default`
+    /// lay text out from the left.
+    fn from(justify: JustifyMethod) -> Self {
+        match justify {
+            JustifyMethod::Center => Self::Center,
+            JustifyMethod::Right => Self::Right,
+            JustifyMethod::Left | JustifyMethod::Full | JustifyMethod::Default => Self::Left,
+        }
+    }
+}
+
 /// Vertical alignment method for multi-line content.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum VerticalAlignMethod {
@@ -0,0 +1,355 @@
+//! Bar chart - horizontal bars for labeled values.
+//!
+//! A [`BarChart`] prints one line per value: the label, a bar scaled to the
+//! available width, and optionally the value itself. Bars use eighth-block
+//! characters so small differences remain visible at narrow widths.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::BarChart;
+//! use rich_rust::style::Style;
+//!
+//! let chart = BarChart::new()
+//!     .bar("apples", 12.0)
+//!     .bar("pears", 7.5)
+//!     .bar_styled("plums", 3.0, Style::new().fg("magenta"));
+//! console.print_renderable(&chart);
+//! ```
+
+use crate::cells;
+use crate::console::{Console, ConsoleOptions};
+use crate::renderables::Renderable;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::{JustifyMethod, OverflowMethod, Text};
+
+/// Partial blocks from one eighth up to seven eighths of a cell.
+const PARTIAL_BLOCKS: [&str; 8] = [
+    "", "\u{258F}", "\u{258E}", "\u{258D}", "\u{258C}", "\u{258B}", "\u{258A}", "\u{2589}",
+];
+
+/// A single labeled bar.
+#[derive(Debug, Clone)]
+pub struct Bar {
+    /// Label shown before the bar.
+    pub label: Text,
+    /// Value determining the bar length.
+    pub value: f64,
+    /// Style overriding the chart's bar style.
+    pub style: Option<Style>,
+}
+
+impl Bar {
+    /// Create a bar with the chart's default style.
+    #[must_use]
+    pub fn new(label: impl Into<Text>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            style: None,
+        }
+    }
+
+    /// Set the bar style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// A horizontal bar chart of labeled values.
+#[derive(Debug, Clone)]
+pub struct BarChart {
+    /// Bars in display order.
+    bars: Vec<Bar>,
+    /// Fixed chart width (defaults to the available width).
+    width: Option<usize>,
+    /// Value corresponding to a full-width bar (defaults to the largest value).
+    max_value: Option<f64>,
+    /// Whether to print each value after its bar.
+    show_values: bool,
+    /// Default bar style.
+    bar_style: Style,
+    /// Style for labels.
+    label_style: Style,
+    /// Style for value labels.
+    value_style: Style,
+}
+
+impl Default for BarChart {
+    fn default() -> Self {
+        Self {
+            bars: Vec::new(),
+            width: None,
+            max_value: None,
+            show_values: true,
+            bar_style: Style::new().fg("cyan"),
+            label_style: Style::new(),
+            value_style: Style::new().dim(),
+        }
+    }
+}
+
+impl BarChart {
+    /// Create an empty bar chart.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bar (builder pattern).
+    #[must_use]
+    pub fn bar(mut self, label: impl Into<Text>, value: f64) -> Self {
+        self.bars.push(Bar::new(label, value));
+        self
+    }
+
+    /// Add a bar with its own style (builder pattern).
+    #[must_use]
+    pub fn bar_styled(mut self, label: impl Into<Text>, value: f64, style: Style) -> Self {
+        self.bars.push(Bar::new(label, value).style(style));
+        self
+    }
+
+    /// Add multiple bars (builder pattern).
+    #[must_use]
+    pub fn with_bars(mut self, bars: impl IntoIterator<Item = Bar>) -> Self {
+        self.bars.extend(bars);
+        self
+    }
+
+    /// Add a bar.
+    pub fn add_bar(&mut self, bar: Bar) {
+        self.bars.push(bar);
+    }
+
+    /// Set a fixed chart width.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the value drawn as a full-width bar, for comparable scales across charts.
+    #[must_use]
+    pub fn max_value(mut self, value: f64) -> Self {
+        self.max_value = Some(value);
+        self
+    }
+
+    /// Set whether to print each value after its bar.
+    #[must_use]
+    pub fn show_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    /// Set the default bar style.
+    #[must_use]
+    pub fn bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Set the label style.
+    #[must_use]
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Set the value label style.
+    #[must_use]
+    pub fn value_style(mut self, style: Style) -> Self {
+        self.value_style = style;
+        self
+    }
+
+    /// Render the chart to segments for a given width.
+    ///
+    /// Negative and non-finite values are drawn as empty bars.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        let width = self.width.map_or(max_width, |w| w.min(max_width));
+        if self.bars.is_empty() || width == 0 {
+            return Vec::new();
+        }
+
+        let label_width = self
+            .bars
+            .iter()
+            .map(|bar| bar.label.cell_len())
+            .max()
+            .unwrap_or(0)
+            .min(width / 3);
+        let values: Vec<String> = self.bars.iter().map(|bar| bar.value.to_string()).collect();
+        let value_width = if self.show_values {
+            values.iter().map(|v| cells::cell_len(v)).max().unwrap_or(0) + 1
+        } else {
+            0
+        };
+        let label_gap = usize::from(label_width > 0);
+        let bar_width = width.saturating_sub(label_width + label_gap + value_width);
+
+        let scale = self.max_value.unwrap_or_else(|| {
+            self.bars
+                .iter()
+                .map(|bar| bar.value)
+                .filter(|v| v.is_finite())
+                .fold(0.0, f64::max)
+        });
+
+        let mut segments = Vec::new();
+        for (bar, value) in self.bars.iter().zip(&values) {
+            if label_width > 0 {
+                let mut label = bar.label.clone();
+                if label.plain().contains('\n') {
+                    label = Text::new(label.plain().replace('\n', " "));
+                }
+                label.truncate(label_width, OverflowMethod::Ellipsis, false);
+                label.pad(label_width, JustifyMethod::Left);
+                segments.extend(
+                    label
+                        .render("")
+                        .into_iter()
+                        .map(|seg| apply_base(seg.into_owned(), &self.label_style)),
+                );
+                segments.push(Segment::new(" ", None));
+            }
+
+            let eighths = bar_eighths(bar.value, scale, bar_width);
+            let mut blocks = "\u{2588}".repeat(eighths / 8);
+            blocks.push_str(PARTIAL_BLOCKS[eighths % 8]);
+            let drawn = eighths.div_ceil(8);
+            if !blocks.is_empty() {
+                let style = bar.style.clone().unwrap_or_else(|| self.bar_style.clone());
+                segments.push(Segment::new(blocks, Some(style)));
+            }
+
+            if self.show_values {
+                let padding = bar_width - drawn;
+                segments.push(Segment::new(" ".repeat(padding + 1), None));
+                segments.push(Segment::new(value.clone(), Some(self.value_style.clone())));
+            }
+            segments.push(Segment::line());
+        }
+        segments
+    }
+
+    /// Render the chart as a string (for simple output).
+    #[must_use]
+    pub fn render_plain(&self, width: usize) -> String {
+        self.render(width)
+            .into_iter()
+            .map(|seg| seg.text.into_owned())
+            .collect()
+    }
+}
+
+/// Length of a bar in eighths of a cell.
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
+    reason = "the ratio is clamped to 0..=1 before scaling to the bar width"
+)]
+fn bar_eighths(value: f64, scale: f64, bar_width: usize) -> usize {
+    if !(value.is_finite() && scale.is_finite()) || value <= 0.0 || scale <= 0.0 {
+        return 0;
+    }
+    let ratio = (value / scale).min(1.0);
+    (ratio * (bar_width * 8) as f64).round() as usize
+}
+
+fn apply_base(mut segment: Segment<'static>, base: &Style) -> Segment<'static> {
+    if base.is_null() {
+        return segment;
+    }
+    segment.style = Some(match segment.style.take() {
+        Some(style) => base.combine(&style),
+        None => base.clone(),
+    });
+    segment
+}
+
+impl Renderable for BarChart {
+    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        self.render(options.max_width).into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bars_scale_to_width() {
+        let chart = BarChart::new()
+            .bar("a", 10.0)
+            .bar("bb", 5.0)
+            .show_values(false);
+        let plain = chart.render_plain(22);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0], format!("a  {}", "\u{2588}".repeat(19)));
+        assert_eq!(lines[1], format!("bb {}\u{258C}", "\u{2588}".repeat(9)));
+    }
+
+    #[test]
+    fn test_values_are_aligned_after_bars() {
+        let chart = BarChart::new().bar("x", 4.0).bar("y", 2.5);
+        let plain = chart.render_plain(14);
+        let lines: Vec<&str> = plain.lines().collect();
+        // Label (1) + gap (1) + bars (8) + space and widest value (4).
+        assert_eq!(lines[0], format!("x {} 4", "\u{2588}".repeat(8)));
+        assert_eq!(lines[1], format!("y {}    2.5", "\u{2588}".repeat(5)));
+        for line in lines {
+            assert!(cells::cell_len(line) <= 14, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_max_value_and_non_positive_values() {
+        let chart = BarChart::new()
+            .bar("a", 5.0)
+            .bar("b", -3.0)
+            .bar("c", f64::NAN)
+            .max_value(10.0)
+            .show_values(false);
+        let plain = chart.render_plain(12);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0], format!("a {}", "\u{2588}".repeat(5)));
+        assert_eq!(lines[1], "b ");
+        assert_eq!(lines[2], "c ");
+    }
+
+    #[test]
+    fn test_per_bar_style() {
+        let red = Style::new().fg("red");
+        let chart = BarChart::new()
+            .bar("a", 1.0)
+            .bar_styled("b", 1.0, red.clone());
+        let segments = chart.render(20);
+        let bar_styles: Vec<_> = segments
+            .iter()
+            .filter(|s| s.text.contains('\u{2588}'))
+            .map(|s| s.style.clone())
+            .collect();
+        assert_eq!(bar_styles, vec![Some(Style::new().fg("cyan")), Some(red)]);
+    }
+
+    #[test]
+    fn test_long_labels_are_truncated() {
+        let chart = BarChart::new()
+            .bar("a very long label indeed", 1.0)
+            .show_values(false);
+        let plain = chart.render_plain(30);
+        assert!(plain.starts_with("a very ... "), "{plain:?}");
+    }
+
+    #[test]
+    fn test_empty_chart_renders_nothing() {
+        assert!(BarChart::new().render(40).is_empty());
+    }
+}
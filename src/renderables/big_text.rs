@@ -0,0 +1,352 @@
+//! Big text - Large block-letter text for banners.
+//!
+//! This module renders text with an embedded 5-row bitmap font, in the spirit
+//! of `FIGlet` banners. It is meant for splash screens and section headers.
+//!
+//! # Feature Flag
+//!
+//! This module requires the `bigtext` feature to be enabled:
+//!
+//! ```toml
+//! [dependencies]
+//! rich_rust = { version = "0.1", features = ["bigtext"] }
+//! ```
+//!
+//! The font is compiled into the binary, so the feature adds no dependencies.
+//!
+//! # Basic Usage
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::BigText;
+//! use rich_rust::color::Color;
+//!
+//! let banner = BigText::new("Hello")
+//!     .gradient(Color::parse("magenta").unwrap(), Color::parse("cyan").unwrap());
+//! console.print_renderable(&banner);
+//!
+//! // Half-block rendering uses three lines instead of five
+//! let header = BigText::new("Setup").compact(true);
+//! ```
+//!
+//! # Known Limitations
+//!
+//! - The font covers ASCII letters (case-insensitive), digits, and common
+//!   punctuation; other characters render as `?`.
+//! - Words that do not fit the available width are cropped.
+
+use crate::color::{Color, blend_rgb};
+use crate::segment::{Segment, push_runs};
+use crate::style::Style;
+use crate::text::JustifyMethod;
+
+/// Number of pixel rows in each glyph.
+const GLYPH_HEIGHT: usize = 5;
+
+/// The embedded font: `#` marks a filled pixel.
+const FONT: &[(char, [&str; GLYPH_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "###."]),
+    ('C', [".###", "#...", "#...", "#...", ".###"]),
+    ('D', ["###.", "#..#", "#..#", "#..#", "###."]),
+    ('E', ["####", "#...", "###.", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#..."]),
+    ('G', [".###", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "####"]),
+    ('M', ["#...#", "##.##", "#.#.#", "#...#", "#...#"]),
+    ('N', ["#..#", "##.#", "#.##", "#..#", "#..#"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "###.", "#...", "#..."]),
+    ('Q', [".##.", "#..#", "#..#", "#.##", ".###"]),
+    ('R', ["###.", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", ".##.", "...#", "###."]),
+    ('T', ["#####", "..#..", "..#..", "..#..", "..#.."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('V', ["#...#", "#...#", "#...#", ".#.#.", "..#.."]),
+    ('W', ["#...#", "#...#", "#.#.#", "##.##", "#...#"]),
+    ('X', ["#...#", ".#.#.", "..#..", ".#.#.", "#...#"]),
+    ('Y', ["#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", ".##.", "#...", "####"]),
+    ('0', [".##.", "#.##", "#..#", "##.#", ".##."]),
+    ('1', [".#.", "##.", ".#.", ".#.", "###"]),
+    ('2', ["###.", "...#", ".##.", "#...", "####"]),
+    ('3', ["###.", "...#", ".##.", "...#", "###."]),
+    ('4', ["#..#", "#..#", "####", "...#", "...#"]),
+    ('5', ["####", "#...", "###.", "...#", "###."]),
+    ('6', [".##.", "#...", "###.", "#..#", ".##."]),
+    ('7', ["####", "...#", "..#.", ".#..", ".#.."]),
+    ('8', [".##.", "#..#", ".##.", "#..#", ".##."]),
+    ('9', [".##.", "#..#", ".###", "...#", ".##."]),
+    (' ', ["..", "..", "..", "..", ".."]),
+    ('!', ["#", "#", "#", ".", "#"]),
+    ('?', ["###.", "...#", ".##.", "....", ".#.."]),
+    ('.', [".", ".", ".", ".", "#"]),
+    (',', ["..", "..", "..", ".#", "#."]),
+    (':', [".", "#", ".", "#", "."]),
+    ('\'', ["#", "#", ".", ".", "."]),
+    ('-', ["...", "...", "###", "...", "..."]),
+    ('+', ["...", ".#.", "###", ".#.", "..."]),
+    ('=', ["...", "###", "...", "###", "..."]),
+    ('_', ["....", "....", "....", "....", "####"]),
+    ('/', ["..#", "..#", ".#.", "#..", "#.."]),
+    ('(', [".#", "#.", "#.", "#.", ".#"]),
+    (')', ["#.", ".#", ".#", ".#", "#."]),
+];
+
+fn glyph(c: char) -> &'static [&'static str; GLYPH_HEIGHT] {
+    let upper = c.to_ascii_uppercase();
+    FONT.iter()
+        .find(|(ch, _)| *ch == upper)
+        .or_else(|| FONT.iter().find(|(ch, _)| *ch == '?'))
+        .map(|(_, rows)| rows)
+        .expect("font defines '?'")
+}
+
+/// Pixel rows for a run of characters, one column of spacing between glyphs.
+fn rasterize(text: &str) -> Vec<Vec<bool>> {
+    let mut rows = vec![Vec::new(); GLYPH_HEIGHT];
+    for (index, c) in text.chars().enumerate() {
+        for (row, pattern) in rows.iter_mut().zip(glyph(c)) {
+            if index > 0 {
+                row.push(false);
+            }
+            row.extend(pattern.chars().map(|p| p == '#'));
+        }
+    }
+    rows
+}
+
+fn text_width(text: &str) -> usize {
+    rasterize(text).first().map_or(0, Vec::len)
+}
+
+/// Large block-letter text.
+#[derive(Debug, Clone)]
+pub struct BigText {
+    /// The text to render.
+    text: String,
+    /// Base style for filled pixels.
+    style: Style,
+    /// Left-to-right color gradient, overriding the style's foreground.
+    gradient: Option<(Color, Color)>,
+    /// Use half blocks to fit two pixel rows per line.
+    compact: bool,
+    /// Horizontal alignment within the available width.
+    justify: JustifyMethod,
+}
+
+impl BigText {
+    /// Create a new banner.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::new().bold(),
+            gradient: None,
+            compact: false,
+            justify: JustifyMethod::Left,
+        }
+    }
+
+    /// Set the style for filled pixels.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Color the banner with a left-to-right gradient from `start` to `end`.
+    #[must_use]
+    pub fn gradient(mut self, start: Color, end: Color) -> Self {
+        self.gradient = Some((start, end));
+        self
+    }
+
+    /// Set whether to use half blocks (three lines tall instead of five).
+    #[must_use]
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Set horizontal alignment within the available width.
+    #[must_use]
+    pub fn justify(mut self, justify: JustifyMethod) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Split the text into runs that fit `max_width`, breaking at spaces.
+    fn layout(&self, max_width: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in self.text.lines() {
+            let mut current = String::new();
+            for word in paragraph.split_whitespace() {
+                let candidate = if current.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{current} {word}")
+                };
+                if current.is_empty() || text_width(&candidate) <= max_width {
+                    current = candidate;
+                } else {
+                    lines.push(std::mem::replace(&mut current, word.to_string()));
+                }
+            }
+            lines.push(current);
+        }
+        lines
+    }
+
+    fn pixel_style(&self, column: usize, width: usize) -> Style {
+        let Some((start, end)) = &self.gradient else {
+            return self.style.clone();
+        };
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "banner widths are far below f64 precision limits"
+        )]
+        let factor = if width > 1 {
+            column as f64 / (width - 1) as f64
+        } else {
+            0.0
+        };
+        let triplet = blend_rgb(start.get_truecolor(), end.get_truecolor(), factor);
+        self.style.clone().color(Color::from_triplet(triplet))
+    }
+
+    /// Render the banner to segments for a given width.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        if max_width == 0 {
+            return Vec::new();
+        }
+
+        let mut segments = Vec::new();
+        for (index, line) in self.layout(max_width).iter().enumerate() {
+            if index > 0 {
+                segments.push(Segment::line());
+            }
+            let mut rows = rasterize(line);
+            let width = rows[0].len().min(max_width);
+            for row in &mut rows {
+                row.truncate(width);
+            }
+            let indent = match self.justify {
+                JustifyMethod::Center => (max_width - width) / 2,
+                JustifyMethod::Right => max_width - width,
+                _ => 0,
+            };
+
+            let step = if self.compact { 2 } else { 1 };
+            for y in (0..GLYPH_HEIGHT).step_by(step) {
+                let mut cells: Vec<(char, Option<Style>)> = Vec::with_capacity(width);
+                for x in 0..width {
+                    let top = rows[y][x];
+                    let bottom = self.compact && rows.get(y + 1).is_some_and(|row| row[x]);
+                    let ch = match (self.compact, top, bottom) {
+                        (false, true, _) | (true, true, true) => '\u{2588}',
+                        (true, true, false) => '\u{2580}',
+                        (true, false, true) => '\u{2584}',
+                        _ => ' ',
+                    };
+                    let style = (ch != ' ').then(|| self.pixel_style(x, width));
+                    cells.push((ch, style));
+                }
+                if indent > 0 {
+                    segments.push(Segment::new(" ".repeat(indent), None));
+                }
+                push_runs(&mut segments, cells);
+                segments.push(Segment::line());
+            }
+        }
+        segments
+    }
+
+    /// Render the banner as a string (for simple output).
+    #[must_use]
+    pub fn render_plain(&self, max_width: usize) -> String {
+        self.render(max_width)
+            .into_iter()
+            .map(|seg| seg.text.into_owned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_rows_have_consistent_width() {
+        for (ch, rows) in FONT {
+            let width = rows[0].len();
+            assert!(rows.iter().all(|row| row.len() == width), "glyph {ch:?}");
+        }
+    }
+
+    #[test]
+    fn test_render_single_letter() {
+        let plain = BigText::new("h").render_plain(40);
+        assert_eq!(
+            plain,
+            "\u{2588}  \u{2588}\n\u{2588}  \u{2588}\n\u{2588}\u{2588}\u{2588}\u{2588}\n\u{2588}  \u{2588}\n\u{2588}  \u{2588}\n"
+        );
+    }
+
+    #[test]
+    fn test_compact_uses_three_lines() {
+        let plain = BigText::new("I").compact(true).render_plain(40);
+        assert_eq!(
+            plain,
+            "\u{2580}\u{2588}\u{2580}\n \u{2588} \n\u{2580}\u{2580}\u{2580}\n"
+        );
+    }
+
+    #[test]
+    fn test_unknown_characters_render_as_question_mark() {
+        assert_eq!(
+            BigText::new("\u{00e9}").render_plain(40),
+            BigText::new("?").render_plain(40)
+        );
+    }
+
+    #[test]
+    fn test_words_wrap_to_width() {
+        let banner = BigText::new("HI HI");
+        // "HI HI" is 4+1+3+1+2+1+4+1+3 = 20 columns wide.
+        assert_eq!(banner.render_plain(40).lines().count(), 5);
+        let plain = banner.render_plain(10);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines.len(), 11);
+        assert!(lines.iter().all(|line| line.chars().count() <= 10));
+    }
+
+    #[test]
+    fn test_gradient_colors_columns() {
+        let start = Color::from_rgb(255, 0, 0);
+        let end = Color::from_rgb(0, 0, 255);
+        let segments = BigText::new("T")
+            .gradient(start.clone(), end.clone())
+            .render(40);
+        let first = segments[0].style.as_ref().and_then(|s| s.color.clone());
+        assert_eq!(first, Some(start));
+        let last = segments
+            .iter()
+            .take_while(|s| !s.text.contains('\n'))
+            .filter_map(|s| s.style.as_ref().and_then(|s| s.color.clone()))
+            .last();
+        assert_eq!(last, Some(end));
+    }
+
+    #[test]
+    fn test_justify_center() {
+        let plain = BigText::new("I")
+            .justify(JustifyMethod::Center)
+            .render_plain(9);
+        assert!(plain.lines().all(|line| line.starts_with("   ")));
+    }
+}
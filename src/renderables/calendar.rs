@@ -0,0 +1,302 @@
+//! Calendar - Month grid renderable.
+//!
+//! A [`Calendar`] lays out one month as a grid of day numbers under weekday
+//! headings, built on [`Table::grid`]. The current date is highlighted and
+//! arbitrary dates can be marked with their own style, which makes it handy
+//! for planners and release dashboards.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::Calendar;
+//! use rich_rust::style::Style;
+//! use time::{Date, Month};
+//!
+//! let release = Date::from_calendar_date(2026, Month::March, 17).unwrap();
+//! let calendar = Calendar::new(2026, Month::March)
+//!     .mark(release, Style::new().fg("green").bold());
+//! console.print_renderable(&calendar);
+//! ```
+
+use time::{Date, Month, OffsetDateTime, Weekday};
+
+use crate::console::{Console, ConsoleOptions};
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::{JustifyMethod, Text};
+
+use super::Renderable;
+use super::table::{Cell, Column, Row, Table};
+
+/// A month calendar rendered as a grid.
+#[derive(Debug, Clone)]
+pub struct Calendar {
+    /// Year of the displayed month.
+    year: i32,
+    /// Displayed month.
+    month: Month,
+    /// Date highlighted as today, if any.
+    today: Option<Date>,
+    /// Dates with an extra style applied.
+    marks: Vec<(Date, Style)>,
+    /// Weekday shown in the first column.
+    first_weekday: Weekday,
+    /// Whether to show the "Month Year" title.
+    show_title: bool,
+    /// Style for the title.
+    title_style: Style,
+    /// Style for the weekday headings.
+    header_style: Style,
+    /// Style for today's date.
+    today_style: Style,
+}
+
+impl Calendar {
+    /// Create a calendar for the given month, highlighting the local date.
+    #[must_use]
+    pub fn new(year: i32, month: Month) -> Self {
+        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+        Self {
+            year,
+            month,
+            today: Some(now.date()),
+            marks: Vec::new(),
+            first_weekday: Weekday::Monday,
+            show_title: true,
+            title_style: Style::new().bold(),
+            header_style: Style::new().dim(),
+            today_style: Style::new().bold().reverse(),
+        }
+    }
+
+    /// Create a calendar for the month containing `date`.
+    #[must_use]
+    pub fn for_date(date: Date) -> Self {
+        Self::new(date.year(), date.month())
+    }
+
+    /// Create a calendar for the current local month.
+    #[must_use]
+    pub fn current() -> Self {
+        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+        Self::for_date(now.date())
+    }
+
+    /// Set the date highlighted as today, or `None` to disable the highlight.
+    #[must_use]
+    pub fn today(mut self, today: Option<Date>) -> Self {
+        self.today = today;
+        self
+    }
+
+    /// Mark a date with a style; dates outside the month are ignored.
+    #[must_use]
+    pub fn mark(mut self, date: Date, style: Style) -> Self {
+        self.marks.push((date, style));
+        self
+    }
+
+    /// Mark several dates with the same style.
+    #[must_use]
+    pub fn mark_all(mut self, dates: impl IntoIterator<Item = Date>, style: &Style) -> Self {
+        self.marks
+            .extend(dates.into_iter().map(|date| (date, style.clone())));
+        self
+    }
+
+    /// Set the weekday shown in the first column (default Monday).
+    #[must_use]
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Set whether to show the "Month Year" title.
+    #[must_use]
+    pub fn show_title(mut self, show: bool) -> Self {
+        self.show_title = show;
+        self
+    }
+
+    /// Set the title style.
+    #[must_use]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    /// Set the weekday heading style.
+    #[must_use]
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the style used to highlight today.
+    #[must_use]
+    pub fn today_style(mut self, style: Style) -> Self {
+        self.today_style = style;
+        self
+    }
+
+    /// Build the grid table for this month.
+    ///
+    /// Returns an empty grid if the year is outside the supported date range.
+    #[must_use]
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::grid()
+            .with_columns((0..7).map(|_| Column::new("").justify(JustifyMethod::Right)));
+        if self.show_title {
+            table = table
+                .title(format!("{} {}", self.month, self.year))
+                .title_style(self.title_style.clone());
+        }
+
+        let Ok(first) = Date::from_calendar_date(self.year, self.month, 1) else {
+            return table;
+        };
+
+        let mut weekday = self.first_weekday;
+        let mut headings = Vec::with_capacity(7);
+        for _ in 0..7 {
+            let name: String = weekday.to_string().chars().take(2).collect();
+            headings.push(Cell::new(name).style(self.header_style.clone()));
+            weekday = weekday.next();
+        }
+        table.add_row(Row::new(headings));
+
+        let offset = (7 + first.weekday().number_days_from_monday()
+            - self.first_weekday.number_days_from_monday())
+            % 7;
+        let mut week: Vec<Cell> = (0..offset).map(|_| Cell::new("")).collect();
+        for day in 1..=self.month.length(self.year) {
+            week.push(self.day_cell(first.replace_day(day).unwrap_or(first)));
+            if week.len() == 7 {
+                table.add_row(Row::new(std::mem::take(&mut week)));
+            }
+        }
+        if !week.is_empty() {
+            week.resize_with(7, || Cell::new(""));
+            table.add_row(Row::new(week));
+        }
+        table
+    }
+
+    fn day_cell(&self, date: Date) -> Cell {
+        let mut style: Option<Style> = None;
+        for (marked, mark_style) in &self.marks {
+            if *marked == date {
+                style = Some(match style {
+                    Some(existing) => existing.combine(mark_style),
+                    None => mark_style.clone(),
+                });
+            }
+        }
+        if self.today == Some(date) {
+            style = Some(match style {
+                Some(existing) => existing.combine(&self.today_style),
+                None => self.today_style.clone(),
+            });
+        }
+
+        let cell = Cell::new(Text::new(date.day().to_string()));
+        match style {
+            Some(style) => cell.style(style),
+            None => cell,
+        }
+    }
+
+    /// Render the calendar to segments.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        self.to_table().render(max_width)
+    }
+
+    /// Render to plain text.
+    #[must_use]
+    pub fn render_plain(&self, max_width: usize) -> String {
+        self.to_table().render_plain(max_width)
+    }
+}
+
+impl Renderable for Calendar {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        Renderable::render(&self.to_table(), console, options)
+            .into_iter()
+            .map(Segment::into_owned)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).expect("valid date")
+    }
+
+    #[test]
+    fn test_month_grid_layout() {
+        // October 2026 starts on a Thursday.
+        let plain = Calendar::new(2026, Month::October)
+            .today(None)
+            .render_plain(40);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0].trim(), "October 2026");
+        assert_eq!(lines[1], "Mo Tu We Th Fr Sa Su");
+        assert_eq!(lines[2], "          1  2  3  4");
+        assert_eq!(lines[6], "26 27 28 29 30 31   ");
+        assert_eq!(lines.len(), 7);
+    }
+
+    #[test]
+    fn test_first_weekday_sunday() {
+        let plain = Calendar::new(2026, Month::February)
+            .today(None)
+            .first_weekday(Weekday::Sunday)
+            .show_title(false)
+            .render_plain(40);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0], "Su Mo Tu We Th Fr Sa");
+        // February 2026 starts on a Sunday and has exactly four weeks.
+        assert_eq!(lines[1], " 1  2  3  4  5  6  7");
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[test]
+    fn test_today_and_marks_are_styled() {
+        let today = date(2026, Month::October, 16);
+        let release = date(2026, Month::October, 20);
+        let green = Style::new().fg("green");
+        let calendar = Calendar::new(2026, Month::October)
+            .today(Some(today))
+            .mark(release, green.clone())
+            .mark(date(2026, Month::November, 1), Style::new().fg("red"));
+
+        let segments = calendar.render(40);
+        let style_of = |text: &str| {
+            segments
+                .iter()
+                .find(|s| s.text.trim() == text)
+                .and_then(|s| s.style.clone())
+        };
+        assert_eq!(style_of("16"), Some(Style::new().bold().reverse()));
+        assert_eq!(style_of("20"), Some(green));
+        assert!(
+            segments
+                .iter()
+                .all(|s| s.style != Some(Style::new().fg("red")))
+        );
+    }
+
+    #[test]
+    fn test_for_date_selects_month() {
+        let plain = Calendar::for_date(date(2024, Month::February, 10))
+            .today(None)
+            .render_plain(40);
+        assert!(plain.contains("February 2024"));
+        assert!(plain.contains("29"));
+        assert!(!plain.contains("30"));
+    }
+}
@@ -0,0 +1,444 @@
+//! Canvas - low-resolution plotting with braille or half-block characters.
+//!
+//! A [`Canvas`] maps points and lines in data coordinates onto a grid of
+//! terminal cells. Braille markers give 2x4 dots per cell; half blocks give
+//! 1x2 pixels per cell but can color the upper and lower pixel separately.
+//! This is enough for sparklines, scatter plots, and line plots inside panels
+//! and live dashboards.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::{Canvas, CanvasMarker};
+//! use rich_rust::style::Style;
+//!
+//! let samples: Vec<(f64, f64)> = (0..100)
+//!     .map(|i| (f64::from(i), (f64::from(i) / 10.0).sin()))
+//!     .collect();
+//! let canvas = Canvas::new(40, 8)
+//!     .style(Style::new().fg("green"))
+//!     .plot(samples);
+//! console.print_renderable(&canvas);
+//! ```
+
+use crate::console::{Console, ConsoleOptions};
+use crate::renderables::Renderable;
+use crate::segment::{Segment, push_runs};
+use crate::style::Style;
+
+/// Character set used to draw canvas pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CanvasMarker {
+    /// Braille patterns: 2x4 dots per cell, one color per cell.
+    #[default]
+    Braille,
+    /// Upper/lower half blocks: 1x2 pixels per cell, one color per pixel.
+    HalfBlock,
+}
+
+impl CanvasMarker {
+    /// Pixels per cell as `(columns, rows)`.
+    const fn resolution(self) -> (usize, usize) {
+        match self {
+            Self::Braille => (2, 4),
+            Self::HalfBlock => (1, 2),
+        }
+    }
+}
+
+/// Braille dot bits indexed by `[row][column]` within a cell.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+#[derive(Debug, Clone)]
+enum Shape {
+    Point {
+        x: f64,
+        y: f64,
+        style: Option<Style>,
+    },
+    Line {
+        from: (f64, f64),
+        to: (f64, f64),
+        style: Option<Style>,
+    },
+}
+
+/// A fixed-size plotting surface.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    /// Width in cells.
+    width: usize,
+    /// Height in cells.
+    height: usize,
+    /// Character set used for pixels.
+    marker: CanvasMarker,
+    /// Visible x range (defaults to the data extent).
+    x_bounds: Option<(f64, f64)>,
+    /// Visible y range (defaults to the data extent).
+    y_bounds: Option<(f64, f64)>,
+    /// Style for shapes without their own style.
+    style: Style,
+    /// Shapes in drawing order.
+    shapes: Vec<Shape>,
+}
+
+impl Canvas {
+    /// Create an empty canvas of `width` x `height` cells.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            marker: CanvasMarker::default(),
+            x_bounds: None,
+            y_bounds: None,
+            style: Style::new(),
+            shapes: Vec::new(),
+        }
+    }
+
+    /// Set the marker character set.
+    #[must_use]
+    pub fn marker(mut self, marker: CanvasMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set the visible x range; points outside it are clipped.
+    #[must_use]
+    pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+        self.x_bounds = Some((min, max));
+        self
+    }
+
+    /// Set the visible y range; points outside it are clipped.
+    #[must_use]
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = Some((min, max));
+        self
+    }
+
+    /// Set the default style for shapes without their own style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Add a point (builder pattern).
+    #[must_use]
+    pub fn point(mut self, x: f64, y: f64) -> Self {
+        self.add_point(x, y, None);
+        self
+    }
+
+    /// Add a point with its own style (builder pattern).
+    #[must_use]
+    pub fn point_styled(mut self, x: f64, y: f64, style: Style) -> Self {
+        self.add_point(x, y, Some(style));
+        self
+    }
+
+    /// Add several points (builder pattern), e.g. for a scatter plot.
+    #[must_use]
+    pub fn points(mut self, points: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        for (x, y) in points {
+            self.add_point(x, y, None);
+        }
+        self
+    }
+
+    /// Add a line segment (builder pattern).
+    #[must_use]
+    pub fn line(mut self, from: (f64, f64), to: (f64, f64)) -> Self {
+        self.add_line(from, to, None);
+        self
+    }
+
+    /// Add a line segment with its own style (builder pattern).
+    #[must_use]
+    pub fn line_styled(mut self, from: (f64, f64), to: (f64, f64), style: Style) -> Self {
+        self.add_line(from, to, Some(style));
+        self
+    }
+
+    /// Connect consecutive points with lines (builder pattern), e.g. for a line plot.
+    #[must_use]
+    pub fn plot(mut self, points: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        let mut previous = None;
+        for point in points {
+            match previous {
+                Some(from) => self.add_line(from, point, None),
+                None => self.add_point(point.0, point.1, None),
+            }
+            previous = Some(point);
+        }
+        self
+    }
+
+    /// Add a point.
+    pub fn add_point(&mut self, x: f64, y: f64, style: Option<Style>) {
+        self.shapes.push(Shape::Point { x, y, style });
+    }
+
+    /// Add a line segment.
+    pub fn add_line(&mut self, from: (f64, f64), to: (f64, f64), style: Option<Style>) {
+        self.shapes.push(Shape::Line { from, to, style });
+    }
+
+    /// Remove all shapes, keeping size, bounds, and styles.
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+    }
+
+    /// Render the canvas to segments, narrowing it to `max_width` if needed.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        let width = self.width.min(max_width);
+        if width == 0 || self.height == 0 {
+            return Vec::new();
+        }
+
+        let pixels = self.rasterize(width);
+        let (cols, rows) = self.marker.resolution();
+        let pixel_width = width * cols;
+        let pixel = |x: usize, y: usize| pixels[y * pixel_width + x].as_ref();
+
+        let mut segments = Vec::new();
+        for row in 0..self.height {
+            let mut cells: Vec<(char, Option<Style>)> = Vec::with_capacity(width);
+            for col in 0..width {
+                let cell = match self.marker {
+                    CanvasMarker::Braille => {
+                        let mut bits = 0;
+                        let mut style = None;
+                        for (dy, dots) in BRAILLE_DOTS.iter().enumerate() {
+                            for (dx, bit) in dots.iter().enumerate() {
+                                if let Some(s) = pixel(col * cols + dx, row * rows + dy) {
+                                    bits |= bit;
+                                    style = Some(s.clone());
+                                }
+                            }
+                        }
+                        if bits == 0 {
+                            (' ', None)
+                        } else {
+                            (char::from_u32(0x2800 + bits).unwrap_or(' '), style)
+                        }
+                    }
+                    CanvasMarker::HalfBlock => {
+                        let top = pixel(col, row * 2);
+                        let bottom = pixel(col, row * 2 + 1);
+                        match (top, bottom) {
+                            (None, None) => (' ', None),
+                            (Some(t), None) => ('\u{2580}', Some(t.clone())),
+                            (None, Some(b)) => ('\u{2584}', Some(b.clone())),
+                            (Some(t), Some(b)) if t == b => ('\u{2588}', Some(t.clone())),
+                            (Some(t), Some(b)) => {
+                                let mut style = t.clone();
+                                style.bgcolor.clone_from(&b.color);
+                                ('\u{2580}', Some(style))
+                            }
+                        }
+                    }
+                };
+                cells.push(cell);
+            }
+            push_runs(&mut segments, cells);
+            segments.push(Segment::line());
+        }
+        segments
+    }
+
+    /// Render the canvas as a string (for simple output).
+    #[must_use]
+    pub fn render_plain(&self, max_width: usize) -> String {
+        self.render(max_width)
+            .into_iter()
+            .map(|seg| seg.text.into_owned())
+            .collect()
+    }
+
+    /// Data range on each axis, widened when all values coincide.
+    fn bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let mut xs = Vec::new();
+        let mut ys = Vec::new();
+        for shape in &self.shapes {
+            match shape {
+                Shape::Point { x, y, .. } => {
+                    xs.push(*x);
+                    ys.push(*y);
+                }
+                Shape::Line { from, to, .. } => {
+                    xs.extend([from.0, to.0]);
+                    ys.extend([from.1, to.1]);
+                }
+            }
+        }
+        let extent = |values: &[f64]| {
+            let (min, max) = values
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                    (lo.min(*v), hi.max(*v))
+                });
+            if min > max {
+                (0.0, 1.0)
+            } else if (max - min).abs() < f64::EPSILON {
+                (min - 0.5, max + 0.5)
+            } else {
+                (min, max)
+            }
+        };
+        (
+            self.x_bounds.unwrap_or_else(|| extent(&xs)),
+            self.y_bounds.unwrap_or_else(|| extent(&ys)),
+        )
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "pixel coordinates are small and range-checked before indexing"
+    )]
+    fn rasterize(&self, width: usize) -> Vec<Option<Style>> {
+        let (cols, rows) = self.marker.resolution();
+        let pixel_width = width * cols;
+        let pixel_height = self.height * rows;
+        let mut pixels: Vec<Option<Style>> = vec![None; pixel_width * pixel_height];
+
+        let ((x_min, x_max), (y_min, y_max)) = self.bounds();
+        // Map data coordinates to pixel coordinates, with y growing upwards.
+        let to_pixel = |x: f64, y: f64| {
+            let px = (x - x_min) / (x_max - x_min) * (pixel_width - 1) as f64;
+            let py = (y_max - y) / (y_max - y_min) * (pixel_height - 1) as f64;
+            (px, py)
+        };
+        let mut set = |px: f64, py: f64, style: &Style| {
+            let (px, py) = (px.round(), py.round());
+            if px.is_finite()
+                && py.is_finite()
+                && px >= 0.0
+                && py >= 0.0
+                && px < pixel_width as f64
+                && py < pixel_height as f64
+            {
+                pixels[py as usize * pixel_width + px as usize] = Some(style.clone());
+            }
+        };
+
+        for shape in &self.shapes {
+            match shape {
+                Shape::Point { x, y, style } => {
+                    let (px, py) = to_pixel(*x, *y);
+                    set(px, py, style.as_ref().unwrap_or(&self.style));
+                }
+                Shape::Line { from, to, style } => {
+                    let style = style.as_ref().unwrap_or(&self.style);
+                    let (x0, y0) = to_pixel(from.0, from.1);
+                    let (x1, y1) = to_pixel(to.0, to.1);
+                    let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil();
+                    if !steps.is_finite() {
+                        continue;
+                    }
+                    // Lines far outside the canvas are clipped; cap the work.
+                    let steps = steps.min((pixel_width + pixel_height) as f64 * 4.0) as usize;
+                    for step in 0..=steps {
+                        let t = if steps == 0 {
+                            0.0
+                        } else {
+                            step as f64 / steps as f64
+                        };
+                        set(x0 + (x1 - x0) * t, y0 + (y1 - y0) * t, style);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+}
+
+impl Renderable for Canvas {
+    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        self.render(options.max_width).into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_braille_corners() {
+        let canvas = Canvas::new(2, 1)
+            .x_bounds(0.0, 3.0)
+            .y_bounds(0.0, 3.0)
+            .point(0.0, 3.0)
+            .point(3.0, 0.0);
+        // Top-left dot of the first cell, bottom-right dot of the second.
+        assert_eq!(canvas.render_plain(10), "\u{2801}\u{2880}\n");
+    }
+
+    #[test]
+    fn test_empty_cells_are_spaces() {
+        let canvas = Canvas::new(3, 2);
+        assert_eq!(canvas.render_plain(10), "   \n   \n");
+    }
+
+    #[test]
+    fn test_half_block_colors_each_pixel() {
+        let red = Style::new().fg("red");
+        let blue = Style::new().fg("blue");
+        let canvas = Canvas::new(2, 1)
+            .marker(CanvasMarker::HalfBlock)
+            .x_bounds(0.0, 1.0)
+            .y_bounds(0.0, 1.0)
+            .point_styled(0.0, 1.0, red.clone())
+            .point_styled(0.0, 0.0, blue.clone())
+            .point_styled(1.0, 0.0, blue.clone());
+
+        let segments = canvas.render(10);
+        assert_eq!(segments[0].text, "\u{2580}");
+        let style = segments[0].style.clone().unwrap();
+        assert_eq!(style.color, red.color);
+        assert_eq!(style.bgcolor, blue.color);
+        assert_eq!(segments[1].text, "\u{2584}");
+        assert_eq!(segments[1].style, Some(blue));
+    }
+
+    #[test]
+    fn test_line_fills_pixels() {
+        let canvas = Canvas::new(4, 1)
+            .marker(CanvasMarker::HalfBlock)
+            .x_bounds(0.0, 3.0)
+            .y_bounds(0.0, 1.0)
+            .line((0.0, 0.0), (3.0, 0.0));
+        assert_eq!(canvas.render_plain(10), "\u{2584}".repeat(4) + "\n");
+    }
+
+    #[test]
+    fn test_auto_bounds_and_clipping() {
+        let canvas = Canvas::new(2, 1)
+            .marker(CanvasMarker::HalfBlock)
+            .y_bounds(0.0, 1.0)
+            .point(5.0, 1.0)
+            .point(7.0, 1.0)
+            .point(6.0, 9.0);
+        assert_eq!(canvas.render_plain(10), "\u{2580}\u{2580}\n");
+    }
+
+    #[test]
+    fn test_plot_and_width_limit() {
+        let samples = (0..20).map(|i| (f64::from(i), f64::from(i % 5)));
+        let canvas = Canvas::new(30, 3).plot(samples);
+        let plain = canvas.render_plain(12);
+        assert_eq!(plain.lines().count(), 3);
+        assert!(plain.lines().all(|line| line.chars().count() == 12));
+        assert!(
+            plain
+                .chars()
+                .any(|c| ('\u{2801}'..='\u{28FF}').contains(&c))
+        );
+    }
+}
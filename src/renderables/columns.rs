@@ -3,6 +3,11 @@
 //! This module provides a Columns renderable for arranging content
 //! in a newspaper-style multi-column layout.
 //!
+//! Items fill rows left to right by default; [`Columns::column_first`] fills
+//! each column top to bottom instead, and [`Columns::right_to_left`] mirrors
+//! every row. Without an explicit [`Columns::column_count`], the largest
+//! number of columns whose actual widths fit is chosen, as in Python Rich.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -41,12 +46,18 @@ pub struct Columns<'a> {
     expand: bool,
     /// Whether columns should have equal width.
     equal_width: bool,
+    /// Fill columns top to bottom instead of rows left to right.
+    column_first: bool,
+    /// Lay rows out from right to left.
+    right_to_left: bool,
     /// Alignment within each column.
     align: AlignMethod,
     /// Padding around each item.
     padding: usize,
     /// Style for column separators (gutter).
     gutter_style: Style,
+    /// Fixed total width for the columns layout (None = available width).
+    width: Option<usize>,
     /// Maximum total width for the columns layout.
     /// When set, prevents columns from spreading across very wide terminals.
     max_width: Option<usize>,
@@ -60,9 +71,12 @@ impl Default for Columns<'_> {
             gutter: 2,
             expand: true,
             equal_width: false,
+            column_first: false,
+            right_to_left: false,
             align: AlignMethod::Left,
             padding: 0,
             gutter_style: Style::new(),
+            width: None,
             max_width: None,
         }
     }
@@ -114,6 +128,33 @@ impl<'a> Columns<'a> {
         self
     }
 
+    /// Set whether columns should have equal width (Python Rich's `equal`).
+    ///
+    /// Every column is as wide as the widest item, or shares the width
+    /// evenly when expanding.
+    #[must_use]
+    pub fn equal(self, equal: bool) -> Self {
+        self.equal_width(equal)
+    }
+
+    /// Set whether items fill each column top to bottom (default `false`).
+    ///
+    /// With `false`, items fill each row left to right.
+    #[must_use]
+    pub fn column_first(mut self, column_first: bool) -> Self {
+        self.column_first = column_first;
+        self
+    }
+
+    /// Set whether rows are laid out from right to left (default `false`).
+    ///
+    /// A short last row then ends at the right edge.
+    #[must_use]
+    pub fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
     /// Set alignment within columns.
     #[must_use]
     pub fn align(mut self, align: AlignMethod) -> Self {
@@ -135,6 +176,16 @@ impl<'a> Columns<'a> {
         self
     }
 
+    /// Set a fixed total width for the columns layout.
+    ///
+    /// Items are laid out within `width` cells regardless of the terminal
+    /// width (but never wider than the space available).
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
     /// Set a maximum width for the columns layout.
     ///
     /// When set, the columns will not expand beyond this width even if
@@ -159,6 +210,74 @@ impl<'a> Columns<'a> {
         item.iter().map(|s| cell_len(&s.text)).sum()
     }
 
+    /// Widths of every item including padding; all equal to the widest with `equal_width`.
+    fn item_widths(&self) -> Vec<usize> {
+        let widths: Vec<usize> = self
+            .items
+            .iter()
+            .map(|item| Self::item_width(item) + self.padding * 2)
+            .collect();
+        if self.equal_width {
+            let widest = widths.iter().copied().max().unwrap_or(0);
+            vec![widest; widths.len()]
+        } else {
+            widths
+        }
+    }
+
+    /// Place item indices into display rows for `num_columns` columns.
+    ///
+    /// Honors `column_first` and `right_to_left`; `None` marks an empty cell.
+    fn cell_grid(&self, num_columns: usize) -> Vec<Vec<Option<usize>>> {
+        let item_count = self.items.len();
+        let num_rows = item_count.div_ceil(num_columns);
+        let mut grid = vec![vec![None; num_columns]; num_rows];
+
+        if self.column_first {
+            // Earlier columns take one extra item each when the count doesn't divide evenly.
+            let mut column_lengths = vec![item_count / num_columns; num_columns];
+            for length in column_lengths.iter_mut().take(item_count % num_columns) {
+                *length += 1;
+            }
+            let (mut row, mut col) = (0, 0);
+            for index in 0..item_count {
+                grid[row][col] = Some(index);
+                column_lengths[col] -= 1;
+                if column_lengths[col] > 0 {
+                    row += 1;
+                } else {
+                    col += 1;
+                    row = 0;
+                }
+            }
+        } else {
+            for index in 0..item_count {
+                grid[index / num_columns][index % num_columns] = Some(index);
+            }
+        }
+
+        if self.right_to_left {
+            for row in &mut grid {
+                row.reverse();
+            }
+        }
+        grid
+    }
+
+    /// Widest item in each display column of `grid`.
+    fn content_widths(grid: &[Vec<Option<usize>>], item_widths: &[usize]) -> Vec<usize> {
+        let num_columns = grid.first().map_or(0, Vec::len);
+        let mut widths = vec![0usize; num_columns];
+        for row in grid {
+            for (col, cell) in row.iter().enumerate() {
+                if let Some(index) = cell {
+                    widths[col] = widths[col].max(item_widths[*index]);
+                }
+            }
+        }
+        widths
+    }
+
     /// Calculate column widths.
     fn calculate_column_widths(&self, total_width: usize, num_columns: usize) -> Vec<usize> {
         if num_columns == 0 || self.items.is_empty() {
@@ -169,19 +288,14 @@ impl<'a> Columns<'a> {
         let total_gutter = self.gutter * (num_columns - 1);
         let available_width = total_width.saturating_sub(total_gutter);
 
-        if self.equal_width {
-            // Equal width columns
+        if self.equal_width && self.expand {
+            // Equal width columns sharing the whole width
             let column_width = available_width / num_columns;
             vec![column_width; num_columns]
         } else {
             // Calculate max width for each column based on content
-            let mut max_widths = vec![0usize; num_columns];
-
-            for (idx, item) in self.items.iter().enumerate() {
-                let col = idx % num_columns;
-                let item_w = Self::item_width(item) + self.padding * 2;
-                max_widths[col] = max_widths[col].max(item_w);
-            }
+            let grid = self.cell_grid(num_columns);
+            let mut max_widths = Self::content_widths(&grid, &self.item_widths());
 
             if self.expand {
                 // Distribute remaining space proportionally
@@ -256,30 +370,26 @@ impl<'a> Columns<'a> {
     }
 
     /// Auto-calculate number of columns based on content and width.
+    ///
+    /// Like Python Rich, picks the largest column count whose columns, each
+    /// as wide as its own widest item, fit in `total_width` with gutters.
     fn auto_column_count(&self, total_width: usize) -> usize {
         if self.items.is_empty() {
             return 1;
         }
 
-        // Find the widest item
-        let max_item_width = self
-            .items
-            .iter()
-            .map(|item| Self::item_width(item) + self.padding * 2)
-            .max()
-            .unwrap_or(1);
-
-        // Calculate how many columns can fit
-        let min_column_width = max_item_width.max(1);
-        let mut columns = 1;
+        let item_widths = self.item_widths();
+        // Columns are at least one cell wide, which bounds the count to try.
+        let most = (total_width + self.gutter) / (self.gutter + 1);
+        let mut columns = self.items.len().min(most).max(1);
 
-        while columns < self.items.len() {
-            let next = columns + 1;
-            let needed_width = next * min_column_width + (next - 1) * self.gutter;
-            if needed_width > total_width {
+        while columns > 1 {
+            let widths = Self::content_widths(&self.cell_grid(columns), &item_widths);
+            let needed_width = widths.iter().sum::<usize>() + (columns - 1) * self.gutter;
+            if needed_width <= total_width {
                 break;
             }
-            columns = next;
+            columns -= 1;
         }
 
         columns
@@ -292,10 +402,11 @@ impl<'a> Columns<'a> {
             return vec![];
         }
 
-        // Apply max_width constraint to prevent excessive spreading on wide terminals
+        // Apply width constraints to prevent excessive spreading on wide terminals
+        let effective_width = self.width.unwrap_or(total_width).min(total_width);
         let effective_width = match self.max_width {
-            Some(max) => total_width.min(max),
-            None => total_width,
+            Some(max) => effective_width.min(max),
+            None => effective_width,
         };
 
         let num_columns = self
@@ -307,22 +418,13 @@ impl<'a> Columns<'a> {
             return vec![];
         }
 
-        // Calculate number of rows needed
-        let num_rows = self.items.len().div_ceil(num_columns);
+        let grid = self.cell_grid(num_columns);
+        let mut result = Vec::with_capacity(grid.len());
 
-        let mut result = Vec::with_capacity(num_rows);
-
-        for row_idx in 0..num_rows {
+        for row in &grid {
             let mut row_segments = Vec::new();
 
-            #[expect(
-                clippy::needless_range_loop,
-                reason = "col_idx used for multiple purposes"
-            )]
-            for col_idx in 0..num_columns {
-                let item_idx = row_idx * num_columns + col_idx;
-                let column_width = column_widths[col_idx];
-
+            for (col_idx, (cell, &column_width)) in row.iter().zip(&column_widths).enumerate() {
                 // Add gutter before columns (except first)
                 if col_idx > 0 && self.gutter > 0 {
                     row_segments.push(Segment::new(
@@ -331,7 +433,7 @@ impl<'a> Columns<'a> {
                     ));
                 }
 
-                if item_idx < self.items.len() {
+                if let Some(item_idx) = *cell {
                     // Add padding, content, padding
                     let effective_padding = self.padding.min(column_width / 2);
                     if effective_padding > 0 {
@@ -404,6 +506,70 @@ impl Renderable for Columns<'_> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_columns_fixed_width() {
+        let cols = Columns::from_strings(&["A", "B"]).column_count(2).width(10);
+        let lines = cols.render(80);
+        let text: String = lines[0].iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(cell_len(&text), 10);
+        // Never wider than the space available.
+        let lines = cols.render(6);
+        let text: String = lines[0].iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(cell_len(&text), 6);
+    }
+
+    fn plain_lines(cols: &Columns<'_>, width: usize) -> Vec<String> {
+        cols.render(width)
+            .iter()
+            .map(|line| {
+                let text: String = line.iter().map(|s| s.text.as_ref()).collect();
+                text.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_columns_optimal_count_uses_per_column_widths() {
+        // The widest items share a column, so two columns fit where the
+        // widest-item estimate would only allow one.
+        let cols = Columns::from_strings(&["a", "bbbbbbbbbb", "c", "dddddddddd"])
+            .gutter(1)
+            .expand(false);
+        assert_eq!(cols.auto_column_count(13), 2);
+        assert_eq!(plain_lines(&cols, 13), vec!["a bbbbbbbbbb", "c dddddddddd"]);
+    }
+
+    #[test]
+    fn test_columns_column_first() {
+        let cols = Columns::from_strings(&["1", "2", "3", "4", "5"])
+            .column_count(2)
+            .gutter(1)
+            .expand(false)
+            .column_first(true);
+        assert_eq!(plain_lines(&cols, 20), vec!["1 4", "2 5", "3"]);
+    }
+
+    #[test]
+    fn test_columns_right_to_left() {
+        let cols = Columns::from_strings(&["1", "2", "3"])
+            .column_count(2)
+            .gutter(1)
+            .expand(false)
+            .right_to_left(true);
+        assert_eq!(plain_lines(&cols, 20), vec!["2 1", "  3"]);
+    }
+
+    #[test]
+    fn test_columns_equal_without_expand() {
+        let cols = Columns::from_strings(&["a", "bbb"])
+            .column_count(2)
+            .gutter(1)
+            .expand(false)
+            .equal(true);
+        assert_eq!(cols.calculate_column_widths(20, 2), vec![3, 3]);
+        assert_eq!(plain_lines(&cols, 20), vec!["a   bbb"]);
+    }
+
     #[test]
     fn test_columns_new() {
         let items = vec![vec![Segment::new("A", None)], vec![Segment::new("B", None)]];
@@ -684,7 +850,7 @@ mod tests {
 
         // Without equal_width, columns can have different widths based on content
         // Both should be within bounds
-        assert!(widths.len() == 2);
+        assert_eq!(widths.len(), 2);
     }
 
     #[test]
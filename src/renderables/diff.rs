@@ -0,0 +1,541 @@
+//! Diff - Unified and side-by-side text comparison.
+//!
+//! This module provides a renderable that compares two texts line by line and
+//! renders the result as a unified diff or as two side-by-side columns. Removed
+//! lines are red, added lines are green, and the changed words inside a
+//! modified line are emphasized.
+//!
+//! # Feature Flag
+//!
+//! This module requires the `diff` feature to be enabled:
+//!
+//! ```toml
+//! [dependencies]
+//! rich_rust = { version = "0.1", features = ["diff"] }
+//! ```
+//!
+//! # Dependencies
+//!
+//! Enabling this feature adds the [`similar`](https://docs.rs/similar) crate,
+//! which provides the Myers diff algorithm and word-level inline changes.
+//!
+//! # Basic Usage
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::diff::{Diff, DiffMode};
+//!
+//! let diff = Diff::new("a\nb\nc\n", "a\nB\nc\n")
+//!     .labels("before.txt", "after.txt")
+//!     .context(1);
+//! console.print_renderable(&diff);
+//!
+//! // Two columns, split across the available width
+//! let diff = Diff::new(old, new).mode(DiffMode::SideBySide);
+//! ```
+//!
+//! # Width Handling
+//!
+//! Lines wider than the available width are folded onto continuation lines,
+//! so the diff never exceeds the console width. Side-by-side mode falls back
+//! to unified output when the width is too small for two columns.
+
+use similar::{ChangeTag, InlineChange, TextDiff};
+
+use crate::cells::cell_len;
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::{JustifyMethod, OverflowMethod, Text};
+
+/// Layout used to render a [`Diff`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffMode {
+    /// Classic unified diff: `-`/`+` prefixed lines under `@@` hunk headers.
+    #[default]
+    Unified,
+    /// Old and new text in two columns with line numbers.
+    SideBySide,
+}
+
+/// Styles used when rendering a [`Diff`].
+#[derive(Debug, Clone)]
+pub struct DiffTheme {
+    /// Style for the `---`/`+++` file labels.
+    pub header: Style,
+    /// Style for `@@ ... @@` hunk headers.
+    pub hunk: Style,
+    /// Style for removed lines.
+    pub delete: Style,
+    /// Style for added lines.
+    pub insert: Style,
+    /// Style for the changed part of a removed line.
+    pub delete_emphasis: Style,
+    /// Style for the changed part of an added line.
+    pub insert_emphasis: Style,
+    /// Style for unchanged context lines.
+    pub context: Style,
+    /// Style for line numbers in side-by-side mode.
+    pub line_number: Style,
+    /// Style for the column separator in side-by-side mode.
+    pub separator: Style,
+}
+
+impl Default for DiffTheme {
+    fn default() -> Self {
+        Self {
+            header: Style::new().bold(),
+            hunk: Style::new().fg("cyan"),
+            delete: Style::new().fg("red"),
+            insert: Style::new().fg("green"),
+            delete_emphasis: Style::new().fg("red").bold().reverse(),
+            insert_emphasis: Style::new().fg("green").bold().reverse(),
+            context: Style::new(),
+            line_number: Style::new().dim(),
+            separator: Style::new().dim(),
+        }
+    }
+}
+
+/// A single rendered line of a diff, before layout.
+#[derive(Debug, Clone)]
+struct DiffLine {
+    tag: ChangeTag,
+    old_index: Option<usize>,
+    new_index: Option<usize>,
+    text: Text,
+}
+
+/// A hunk: its `@@` header and the lines of each diff operation in it.
+#[derive(Debug, Clone)]
+struct Hunk {
+    header: String,
+    ops: Vec<Vec<DiffLine>>,
+}
+
+/// A renderable comparing two texts line by line.
+#[derive(Debug, Clone)]
+pub struct Diff {
+    /// The original text.
+    old: String,
+    /// The changed text.
+    new: String,
+    /// Label shown for the original text.
+    old_label: String,
+    /// Label shown for the changed text.
+    new_label: String,
+    /// Unified or side-by-side layout.
+    mode: DiffMode,
+    /// Number of unchanged lines shown around each change.
+    context: usize,
+    /// Whether to emphasize changed words within modified lines.
+    intraline: bool,
+    /// Number of spaces a tab expands to.
+    tab_size: usize,
+    /// Styles for each part of the diff.
+    theme: DiffTheme,
+}
+
+impl Diff {
+    /// Create a diff from `old` to `new`.
+    #[must_use]
+    pub fn new(old: impl Into<String>, new: impl Into<String>) -> Self {
+        Self {
+            old: old.into(),
+            new: new.into(),
+            old_label: "old".to_string(),
+            new_label: "new".to_string(),
+            mode: DiffMode::default(),
+            context: 3,
+            intraline: true,
+            tab_size: 8,
+            theme: DiffTheme::default(),
+        }
+    }
+
+    /// Set the labels shown in the diff header.
+    #[must_use]
+    pub fn labels(mut self, old: impl Into<String>, new: impl Into<String>) -> Self {
+        self.old_label = old.into();
+        self.new_label = new.into();
+        self
+    }
+
+    /// Set the layout mode.
+    #[must_use]
+    pub fn mode(mut self, mode: DiffMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the number of unchanged context lines around each change (default 3).
+    #[must_use]
+    pub fn context(mut self, lines: usize) -> Self {
+        self.context = lines;
+        self
+    }
+
+    /// Set whether changed words within a modified line are emphasized.
+    #[must_use]
+    pub fn intraline(mut self, intraline: bool) -> Self {
+        self.intraline = intraline;
+        self
+    }
+
+    /// Set the number of spaces a tab expands to (default 8).
+    #[must_use]
+    pub fn tab_size(mut self, tab_size: usize) -> Self {
+        self.tab_size = tab_size;
+        self
+    }
+
+    /// Set the styles used for rendering.
+    #[must_use]
+    pub fn theme(mut self, theme: DiffTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Check whether the two texts differ.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.old != self.new
+    }
+
+    /// Render the diff to segments, fitting within `max_width` cells.
+    ///
+    /// Identical texts render to nothing.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        let hunks = self.hunks();
+        if hunks.is_empty() || max_width == 0 {
+            return Vec::new();
+        }
+
+        match self.mode {
+            DiffMode::SideBySide => self
+                .render_side_by_side(&hunks, max_width)
+                .unwrap_or_else(|| self.render_unified(&hunks, max_width)),
+            DiffMode::Unified => self.render_unified(&hunks, max_width),
+        }
+    }
+
+    /// Render to plain text.
+    #[must_use]
+    pub fn render_plain(&self, max_width: usize) -> String {
+        self.render(max_width)
+            .into_iter()
+            .map(|seg| seg.text)
+            .collect()
+    }
+
+    fn hunks(&self) -> Vec<Hunk> {
+        let diff = TextDiff::from_lines(self.old.as_str(), self.new.as_str());
+        diff.grouped_ops(self.context)
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .map(|group| {
+                let first = &group[0];
+                let last = &group[group.len() - 1];
+                let old_start = first.old_range().start;
+                let new_start = first.new_range().start;
+                let header = format!(
+                    "@@ -{} +{} @@",
+                    hunk_range(old_start, last.old_range().end - old_start),
+                    hunk_range(new_start, last.new_range().end - new_start),
+                );
+                let ops = group
+                    .iter()
+                    .map(|op| {
+                        diff.iter_inline_changes(op)
+                            .map(|change| self.diff_line(&change))
+                            .collect()
+                    })
+                    .collect();
+                Hunk { header, ops }
+            })
+            .collect()
+    }
+
+    fn diff_line(&self, change: &InlineChange<'_, str>) -> DiffLine {
+        let theme = &self.theme;
+        let (style, emphasis) = match change.tag() {
+            ChangeTag::Delete => (&theme.delete, &theme.delete_emphasis),
+            ChangeTag::Insert => (&theme.insert, &theme.insert_emphasis),
+            ChangeTag::Equal => (&theme.context, &theme.context),
+        };
+
+        let mut text = Text::new("");
+        for (emphasized, value) in change.iter_strings_lossy() {
+            let value = value.trim_end_matches(['\n', '\r']);
+            if value.is_empty() {
+                continue;
+            }
+            let style = if emphasized && self.intraline {
+                emphasis
+            } else {
+                style
+            };
+            text.append_styled(value, style.clone());
+        }
+
+        DiffLine {
+            tag: change.tag(),
+            old_index: change.old_index(),
+            new_index: change.new_index(),
+            text: text.expand_tabs(self.tab_size),
+        }
+    }
+
+    fn line_style(&self, tag: ChangeTag) -> &Style {
+        match tag {
+            ChangeTag::Delete => &self.theme.delete,
+            ChangeTag::Insert => &self.theme.insert,
+            ChangeTag::Equal => &self.theme.context,
+        }
+    }
+
+    fn render_unified(&self, hunks: &[Hunk], width: usize) -> Vec<Segment<'static>> {
+        let mut segments = Vec::new();
+        let headers = [
+            format!("--- {}", self.old_label),
+            format!("+++ {}", self.new_label),
+        ];
+        for header in headers {
+            push_folded(
+                &mut segments,
+                &Text::styled(header, self.theme.header.clone()),
+                width,
+            );
+        }
+
+        let body_width = width.saturating_sub(1).max(1);
+        for hunk in hunks {
+            push_folded(
+                &mut segments,
+                &Text::styled(hunk.header.clone(), self.theme.hunk.clone()),
+                width,
+            );
+            for line in hunk.ops.iter().flatten() {
+                let sign = match line.tag {
+                    ChangeTag::Delete => "-",
+                    ChangeTag::Insert => "+",
+                    ChangeTag::Equal => " ",
+                };
+                let style = self.line_style(line.tag);
+                for (index, chunk) in line.text.fold(body_width).iter().enumerate() {
+                    let prefix = if index == 0 { sign } else { " " };
+                    let mut row = Text::styled(prefix, style.clone());
+                    row.append_text(chunk);
+                    push_line(&mut segments, &row);
+                }
+            }
+        }
+        segments
+    }
+
+    /// Lay out hunks in two columns, or `None` if `width` is too narrow.
+    fn render_side_by_side(&self, hunks: &[Hunk], width: usize) -> Option<Vec<Segment<'static>>> {
+        const SEPARATOR: &str = " \u{2502} ";
+
+        let line_count = self.old.lines().count().max(self.new.lines().count());
+        let gutter = line_count.max(1).to_string().len();
+        let column_width = width.saturating_sub(cell_len(SEPARATOR)) / 2;
+        // Line number, a space, then at least a few cells of content.
+        let content_width = column_width.checked_sub(gutter + 1).filter(|w| *w >= 4)?;
+
+        let mut segments = Vec::new();
+        let separator = Text::styled(SEPARATOR, self.theme.separator.clone());
+        let join = |left: &Text, right: &Text| {
+            let mut row = left.clone();
+            row.pad(column_width, JustifyMethod::Left);
+            row.append_text(&separator);
+            row.append_text(right);
+            row
+        };
+
+        let mut old_label = Text::styled(self.old_label.clone(), self.theme.header.clone());
+        old_label.truncate(column_width, OverflowMethod::Ellipsis, false);
+        let mut new_label = Text::styled(self.new_label.clone(), self.theme.header.clone());
+        new_label.truncate(column_width, OverflowMethod::Ellipsis, false);
+        push_line(&mut segments, &join(&old_label, &new_label));
+
+        for hunk in hunks {
+            push_folded(
+                &mut segments,
+                &Text::styled(hunk.header.clone(), self.theme.hunk.clone()),
+                width,
+            );
+            for op in &hunk.ops {
+                let mut left = Vec::new();
+                let mut right = Vec::new();
+                for line in op {
+                    if line.tag != ChangeTag::Insert {
+                        left.push(self.numbered(line, line.old_index, gutter, content_width));
+                    }
+                    if line.tag != ChangeTag::Delete {
+                        right.push(self.numbered(line, line.new_index, gutter, content_width));
+                    }
+                }
+
+                let left_rows: Vec<Text> = left.into_iter().flatten().collect();
+                let right_rows: Vec<Text> = right.into_iter().flatten().collect();
+                let empty = Text::new("");
+                for index in 0..left_rows.len().max(right_rows.len()) {
+                    let row = join(
+                        left_rows.get(index).unwrap_or(&empty),
+                        right_rows.get(index).unwrap_or(&empty),
+                    );
+                    push_line(&mut segments, &row);
+                }
+            }
+        }
+        Some(segments)
+    }
+
+    /// Fold one side of a side-by-side row, numbering its first line.
+    fn numbered(
+        &self,
+        line: &DiffLine,
+        index: Option<usize>,
+        gutter: usize,
+        content_width: usize,
+    ) -> Vec<Text> {
+        line.text
+            .fold(content_width)
+            .into_iter()
+            .enumerate()
+            .map(|(chunk_index, chunk)| {
+                let number = match index {
+                    Some(n) if chunk_index == 0 => format!("{:>gutter$} ", n + 1),
+                    _ => " ".repeat(gutter + 1),
+                };
+                let mut row = Text::styled(number, self.theme.line_number.clone());
+                row.append_text(&chunk);
+                row
+            })
+            .collect()
+    }
+}
+
+/// Format a unified diff range (`start,len`, 1-based).
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{len}", start + 1),
+    }
+}
+
+fn push_line(segments: &mut Vec<Segment<'static>>, text: &Text) {
+    segments.extend(text.render("").into_iter().map(Segment::into_owned));
+    segments.push(Segment::line());
+}
+
+fn push_folded(segments: &mut Vec<Segment<'static>>, text: &Text, width: usize) {
+    for line in text.fold(width) {
+        push_line(segments, &line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OLD: &str = "one\ntwo\nthree\nfour\n";
+    const NEW: &str = "one\n2\nthree\nfour\nfive\n";
+
+    #[test]
+    fn test_unified_output() {
+        let plain = Diff::new(OLD, NEW)
+            .labels("a.txt", "b.txt")
+            .render_plain(80);
+        assert_eq!(
+            plain,
+            "--- a.txt\n+++ b.txt\n@@ -1,4 +1,5 @@\n one\n-two\n+2\n three\n four\n+five\n"
+        );
+    }
+
+    #[test]
+    fn test_identical_texts_render_nothing() {
+        let diff = Diff::new(OLD, OLD);
+        assert!(!diff.has_changes());
+        assert!(diff.render(80).is_empty());
+    }
+
+    #[test]
+    fn test_context_splits_hunks() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "A\nb\nc\nd\ne\nf\ng\nH\n";
+        let plain = Diff::new(old, new).context(1).render_plain(80);
+        assert_eq!(plain.matches("@@ -").count(), 2, "{plain}");
+        assert!(plain.contains("@@ -1,2 +1,2 @@"));
+        assert!(plain.contains("@@ -7,2 +7,2 @@"));
+        assert!(!plain.contains(" d\n"));
+    }
+
+    #[test]
+    fn test_styles_and_intraline_emphasis() {
+        let theme = DiffTheme::default();
+        let segments = Diff::new("let x = 1;\n", "let x = 2;\n").render(80);
+        let styled = |text: &str, style: &Style| {
+            segments
+                .iter()
+                .any(|s| s.text.contains(text) && s.style.as_ref() == Some(style))
+        };
+        assert!(styled("let x = ", &theme.delete));
+        assert!(styled("1", &theme.delete_emphasis));
+        assert!(styled("2", &theme.insert_emphasis));
+
+        let segments = Diff::new("let x = 1;\n", "let x = 2;\n")
+            .intraline(false)
+            .render(80);
+        let theme = DiffTheme::default();
+        assert!(
+            !segments
+                .iter()
+                .any(|s| s.style.as_ref() == Some(&theme.delete_emphasis))
+        );
+    }
+
+    #[test]
+    fn test_unified_folds_long_lines() {
+        let old = format!("{}\n", "x".repeat(30));
+        let new = format!("{}\n", "y".repeat(30));
+        let plain = Diff::new(old, new).render_plain(12);
+        for line in plain.lines() {
+            assert!(cell_len(line) <= 12, "{line:?}");
+        }
+        assert!(plain.contains("-xxxxxxxxxxx\n xxxxxxxxxxx\n"));
+    }
+
+    #[test]
+    fn test_side_by_side_layout() {
+        let plain = Diff::new(OLD, NEW)
+            .mode(DiffMode::SideBySide)
+            .render_plain(31);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0], "old            \u{2502} new");
+        assert_eq!(lines[1], "@@ -1,4 +1,5 @@");
+        assert!(lines[3].starts_with("2 two"), "{plain}");
+        assert!(lines[3].ends_with("2 2"), "{plain}");
+        assert!(lines.last().unwrap().ends_with("5 five"), "{plain}");
+        for line in &lines {
+            assert!(cell_len(line) <= 31, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_side_by_side_falls_back_when_narrow() {
+        let diff = Diff::new(OLD, NEW);
+        assert_eq!(
+            diff.clone().mode(DiffMode::SideBySide).render_plain(8),
+            diff.render_plain(8)
+        );
+    }
+
+    #[test]
+    fn test_hunk_range_format() {
+        assert_eq!(hunk_range(0, 0), "0,0");
+        assert_eq!(hunk_range(4, 1), "5");
+        assert_eq!(hunk_range(4, 3), "5,3");
+    }
+}
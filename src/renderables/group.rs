@@ -7,8 +7,9 @@
 //! # Examples
 //!
 //! ```rust,ignore
+//! use rich_rust::measure::Measurement;
 //! use rich_rust::renderables::{Group, Panel, Rule};
-//! use rich_rust::text::Text;
+//! use rich_rust::segment::split_lines;
 //!
 //! // Combine multiple renderables into a group
 //! let group = Group::new()
@@ -16,21 +17,34 @@
 //!     .push(Rule::new())
 //!     .push("Second paragraph");
 //!
-//! // Use the group as panel content
-//! let panel = Panel::from_renderable(&group, 80)
-//!     .title("Grouped Content");
+//! // The same group, built with the `group!` macro
+//! let group = rich_rust::group!["First paragraph of text", Rule::new(), "Second paragraph"];
+//!
+//! // Use the group as panel content, sized to fit it
+//! let width = Measurement::get(&console, &options, Some(&group)).maximum;
+//! let lines = split_lines(group.render(&console, &options.update_width(width)).into_iter());
+//! let panel = Panel::fit(lines).title("Grouped Content");
 //!
 //! // Or render directly
 //! console.print_renderable(&group);
 //! ```
 //!
+//! Boxed renderables of different types can be collected into a group with
+//! [`Group::from_iter`].
+//!
 //! # Fit Option
 //!
 //! By default, each renderable is rendered on its own lines. Use `fit(true)`
 //! to attempt to render items inline when they fit.
+//!
+//! # Measurement
+//!
+//! A group measures as wide as its widest child (or, with `fit(true)`, the
+//! children side by side), so containers can size themselves to it.
 
 use crate::console::{Console, ConsoleOptions};
-use crate::segment::Segment;
+use crate::measure::{Measurement, RichMeasure, measure_sum, measure_union};
+use crate::segment::{Segment, line_length, split_lines};
 
 use super::Renderable;
 
@@ -113,6 +127,78 @@ impl Renderable for Group<'_> {
     }
 }
 
+impl<'a> FromIterator<Box<dyn Renderable + 'a>> for Group<'a> {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Renderable + 'a>>>(iter: I) -> Self {
+        Self {
+            children: iter.into_iter().collect(),
+            fit: false,
+        }
+    }
+}
+
+impl<'a> Extend<Box<dyn Renderable + 'a>> for Group<'a> {
+    fn extend<I: IntoIterator<Item = Box<dyn Renderable + 'a>>>(&mut self, iter: I) {
+        self.children.extend(iter);
+    }
+}
+
+/// Widest line of `renderable` when rendered within `width` cells.
+fn rendered_width(
+    renderable: &dyn Renderable,
+    console: &Console,
+    options: &ConsoleOptions,
+    width: usize,
+) -> usize {
+    let segments = renderable.render(console, &options.update_width(width));
+    split_lines(segments.into_iter())
+        .iter()
+        .map(|line| line_length(line))
+        .max()
+        .unwrap_or(0)
+}
+
+impl RichMeasure for Group<'_> {
+    /// Children are measured by rendering them: the maximum is the widest line
+    /// at the available width, the minimum the widest line when squeezed to a
+    /// single cell (the longest word, for text).
+    fn rich_measure(&self, console: &Console, options: &ConsoleOptions) -> Measurement {
+        let measurements: Vec<Measurement> = self
+            .children
+            .iter()
+            .map(|child| {
+                let maximum = rendered_width(child.as_ref(), console, options, options.max_width);
+                let minimum = rendered_width(child.as_ref(), console, options, 1);
+                Measurement::new(minimum.min(maximum), maximum).with_maximum(options.max_width)
+            })
+            .collect();
+        if self.fit {
+            measure_sum(&measurements)
+        } else {
+            measure_union(&measurements)
+        }
+    }
+}
+
+/// Build a [`Group`] from a list of renderables of any types.
+///
+/// Each expression is added with [`Group::push`].
+///
+/// # Examples
+///
+/// ```
+/// use rich_rust::renderables::Rule;
+/// use rich_rust::text::Text;
+///
+/// let group = rich_rust::group!["Heading", Rule::new(), Text::new("Body")];
+/// assert_eq!(group.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! group {
+    ($($renderable:expr),* $(,)?) => {
+        $crate::renderables::Group::new()$(.push($renderable))*
+    };
+}
+
 /// Create a group from an iterator of renderables.
 ///
 /// This is a convenience function for creating groups from iterators.
@@ -142,6 +228,50 @@ mod tests {
     use super::*;
     use crate::console::Console;
 
+    #[test]
+    fn test_group_from_iter_boxed() {
+        use crate::renderables::Rule;
+        use crate::text::Text;
+
+        let items: Vec<Box<dyn Renderable>> = vec![
+            Box::new("plain"),
+            Box::new(Text::new("text")),
+            Box::new(Rule::new()),
+        ];
+        let mut g: Group = items.into_iter().collect();
+        assert_eq!(g.len(), 3);
+        g.extend([Box::new("more") as Box<dyn Renderable>]);
+        assert_eq!(g.len(), 4);
+    }
+
+    #[test]
+    fn test_group_macro() {
+        let g = crate::group!["one", String::from("two"), crate::text::Text::new("three"),];
+        assert_eq!(g.len(), 3);
+        let empty: Group = crate::group![];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_group_measure() {
+        let console = Console::builder().markup(false).width(40).build();
+        let options = console.options();
+
+        let g = Group::new().push("short").push("a longer line");
+        assert_eq!(g.rich_measure(&console, &options), Measurement::new(13, 13));
+
+        // A rule squeezes to one cell but fills whatever width it gets.
+        let g = g.push(crate::renderables::Rule::new());
+        assert_eq!(g.rich_measure(&console, &options), Measurement::new(13, 40));
+
+        let fit = Group::new().push("ab").push("cde").fit(true);
+        assert_eq!(fit.rich_measure(&console, &options).maximum, 5);
+
+        // Never wider than the available width.
+        let wide = Group::new().push("x".repeat(60));
+        assert_eq!(wide.rich_measure(&console, &options).maximum, 40);
+    }
+
     #[test]
     fn test_group_new() {
         let g: Group = Group::new();
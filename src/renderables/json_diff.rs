@@ -0,0 +1,381 @@
+//! JSON diff - structural comparison of two JSON documents.
+//!
+//! [`JsonDiff`] walks two JSON values side by side and renders the result as
+//! a pretty-printed document with a `-`/`+` gutter: removed keys and values
+//! are red, added ones green, and unchanged ones plain. Objects are compared
+//! key by key and arrays index by index, so a changed nested value shows as a
+//! single `-`/`+` pair inside its unchanged parents.
+//!
+//! # Feature Flag
+//!
+//! This module requires the `json` feature to be enabled:
+//!
+//! ```toml
+//! [dependencies]
+//! rich_rust = { version = "0.1", features = ["json"] }
+//! ```
+//!
+//! # Basic Usage
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::json_diff::JsonDiff;
+//!
+//! let diff = JsonDiff::from_str(
+//!     r#"{"port": 80, "debug": false}"#,
+//!     r#"{"port": 8080, "debug": false, "tls": true}"#,
+//! )?;
+//! console.print_renderable(&diff);
+//!
+//! // Only the changed keys and the objects/arrays that contain them
+//! let diff = diff.show_unchanged(false);
+//! ```
+//!
+//! # Width Handling
+//!
+//! Lines wider than the available width are folded onto continuation lines
+//! that keep the gutter and indentation, so the diff never exceeds the
+//! console width.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::segment::Segment;
+use crate::style::Style;
+use crate::text::Text;
+
+use super::json::JsonError;
+
+/// Styles used when rendering a [`JsonDiff`].
+#[derive(Debug, Clone)]
+pub struct JsonDiffTheme {
+    /// Style for removed keys and values.
+    pub delete: Style,
+    /// Style for added keys and values.
+    pub insert: Style,
+    /// Style for unchanged keys and values.
+    pub context: Style,
+}
+
+impl Default for JsonDiffTheme {
+    fn default() -> Self {
+        Self {
+            delete: Style::new().fg("red"),
+            insert: Style::new().fg("green"),
+            context: Style::new(),
+        }
+    }
+}
+
+/// Whether a line was removed, added, or is shared by both documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Delete,
+    Insert,
+    Equal,
+}
+
+/// One output line before layout.
+#[derive(Debug, Clone)]
+struct DiffLine {
+    change: Change,
+    depth: usize,
+    text: String,
+}
+
+/// A renderable comparing two JSON documents structurally.
+#[derive(Debug, Clone)]
+pub struct JsonDiff {
+    /// The original document.
+    before: Value,
+    /// The changed document.
+    after: Value,
+    /// Spaces per nesting level.
+    indent: usize,
+    /// Whether unchanged members are shown.
+    show_unchanged: bool,
+    /// Styles for each kind of line.
+    theme: JsonDiffTheme,
+}
+
+impl JsonDiff {
+    /// Create a diff from `before` to `after`.
+    #[must_use]
+    pub fn new(before: Value, after: Value) -> Self {
+        Self {
+            before,
+            after,
+            indent: 2,
+            show_unchanged: true,
+            theme: JsonDiffTheme::default(),
+        }
+    }
+
+    /// Create a diff from two JSON strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either string is not valid JSON.
+    pub fn from_str(before: &str, after: &str) -> Result<Self, JsonError> {
+        let before = serde_json::from_str(before).map_err(JsonError::Parse)?;
+        let after = serde_json::from_str(after).map_err(JsonError::Parse)?;
+        Ok(Self::new(before, after))
+    }
+
+    /// Create a diff from two serializable Rust values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails (e.g. non-finite floats).
+    pub fn from_data<B: Serialize, A: Serialize>(before: &B, after: &A) -> Result<Self, JsonError> {
+        let before = serde_json::to_value(before).map_err(JsonError::Serialize)?;
+        let after = serde_json::to_value(after).map_err(JsonError::Serialize)?;
+        Ok(Self::new(before, after))
+    }
+
+    /// Set the number of spaces per nesting level (default 2).
+    #[must_use]
+    pub fn indent(mut self, spaces: usize) -> Self {
+        self.indent = spaces;
+        self
+    }
+
+    /// Set whether unchanged members are shown (default `true`).
+    ///
+    /// When `false`, only changed members and the objects and arrays that
+    /// contain them are rendered.
+    #[must_use]
+    pub fn show_unchanged(mut self, show: bool) -> Self {
+        self.show_unchanged = show;
+        self
+    }
+
+    /// Set the styles used for rendering.
+    #[must_use]
+    pub fn theme(mut self, theme: JsonDiffTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Check whether the two documents differ.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.before != self.after
+    }
+
+    /// Render the diff to segments, fitting within `max_width` cells.
+    ///
+    /// Identical documents render to nothing.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        if !self.has_changes() || max_width == 0 {
+            return Vec::new();
+        }
+
+        let mut lines = Vec::new();
+        self.diff_value("", &self.before, &self.after, 0, &mut lines);
+
+        let mut segments = Vec::new();
+        for line in &lines {
+            let (sign, style) = match line.change {
+                Change::Delete => ("- ", &self.theme.delete),
+                Change::Insert => ("+ ", &self.theme.insert),
+                Change::Equal => ("  ", &self.theme.context),
+            };
+            let indent = " ".repeat(line.depth * self.indent);
+            let body_width = max_width.saturating_sub(sign.len() + indent.len()).max(1);
+            let body = Text::styled(line.text.as_str(), style.clone());
+            for (index, chunk) in body.fold(body_width).iter().enumerate() {
+                let prefix = if index == 0 { sign } else { "  " };
+                let mut row = Text::styled(format!("{prefix}{indent}"), style.clone());
+                row.append_text(chunk);
+                segments.extend(row.render("").into_iter().map(Segment::into_owned));
+                segments.push(Segment::line());
+            }
+        }
+        segments
+    }
+
+    /// Render to plain text.
+    #[must_use]
+    pub fn render_plain(&self, max_width: usize) -> String {
+        self.render(max_width)
+            .into_iter()
+            .map(|seg| seg.text)
+            .collect()
+    }
+
+    /// Diff one member; `label` is the `"key": ` prefix, empty for array items.
+    fn diff_value(
+        &self,
+        label: &str,
+        before: &Value,
+        after: &Value,
+        depth: usize,
+        out: &mut Vec<DiffLine>,
+    ) {
+        if before == after {
+            if self.show_unchanged {
+                push_value(out, Change::Equal, label, before, depth);
+            }
+            return;
+        }
+
+        match (before, after) {
+            (Value::Object(old), Value::Object(new)) => {
+                push(out, Change::Equal, depth, format!("{label}{{"));
+                for (key, old_value) in old {
+                    let label = key_label(key);
+                    match new.get(key) {
+                        Some(new_value) => {
+                            self.diff_value(&label, old_value, new_value, depth + 1, out);
+                        }
+                        None => push_value(out, Change::Delete, &label, old_value, depth + 1),
+                    }
+                }
+                for (key, new_value) in new {
+                    if !old.contains_key(key) {
+                        push_value(out, Change::Insert, &key_label(key), new_value, depth + 1);
+                    }
+                }
+                push(out, Change::Equal, depth, "}".to_string());
+            }
+            (Value::Array(old), Value::Array(new)) => {
+                push(out, Change::Equal, depth, format!("{label}["));
+                for (index, old_item) in old.iter().enumerate() {
+                    match new.get(index) {
+                        Some(new_item) => self.diff_value("", old_item, new_item, depth + 1, out),
+                        None => push_value(out, Change::Delete, "", old_item, depth + 1),
+                    }
+                }
+                for new_item in new.iter().skip(old.len()) {
+                    push_value(out, Change::Insert, "", new_item, depth + 1);
+                }
+                push(out, Change::Equal, depth, "]".to_string());
+            }
+            _ => {
+                push_value(out, Change::Delete, label, before, depth);
+                push_value(out, Change::Insert, label, after, depth);
+            }
+        }
+    }
+}
+
+fn push(out: &mut Vec<DiffLine>, change: Change, depth: usize, text: String) {
+    out.push(DiffLine {
+        change,
+        depth,
+        text,
+    });
+}
+
+/// Push a whole value, pretty-printed across as many lines as it needs.
+fn push_value(out: &mut Vec<DiffLine>, change: Change, label: &str, value: &Value, depth: usize) {
+    let pretty = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+    for (index, line) in pretty.lines().enumerate() {
+        // serde_json indents nested lines by two spaces per level.
+        let trimmed = line.trim_start_matches(' ');
+        let nested = (line.len() - trimmed.len()) / 2;
+        let text = if index == 0 {
+            format!("{label}{trimmed}")
+        } else {
+            trimmed.to_string()
+        };
+        push(out, change, depth + nested, text);
+    }
+}
+
+fn key_label(key: &str) -> String {
+    format!("{}: ", Value::String(key.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cells::cell_len;
+    use serde_json::json;
+
+    #[test]
+    fn test_changed_added_and_removed_keys() {
+        let diff = JsonDiff::new(
+            json!({"name": "app", "port": 80, "old": true}),
+            json!({"name": "app", "port": 8080, "tls": {"on": true}}),
+        );
+        assert_eq!(
+            diff.render_plain(80),
+            concat!(
+                "  {\n",
+                "    \"name\": \"app\"\n",
+                "-   \"old\": true\n",
+                "-   \"port\": 80\n",
+                "+   \"port\": 8080\n",
+                "+   \"tls\": {\n",
+                "+     \"on\": true\n",
+                "+   }\n",
+                "  }\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_arrays_compare_by_index() {
+        let diff = JsonDiff::new(json!([1, 2, 3]), json!([1, 5]));
+        assert_eq!(
+            diff.render_plain(80),
+            "  [\n    1\n-   2\n+   5\n-   3\n  ]\n"
+        );
+    }
+
+    #[test]
+    fn test_hide_unchanged() {
+        let diff = JsonDiff::new(
+            json!({"a": 1, "b": {"c": 1, "d": 2}}),
+            json!({"a": 1, "b": {"c": 1, "d": 3}}),
+        )
+        .show_unchanged(false);
+        assert_eq!(
+            diff.render_plain(80),
+            "  {\n    \"b\": {\n-     \"d\": 2\n+     \"d\": 3\n    }\n  }\n"
+        );
+    }
+
+    #[test]
+    fn test_identical_documents_render_nothing() {
+        let diff = JsonDiff::from_str(r#"{"a": 1}"#, r#"{ "a" : 1 }"#).expect("valid json");
+        assert!(!diff.has_changes());
+        assert!(diff.render(80).is_empty());
+    }
+
+    #[test]
+    fn test_styles() {
+        let theme = JsonDiffTheme::default();
+        let segments = JsonDiff::new(json!({"a": 1}), json!({"a": 2})).render(80);
+        let styled = |text: &str, style: &Style| {
+            segments
+                .iter()
+                .any(|s| s.text.contains(text) && s.style.as_ref() == Some(style))
+        };
+        assert!(styled("1", &theme.delete));
+        assert!(styled("2", &theme.insert));
+    }
+
+    #[test]
+    fn test_long_lines_fold_within_width() {
+        let long = "x".repeat(40);
+        let plain = JsonDiff::new(json!({"k": "short"}), json!({"k": long})).render_plain(20);
+        for line in plain.lines() {
+            assert!(cell_len(line) <= 20, "{line:?}");
+        }
+        assert!(
+            plain.contains("+   \"k\": \"xxxxxxxxxx\n    xxxxxxxxxxxxxxxx\n"),
+            "{plain}"
+        );
+    }
+
+    #[test]
+    fn test_from_str_rejects_invalid_json() {
+        assert!(matches!(
+            JsonDiff::from_str("{", "{}"),
+            Err(JsonError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,277 @@
+//! Key-value definition list renderable.
+//!
+//! A [`KeyValue`] lays out entries as right-aligned keys followed by their
+//! values, which is the usual shape of "show config" and "show status"
+//! output. Values are wrapped to the width left over after the key column,
+//! and any renderable (a table, a panel, another `KeyValue`) can be a value.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::{KeyValue, Table};
+//!
+//! let info = KeyValue::new()
+//!     .entry("name", "rich_rust")
+//!     .entry("version", "[green]0.2.1[/]")
+//!     .entry("features", KeyValue::new().entry("json", "on").entry("diff", "off"));
+//!
+//! console.print_renderable(&info);
+//! ```
+
+use crate::cells;
+use crate::console::{Console, ConsoleOptions};
+use crate::segment::{Segment, split_lines};
+use crate::style::Style;
+use crate::text::{JustifyMethod, OverflowMethod, Text};
+
+use super::Renderable;
+
+/// A list of key/value entries with an aligned key column.
+pub struct KeyValue<'a> {
+    /// Entries in display order.
+    entries: Vec<(Text, Box<dyn Renderable + 'a>)>,
+    /// Text placed between key and value.
+    separator: String,
+    /// Style for keys.
+    key_style: Style,
+    /// Style for the separator.
+    separator_style: Style,
+    /// Maximum width of the key column.
+    max_key_width: Option<usize>,
+}
+
+impl Default for KeyValue<'_> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            separator: ": ".to_string(),
+            key_style: Style::new().bold(),
+            separator_style: Style::new().dim(),
+            max_key_width: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for KeyValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyValue")
+            .field(
+                "keys",
+                &self
+                    .entries
+                    .iter()
+                    .map(|(k, _)| k.plain())
+                    .collect::<Vec<_>>(),
+            )
+            .field("separator", &self.separator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> KeyValue<'a> {
+    /// Create an empty list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an entry (builder pattern).
+    ///
+    /// String values are parsed as markup, like any other string renderable.
+    #[must_use]
+    pub fn entry<R: Renderable + 'a>(mut self, key: impl Into<Text>, value: R) -> Self {
+        self.add_entry(key, value);
+        self
+    }
+
+    /// Add an entry.
+    pub fn add_entry<R: Renderable + 'a>(&mut self, key: impl Into<Text>, value: R) {
+        self.entries.push((key.into(), Box::new(value)));
+    }
+
+    /// Set the text between key and value (default `": "`).
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set the key style.
+    #[must_use]
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Set the separator style.
+    #[must_use]
+    pub fn separator_style(mut self, style: Style) -> Self {
+        self.separator_style = style;
+        self
+    }
+
+    /// Limit the key column width; longer keys are truncated.
+    ///
+    /// Defaults to a third of the available width.
+    #[must_use]
+    pub fn max_key_width(mut self, width: usize) -> Self {
+        self.max_key_width = Some(width);
+        self
+    }
+
+    /// Check if the list is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl Renderable for KeyValue<'_> {
+    fn render(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'_>> {
+        let width = options.max_width;
+        let key_cap = self.max_key_width.unwrap_or(width / 3);
+        let key_width = self
+            .entries
+            .iter()
+            .map(|(key, _)| key.cell_len())
+            .max()
+            .unwrap_or(0)
+            .min(key_cap);
+        let separator_width = cells::cell_len(&self.separator);
+        let value_width = width.saturating_sub(key_width + separator_width).max(1);
+        let value_options = options.update_width(value_width);
+        let indent = " ".repeat(key_width + separator_width);
+
+        let mut segments = Vec::new();
+        for (key, value) in &self.entries {
+            let mut key = key.clone();
+            if key.plain().contains('\n') {
+                key = Text::new(key.plain().replace('\n', " "));
+            }
+            key.truncate(key_width, OverflowMethod::Ellipsis, false);
+            key.pad(key_width, JustifyMethod::Right);
+
+            let mut lines = split_lines(value.render(console, &value_options).into_iter());
+            if lines.len() > 1 && lines.last().is_some_and(Vec::is_empty) {
+                lines.pop();
+            }
+
+            let mut first = true;
+            for line in lines {
+                let mut text = Text::new("");
+                for segment in line.iter().filter(|s| !s.is_control()) {
+                    match &segment.style {
+                        Some(style) => text.append_styled(&segment.text, style.clone()),
+                        None => text.append(&segment.text),
+                    }
+                }
+                let mut wrapped_lines = text.wrap(value_width);
+                if wrapped_lines.is_empty() {
+                    wrapped_lines.push(Text::new(""));
+                }
+                for wrapped in wrapped_lines {
+                    if first {
+                        segments.extend(key.render("").into_iter().map(|seg| {
+                            let style = match seg.style {
+                                Some(style) => self.key_style.combine(&style),
+                                None => self.key_style.clone(),
+                            };
+                            Segment::new(seg.text.into_owned(), Some(style))
+                        }));
+                        segments.push(Segment::new(
+                            self.separator.clone(),
+                            Some(self.separator_style.clone()),
+                        ));
+                        first = false;
+                    } else {
+                        segments.push(Segment::new(indent.clone(), None));
+                    }
+                    segments.extend(wrapped.render("").into_iter().map(Segment::into_owned));
+                    segments.push(Segment::line());
+                }
+            }
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderables::table::{Column, Table};
+
+    fn render_plain(renderable: &dyn Renderable, width: usize) -> String {
+        let console = Console::builder().width(width).markup(true).build();
+        let options = console.options();
+        renderable
+            .render(&console, &options)
+            .iter()
+            .map(|s| s.text.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_keys_are_right_aligned() {
+        let kv = KeyValue::new()
+            .entry("name", "rich_rust")
+            .entry("version", "0.2.1");
+        assert_eq!(
+            render_plain(&kv, 40),
+            "   name: rich_rust\nversion: 0.2.1\n"
+        );
+    }
+
+    #[test]
+    fn test_values_wrap_under_value_column() {
+        let kv = KeyValue::new().entry("about", "one two three four five");
+        let plain = render_plain(&kv, 16);
+        assert_eq!(plain, "about: one two \n       three \n       four five\n");
+    }
+
+    #[test]
+    fn test_markup_values_and_key_style() {
+        let kv = KeyValue::new().entry("state", "[green]ok[/]");
+        let console = Console::builder().width(40).build();
+        let segments = kv.render(&console, &console.options());
+        let key = segments.iter().find(|s| s.text == "state").unwrap();
+        assert_eq!(key.style, Some(Style::new().bold()));
+        let value = segments.iter().find(|s| s.text == "ok").unwrap();
+        assert_eq!(value.style, Some(Style::new().fg("green")));
+    }
+
+    #[test]
+    fn test_nested_renderables() {
+        let inner = KeyValue::new().entry("a", "1").entry("bb", "2");
+        let table = Table::new()
+            .with_column(Column::new("x"))
+            .with_row_cells(["y"])
+            .ascii();
+        let kv = KeyValue::new().entry("nested", inner).entry("table", table);
+        let plain = render_plain(&kv, 40);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0], "nested:  a: 1");
+        assert_eq!(lines[1], "        bb: 2");
+        assert!(lines[2].starts_with(" table: +"), "{plain}");
+        assert!(lines[3].starts_with("        | x |"), "{plain}");
+    }
+
+    #[test]
+    fn test_empty_value_keeps_key() {
+        let kv = KeyValue::new().entry("empty", "").entry("x", "1");
+        assert_eq!(render_plain(&kv, 40), "empty: \n    x: 1\n");
+    }
+
+    #[test]
+    fn test_long_keys_are_truncated() {
+        let kv = KeyValue::new()
+            .entry("an extremely long key", "v")
+            .max_key_width(8);
+        assert_eq!(render_plain(&kv, 40), "an ex...: v\n");
+    }
+}
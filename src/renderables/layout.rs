@@ -1,5 +1,6 @@
 //! Layout - split the terminal into rows/columns with nested regions.
 
+use std::collections::HashMap;
 use std::ops::{Index, IndexMut};
 
 use num_rational::Ratio;
@@ -29,6 +30,119 @@ impl Region {
             height,
         }
     }
+
+    /// One past the right-most column.
+    #[must_use]
+    pub const fn right(&self) -> usize {
+        self.x.saturating_add(self.width)
+    }
+
+    /// One past the bottom-most row.
+    #[must_use]
+    pub const fn bottom(&self) -> usize {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Number of cells covered by the region.
+    #[must_use]
+    pub const fn area(&self) -> usize {
+        self.width.saturating_mul(self.height)
+    }
+
+    /// True if the region covers no cells.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// True if the cell at (`x`, `y`) lies inside the region.
+    #[must_use]
+    pub const fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// True if `other` lies entirely inside this region.
+    ///
+    /// An empty `other` is contained if its origin is within (or on the edge of) `self`.
+    #[must_use]
+    pub const fn contains_region(&self, other: &Region) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+
+    /// The overlapping area of two regions, or `None` if they don't overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Region) -> Option<Region> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        if right <= x || bottom <= y {
+            return None;
+        }
+        Some(Region::new(x, y, right - x, bottom - y))
+    }
+
+    /// The smallest region covering both regions.
+    ///
+    /// Empty regions are ignored, so the union with an empty region is the other region.
+    #[must_use]
+    pub fn union(&self, other: &Region) -> Region {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+        Region::new(x, y, right - x, bottom - y)
+    }
+
+    /// Split into a left part `width` columns wide and the remaining right part.
+    ///
+    /// `width` is clamped to the region width.
+    #[must_use]
+    pub fn split_vertical(&self, width: usize) -> (Region, Region) {
+        let width = width.min(self.width);
+        (
+            Region::new(self.x, self.y, width, self.height),
+            Region::new(self.x + width, self.y, self.width - width, self.height),
+        )
+    }
+
+    /// Split into a top part `height` rows tall and the remaining bottom part.
+    ///
+    /// `height` is clamped to the region height.
+    #[must_use]
+    pub fn split_horizontal(&self, height: usize) -> (Region, Region) {
+        let height = height.min(self.height);
+        (
+            Region::new(self.x, self.y, self.width, height),
+            Region::new(self.x, self.y + height, self.width, self.height - height),
+        )
+    }
+
+    /// Clip the region to a `width` x `height` screen anchored at the origin.
+    ///
+    /// A region that lies completely off-screen becomes an empty region at its
+    /// (clamped) origin.
+    #[must_use]
+    pub fn clamp(&self, width: usize, height: usize) -> Region {
+        let screen = Region::new(0, 0, width, height);
+        self.intersection(&screen)
+            .unwrap_or_else(|| Region::new(self.x.min(width), self.y.min(height), 0, 0))
+    }
+
+    /// Clip the region to the console's current dimensions.
+    #[must_use]
+    pub fn clamp_to_console(&self, console: &Console) -> Region {
+        self.clamp(console.width(), console.height())
+    }
 }
 
 /// Layout splitter direction.
@@ -199,6 +313,31 @@ impl Layout {
         None
     }
 
+    /// Compute the region of every visible named layout for a `width` x `height` area.
+    ///
+    /// Sizes are resolved from `size`, `ratio`, and `minimum_size` on every
+    /// call, so calling this again after the console is resized gives the new
+    /// regions; rendering re-measures the same way.
+    #[must_use]
+    pub fn regions(&self, width: usize, height: usize) -> HashMap<String, Region> {
+        let mut regions = HashMap::new();
+        self.collect_regions(Region::new(0, 0, width, height), &mut regions);
+        regions
+    }
+
+    fn collect_regions(&self, region: Region, regions: &mut HashMap<String, Region>) {
+        if !self.visible {
+            return;
+        }
+        if let Some(name) = &self.name {
+            regions.insert(name.clone(), region);
+        }
+        let child_layouts = self.visible_children();
+        for (child_region, child) in self.splitter.divide(&child_layouts, region) {
+            child.collect_regions(child_region, regions);
+        }
+    }
+
     fn visible_children(&self) -> Vec<&Layout> {
         self.children.iter().filter(|c| c.visible).collect()
     }
@@ -344,7 +483,7 @@ fn merge_children(
 
         let mut children_for_row: Vec<(usize, &Vec<Segment<'static>>)> = Vec::new();
         for (child_region, child_lines) in children {
-            if abs_row >= child_region.y && abs_row < child_region.y + child_region.height {
+            if abs_row >= child_region.y && abs_row < child_region.bottom() {
                 let child_line = &child_lines[abs_row - child_region.y];
                 // Store relative x offset
                 children_for_row.push((child_region.x - region.x, child_line));
@@ -395,64 +534,55 @@ fn divide_column<'a>(children: &'a [&Layout], region: Region) -> Vec<(Region, &'
     result
 }
 
+/// Resolve child sizes along the split axis, like Rich's `ratio_resolve`.
+///
+/// Fixed sizes are taken first and the rest is shared by ratio. A flexible
+/// child whose share would fall to its `minimum_size` or below is pinned to
+/// that minimum and the remaining children are resolved again.
 fn ratio_resolve(total: usize, children: &[&Layout]) -> Vec<usize> {
     if children.is_empty() {
         return Vec::new();
     }
 
-    let mut sizes = Vec::with_capacity(children.len());
-    let mut mins = Vec::with_capacity(children.len());
-    let mut ratios = Vec::with_capacity(children.len());
-
-    let mut fixed_total = 0usize;
-    let mut flex_min_total = 0usize;
-
-    for child in children {
-        let min_size = child.minimum_size.max(1);
-        mins.push(min_size);
-        if let Some(size) = child.size {
-            let size = size.max(min_size);
-            sizes.push(size);
-            ratios.push(0);
-            fixed_total += size;
-        } else {
-            sizes.push(min_size);
-            ratios.push(child.ratio.max(1));
-            flex_min_total += min_size;
+    let mins: Vec<usize> = children
+        .iter()
+        .map(|child| child.minimum_size.max(1))
+        .collect();
+    let mut sizes: Vec<Option<usize>> = children
+        .iter()
+        .zip(&mins)
+        .map(|(child, &min_size)| child.size.map(|size| size.max(min_size)))
+        .collect();
+
+    'resolve: while sizes.iter().any(Option::is_none) {
+        let flexible: Vec<usize> = (0..sizes.len()).filter(|&i| sizes[i].is_none()).collect();
+        let remaining = total.saturating_sub(sizes.iter().flatten().sum());
+        if remaining == 0 {
+            for &i in &flexible {
+                sizes[i] = Some(mins[i]);
+            }
+            break;
         }
-    }
 
-    let mut remaining = total.saturating_sub(fixed_total + flex_min_total);
-    let total_ratio: usize = ratios.iter().sum();
-    if total_ratio > 0 && remaining > 0 {
-        let mut distributed = 0usize;
-        let mut flex_idx = 0usize;
-        let flex_count = ratios.iter().filter(|&&r| r > 0).count();
-        for (i, &ratio) in ratios.iter().enumerate() {
-            if ratio > 0 {
-                flex_idx += 1;
-                let share = Ratio::new(ratio, total_ratio);
-                let extra = if flex_idx == flex_count {
-                    remaining - distributed
-                } else {
-                    (share * remaining).round().to_integer()
-                };
-                sizes[i] = sizes[i].saturating_add(extra);
-                distributed += extra;
+        let total_ratio: usize = flexible.iter().map(|&i| children[i].ratio.max(1)).sum();
+        let portion = Ratio::new(remaining, total_ratio);
+        for &i in &flexible {
+            if portion * children[i].ratio.max(1) <= Ratio::from_integer(mins[i]) {
+                sizes[i] = Some(mins[i]);
+                continue 'resolve;
             }
         }
-        remaining = remaining.saturating_sub(distributed);
-    }
 
-    if remaining > 0 {
-        let mut i = 0usize;
-        while remaining > 0 && !sizes.is_empty() {
-            sizes[i] = sizes[i].saturating_add(1);
-            remaining = remaining.saturating_sub(1);
-            i = (i + 1) % sizes.len();
+        // Carry the fractional parts forward so the shares add up exactly.
+        let mut carry = Ratio::from_integer(0);
+        for &i in &flexible {
+            let share = portion * children[i].ratio.max(1) + carry;
+            sizes[i] = Some(share.to_integer());
+            carry = share.fract();
         }
     }
 
+    let sizes = sizes.into_iter().map(|size| size.unwrap_or(1)).collect();
     clamp_sizes(sizes, &mins, total)
 }
 
@@ -528,6 +658,76 @@ mod tests {
         assert_eq!(r1, r2);
     }
 
+    #[test]
+    fn test_region_edges_and_area() {
+        let region = Region::new(2, 3, 4, 5);
+        assert_eq!(region.right(), 6);
+        assert_eq!(region.bottom(), 8);
+        assert_eq!(region.area(), 20);
+        assert!(!region.is_empty());
+        assert!(Region::new(2, 3, 0, 5).is_empty());
+    }
+
+    #[test]
+    fn test_region_contains() {
+        let region = Region::new(2, 3, 4, 5);
+        assert!(region.contains(2, 3));
+        assert!(region.contains(5, 7));
+        assert!(!region.contains(6, 7));
+        assert!(!region.contains(1, 3));
+        assert!(region.contains_region(&Region::new(3, 4, 2, 2)));
+        assert!(!region.contains_region(&Region::new(3, 4, 4, 2)));
+    }
+
+    #[test]
+    fn test_region_intersection() {
+        let a = Region::new(0, 0, 10, 10);
+        let b = Region::new(5, 5, 10, 10);
+        assert_eq!(a.intersection(&b), Some(Region::new(5, 5, 5, 5)));
+        assert_eq!(b.intersection(&a), Some(Region::new(5, 5, 5, 5)));
+        // Touching edges do not overlap.
+        assert_eq!(a.intersection(&Region::new(10, 0, 5, 5)), None);
+    }
+
+    #[test]
+    fn test_region_union() {
+        let a = Region::new(0, 0, 2, 2);
+        let b = Region::new(5, 5, 1, 1);
+        assert_eq!(a.union(&b), Region::new(0, 0, 6, 6));
+        assert_eq!(a.union(&Region::new(9, 9, 0, 0)), a);
+    }
+
+    #[test]
+    fn test_region_split() {
+        let region = Region::new(1, 1, 10, 4);
+        assert_eq!(
+            region.split_vertical(3),
+            (Region::new(1, 1, 3, 4), Region::new(4, 1, 7, 4))
+        );
+        assert_eq!(
+            region.split_horizontal(1),
+            (Region::new(1, 1, 10, 1), Region::new(1, 2, 10, 3))
+        );
+        assert_eq!(region.split_vertical(99).1, Region::new(11, 1, 0, 4));
+    }
+
+    #[test]
+    fn test_region_clamp() {
+        assert_eq!(
+            Region::new(70, 20, 20, 10).clamp(80, 24),
+            Region::new(70, 20, 10, 4)
+        );
+        assert_eq!(
+            Region::new(100, 5, 5, 5).clamp(80, 24),
+            Region::new(80, 5, 0, 0)
+        );
+        let console = Console::builder().width(40).height(10).build();
+        assert_eq!(
+            Region::new(30, 0, 20, 20).clamp_to_console(&console),
+            Region::new(30, 0, 10, 10)
+        );
+    }
+
     // =========================================================================
     // LayoutSplitter Tests
     // =========================================================================
@@ -686,6 +886,30 @@ mod tests {
         assert!(layout.get("missing").is_none());
     }
 
+    #[test]
+    fn test_layout_regions_follow_resize() {
+        let mut layout = Layout::new().name("root");
+        let mut body = Layout::new().name("body");
+        body.split_row(vec![
+            Layout::new().name("sidebar").ratio(1).minimum_size(10),
+            Layout::new().name("main").ratio(3),
+            Layout::new().name("hidden").visible(false),
+        ]);
+        layout.split_column(vec![Layout::new().name("header").size(3), body]);
+
+        let regions = layout.regions(80, 24);
+        assert_eq!(regions["root"], Region::new(0, 0, 80, 24));
+        assert_eq!(regions["header"], Region::new(0, 0, 80, 3));
+        assert_eq!(regions["sidebar"], Region::new(0, 3, 20, 21));
+        assert_eq!(regions["main"], Region::new(20, 3, 60, 21));
+        assert!(!regions.contains_key("hidden"));
+
+        // Narrower console: the ratio would give the sidebar 8 columns.
+        let regions = layout.regions(32, 10);
+        assert_eq!(regions["sidebar"], Region::new(0, 3, 10, 7));
+        assert_eq!(regions["main"], Region::new(10, 3, 22, 7));
+    }
+
     #[test]
     fn test_layout_get_mut() {
         let mut layout = Layout::new().name("root");
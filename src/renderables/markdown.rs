@@ -44,6 +44,9 @@
 //! - **Blockquotes**: `> quoted text`
 //! - **Tables**: GitHub Flavored Markdown tables with alignment
 //! - **Horizontal rules**: `---` or `***`
+//! - **Front matter**: a leading YAML `---` block is shown as a compact key-value panel
+//! - **Heading anchors**: `# Title {#anchor}` renders the anchor as a dim `#anchor` suffix
+//! - **Inline HTML**: stripped, shown as code, or shown literally (see [`HtmlPolicy`])
 //!
 //! # Customizing Styles
 //!
@@ -100,14 +103,31 @@
 use std::fmt::Write;
 
 use crate::cells;
+use crate::renderables::Panel;
 use crate::segment::Segment;
 use crate::style::Style;
 
-use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{
+    Alignment, CodeBlockKind, Event, HeadingLevel, MetadataBlockKind, Options, Parser, Tag, TagEnd,
+};
 
 #[cfg(feature = "syntax")]
 use crate::renderables::Syntax;
 
+/// How HTML fragments in Markdown source are rendered.
+///
+/// Applies to both inline HTML (`<kbd>Ctrl</kbd>`) and HTML blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HtmlPolicy {
+    /// Drop HTML tags, keeping only the Markdown around them (default).
+    #[default]
+    Strip,
+    /// Show HTML as code, using the inline code / code block styles.
+    Code,
+    /// Show HTML literally as plain text.
+    Escape,
+}
+
 /// A markdown document that can be rendered to the terminal.
 #[derive(Debug, Clone)]
 pub struct Markdown {
@@ -147,6 +167,16 @@ pub struct Markdown {
     list_indent: usize,
     /// Whether to emit OSC8 hyperlinks for links and images.
     hyperlinks: bool,
+    /// Whether to render YAML front matter as a key-value panel.
+    front_matter: bool,
+    /// Style for front matter keys.
+    front_matter_key_style: Style,
+    /// Whether to parse `{#anchor}` heading attributes and show the anchor.
+    heading_anchors: bool,
+    /// Style for heading anchor suffixes.
+    heading_anchor_style: Style,
+    /// How HTML fragments are rendered.
+    html: HtmlPolicy,
 }
 
 impl Default for Markdown {
@@ -194,6 +224,11 @@ impl Default for Markdown {
             bullet_char: '•',
             list_indent: 2,
             hyperlinks: true,
+            front_matter: true,
+            front_matter_key_style: Style::new().bold(),
+            heading_anchors: true,
+            heading_anchor_style: Style::new().dim(),
+            html: HtmlPolicy::Strip,
         }
     }
 }
@@ -317,6 +352,49 @@ impl Markdown {
         self
     }
 
+    /// Enable or disable front matter rendering (default: enabled).
+    ///
+    /// When enabled, a YAML block delimited by `---` at the very start of the
+    /// document is shown as a compact key-value panel. When disabled, it is
+    /// parsed as ordinary Markdown.
+    #[must_use]
+    pub fn front_matter(mut self, enabled: bool) -> Self {
+        self.front_matter = enabled;
+        self
+    }
+
+    /// Set the style for front matter keys.
+    #[must_use]
+    pub fn front_matter_key_style(mut self, style: Style) -> Self {
+        self.front_matter_key_style = style;
+        self
+    }
+
+    /// Enable or disable heading anchors (default: enabled).
+    ///
+    /// When enabled, `# Title {#anchor}` renders as `Title #anchor` with the
+    /// anchor in [`Self::heading_anchor_style`]. When disabled, the braces are
+    /// left in the heading text.
+    #[must_use]
+    pub fn heading_anchors(mut self, enabled: bool) -> Self {
+        self.heading_anchors = enabled;
+        self
+    }
+
+    /// Set the style for heading anchor suffixes.
+    #[must_use]
+    pub fn heading_anchor_style(mut self, style: Style) -> Self {
+        self.heading_anchor_style = style;
+        self
+    }
+
+    /// Set how HTML fragments are rendered (default: [`HtmlPolicy::Strip`]).
+    #[must_use]
+    pub fn html(mut self, policy: HtmlPolicy) -> Self {
+        self.html = policy;
+        self
+    }
+
     /// Render the markdown to segments.
     #[must_use]
     #[allow(clippy::too_many_lines)]
@@ -337,6 +415,9 @@ impl Markdown {
         let mut blockquote_first_paragraph = false;
         let mut current_link_url = String::new();
         let mut image_style_pushed = false;
+        let mut heading_anchor: Option<String> = None;
+        let mut in_front_matter = false;
+        let mut front_matter_text = String::new();
 
         // Table state
         let mut in_table = false;
@@ -347,10 +428,16 @@ impl Markdown {
         let mut in_table_head = false;
         let mut header_row = None;
 
-        let options = Options::ENABLE_STRIKETHROUGH
+        let mut options = Options::ENABLE_STRIKETHROUGH
             | Options::ENABLE_TABLES
             | Options::ENABLE_FOOTNOTES
             | Options::ENABLE_TASKLISTS;
+        if self.front_matter {
+            options |= Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        }
+        if self.heading_anchors {
+            options |= Options::ENABLE_HEADING_ATTRIBUTES;
+        }
 
         let parser = Parser::new_ext(&self.source, options);
 
@@ -401,11 +488,12 @@ impl Markdown {
             match event {
                 Event::Start(tag) => {
                     match tag {
-                        Tag::Heading { level, .. } => {
+                        Tag::Heading { level, id, .. } => {
                             // Add newline before heading if not at start
                             if !segments.is_empty() {
                                 segments.push(Segment::new("\n\n", None));
                             }
+                            heading_anchor = id.map(|id| id.to_string());
                             let style = match level {
                                 HeadingLevel::H1 => self.h1_style.clone(),
                                 HeadingLevel::H2 => self.h2_style.clone(),
@@ -554,6 +642,15 @@ impl Markdown {
                         Tag::TableCell => {
                             current_cell_content.clear();
                         }
+                        Tag::MetadataBlock(MetadataBlockKind::YamlStyle) => {
+                            in_front_matter = true;
+                            front_matter_text.clear();
+                        }
+                        Tag::HtmlBlock
+                            if self.html != HtmlPolicy::Strip && !segments.is_empty() =>
+                        {
+                            segments.push(Segment::new("\n\n", None));
+                        }
                         _ => {}
                     }
                 }
@@ -561,6 +658,12 @@ impl Markdown {
                     match tag_end {
                         TagEnd::Heading(_) => {
                             style_stack.pop();
+                            if let Some(anchor) = heading_anchor.take() {
+                                segments.push(Segment::new(
+                                    format!(" #{anchor}"),
+                                    Some(self.heading_anchor_style.clone()),
+                                ));
+                            }
                         }
                         TagEnd::Paragraph => {}
                         TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
@@ -718,11 +821,23 @@ impl Markdown {
                         TagEnd::TableCell => {
                             current_row.push(std::mem::take(&mut current_cell_content));
                         }
+                        TagEnd::MetadataBlock(MetadataBlockKind::YamlStyle) => {
+                            in_front_matter = false;
+                            self.render_front_matter(&mut segments, &front_matter_text, max_width);
+                        }
+                        // Following blocks add their own separation, as after a paragraph.
+                        TagEnd::HtmlBlock
+                            if segments.last().is_some_and(|seg| seg.text == "\n") =>
+                        {
+                            segments.pop();
+                        }
                         _ => {}
                     }
                 }
                 Event::Text(text) => {
-                    if in_table {
+                    if in_front_matter {
+                        front_matter_text.push_str(&text);
+                    } else if in_table {
                         current_cell_content.push_str(&text.replace('\n', " "));
                     } else {
                         let current_style = combined_style(&style_stack);
@@ -749,6 +864,39 @@ impl Markdown {
                         ));
                     }
                 }
+                Event::Html(html) => {
+                    let style = match self.html {
+                        HtmlPolicy::Strip => continue,
+                        HtmlPolicy::Code => Some(self.code_block_style.clone()),
+                        HtmlPolicy::Escape => None,
+                    };
+                    for line in html.split_inclusive('\n') {
+                        let content = line.trim_end_matches('\n');
+                        if !content.is_empty() {
+                            segments.push(Segment::new(content.to_string(), style.clone()));
+                        }
+                        if line.ends_with('\n') {
+                            segments.push(Segment::new("\n", None));
+                        }
+                    }
+                }
+                Event::InlineHtml(html) => match self.html {
+                    HtmlPolicy::Strip => {}
+                    _ if in_table => current_cell_content.push_str(&html.replace('\n', " ")),
+                    HtmlPolicy::Code => {
+                        ensure_blockquote_prefix!(segments);
+                        ensure_list_prefix!(segments);
+                        segments.push(Segment::new(
+                            html.to_string(),
+                            Some(self.code_style.clone()),
+                        ));
+                    }
+                    HtmlPolicy::Escape => {
+                        ensure_blockquote_prefix!(segments);
+                        ensure_list_prefix!(segments);
+                        segments.push(Segment::new(html.to_string(), combined_style(&style_stack)));
+                    }
+                },
                 Event::SoftBreak => {
                     if in_table {
                         current_cell_content.push(' ');
@@ -801,6 +949,47 @@ impl Markdown {
         }
     }
 
+    /// Render YAML front matter as a compact key-value panel.
+    ///
+    /// Top-level `key: value` lines become entries; indented lines and list
+    /// items are folded into the preceding value.
+    fn render_front_matter(&self, segments: &mut Vec<Segment>, yaml: &str, max_width: usize) {
+        let entries = parse_front_matter(yaml);
+        if entries.is_empty() {
+            return;
+        }
+
+        let key_width = entries
+            .iter()
+            .map(|(key, _)| cells::cell_len(key))
+            .max()
+            .unwrap_or(0);
+        let separator_style = Style::new().dim();
+        let lines: Vec<Vec<Segment<'static>>> = entries
+            .into_iter()
+            .map(|(key, value)| {
+                let pad = key_width.saturating_sub(cells::cell_len(&key));
+                vec![
+                    Segment::new(" ".repeat(pad), None),
+                    Segment::new(key, Some(self.front_matter_key_style.clone())),
+                    Segment::new(": ", Some(separator_style.clone())),
+                    Segment::new(value, None),
+                ]
+            })
+            .collect();
+
+        let width = if max_width > 0 { max_width } else { 80 };
+        let mut panel = Panel::new(lines)
+            .expand(false)
+            .border_style(self.table_border_style.clone())
+            .render(width);
+        // Following blocks add their own separation, as after a paragraph.
+        if panel.last().is_some_and(|seg| seg.text == "\n") {
+            panel.pop();
+        }
+        segments.extend(panel);
+    }
+
     /// Render a table to segments.
     fn render_table(
         &self,
@@ -926,6 +1115,45 @@ impl Markdown {
     }
 }
 
+/// Split YAML front matter into display entries.
+///
+/// This is a display-oriented reading of the common subset (scalars, inline
+/// lists, and block lists), not a YAML parser.
+fn parse_front_matter(yaml: &str) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for line in yaml.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let is_top_level = !line.starts_with([' ', '\t']) && !trimmed.starts_with("- ");
+        if is_top_level && let Some((key, value)) = trimmed.split_once(':') {
+            entries.push((key.trim().to_string(), unquote(value.trim()).to_string()));
+            continue;
+        }
+        if let Some((_, value)) = entries.last_mut() {
+            let item = unquote(trimmed.strip_prefix("- ").unwrap_or(trimmed).trim());
+            if !value.is_empty() {
+                value.push_str(", ");
+            }
+            value.push_str(item);
+        }
+    }
+    entries
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
 fn pad_segments_to_width(segments: Vec<Segment<'_>>, width: usize) -> Vec<Segment<'_>> {
     let mut padded = Vec::new();
     let mut line_width = 0usize;
@@ -1270,4 +1498,112 @@ mod tests {
         assert!(text.contains("Task item"));
         assert!(text.contains("☐"), "task item should have checkbox");
     }
+
+    #[test]
+    fn test_inline_html_policies() {
+        let source = "Press <kbd>Enter</kbd> now";
+        let render = |policy| -> String {
+            Markdown::new(source)
+                .html(policy)
+                .render(0)
+                .iter()
+                .map(|s| s.text.as_ref())
+                .collect()
+        };
+
+        assert_eq!(render(HtmlPolicy::Strip), "Press Enter now");
+        assert_eq!(render(HtmlPolicy::Escape), "Press <kbd>Enter</kbd> now");
+        assert_eq!(render(HtmlPolicy::Code), "Press <kbd>Enter</kbd> now");
+
+        let md = Markdown::new(source).html(HtmlPolicy::Code);
+        let segments = md.render(0);
+        let tag = segments
+            .iter()
+            .find(|s| s.text == "<kbd>")
+            .expect("tag segment");
+        assert_eq!(tag.style.as_ref(), Some(&md.code_style));
+    }
+
+    #[test]
+    fn test_html_block_policies() {
+        let source = "Intro\n\n<div align=\"center\">\n<b>hi</b>\n</div>\n\nOutro";
+        let render = |policy| -> String {
+            Markdown::new(source)
+                .html(policy)
+                .render(0)
+                .iter()
+                .map(|s| s.text.as_ref())
+                .collect()
+        };
+
+        assert_eq!(render(HtmlPolicy::Strip), "Intro\n\nOutro");
+        assert_eq!(
+            render(HtmlPolicy::Escape),
+            "Intro\n\n<div align=\"center\">\n<b>hi</b>\n</div>\n\nOutro"
+        );
+    }
+
+    #[test]
+    fn test_render_front_matter_panel() {
+        let md = Markdown::new(
+            "---\ntitle: \"Hello\"\nauthor: Ada\ntags:\n  - cli\n  - tui\n---\n# Intro\n",
+        );
+        let text: String = md.render(40).iter().map(|s| s.text.as_ref()).collect();
+        let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+
+        assert!(lines[0].starts_with('╭'), "{text}");
+        assert_eq!(lines[1], "│  title: Hello    │");
+        assert_eq!(lines[2], "│ author: Ada      │");
+        assert_eq!(lines[3], "│   tags: cli, tui │");
+        assert!(lines[4].starts_with('╰'));
+        assert_eq!(lines[5], "");
+        assert_eq!(lines[6], "Intro");
+        assert!(!text.contains("---"));
+    }
+
+    #[test]
+    fn test_front_matter_disabled_renders_markdown() {
+        let md = Markdown::new("---\ntitle: Hello\n---\n").front_matter(false);
+        let text: String = md.render(40).iter().map(|s| s.text.as_ref()).collect();
+        assert!(text.contains("title: Hello"));
+        assert!(!text.contains('╭'));
+    }
+
+    #[test]
+    fn test_render_heading_anchor_suffix() {
+        let md = Markdown::new("## Install {#install}");
+        let segments = md.render(0);
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(text, "Install #install");
+
+        let anchor = segments
+            .iter()
+            .find(|s| s.text == " #install")
+            .expect("anchor segment");
+        assert!(
+            anchor
+                .style
+                .as_ref()
+                .is_some_and(|style| style.attributes.contains(Attributes::DIM))
+        );
+
+        let raw = Markdown::new("## Install {#install}").heading_anchors(false);
+        let text: String = raw.render(0).iter().map(|s| s.text.as_ref()).collect();
+        assert!(text.contains("{#install}"));
+    }
+
+    #[test]
+    fn test_parse_front_matter_entries() {
+        let entries =
+            parse_front_matter("a: 1\n# comment\nb: 'two'\nc: [x, y]\nd:\n  - p\n  - q\n");
+        assert_eq!(
+            entries,
+            vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "two".to_string()),
+                ("c".to_string(), "[x, y]".to_string()),
+                ("d".to_string(), "p, q".to_string()),
+            ]
+        );
+    }
 }
@@ -6,11 +6,17 @@
 //! - [`Panel`]: Frame content with a title and border
 //! - [`Tree`]: Hierarchical data with guide lines
 //! - [`ProgressBar`] / [`Spinner`]: Visual progress indicators
+//! - [`Progress`]: Live display of many concurrent tasks
 //! - [`Rule`]: Horizontal divider lines
 //! - [`Columns`]: Multi-column text layout
 //! - [`Align`]: Text alignment utilities
+//! - [`BarChart`]: Horizontal bars for labeled values
+//! - [`Calendar`]: Month grid with highlighted dates
+//! - [`Canvas`]: Braille or half-block point and line plots
 //! - [`Emoji`]: Single emoji renderable (Rich-style)
 //! - [`Group`]: Combine multiple renderables into one
+//! - [`KeyValue`]: Aligned key/value definition list
+//! - [`Numbered`]: Line numbers and gutter for any renderable
 //!
 //! # Examples
 //!
@@ -85,7 +91,11 @@
 //!
 //! - **`syntax`**: [`Syntax`] - Syntax-highlighted source code
 //! - **`markdown`**: [`Markdown`] - Markdown document rendering
-//! - **`json`**: [`Json`] - JSON formatting with syntax highlighting
+//! - **`json`**: [`Json`] - JSON formatting with syntax highlighting, [`JsonDiff`] - Structural JSON diffs
+//! - **`diff`**: [`Diff`] - Unified and side-by-side text diffs
+//! - **`bigtext`**: [`BigText`] - Large block-letter banners
+
+use std::sync::Arc;
 
 use crate::console::{Console, ConsoleOptions};
 use crate::markup;
@@ -96,15 +106,44 @@ use crate::text::Text;
 pub trait Renderable {
     /// Render the object to a list of segments.
     fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>>;
+
+    /// What kind of renderable this is (e.g. `"table"`), for exporters.
+    ///
+    /// A recording [`Console`] keeps this alongside the printed output, so
+    /// [`Console::export_markdown`] can turn a printed `"table"` into a pipe
+    /// table. Terminals ignore it. Defaults to `None`.
+    fn origin(&self) -> Option<&'static str> {
+        None
+    }
 }
 
+/// A renderable of any type, for storing mixed renderables in one field or list.
+///
+/// A `Vec<BoxedRenderable>` is itself renderable, drawing each item in turn.
+///
+/// ```
+/// use rich_rust::renderables::{BoxedRenderable, Panel, Rule};
+///
+/// let report: Vec<BoxedRenderable> = vec![
+///     Box::new(Rule::with_title("Report")),
+///     Box::new("all checks passed"),
+///     Box::new(Panel::from_text("3 warnings")),
+/// ];
+/// ```
+pub type BoxedRenderable = Box<dyn Renderable + Send + Sync>;
+
 pub mod align;
+pub mod bar_chart;
+pub mod calendar;
+pub mod canvas;
 pub mod columns;
 pub mod constrain;
 pub mod control;
 pub mod emoji;
 pub mod group;
+pub mod key_value;
 pub mod layout;
+pub mod numbered;
 pub mod padding;
 pub mod panel;
 pub mod pretty;
@@ -116,23 +155,30 @@ pub mod tree;
 
 // Re-export commonly used types
 pub use align::{Align, AlignLines, AlignMethod, VerticalAlignMethod, align_text};
+pub use bar_chart::{Bar, BarChart};
+pub use calendar::Calendar;
+pub use canvas::{Canvas, CanvasMarker};
 pub use columns::Columns;
 pub use constrain::Constrain;
 pub use control::Control;
 pub use emoji::{Emoji, NoEmoji};
 pub use group::{Group, group};
+pub use key_value::KeyValue;
 pub use layout::{Layout, LayoutSplitter, Region};
+pub use numbered::Numbered;
 pub use padding::{Padding, PaddingDimensions};
 pub use panel::Panel;
 pub use pretty::{Inspect, InspectOptions, Pretty, PrettyOptions, inspect};
 pub use progress::{
-    BarStyle, DownloadColumn, FileSizeColumn, ProgressBar, Spinner, TotalFileSizeColumn,
-    TransferSpeedColumn,
+    BarStyle, DownloadColumn, FileSizeColumn, MofNCompleteColumn, PercentageColumn, Progress,
+    ProgressBar, ProgressColumn, ProgressIteratorExt, ProgressTask, Spinner, SpinnerColumn,
+    TaskColumn, TaskId, TimeElapsedColumn, TimeRemainingColumn, TotalFileSizeColumn, Track,
+    TransferSpeedColumn, track,
 };
 pub use rule::Rule;
-pub use table::{Cell, Column, Row, Table, VerticalAlign};
+pub use table::{Cell, Column, IntoRow, Row, RowRegion, Table, VerticalAlign};
 pub use traceback::{Traceback, TracebackFrame, print_exception};
-pub use tree::{Tree, TreeGuides, TreeNode};
+pub use tree::{FileTreeOptions, FileTreeSort, Tree, TreeGuides, TreeNode};
 
 impl Renderable for str {
     fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
@@ -171,6 +217,47 @@ impl<T: Renderable + ?Sized> Renderable for &T {
     fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
         (*self).render(console, options)
     }
+
+    fn origin(&self) -> Option<&'static str> {
+        (*self).origin()
+    }
+}
+
+impl<T: Renderable + ?Sized> Renderable for Box<T> {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        (**self).render(console, options)
+    }
+
+    fn origin(&self) -> Option<&'static str> {
+        (**self).origin()
+    }
+}
+
+impl<T: Renderable + ?Sized> Renderable for Arc<T> {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        (**self).render(console, options)
+    }
+
+    fn origin(&self) -> Option<&'static str> {
+        (**self).origin()
+    }
+}
+
+/// Renders each item in turn, each starting on a new line.
+impl Renderable for Vec<BoxedRenderable> {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        let mut segments: Vec<Segment<'a>> = Vec::new();
+        for item in self {
+            if segments
+                .last()
+                .is_some_and(|segment| !segment.text.ends_with('\n'))
+            {
+                segments.push(Segment::line());
+            }
+            segments.extend(item.render(console, options));
+        }
+        segments
+    }
 }
 
 // Phase 3+: Syntax highlighting (requires "syntax" feature)
@@ -182,8 +269,15 @@ pub use syntax::{Syntax, SyntaxError};
 
 #[cfg(feature = "syntax")]
 impl Renderable for Syntax {
-    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
-        self.render(Some(options.max_width))
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        let result = if self.uses_console_theme() {
+            self.render_with_theme(Some(options.max_width), |name| {
+                console.try_get_style(name).ok()
+            })
+        } else {
+            self.render(Some(options.max_width))
+        };
+        result
             .unwrap_or_default()
             .into_iter()
             .map(Segment::into_owned) // Ensure static/owned segments
@@ -196,7 +290,7 @@ impl Renderable for Syntax {
 pub mod markdown;
 
 #[cfg(feature = "markdown")]
-pub use markdown::Markdown;
+pub use markdown::{HtmlPolicy, Markdown};
 
 #[cfg(feature = "markdown")]
 impl Renderable for Markdown {
@@ -247,11 +341,54 @@ impl Renderable for Json {
     }
 }
 
+// Structural JSON diffs (requires "json" feature)
+#[cfg(feature = "json")]
+pub mod json_diff;
+
+#[cfg(feature = "json")]
+pub use json_diff::{JsonDiff, JsonDiffTheme};
+
+#[cfg(feature = "json")]
+impl Renderable for JsonDiff {
+    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        self.render(options.max_width).into_iter().collect()
+    }
+}
+
+// Text diffs (requires "diff" feature)
+#[cfg(feature = "diff")]
+pub mod diff;
+
+#[cfg(feature = "diff")]
+pub use diff::{Diff, DiffMode, DiffTheme};
+
+#[cfg(feature = "diff")]
+impl Renderable for Diff {
+    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        self.render(options.max_width).into_iter().collect()
+    }
+}
+
+// Block-letter banners (requires "bigtext" feature)
+#[cfg(feature = "bigtext")]
+pub mod big_text;
+
+#[cfg(feature = "bigtext")]
+pub use big_text::BigText;
+
+#[cfg(feature = "bigtext")]
+impl Renderable for BigText {
+    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        self.render(options.max_width).into_iter().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::color::ColorSystem;
     use crate::console::Console;
-    use crate::renderables::Renderable;
+    use crate::renderables::{BoxedRenderable, Panel, Renderable, Rule};
+    use std::sync::Arc;
 
     #[test]
     fn str_renderable_applies_console_highlighter_when_enabled() {
@@ -272,4 +409,30 @@ mod tests {
         let ansi = String::from_utf8(buf).expect("utf8");
         assert!(ansi.contains("\x1b["));
     }
+
+    #[test]
+    fn boxed_renderables_render_in_sequence() {
+        let console = Console::builder().width(20).markup(false).build();
+        let options = console.options();
+        let shared: Arc<dyn Renderable + Send + Sync> = Arc::new(Rule::new());
+        let items: Vec<BoxedRenderable> = vec![
+            Box::new("first"),
+            Box::new(shared),
+            Box::new(Panel::from_text("boxed")),
+            Box::new(String::from("last")),
+        ];
+
+        let text: String = items
+            .render(&console, &options)
+            .iter()
+            .map(|segment| segment.text.as_ref())
+            .collect();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "first");
+        assert_eq!(lines[1], "─".repeat(20));
+        assert!(lines[2].starts_with('╭'), "{text:?}");
+        assert!(lines[3].contains("boxed"), "{text:?}");
+        assert_eq!(lines[5], "last");
+        assert_eq!(lines.len(), 6);
+    }
 }
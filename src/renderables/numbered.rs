@@ -0,0 +1,206 @@
+//! Line-numbered wrapper renderable.
+//!
+//! [`Numbered`] renders any child renderable and prefixes each of its lines
+//! with a right-aligned line number and a gutter separator. It works for plain
+//! text dumps as well as richer content such as syntax-highlighted code.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use rich_rust::renderables::Numbered;
+//! use rich_rust::text::Text;
+//!
+//! let source = Text::new("fn main() {\n    println!(\"hi\");\n}");
+//! console.print_renderable(&Numbered::new(source).start(10));
+//! //  10 │ fn main() {
+//! //  11 │     println!("hi");
+//! //  12 │ }
+//! ```
+
+use crate::cells;
+use crate::console::{Console, ConsoleOptions};
+use crate::segment::{Segment, split_lines};
+use crate::style::Style;
+use crate::text::Text;
+
+use super::Renderable;
+
+/// Wraps a renderable, numbering each rendered line.
+pub struct Numbered<'a> {
+    /// The renderable being numbered.
+    child: Box<dyn Renderable + 'a>,
+    /// Number of the first line.
+    start: usize,
+    /// Style for line numbers.
+    style: Style,
+    /// Text between the numbers and the content.
+    separator: String,
+    /// Style for the separator.
+    separator_style: Style,
+}
+
+impl std::fmt::Debug for Numbered<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Numbered")
+            .field("start", &self.start)
+            .field("separator", &self.separator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Numbered<'a> {
+    /// Wrap a renderable, numbering lines from 1.
+    #[must_use]
+    pub fn new<R: Renderable + 'a>(child: R) -> Self {
+        Self {
+            child: Box::new(child),
+            start: 1,
+            style: Style::new().dim(),
+            separator: " \u{2502} ".to_string(),
+            separator_style: Style::new().dim(),
+        }
+    }
+
+    /// Set the number of the first line.
+    #[must_use]
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Set the line number style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the gutter separator (default `" │ "`).
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set the separator style.
+    #[must_use]
+    pub fn separator_style(mut self, style: Style) -> Self {
+        self.separator_style = style;
+        self
+    }
+}
+
+impl Renderable for Numbered<'_> {
+    fn render(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'_>> {
+        let separator_width = cells::cell_len(&self.separator);
+
+        // The gutter width depends on the line count, which depends on the content
+        // width; estimate from a first render and re-render if the gutter grows.
+        let mut gutter = self.start.to_string().len();
+        let mut lines;
+        loop {
+            let content_width = options
+                .max_width
+                .saturating_sub(gutter + separator_width)
+                .max(1);
+            lines = split_lines(
+                self.child
+                    .render(console, &options.update_width(content_width))
+                    .into_iter(),
+            );
+            if lines.len() > 1 && lines.last().is_some_and(Vec::is_empty) {
+                lines.pop();
+            }
+            let needed = (self.start + lines.len().saturating_sub(1))
+                .to_string()
+                .len();
+            if needed <= gutter {
+                break;
+            }
+            gutter = needed;
+        }
+        let content_width = options
+            .max_width
+            .saturating_sub(gutter + separator_width)
+            .max(1);
+
+        let mut segments = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            let controls = line.iter().filter(|s| s.is_control()).cloned();
+            segments.extend(controls.map(Segment::into_owned));
+
+            let mut text = Text::new("");
+            for segment in line.iter().filter(|s| !s.is_control()) {
+                match &segment.style {
+                    Some(style) => text.append_styled(&segment.text, style.clone()),
+                    None => text.append(&segment.text),
+                }
+            }
+            for (chunk_index, chunk) in text.fold(content_width).iter().enumerate() {
+                let number = if chunk_index == 0 {
+                    format!("{:>gutter$}", self.start + index)
+                } else {
+                    " ".repeat(gutter)
+                };
+                segments.push(Segment::new(number, Some(self.style.clone())));
+                segments.push(Segment::new(
+                    self.separator.clone(),
+                    Some(self.separator_style.clone()),
+                ));
+                segments.extend(chunk.render("").into_iter().map(Segment::into_owned));
+                segments.push(Segment::line());
+            }
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_plain(renderable: &dyn Renderable, width: usize) -> String {
+        let console = Console::builder().width(width).build();
+        let options = console.options();
+        renderable
+            .render(&console, &options)
+            .iter()
+            .map(|s| s.text.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_numbers_each_line() {
+        let numbered = Numbered::new(Text::new("alpha\nbeta\ngamma"));
+        assert_eq!(
+            render_plain(&numbered, 40),
+            "1 \u{2502} alpha\n2 \u{2502} beta\n3 \u{2502} gamma\n"
+        );
+    }
+
+    #[test]
+    fn test_numbers_are_right_aligned() {
+        let content: Vec<String> = (0..3).map(|i| format!("l{i}")).collect();
+        let numbered = Numbered::new(Text::new(content.join("\n")))
+            .start(9)
+            .separator(": ");
+        assert_eq!(render_plain(&numbered, 40), " 9: l0\n10: l1\n11: l2\n");
+    }
+
+    #[test]
+    fn test_long_lines_fold_under_blank_gutter() {
+        let numbered = Numbered::new(Text::new("abcdefghij")).separator("|");
+        assert_eq!(render_plain(&numbered, 7), "1|abcde\n |fghij\n");
+    }
+
+    #[test]
+    fn test_styles_are_kept() {
+        let red = Style::new().fg("red");
+        let numbered = Numbered::new(Text::styled("x", red.clone()));
+        let console = Console::builder().width(20).build();
+        let segments = numbered.render(&console, &console.options());
+        assert_eq!(segments[0].style, Some(Style::new().dim()));
+        let content = segments.iter().find(|s| s.text == "x").unwrap();
+        assert_eq!(content.style, Some(red));
+    }
+}
@@ -7,6 +7,7 @@ use crate::r#box::{ASCII, BoxChars, ROUNDED, SQUARE};
 use crate::cells;
 use crate::console::{Console, ConsoleOptions};
 use crate::markup;
+use crate::measure::{Measurement, RichMeasure};
 use crate::renderables::Renderable;
 use crate::segment::{Segment, adjust_line_length};
 use crate::style::Style;
@@ -31,6 +32,8 @@ pub struct Panel<'a> {
     border_style: Style,
     /// Fixed width (None = auto).
     width: Option<usize>,
+    /// Maximum width (None = available width).
+    max_width: Option<usize>,
     /// Fixed height (None = auto).
     height: Option<usize>,
     /// Padding inside the border.
@@ -55,6 +58,7 @@ impl Default for Panel<'_> {
             style: Style::new(),
             border_style: Style::new(),
             width: None,
+            max_width: None,
             height: None,
             padding: PaddingDimensions::symmetric(0, 1),
             title: None,
@@ -75,6 +79,15 @@ impl<'a> Panel<'a> {
         }
     }
 
+    /// Create a panel that shrinks to its content's measured width.
+    ///
+    /// Equivalent to `Panel::new(content_lines).expand(false)`, like Rich's
+    /// `Panel.fit`. Call [`Panel::expand`] afterwards to change it back.
+    #[must_use]
+    pub fn fit(content_lines: Vec<Vec<Segment<'a>>>) -> Self {
+        Self::new(content_lines).expand(false)
+    }
+
     /// Create a panel from plain text content.
     ///
     /// This does **NOT** parse Rich markup. If you want markup styling,
@@ -150,7 +163,10 @@ impl<'a> Panel<'a> {
         self
     }
 
-    /// Set whether to expand to fill width.
+    /// Set whether to expand to fill width (default `true`).
+    ///
+    /// When `false` the panel is only as wide as its [measurement](RichMeasure):
+    /// the widest content line plus padding and borders, widened to fit the title.
     #[must_use]
     pub fn expand(mut self, expand: bool) -> Self {
         self.expand = expand;
@@ -178,6 +194,15 @@ impl<'a> Panel<'a> {
         self
     }
 
+    /// Set maximum width.
+    ///
+    /// The panel never renders wider than this, even when expanded.
+    #[must_use]
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
     /// Set fixed height.
     #[must_use]
     pub fn height(mut self, height: usize) -> Self {
@@ -283,18 +308,33 @@ impl<'a> Panel<'a> {
             .unwrap_or(0)
     }
 
+    /// Width of a non-expanded panel without a fixed width.
+    ///
+    /// Content plus padding and borders; like Rich, the title (with its
+    /// surrounding spaces) gets at least one border cell on each side.
+    fn fit_width(&self) -> usize {
+        let mut inner = self.content_width() + self.padding.horizontal();
+        if let Some(title) = &self.title {
+            inner = inner.max(title.cell_len() + 4);
+        }
+        inner + 2
+    }
+
     /// Render the panel to segments.
     #[must_use]
     pub fn render(&self, max_width: usize) -> Vec<Segment<'a>> {
         let box_chars = self.effective_box();
+        let max_width = self
+            .max_width
+            .map_or(max_width, |limit| limit.min(max_width));
 
         // Calculate panel width
         let panel_width = if self.expand {
             self.width.unwrap_or(max_width).min(max_width)
         } else {
-            let content_w = self.content_width();
-            let min_width = content_w + 2 + self.padding.horizontal();
-            self.width.unwrap_or(min_width).min(max_width)
+            self.width
+                .unwrap_or_else(|| self.fit_width())
+                .min(max_width)
         };
 
         // Inner width (inside borders)
@@ -660,6 +700,26 @@ impl<'a> Panel<'a> {
         segments
     }
 
+    /// Announce the panel and its content in words for an accessible console.
+    fn render_accessible(&self) -> Vec<Segment<'a>> {
+        let header = match &self.title {
+            Some(title) => format!("Panel: {}", title.plain()),
+            None => "Panel".to_string(),
+        };
+        let mut segments = vec![Segment::new(header, None), Segment::line()];
+        for line in &self.content_lines {
+            segments.extend(line.iter().cloned());
+            segments.push(Segment::line());
+        }
+        if let Some(subtitle) = &self.subtitle {
+            segments.push(Segment::new(subtitle.plain().to_string(), None));
+            segments.push(Segment::line());
+        }
+        segments.push(Segment::new("End of panel", None));
+        segments.push(Segment::line());
+        segments
+    }
+
     /// Render to plain text.
     #[must_use]
     pub fn render_plain(&self, max_width: usize) -> String {
@@ -672,6 +732,9 @@ impl<'a> Panel<'a> {
 
 impl Renderable for Panel<'_> {
     fn render<'b>(&'b self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'b>> {
+        if console.is_accessible() {
+            return self.render_accessible();
+        }
         if self.safe_box.is_some() {
             return self.render(options.max_width).into_iter().collect();
         }
@@ -682,6 +745,19 @@ impl Renderable for Panel<'_> {
     }
 }
 
+impl RichMeasure for Panel<'_> {
+    fn rich_measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        let max_width = self
+            .max_width
+            .map_or(options.max_width, |limit| limit.min(options.max_width));
+        let width = self
+            .width
+            .unwrap_or_else(|| self.fit_width())
+            .min(max_width);
+        Measurement::new(width, width)
+    }
+}
+
 /// Truncate a Text object to a maximum cell width with ellipsis.
 fn truncate_text_to_width(text: &Text, max_width: usize) -> Text {
     let mut truncated = text.clone();
@@ -701,6 +777,21 @@ mod tests {
     use crate::segment::split_lines;
     use crate::style::Attributes;
 
+    #[test]
+    fn test_panel_max_width_caps_expansion() {
+        let panel = Panel::from_text("Hi").max_width(12);
+        let lines = split_lines(panel.render(40).into_iter());
+        assert!(
+            lines
+                .iter()
+                .filter(|line| !line.is_empty())
+                .all(|line| crate::segment::line_length(line) == 12)
+        );
+        // A narrower render width still wins.
+        let lines = split_lines(panel.render(8).into_iter());
+        assert_eq!(crate::segment::line_length(&lines[0]), 8);
+    }
+
     #[test]
     fn test_panel_from_text() {
         let panel = Panel::from_text("Hello\nWorld");
@@ -834,6 +925,50 @@ mod tests {
         assert!(!panel.expand);
     }
 
+    #[test]
+    fn test_panel_fit_shrinks_to_content() {
+        let panel = Panel::fit(vec![vec![Segment::new("Hello", None)]]);
+        let output = panel.render_plain(40);
+        let widths: Vec<usize> = output.lines().map(cells::cell_len).collect();
+        assert_eq!(widths, [9, 9, 9]);
+
+        let expanded = panel.expand(true).render_plain(40);
+        assert!(expanded.lines().all(|line| cells::cell_len(line) == 40));
+    }
+
+    #[test]
+    fn test_panel_fit_widens_for_title() {
+        let panel = Panel::fit(vec![vec![Segment::new("Hi", None)]]).title("Long title");
+        let output = panel.render_plain(40);
+        let first = output.lines().next().expect("top border");
+        assert_eq!(cells::cell_len(first), 16);
+        assert!(first.contains(" Long title "), "{first}");
+    }
+
+    #[test]
+    fn test_panel_measure() {
+        let console = Console::builder().width(30).build();
+        let options = console.options();
+
+        let panel = Panel::fit(vec![vec![Segment::new("Hello", None)]]);
+        assert_eq!(
+            panel.rich_measure(&console, &options),
+            Measurement::new(9, 9)
+        );
+
+        let wide = Panel::fit(vec![vec![Segment::new("x".repeat(50), None)]]);
+        assert_eq!(
+            wide.rich_measure(&console, &options),
+            Measurement::new(30, 30)
+        );
+
+        let fixed = Panel::fit(Vec::new()).width(12);
+        assert_eq!(
+            fixed.rich_measure(&console, &options),
+            Measurement::new(12, 12)
+        );
+    }
+
     #[test]
     fn test_truncate_text_to_width() {
         let text = Text::new("Hello World");
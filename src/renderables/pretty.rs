@@ -14,8 +14,12 @@
 
 use std::any;
 use std::fmt::Debug;
+use std::sync::LazyLock;
+
+use regex::Regex;
 
 use crate::cells::cell_len;
+use crate::color::Color;
 use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
 use crate::segment::Segment;
@@ -204,32 +208,73 @@ impl<T: Debug + ?Sized> Renderable for Inspect<'_, T> {
             output.push(Segment::line());
         }
 
-        if self.options.show_fields {
-            let repr = format!("{:#?}", self.value);
-            if let Some(fields) = extract_simple_struct_fields(&repr) {
-                let mut table = Table::new()
-                    .with_column(Column::new("Field").style(Style::new().bold()))
-                    .with_column(Column::new("Value"));
-                for (name, value) in fields {
-                    table.add_row_cells([name, value]);
-                }
-                let mut rendered: Vec<Segment<'static>> = table.render(width);
-                output.append(&mut rendered);
-                return output.into_iter().collect();
+        let repr = format!("{:#?}", self.value);
+        let fields = if self.options.show_fields {
+            extract_simple_struct_fields(&repr)
+        } else {
+            None
+        };
+        if let Some(fields) = fields {
+            let mut table = Table::new()
+                .with_column(Column::new("Field").style(Style::new().bold()))
+                .with_column(Column::new("Value"));
+            for (name, value) in fields {
+                table.add_row_cells([name, value]);
             }
+            let mut rendered: Vec<Segment<'static>> = table.render(width);
+            output.append(&mut rendered);
+        } else {
+            let pretty = Pretty::new(self.value).max_width(width);
+            output.extend(
+                pretty
+                    .render(console, options)
+                    .into_iter()
+                    .map(Segment::into_owned),
+            );
         }
 
-        let pretty = Pretty::new(self.value).max_width(width);
-        output.extend(
-            pretty
-                .render(console, options)
-                .into_iter()
-                .map(Segment::into_owned),
-        );
+        let colors = describe_colors(&repr);
+        if !colors.is_empty() {
+            let line = Text::assemble(&[
+                ("Colors: ", Some(Style::new().bold())),
+                (colors.join(", ").as_str(), None),
+            ]);
+            for wrapped in line.wrap(width) {
+                output.extend(wrapped.render("").into_iter().map(Segment::into_owned));
+                output.push(Segment::line());
+            }
+        }
         output.into_iter().collect()
     }
 }
 
+/// Describe the colors in a `Debug` representation that have no exact name.
+///
+/// Each [`Color`] (as found in a [`Style`]) whose value isn't a named color
+/// is listed with its nearest name, e.g. `#fe0102 ≈ red1`.
+fn describe_colors(repr: &str) -> Vec<String> {
+    static COLOR_NAME: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"Color \{\s*name: "((?:[^"\\]|\\.)*)""#).expect("valid regex")
+    });
+
+    let mut descriptions: Vec<String> = Vec::new();
+    for captures in COLOR_NAME.captures_iter(repr) {
+        let name = &captures[1];
+        let Ok(color) = Color::parse(name) else {
+            continue;
+        };
+        let (nearest, distance) = color.name_approx();
+        if distance == 0.0 && nearest == name {
+            continue;
+        }
+        let description = format!("{name} \u{2248} {nearest}");
+        if !descriptions.contains(&description) {
+            descriptions.push(description);
+        }
+    }
+    descriptions
+}
+
 /// Convenience helper to print an [`Inspect`] view to a [`Console`].
 pub fn inspect<T: Debug + ?Sized>(console: &Console, value: &T) {
     let renderable = Inspect::new(value);
@@ -646,6 +691,27 @@ mod tests {
         assert!(text.contains("field1") || text.contains("Simple"));
     }
 
+    #[test]
+    fn test_inspect_describes_unnamed_colors() {
+        let style = Style::parse("bold #fe0102 on red").unwrap();
+        let console = test_console(80);
+        let text: String = Inspect::new(&style)
+            .show_type(false)
+            .render(&console, &console.options())
+            .iter()
+            .map(|s| s.text.as_ref())
+            .collect();
+        assert!(text.ends_with("Colors: #fe0102 \u{2248} red1\n"), "{text}");
+
+        let named = Style::parse("red").unwrap();
+        let text: String = Inspect::new(&named)
+            .render(&console, &console.options())
+            .iter()
+            .map(|s| s.text.as_ref())
+            .collect();
+        assert!(!text.contains("Colors:"), "{text}");
+    }
+
     #[test]
     fn test_inspect_render_without_fields() {
         let value = Simple {
@@ -6,10 +6,18 @@
 use crate::cells;
 use crate::console::{Console, ConsoleOptions};
 use crate::filesize::{self, SizeUnit, binary, binary_speed, decimal, decimal_speed};
+use crate::live::{Live, LiveOptions};
+use crate::markup;
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{self, Segment};
 use crate::style::Style;
+use crate::sync::lock_recover;
 use crate::text::Text;
+use crate::theme;
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::{Duration, Instant};
 
 /// Bar style variants for the progress bar.
@@ -67,6 +75,10 @@ impl BarStyle {
 }
 
 /// Spinner animation frames.
+///
+/// Each spinner carries its own frame interval, so time-based rendering
+/// ([`Spinner::frame_at`], [`Spinner::render_at`]) animates at the spinner's
+/// pace regardless of how often the display refreshes.
 #[derive(Debug, Clone)]
 pub struct Spinner {
     /// Animation frames.
@@ -75,8 +87,50 @@ pub struct Spinner {
     frame_index: usize,
     /// Style for the spinner.
     style: Style,
+    /// Per-frame styles, cycled and layered over `style`.
+    frame_styles: Vec<Style>,
+    /// Time each frame is shown at normal speed.
+    interval: Duration,
+    /// Per-frame durations overriding `interval`, cycled over the frames.
+    frame_intervals: Vec<Duration>,
+    /// Speed multiplier (2.0 = twice as fast).
+    speed: f64,
 }
 
+/// Named spinners: name -> (interval in ms, frames).
+static NAMED_SPINNERS: LazyLock<HashMap<&'static str, (u64, Vec<&'static str>)>> =
+    LazyLock::new(|| {
+        let mut spinners = HashMap::new();
+
+        for (line_no, line) in include_str!("../spinners.tsv").lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split('\t');
+            let name = fields.next().unwrap_or_default();
+            let interval = fields
+                .next()
+                .and_then(|interval| interval.parse().ok())
+                .expect("src/spinners.tsv: expected TAB-separated name + interval");
+            let frames: Vec<&'static str> = fields.collect();
+            assert!(
+                !frames.is_empty(),
+                "src/spinners.tsv:{}: spinner {name:?} has no frames",
+                line_no + 1
+            );
+
+            let prior = spinners.insert(name, (interval, frames));
+            assert!(
+                prior.is_none(),
+                "src/spinners.tsv:{}: duplicate spinner {name:?}",
+                line_no + 1
+            );
+        }
+
+        spinners
+    });
+
 impl Default for Spinner {
     fn default() -> Self {
         Self::dots()
@@ -84,86 +138,106 @@ impl Default for Spinner {
 }
 
 impl Spinner {
-    /// Create a dots spinner (⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏).
-    #[must_use]
-    pub fn dots() -> Self {
+    fn from_frames(frames: Vec<&'static str>, interval_ms: u64) -> Self {
         Self {
-            frames: vec!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            frames,
             frame_index: 0,
             style: Style::new(),
+            frame_styles: Vec::new(),
+            interval: Duration::from_millis(interval_ms),
+            frame_intervals: Vec::new(),
+            speed: 1.0,
         }
     }
 
+    /// Create a dots spinner (⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏).
+    #[must_use]
+    pub fn dots() -> Self {
+        Self::from_frames(vec!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"], 80)
+    }
+
     /// Create a line spinner (⎺⎻⎼⎽⎼⎻).
     #[must_use]
     pub fn line() -> Self {
-        Self {
-            frames: vec!["⎺", "⎻", "⎼", "⎽", "⎼", "⎻"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::from_frames(vec!["⎺", "⎻", "⎼", "⎽", "⎼", "⎻"], 130)
     }
 
     /// Create a simple spinner (|/-\).
     #[must_use]
     pub fn simple() -> Self {
-        Self {
-            frames: vec!["|", "/", "-", "\\"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::from_frames(vec!["|", "/", "-", "\\"], 100)
     }
 
     /// Create a bouncing ball spinner (⠁⠂⠄⠂).
     #[must_use]
     pub fn bounce() -> Self {
-        Self {
-            frames: vec!["⠁", "⠂", "⠄", "⠂"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::from_frames(vec!["⠁", "⠂", "⠄", "⠂"], 120)
     }
 
     /// Create a growing dots spinner (⣾⣽⣻⢿⡿⣟⣯⣷).
     #[must_use]
     pub fn growing() -> Self {
-        Self {
-            frames: vec!["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::from_frames(vec!["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"], 80)
     }
 
     /// Create a moon phase spinner (🌑🌒🌓🌔🌕🌖🌗🌘).
     #[must_use]
     pub fn moon() -> Self {
-        Self {
-            frames: vec!["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::from_frames(vec!["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"], 80)
     }
 
     /// Create a clock spinner (🕐🕑🕒🕓🕔🕕🕖🕗🕘🕙🕚🕛).
     #[must_use]
     pub fn clock() -> Self {
-        Self {
-            frames: vec![
+        Self::from_frames(
+            vec![
                 "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚", "🕛",
             ],
-            frame_index: 0,
-            style: Style::new(),
+            100,
+        )
+    }
+
+    /// Look up a named spinner from the cli-spinners set bundled with Python
+    /// Rich (`"dots"`, `"dots12"`, `"line"`, `"earth"`, `"bouncingBar"`, ...),
+    /// using its interval.
+    ///
+    /// `"simple"` and `"growing"` name [`Spinner::simple`] and
+    /// [`Spinner::growing`]. See [`Spinner::names`] for the full list.
+    ///
+    /// ```
+    /// use rich_rust::renderables::Spinner;
+    ///
+    /// let spinner = Spinner::named("dots12").unwrap();
+    /// assert_eq!(spinner.current_frame(), "⢀⠀");
+    /// ```
+    #[must_use]
+    pub fn named(name: &str) -> Option<Self> {
+        if let Some((interval_ms, frames)) = NAMED_SPINNERS.get(name) {
+            return Some(Self::from_frames(frames.clone(), *interval_ms));
+        }
+        match name {
+            "simple" => Some(Self::simple()),
+            "growing" => Some(Self::growing()),
+            _ => None,
         }
     }
 
-    /// Create a spinner from custom frames.
+    /// Names accepted by [`Spinner::named`], sorted.
+    #[must_use]
+    pub fn names() -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = NAMED_SPINNERS
+            .keys()
+            .copied()
+            .chain(["simple", "growing"])
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Create a spinner from custom frames (100ms per frame).
     #[must_use]
     pub fn custom(frames: Vec<&'static str>) -> Self {
-        Self {
-            frames,
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::from_frames(frames, 100)
     }
 
     /// Set the spinner style.
@@ -173,6 +247,128 @@ impl Spinner {
         self
     }
 
+    /// Set per-frame styles, layered over the spinner style.
+    ///
+    /// Styles are cycled independently of the frames, so a color list of a
+    /// different length than the frames gives a rainbow effect.
+    ///
+    /// ```
+    /// use rich_rust::renderables::Spinner;
+    /// use rich_rust::style::Style;
+    ///
+    /// let spinner = Spinner::dots().frame_styles(
+    ///     ["red", "yellow", "green", "cyan", "blue", "magenta"].map(|c| Style::new().fg(c)),
+    /// );
+    /// assert_eq!(spinner.frame_style(1), Style::new().fg("yellow"));
+    /// ```
+    #[must_use]
+    pub fn frame_styles(mut self, styles: impl IntoIterator<Item = Style>) -> Self {
+        self.frame_styles = styles.into_iter().collect();
+        self
+    }
+
+    /// Set the time each frame is shown at normal speed.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set how long each frame is shown, overriding the uniform interval.
+    ///
+    /// Durations are cycled over the frames, so frame `i` is shown for
+    /// `intervals[i % intervals.len()]` (before the speed multiplier).
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rich_rust::renderables::Spinner;
+    ///
+    /// // Hold the first frame three times as long as the second.
+    /// let spinner = Spinner::custom(vec!["*", "."])
+    ///     .frame_intervals([Duration::from_millis(300), Duration::from_millis(100)]);
+    /// assert_eq!(spinner.frame_at(Duration::from_millis(250)), "*");
+    /// assert_eq!(spinner.frame_at(Duration::from_millis(350)), ".");
+    /// ```
+    #[must_use]
+    pub fn frame_intervals(mut self, intervals: impl IntoIterator<Item = Duration>) -> Self {
+        self.frame_intervals = intervals.into_iter().collect();
+        self
+    }
+
+    /// Set the speed multiplier (2.0 = twice as fast, 0.5 = half speed).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is not a positive number.
+    #[must_use]
+    pub fn speed(mut self, speed: f64) -> Self {
+        assert!(speed > 0.0, "speed must be > 0");
+        self.speed = speed;
+        self
+    }
+
+    /// Time each frame is shown, after applying the speed multiplier.
+    #[must_use]
+    pub fn frame_interval(&self) -> Duration {
+        self.interval.div_f64(self.speed)
+    }
+
+    /// Style for the frame at `index`: the spinner style plus its frame style.
+    #[must_use]
+    pub fn frame_style(&self, index: usize) -> Style {
+        if self.frame_styles.is_empty() {
+            return self.style.clone();
+        }
+        self.style
+            .combine(&self.frame_styles[index % self.frame_styles.len()])
+    }
+
+    /// Index of the frame shown `elapsed` after the spinner started.
+    fn tick_at(&self, elapsed: Duration) -> usize {
+        if !self.frame_intervals.is_empty() && !self.frames.is_empty() {
+            return self.tick_at_varying(elapsed);
+        }
+        let interval = self.frame_interval().as_nanos();
+        if interval == 0 {
+            return 0;
+        }
+        (elapsed.as_nanos() / interval) as usize
+    }
+
+    /// [`Self::tick_at`] for spinners with per-frame intervals.
+    fn tick_at_varying(&self, elapsed: Duration) -> usize {
+        let durations: Vec<u128> = (0..self.frames.len())
+            .map(|index| {
+                let interval = self.frame_intervals[index % self.frame_intervals.len()];
+                interval.div_f64(self.speed).as_nanos()
+            })
+            .collect();
+        let cycle: u128 = durations.iter().sum();
+        if cycle == 0 {
+            return 0;
+        }
+        let elapsed = elapsed.as_nanos();
+        let mut tick = (elapsed / cycle) as usize * self.frames.len();
+        let mut remainder = elapsed % cycle;
+        for duration in durations {
+            if remainder < duration {
+                break;
+            }
+            remainder -= duration;
+            tick += 1;
+        }
+        tick
+    }
+
+    /// Frame shown `elapsed` after the spinner started.
+    #[must_use]
+    pub fn frame_at(&self, elapsed: Duration) -> &'static str {
+        if self.frames.is_empty() {
+            return " ";
+        }
+        self.frames[self.tick_at(elapsed) % self.frames.len()]
+    }
+
     /// Advance to the next frame and return the current frame.
     pub fn next_frame(&mut self) -> &'static str {
         if self.frames.is_empty() {
@@ -195,7 +391,17 @@ impl Spinner {
     /// Render the current spinner frame as a segment.
     #[must_use]
     pub fn render(&self) -> Segment<'static> {
-        Segment::new(self.current_frame(), Some(self.style.clone()))
+        Segment::new(
+            self.current_frame(),
+            Some(self.frame_style(self.frame_index)),
+        )
+    }
+
+    /// Render the frame shown `elapsed` after the spinner started.
+    #[must_use]
+    pub fn render_at(&self, elapsed: Duration) -> Segment<'static> {
+        let tick = self.tick_at(elapsed);
+        Segment::new(self.frame_at(elapsed), Some(self.frame_style(tick)))
     }
 }
 
@@ -212,12 +418,14 @@ pub struct ProgressBar {
     width: usize,
     /// Bar style.
     bar_style: BarStyle,
-    /// Style for completed portion.
-    completed_style: Style,
-    /// Style for remaining portion.
-    remaining_style: Style,
-    /// Style for the pulse character.
-    pulse_style: Style,
+    /// Style for completed portion (theme `bar.complete` when unset).
+    completed_style: Option<Style>,
+    /// Style for a finished bar (the completed style, else theme `bar.finished`).
+    finished_style: Option<Style>,
+    /// Style for remaining portion (theme `bar.back` when unset).
+    remaining_style: Option<Style>,
+    /// Style for the pulse character (theme `bar.pulse` when unset).
+    pulse_style: Option<Style>,
     /// Show percentage.
     show_percentage: bool,
     /// Show ETA.
@@ -256,9 +464,10 @@ impl Default for ProgressBar {
             current: 0,
             width: 40,
             bar_style: BarStyle::default(),
-            completed_style: Style::new().color_str("green").unwrap_or_default(),
-            remaining_style: Style::new().color_str("bright_black").unwrap_or_default(),
-            pulse_style: Style::new().color_str("cyan").unwrap_or_default(),
+            completed_style: None,
+            finished_style: None,
+            remaining_style: None,
+            pulse_style: None,
             show_percentage: true,
             show_eta: false,
             show_elapsed: false,
@@ -309,24 +518,32 @@ impl ProgressBar {
         self
     }
 
-    /// Set the completed portion style.
+    /// Set the completed portion style, instead of the theme's `bar.complete`.
     #[must_use]
     pub fn completed_style(mut self, style: Style) -> Self {
-        self.completed_style = style;
+        self.completed_style = Some(style);
         self
     }
 
-    /// Set the remaining portion style.
+    /// Set the style of a finished bar, instead of the completed portion
+    /// style or the theme's `bar.finished`.
+    #[must_use]
+    pub fn finished_style(mut self, style: Style) -> Self {
+        self.finished_style = Some(style);
+        self
+    }
+
+    /// Set the remaining portion style, instead of the theme's `bar.back`.
     #[must_use]
     pub fn remaining_style(mut self, style: Style) -> Self {
-        self.remaining_style = style;
+        self.remaining_style = Some(style);
         self
     }
 
-    /// Set the pulse character style.
+    /// Set the pulse character style, instead of the theme's `bar.pulse`.
     #[must_use]
     pub fn pulse_style(mut self, style: Style) -> Self {
-        self.pulse_style = style;
+        self.pulse_style = Some(style);
         self
     }
 
@@ -647,6 +864,25 @@ impl ProgressBar {
     /// Render the progress bar to segments for a given width.
     #[must_use]
     pub fn render(&self, available_width: usize) -> Vec<Segment<'static>> {
+        self.render_with(available_width, &theme::default_style)
+    }
+
+    /// Render the progress bar, taking unset styles from `console`'s theme.
+    #[must_use]
+    pub fn render_themed(
+        &self,
+        available_width: usize,
+        console: &Console,
+    ) -> Vec<Segment<'static>> {
+        self.render_with(available_width, &|name| console.get_style(name))
+    }
+
+    /// Render, looking up unset styles by theme name with `theme`.
+    fn render_with(
+        &self,
+        available_width: usize,
+        theme: &dyn Fn(&str) -> Style,
+    ) -> Vec<Segment<'static>> {
         let mut segments = Vec::new();
 
         // If finished and has a finished message, show that
@@ -677,11 +913,13 @@ impl ProgressBar {
         // Calculate bar width
         let mut suffix_parts: Vec<String> = Vec::new();
 
-        if self.show_percentage {
-            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-            let pct = (self.completed * 100.0) as u32;
-            suffix_parts.push(format!("{pct:3}%"));
-        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let pct = (self.completed * 100.0) as u32;
+        let percentage = if self.show_percentage {
+            format!(" {pct:3}%")
+        } else {
+            String::new()
+        };
 
         if self.show_elapsed
             && let Some(elapsed) = self.elapsed()
@@ -730,7 +968,8 @@ impl ProgressBar {
         } else {
             format!(" {}", suffix_parts.join(" "))
         };
-        let suffix_width = cells::cell_len(&suffix);
+        let suffix_width = cells::cell_len(&percentage) + cells::cell_len(&suffix);
+        let percentage_style = theme("progress.percentage");
 
         let bracket_width = if self.show_brackets { 2 } else { 0 };
         let bar_width = available_width
@@ -742,9 +981,7 @@ impl ProgressBar {
         if bar_width < 3 {
             // Not enough space for a bar, just show percentage
             if self.show_percentage {
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let pct = (self.completed * 100.0) as u32;
-                segments.push(Segment::new(format!("{pct}%"), None));
+                segments.push(Segment::new(format!("{pct}%"), Some(percentage_style)));
             }
             segments.push(Segment::line());
             return segments;
@@ -766,11 +1003,22 @@ impl ProgressBar {
         // Completed portion
         if completed_width > 0 {
             let completed_chars = self.bar_style.completed_char().repeat(completed_width);
-            segments.push(Segment::new(
-                completed_chars,
-                Some(self.completed_style.clone()),
-            ));
+            let completed_style = if self.is_finished || self.completed >= 1.0 {
+                self.finished_style
+                    .clone()
+                    .or_else(|| self.completed_style.clone())
+                    .unwrap_or_else(|| theme("bar.finished"))
+            } else {
+                self.completed_style
+                    .clone()
+                    .unwrap_or_else(|| theme("bar.complete"))
+            };
+            segments.push(Segment::new(completed_chars, Some(completed_style)));
         }
+        let remaining_style = self
+            .remaining_style
+            .clone()
+            .unwrap_or_else(|| theme("bar.back"));
 
         // Pulse character (at the edge)
         // Show pulse if we have remaining space and we are active (progress > 0 and < 1)
@@ -780,27 +1028,22 @@ impl ProgressBar {
         if show_pulse {
             // Replace first remaining char with pulse
             let remaining_after_pulse = remaining_width.saturating_sub(1);
-            segments.push(Segment::new(
-                self.bar_style.pulse_char(),
-                Some(self.pulse_style.clone()),
-            ));
+            let pulse_style = self
+                .pulse_style
+                .clone()
+                .unwrap_or_else(|| theme("bar.pulse"));
+            segments.push(Segment::new(self.bar_style.pulse_char(), Some(pulse_style)));
 
             if remaining_after_pulse > 0 {
                 let remaining_chars = self
                     .bar_style
                     .remaining_char()
                     .repeat(remaining_after_pulse);
-                segments.push(Segment::new(
-                    remaining_chars,
-                    Some(self.remaining_style.clone()),
-                ));
+                segments.push(Segment::new(remaining_chars, Some(remaining_style)));
             }
         } else if remaining_width > 0 {
             let remaining_chars = self.bar_style.remaining_char().repeat(remaining_width);
-            segments.push(Segment::new(
-                remaining_chars,
-                Some(self.remaining_style.clone()),
-            ));
+            segments.push(Segment::new(remaining_chars, Some(remaining_style)));
         }
 
         if self.show_brackets {
@@ -808,6 +1051,9 @@ impl ProgressBar {
         }
 
         // Suffix (percentage, ETA, etc.)
+        if !percentage.is_empty() {
+            segments.push(Segment::new(percentage, Some(percentage_style)));
+        }
         if !suffix.is_empty() {
             segments.push(Segment::new(suffix, None));
         }
@@ -827,8 +1073,10 @@ impl ProgressBar {
 }
 
 impl Renderable for ProgressBar {
-    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
-        self.render(options.max_width).into_iter().collect()
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        self.render_themed(options.max_width, console)
+            .into_iter()
+            .collect()
     }
 }
 
@@ -1043,59 +1291,45 @@ impl DownloadColumn {
         self.total
     }
 
-    #[must_use]
-    pub fn render_plain(&self) -> String {
-        #[allow(clippy::cast_possible_wrap)]
-        let current_str = filesize::format_size(self.current as i64, self.unit, self.precision);
+    /// Format the current and total sizes, both in the unit chosen for the total.
+    fn format_parts(&self) -> (String, String) {
         #[allow(clippy::cast_possible_wrap)]
         let total_str = filesize::format_size(self.total as i64, self.unit, self.precision);
-        let parts: Vec<&str> = total_str.rsplitn(2, ' ').collect();
-        if parts.len() == 2 {
-            let unit_str = parts[0];
-            let total_value = parts[1];
-            let current_parts: Vec<&str> = current_str.rsplitn(2, ' ').collect();
-            let current_value = if current_parts.len() == 2 {
-                current_parts[1]
-            } else {
-                &current_str
-            };
-            format!("{current_value}/{total_value} {unit_str}")
-        } else {
-            format!("{current_str}/{total_str}")
+        let Some((total_value, unit_str)) = total_str.rsplit_once(' ') else {
+            return (self.current.to_string(), total_str);
+        };
+        let base: u64 = match self.unit {
+            SizeUnit::Binary => 1024,
+            SizeUnit::Decimal => 1000,
+        };
+        let mut divisor = 1u64;
+        while self.total / divisor >= base {
+            divisor *= base;
         }
+        let current_value = if divisor == 1 {
+            self.current.to_string()
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            let value = self.current as f64 / divisor as f64;
+            format!("{value:.precision$}", precision = self.precision)
+        };
+        (current_value, format!("{total_value} {unit_str}"))
+    }
+
+    #[must_use]
+    pub fn render_plain(&self) -> String {
+        let (current, total) = self.format_parts();
+        format!("{current}/{total}")
     }
 
     #[must_use]
     pub fn render(&self) -> Vec<Segment<'static>> {
-        #[allow(clippy::cast_possible_wrap)]
-        let current_str = filesize::format_size(self.current as i64, self.unit, self.precision);
-        #[allow(clippy::cast_possible_wrap)]
-        let total_str = filesize::format_size(self.total as i64, self.unit, self.precision);
-        let parts: Vec<&str> = total_str.rsplitn(2, ' ').collect();
-        if parts.len() == 2 {
-            let unit_str = parts[0];
-            let total_value = parts[1];
-            let current_parts: Vec<&str> = current_str.rsplitn(2, ' ').collect();
-            let current_value = if current_parts.len() == 2 {
-                current_parts[1]
-            } else {
-                &current_str
-            };
-            vec![
-                Segment::new(current_value.to_string(), Some(self.current_style.clone())),
-                Segment::new("/", Some(self.separator_style.clone())),
-                Segment::new(
-                    format!("{total_value} {unit_str}"),
-                    Some(self.total_style.clone()),
-                ),
-            ]
-        } else {
-            vec![
-                Segment::new(current_str, Some(self.current_style.clone())),
-                Segment::new("/", Some(self.separator_style.clone())),
-                Segment::new(total_str, Some(self.total_style.clone())),
-            ]
-        }
+        let (current, total) = self.format_parts();
+        vec![
+            Segment::new(current, Some(self.current_style.clone())),
+            Segment::new("/", Some(self.separator_style.clone())),
+            Segment::new(total, Some(self.total_style.clone())),
+        ]
     }
 }
 
@@ -1185,97 +1419,1412 @@ impl Default for TransferSpeedColumn {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::style::Attributes;
+// =============================================================================
+// Multi-task Progress
+// =============================================================================
 
-    #[test]
-    fn test_progress_bar_new() {
-        let bar = ProgressBar::new();
-        assert!((bar.progress() - 0.0).abs() < f64::EPSILON);
-        assert!(!bar.is_finished());
+/// Identifier of a task added to a [`Progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskId(usize);
+
+/// A task tracked by a [`Progress`].
+#[derive(Debug, Clone)]
+pub struct ProgressTask {
+    id: TaskId,
+    /// The task this one is a subtask of.
+    parent: Option<TaskId>,
+    description: Text,
+    /// Custom values shown by [`ProgressColumn::Template`] columns.
+    fields: HashMap<String, String>,
+    total: Option<u64>,
+    completed: u64,
+    visible: bool,
+    start_time: Instant,
+    finish_time: Option<Instant>,
+    /// Last tenth of the total announced by an accessible display.
+    reported: Option<u64>,
+}
+
+impl ProgressTask {
+    fn new(id: TaskId, description: Text, total: Option<u64>) -> Self {
+        Self {
+            id,
+            parent: None,
+            description,
+            fields: HashMap::new(),
+            total,
+            completed: 0,
+            visible: true,
+            start_time: Instant::now(),
+            finish_time: None,
+            reported: None,
+        }
     }
 
-    #[test]
-    fn test_progress_bar_with_total() {
-        let mut bar = ProgressBar::with_total(100);
-        bar.update(50);
-        assert!((bar.progress() - 0.5).abs() < f64::EPSILON);
+    /// The task's identifier.
+    #[must_use]
+    pub fn id(&self) -> TaskId {
+        self.id
     }
 
-    #[test]
-    fn test_progress_bar_set_progress() {
-        let mut bar = ProgressBar::new();
-        bar.set_progress(0.75);
-        assert!((bar.progress() - 0.75).abs() < f64::EPSILON);
+    /// The task this one was added under with [`Progress::add_subtask`].
+    #[must_use]
+    pub fn parent(&self) -> Option<TaskId> {
+        self.parent
     }
 
-    #[test]
-    fn test_progress_bar_advance() {
-        let mut bar = ProgressBar::with_total(10);
-        bar.advance(3);
-        assert!((bar.progress() - 0.3).abs() < f64::EPSILON);
-        bar.advance(2);
-        assert!((bar.progress() - 0.5).abs() < f64::EPSILON);
+    /// The task description.
+    #[must_use]
+    pub fn description(&self) -> &Text {
+        &self.description
     }
 
-    #[test]
-    fn test_progress_bar_finish() {
-        let mut bar = ProgressBar::new();
-        bar.finish();
-        assert!(bar.is_finished());
-        assert!((bar.progress() - 1.0).abs() < f64::EPSILON);
+    /// The task's custom fields.
+    #[must_use]
+    pub fn fields(&self) -> &HashMap<String, String> {
+        &self.fields
     }
 
-    #[test]
-    fn test_progress_bar_render() {
-        let mut bar = ProgressBar::new().width(20).show_brackets(true);
-        bar.set_progress(0.5);
-        let segments = bar.render(80);
-        assert!(!segments.is_empty());
-        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
-        assert!(text.contains('['));
-        assert!(text.contains(']'));
-        assert!(text.contains('%'));
+    /// One custom field, if set on the task.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
     }
 
-    #[test]
-    fn test_progress_bar_render_plain() {
-        let mut bar = ProgressBar::new().width(10).show_brackets(false);
-        bar.set_progress(0.5);
-        let plain = bar.render_plain(40);
-        assert!(!plain.is_empty());
+    /// Total steps, or `None` for an indeterminate task.
+    #[must_use]
+    pub fn total(&self) -> Option<u64> {
+        self.total
     }
 
-    #[test]
-    fn test_progress_bar_styles() {
-        for style in [
-            BarStyle::Ascii,
-            BarStyle::Block,
-            BarStyle::Line,
-            BarStyle::Dots,
-        ] {
-            let mut bar = ProgressBar::new().bar_style(style).width(10);
-            bar.set_progress(0.5);
-            let segments = bar.render(40);
-            assert!(!segments.is_empty());
+    /// Steps completed so far.
+    #[must_use]
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+
+    /// Whether the task is shown.
+    #[must_use]
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Completed fraction (0.0 - 1.0); 0.0 for indeterminate tasks.
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        match self.total {
+            #[allow(clippy::cast_precision_loss)]
+            Some(total) if total > 0 => (self.completed as f64 / total as f64).min(1.0),
+            Some(_) => 1.0,
+            None => 0.0,
         }
     }
 
-    #[test]
-    fn test_progress_bar_with_description() {
-        let mut bar = ProgressBar::new().description("Downloading").width(20);
-        bar.set_progress(0.5);
-        let plain = bar.render_plain(80);
-        assert!(plain.contains("Downloading"));
+    /// Whether the task has reached its total.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finish_time.is_some()
     }
 
-    #[test]
-    fn test_progress_bar_description_preserves_spans() {
-        let mut desc = Text::new("Download");
-        desc.stylize(0, 8, Style::new().bold());
-        let bar = ProgressBar::new().description(desc).width(20);
+    /// Time since the task was added (frozen once finished).
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.finish_time
+            .unwrap_or_else(Instant::now)
+            .duration_since(self.start_time)
+    }
+
+    /// Average steps per second.
+    #[must_use]
+    pub fn speed(&self) -> Option<f64> {
+        let secs = self.elapsed().as_secs_f64();
+        #[allow(clippy::cast_precision_loss)]
+        (secs > 0.0 && self.completed > 0).then(|| self.completed as f64 / secs)
+    }
+
+    /// Estimated time until the task finishes.
+    #[must_use]
+    pub fn time_remaining(&self) -> Option<Duration> {
+        if self.is_finished() {
+            return Some(Duration::ZERO);
+        }
+        let remaining = self.total?.saturating_sub(self.completed);
+        #[allow(clippy::cast_precision_loss)]
+        self.speed()
+            .map(|speed| Duration::from_secs_f64(remaining as f64 / speed))
+    }
+
+    fn set_completed(&mut self, completed: u64) {
+        self.completed = completed;
+        self.update_finished();
+    }
+
+    fn update_finished(&mut self) {
+        let finished = self.total.is_some_and(|total| self.completed >= total);
+        match (finished, self.finish_time) {
+            (true, None) => self.finish_time = Some(Instant::now()),
+            (false, Some(_)) => self.finish_time = None,
+            _ => {}
+        }
+    }
+
+    /// The log line for an accessible display, if the task moved to a new
+    /// tenth of its total since the last one.
+    fn announcement(&mut self) -> Option<Text> {
+        let (step, status) = match self.total {
+            None => (0, "started".to_string()),
+            Some(_) if self.is_finished() => (10, "complete".to_string()),
+            Some(_) => {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let step = (self.fraction() * 10.0).floor() as u64;
+                (step, format!("{}%", step * 10))
+            }
+        };
+        if !self.visible || self.reported == Some(step) {
+            return None;
+        }
+        self.reported = Some(step);
+        let mut line = self.description.clone();
+        line.append(&format!(": {status}"));
+        Some(line)
+    }
+}
+
+/// A column of a [`Progress`] task row.
+///
+/// Size and speed columns hold a configured template (units, precision,
+/// styles) whose values are filled in from each task when rendering. Any
+/// [`TaskColumn`] converts into a [`ProgressColumn::Custom`] with `.into()`.
+#[derive(Debug, Clone)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "columns are configured once per Progress, not stored per task"
+)]
+pub enum ProgressColumn {
+    /// The task description (`progress.description` style).
+    Description,
+    /// A bar filling the width left by the other columns, up to the template's width.
+    Bar(ProgressBar),
+    /// Completed percentage (`progress.percentage` style).
+    Percentage,
+    /// Time since the task started (`progress.elapsed` style).
+    Elapsed,
+    /// Estimated time remaining (`progress.remaining` style).
+    Remaining,
+    /// Completed and total size, e.g. `1.2/4.0 MB`.
+    Download(DownloadColumn),
+    /// Average transfer speed.
+    TransferSpeed(TransferSpeedColumn),
+    /// Completed size.
+    FileSize(FileSizeColumn),
+    /// Total size.
+    TotalFileSize(TotalFileSizeColumn),
+    /// An animated spinner, blank once the task finishes.
+    Spinner(Spinner),
+    /// Fixed text.
+    Text(Text),
+    /// Markup with placeholders filled in from each task; see [`ProgressColumn::template`].
+    Template(String),
+    /// Any other [`TaskColumn`].
+    Custom(Arc<dyn TaskColumn>),
+}
+
+impl<C: TaskColumn + 'static> From<C> for ProgressColumn {
+    fn from(column: C) -> Self {
+        Self::Custom(Arc::new(column))
+    }
+}
+
+impl ProgressColumn {
+    /// A bar column with the default look (no brackets or percentage).
+    #[must_use]
+    pub fn bar() -> Self {
+        Self::Bar(
+            ProgressBar::new()
+                .show_brackets(false)
+                .show_percentage(false),
+        )
+    }
+
+    /// A column of markup with placeholders filled in from each task.
+    ///
+    /// Supported placeholders are `{task.description}`, `{task.completed}`,
+    /// `{task.total}`, `{task.percentage}`, and `{task.fields[name]}`, which
+    /// reads the task's [field](Progress::set_field) or else the
+    /// [global one](Progress::set_global_field) and is empty when neither is
+    /// set. Write `{{` and `}}` for literal braces.
+    ///
+    /// ```
+    /// use rich_rust::renderables::progress::ProgressColumn;
+    ///
+    /// let server = ProgressColumn::template("[dim]{task.fields[server]}[/]");
+    /// ```
+    #[must_use]
+    pub fn template(template: impl Into<String>) -> Self {
+        Self::Template(template.into())
+    }
+
+    /// Python Rich's default columns: description, bar, percentage, time remaining.
+    #[must_use]
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::Description,
+            Self::bar(),
+            Self::Percentage,
+            Self::Remaining,
+        ]
+    }
+
+    /// Columns for file transfers: description, bar, download size, speed, time remaining.
+    #[must_use]
+    pub fn download() -> Vec<Self> {
+        vec![
+            Self::Description,
+            Self::bar(),
+            Self::Download(DownloadColumn::default()),
+            Self::TransferSpeed(TransferSpeedColumn::default()),
+            Self::Remaining,
+        ]
+    }
+
+    /// Render every column except bars, which depend on the remaining width.
+    fn render_cell(
+        &self,
+        task: &ProgressTask,
+        globals: &HashMap<String, String>,
+        console: &Console,
+        clock: Duration,
+    ) -> Option<Vec<Segment<'static>>> {
+        let cell = match self {
+            Self::Bar(_) => return None,
+            Self::Description => {
+                let mut description = task.description.clone();
+                let style = console
+                    .get_style("progress.description")
+                    .combine(description.style());
+                description.set_style(style);
+                description
+                    .render("")
+                    .into_iter()
+                    .map(Segment::into_owned)
+                    .collect()
+            }
+            Self::Percentage => PercentageColumn::new().render_task(task, console),
+            Self::Elapsed => TimeElapsedColumn::new().render_task(task, console),
+            Self::Remaining => TimeRemainingColumn::new().render_task(task, console),
+            Self::Download(template) => template.render_task(task, console),
+            Self::TransferSpeed(template) => template.render_task(task, console),
+            Self::FileSize(template) => template.render_task(task, console),
+            Self::TotalFileSize(template) => template.render_task(task, console),
+            Self::Spinner(spinner) => {
+                if task.is_finished() {
+                    vec![Segment::new(
+                        " ".repeat(cells::cell_len(spinner.current_frame())),
+                        None,
+                    )]
+                } else {
+                    let mut frame = spinner.render_at(clock);
+                    frame.style = Some(
+                        console
+                            .get_style("progress.spinner")
+                            .combine(&frame.style.unwrap_or_default()),
+                    );
+                    vec![frame]
+                }
+            }
+            Self::Text(text) => text
+                .render("")
+                .into_iter()
+                .map(Segment::into_owned)
+                .collect(),
+            Self::Template(template) => {
+                markup::render_or_plain(&fill_template(template, task, globals))
+                    .render("")
+                    .into_iter()
+                    .map(Segment::into_owned)
+                    .collect()
+            }
+            Self::Custom(column) => column.render_task(task, console),
+        };
+        Some(cell)
+    }
+}
+
+/// Substitute `task`'s values into a [`ProgressColumn::Template`], escaping them as markup.
+///
+/// Unknown placeholders are left as written.
+fn fill_template(template: &str, task: &ProgressTask, globals: &HashMap<String, String>) -> String {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(brace) = rest.find(['{', '}']) {
+        filled.push_str(&rest[..brace]);
+        let tail = &rest[brace..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            filled.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        let Some(close) = tail.find('}').filter(|_| tail.starts_with('{')) else {
+            filled.push_str(&tail[..1]);
+            rest = &tail[1..];
+            continue;
+        };
+        match template_value(&tail[1..close], task, globals) {
+            Some(value) => filled.push_str(&markup::escape(&value)),
+            None => filled.push_str(&tail[..=close]),
+        }
+        rest = &tail[close + 1..];
+    }
+    filled.push_str(rest);
+    filled
+}
+
+fn template_value(
+    placeholder: &str,
+    task: &ProgressTask,
+    globals: &HashMap<String, String>,
+) -> Option<String> {
+    match placeholder {
+        "task.description" => Some(task.description.plain().to_string()),
+        "task.completed" => Some(task.completed.to_string()),
+        "task.total" => Some(
+            task.total
+                .map(|total| total.to_string())
+                .unwrap_or_default(),
+        ),
+        "task.percentage" => Some(format!("{:.0}", task.fraction() * 100.0)),
+        _ => {
+            let name = placeholder
+                .strip_prefix("task.fields[")?
+                .strip_suffix(']')?;
+            Some(
+                task.fields
+                    .get(name)
+                    .or_else(|| globals.get(name))
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+        }
+    }
+}
+
+// =============================================================================
+// Task Columns
+// =============================================================================
+
+/// A column that renders one cell of a [`Progress`] task row from the task's state.
+///
+/// Implement this to add columns beyond the built-in ones, and pass them to
+/// [`Progress::columns`] via `.into()`.
+///
+/// ```rust,ignore
+/// let progress = Progress::new(console).columns([
+///     SpinnerColumn::new().into(),
+///     ProgressColumn::Description,
+///     ProgressColumn::bar(),
+///     MofNCompleteColumn::new().into(),
+///     TimeElapsedColumn::new().into(),
+/// ]);
+/// ```
+pub trait TaskColumn: std::fmt::Debug + Send + Sync {
+    /// Render the cell for `task` on a single line.
+    fn render_task(&self, task: &ProgressTask, console: &Console) -> Vec<Segment<'static>>;
+}
+
+/// One segment styled with `style`, or the console's `theme_name` style when unset.
+fn themed_cell(
+    text: String,
+    style: Option<&Style>,
+    theme_name: &str,
+    console: &Console,
+) -> Vec<Segment<'static>> {
+    let style = style
+        .cloned()
+        .unwrap_or_else(|| console.get_style(theme_name));
+    vec![Segment::new(text, Some(style))]
+}
+
+/// Completed percentage, e.g. ` 42%` (`progress.percentage` style).
+#[derive(Debug, Clone, Default)]
+pub struct PercentageColumn {
+    style: Option<Style>,
+}
+
+impl PercentageColumn {
+    /// Create a percentage column.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `style` instead of the theme's `progress.percentage`.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl TaskColumn for PercentageColumn {
+    fn render_task(&self, task: &ProgressTask, console: &Console) -> Vec<Segment<'static>> {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let pct = (task.fraction() * 100.0).floor() as u32;
+        themed_cell(
+            format!("{pct:>3}%"),
+            self.style.as_ref(),
+            "progress.percentage",
+            console,
+        )
+    }
+}
+
+/// Time since the task started (`progress.elapsed` style).
+#[derive(Debug, Clone, Default)]
+pub struct TimeElapsedColumn {
+    style: Option<Style>,
+}
+
+impl TimeElapsedColumn {
+    /// Create an elapsed-time column.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `style` instead of the theme's `progress.elapsed`.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl TaskColumn for TimeElapsedColumn {
+    fn render_task(&self, task: &ProgressTask, console: &Console) -> Vec<Segment<'static>> {
+        themed_cell(
+            ProgressBar::format_duration(task.elapsed()),
+            self.style.as_ref(),
+            "progress.elapsed",
+            console,
+        )
+    }
+}
+
+/// Estimated time remaining, or `-:--` when unknown (`progress.remaining` style).
+#[derive(Debug, Clone, Default)]
+pub struct TimeRemainingColumn {
+    style: Option<Style>,
+    elapsed_when_finished: bool,
+}
+
+impl TimeRemainingColumn {
+    /// Create a remaining-time column.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `style` instead of the theme's `progress.remaining`.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Show the total elapsed time (`progress.elapsed` style) once the task finishes.
+    #[must_use]
+    pub fn elapsed_when_finished(mut self, elapsed_when_finished: bool) -> Self {
+        self.elapsed_when_finished = elapsed_when_finished;
+        self
+    }
+}
+
+impl TaskColumn for TimeRemainingColumn {
+    fn render_task(&self, task: &ProgressTask, console: &Console) -> Vec<Segment<'static>> {
+        if self.elapsed_when_finished && task.is_finished() {
+            return themed_cell(
+                ProgressBar::format_duration(task.elapsed()),
+                self.style.as_ref(),
+                "progress.elapsed",
+                console,
+            );
+        }
+        themed_cell(
+            task.time_remaining()
+                .map_or_else(|| "-:--".to_string(), ProgressBar::format_duration),
+            self.style.as_ref(),
+            "progress.remaining",
+            console,
+        )
+    }
+}
+
+/// Completed and total steps, e.g. ` 7/12` (`progress.download` style).
+///
+/// The completed count is padded to the width of the total so the column
+/// doesn't shift as it grows; indeterminate tasks show `?` as their total.
+#[derive(Debug, Clone)]
+pub struct MofNCompleteColumn {
+    separator: String,
+    style: Option<Style>,
+}
+
+impl Default for MofNCompleteColumn {
+    fn default() -> Self {
+        Self {
+            separator: "/".to_string(),
+            style: None,
+        }
+    }
+}
+
+impl MofNCompleteColumn {
+    /// Create an `M/N` column.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Text placed between the completed and total counts (default `/`).
+    #[must_use]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Use `style` instead of the theme's `progress.download`.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl TaskColumn for MofNCompleteColumn {
+    fn render_task(&self, task: &ProgressTask, console: &Console) -> Vec<Segment<'static>> {
+        let total = task
+            .total
+            .map_or_else(|| "?".to_string(), |total| total.to_string());
+        let text = format!(
+            "{completed:>width$}{separator}{total}",
+            completed = task.completed,
+            width = total.len(),
+            separator = self.separator,
+        );
+        themed_cell(text, self.style.as_ref(), "progress.download", console)
+    }
+}
+
+/// An animated spinner, replaced by `finished_text` once the task finishes
+/// (`progress.spinner` style).
+#[derive(Debug, Clone, Default)]
+pub struct SpinnerColumn {
+    spinner: Spinner,
+    finished_text: Option<Text>,
+}
+
+impl SpinnerColumn {
+    /// Create a spinner column using the `dots` spinner.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `spinner` instead of `dots`.
+    #[must_use]
+    pub fn spinner(mut self, spinner: Spinner) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
+    /// Text shown once the task finishes (default: blank, the width of a frame).
+    #[must_use]
+    pub fn finished_text(mut self, text: impl Into<Text>) -> Self {
+        self.finished_text = Some(text.into());
+        self
+    }
+}
+
+impl TaskColumn for SpinnerColumn {
+    fn render_task(&self, task: &ProgressTask, console: &Console) -> Vec<Segment<'static>> {
+        if task.is_finished() {
+            return match &self.finished_text {
+                Some(text) => text
+                    .render("")
+                    .into_iter()
+                    .map(Segment::into_owned)
+                    .collect(),
+                None => vec![Segment::new(
+                    " ".repeat(cells::cell_len(self.spinner.current_frame())),
+                    None,
+                )],
+            };
+        }
+        let mut frame = self.spinner.render_at(task.elapsed());
+        frame.style = Some(
+            console
+                .get_style("progress.spinner")
+                .combine(&frame.style.unwrap_or_default()),
+        );
+        vec![frame]
+    }
+}
+
+impl TaskColumn for DownloadColumn {
+    fn render_task(&self, task: &ProgressTask, _console: &Console) -> Vec<Segment<'static>> {
+        let mut column = self.clone();
+        column.set_current(task.completed);
+        column.set_total(task.total.unwrap_or(task.completed));
+        column.render()
+    }
+}
+
+impl TaskColumn for TransferSpeedColumn {
+    fn render_task(&self, task: &ProgressTask, _console: &Console) -> Vec<Segment<'static>> {
+        let mut column = self.clone();
+        column.set_speed(task.speed().unwrap_or(0.0));
+        column.render()
+    }
+}
+
+impl TaskColumn for FileSizeColumn {
+    fn render_task(&self, task: &ProgressTask, _console: &Console) -> Vec<Segment<'static>> {
+        let mut column = self.clone();
+        column.set_size(task.completed);
+        column.render()
+    }
+}
+
+impl TaskColumn for TotalFileSizeColumn {
+    fn render_task(&self, task: &ProgressTask, _console: &Console) -> Vec<Segment<'static>> {
+        let mut column = self.clone();
+        column.inner.set_size(task.total.unwrap_or(task.completed));
+        column.render()
+    }
+}
+
+/// Tasks and columns shared between a [`Progress`] and its live display.
+struct ProgressState {
+    columns: Vec<ProgressColumn>,
+    tasks: Vec<ProgressTask>,
+    /// Fields every task falls back to in template columns.
+    fields: HashMap<String, String>,
+    next_id: usize,
+    started: Instant,
+}
+
+impl ProgressState {
+    fn task_mut(&mut self, id: TaskId) -> Option<&mut ProgressTask> {
+        self.tasks.iter_mut().find(|task| task.id == id)
+    }
+
+    /// Whether `task` is `ancestor` or one of its subtasks, at any depth.
+    fn descends_from(&self, task: &ProgressTask, ancestor: TaskId) -> bool {
+        let mut current = Some(task);
+        while let Some(task) = current {
+            if task.id == ancestor {
+                return true;
+            }
+            current = task
+                .parent
+                .and_then(|parent| self.tasks.iter().find(|task| task.id == parent));
+        }
+        false
+    }
+
+    /// Recompute the progress of every task with subtasks from its children.
+    ///
+    /// Subtasks always follow their parent, so walking backwards updates
+    /// children before the parents that sum them.
+    fn sync_parents(&mut self) {
+        for index in (0..self.tasks.len()).rev() {
+            let id = self.tasks[index].id;
+            let mut has_children = false;
+            let mut total = None;
+            let mut completed = 0;
+            for child in self.tasks.iter().filter(|task| task.parent == Some(id)) {
+                has_children = true;
+                if let Some(child_total) = child.total {
+                    total = Some(total.unwrap_or(0) + child_total);
+                    completed += child.completed.min(child_total);
+                }
+            }
+            if has_children {
+                let task = &mut self.tasks[index];
+                task.total = total;
+                task.completed = completed;
+                task.update_finished();
+            }
+        }
+    }
+
+    fn render(&self, console: &Console, max_width: usize) -> Vec<Segment<'static>> {
+        let clock = self.started.elapsed();
+        let visible: Vec<&ProgressTask> = self.tasks.iter().filter(|task| task.visible).collect();
+        let mut segments = Vec::new();
+        for (index, task) in visible.iter().enumerate() {
+            let guide = tree_guide(&visible, index);
+            segments.extend(self.render_row(task, &guide, console, max_width, clock));
+            segments.push(Segment::line());
+        }
+        segments
+    }
+
+    fn render_row(
+        &self,
+        task: &ProgressTask,
+        guide: &str,
+        console: &Console,
+        max_width: usize,
+        clock: Duration,
+    ) -> Vec<Segment<'static>> {
+        let cells: Vec<Option<Vec<Segment<'static>>>> = self
+            .columns
+            .iter()
+            .map(|column| {
+                let mut cell = column.render_cell(task, &self.fields, console, clock);
+                if let (ProgressColumn::Description, Some(cell)) = (column, cell.as_mut())
+                    && !guide.is_empty()
+                {
+                    let style = console.get_style("tree.line");
+                    cell.insert(0, Segment::new(guide.to_string(), Some(style)));
+                }
+                cell
+            })
+            .collect();
+        let gaps = self.columns.len().saturating_sub(1);
+        let used: usize = cells
+            .iter()
+            .flatten()
+            .map(|cell| segment::line_length(cell))
+            .sum::<usize>()
+            + gaps;
+        let bar_count = cells.iter().filter(|cell| cell.is_none()).count().max(1);
+        let bar_width = max_width.saturating_sub(used) / bar_count;
+
+        let mut row = Vec::new();
+        for (index, (column, cell)) in self.columns.iter().zip(cells).enumerate() {
+            if index > 0 {
+                row.push(Segment::new(" ", None));
+            }
+            match (column, cell) {
+                (_, Some(cell)) => row.extend(cell),
+                (ProgressColumn::Bar(template), None) => {
+                    let mut bar = template.clone();
+                    bar.set_progress(task.fraction());
+                    bar.is_finished = task.is_finished();
+                    row.extend(
+                        bar.render_themed(bar_width, console)
+                            .into_iter()
+                            .filter(|segment| segment.text != "\n"),
+                    );
+                }
+                (_, None) => {}
+            }
+        }
+        segment::adjust_line_length(row, max_width, None, false)
+    }
+}
+
+/// Tree guides placing `visible[index]` under its parent, e.g. `│   └── `.
+fn tree_guide(visible: &[&ProgressTask], index: usize) -> String {
+    let has_next_sibling = |index: usize| {
+        visible[index + 1..]
+            .iter()
+            .any(|task| task.parent == visible[index].parent)
+    };
+    let mut guide = String::new();
+    let Some(mut parent) = visible[index].parent else {
+        return guide;
+    };
+    guide.push_str(if has_next_sibling(index) {
+        "├── "
+    } else {
+        "└── "
+    });
+    while let Some(position) = visible.iter().position(|task| task.id == parent) {
+        let Some(grandparent) = visible[position].parent else {
+            break;
+        };
+        let branch = if has_next_sibling(position) {
+            "│   "
+        } else {
+            "    "
+        };
+        guide.insert_str(0, branch);
+        parent = grandparent;
+    }
+    guide
+}
+
+/// Live display of many concurrent tasks (Python Rich's `Progress`).
+///
+/// On an [accessible](crate::console::ConsoleBuilder::accessible) console
+/// nothing is animated: each task instead prints a line when it starts, at
+/// every tenth of its total, and when it completes.
+///
+/// Each task is shown as a row built from [`ProgressColumn`]s. Tasks can be
+/// added and updated from any thread; the display refreshes automatically
+/// while started.
+///
+/// Tasks added with [`Progress::add_subtask`] are drawn under their parent
+/// with tree guides, and the parent's progress is summed from its subtasks,
+/// which models multi-stage pipelines (download → verify → install).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::sync::Arc;
+/// use rich_rust::prelude::*;
+///
+/// let console = Arc::new(Console::new());
+/// let progress = Progress::new(Arc::clone(&console));
+/// let download = progress.add_task("Downloading", Some(1000));
+/// let unpack = progress.add_task("Unpacking", Some(50));
+///
+/// progress.start()?;
+/// while !progress.finished() {
+///     progress.advance(download, 10);
+///     progress.advance(unpack, 1);
+///     std::thread::sleep(std::time::Duration::from_millis(20));
+/// }
+/// progress.stop()?;
+/// ```
+pub struct Progress {
+    console: Arc<Console>,
+    state: Arc<Mutex<ProgressState>>,
+    options: LiveOptions,
+    live: Mutex<Option<Live>>,
+    /// Started on an accessible console: updates print log lines.
+    announcing: AtomicBool,
+}
+
+impl Progress {
+    /// Create a progress display with the [default columns](ProgressColumn::defaults).
+    #[must_use]
+    pub fn new(console: Arc<Console>) -> Self {
+        Self {
+            console,
+            state: Arc::new(Mutex::new(ProgressState {
+                columns: ProgressColumn::defaults(),
+                tasks: Vec::new(),
+                fields: HashMap::new(),
+                next_id: 0,
+                started: Instant::now(),
+            })),
+            options: LiveOptions::default().with_refresh_per_second(10.0),
+            live: Mutex::new(None),
+            announcing: AtomicBool::new(false),
+        }
+    }
+
+    /// Set the columns of each task row.
+    #[must_use]
+    pub fn columns(self, columns: impl IntoIterator<Item = ProgressColumn>) -> Self {
+        lock_recover(&self.state).columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Set how often the display refreshes (default 10).
+    ///
+    /// # Panics
+    ///
+    /// [`Progress::start`] panics if `refresh_per_second` is not positive.
+    #[must_use]
+    pub fn refresh_per_second(mut self, refresh_per_second: f64) -> Self {
+        self.options.refresh_per_second = refresh_per_second;
+        self
+    }
+
+    /// Remove the display when stopped instead of leaving the final state.
+    #[must_use]
+    pub fn transient(mut self, transient: bool) -> Self {
+        self.options.transient = transient;
+        self
+    }
+
+    /// Add a task; `total` is `None` for an indeterminate task.
+    pub fn add_task(&self, description: impl Into<Text>, total: Option<u64>) -> TaskId {
+        let mut state = lock_recover(&self.state);
+        let id = TaskId(state.next_id);
+        state.next_id += 1;
+        state
+            .tasks
+            .push(ProgressTask::new(id, description.into(), total));
+        drop(state);
+        self.announce(Some(id));
+        id
+    }
+
+    /// Add a task under `parent`, drawn below it with tree guides.
+    ///
+    /// Once a task has subtasks its progress is computed from them: its total
+    /// is the sum of their totals (indeterminate subtasks don't count) and its
+    /// completed steps the sum of theirs, so updating it directly has no
+    /// lasting effect. An unknown `parent` adds a top-level task.
+    pub fn add_subtask(
+        &self,
+        parent: TaskId,
+        description: impl Into<Text>,
+        total: Option<u64>,
+    ) -> TaskId {
+        let mut state = lock_recover(&self.state);
+        let id = TaskId(state.next_id);
+        state.next_id += 1;
+        let mut task = ProgressTask::new(id, description.into(), total);
+        // Keep subtrees contiguous: insert after the parent's last descendant.
+        let position = match state.tasks.iter().position(|task| task.id == parent) {
+            Some(start) => {
+                task.parent = Some(parent);
+                start
+                    + 1
+                    + state.tasks[start + 1..]
+                        .iter()
+                        .take_while(|task| state.descends_from(task, parent))
+                        .count()
+            }
+            None => state.tasks.len(),
+        };
+        state.tasks.insert(position, task);
+        state.sync_parents();
+        drop(state);
+        self.announce(Some(id));
+        id
+    }
+
+    /// Remove a task and its subtasks from the display.
+    pub fn remove_task(&self, id: TaskId) {
+        let mut state = lock_recover(&self.state);
+        let removed: Vec<TaskId> = state
+            .tasks
+            .iter()
+            .filter(|task| state.descends_from(task, id))
+            .map(|task| task.id)
+            .collect();
+        state.tasks.retain(|task| !removed.contains(&task.id));
+        state.sync_parents();
+    }
+
+    /// Set the completed steps of a task. Unknown ids are ignored.
+    pub fn update(&self, id: TaskId, completed: u64) {
+        let mut state = lock_recover(&self.state);
+        if let Some(task) = state.task_mut(id) {
+            task.set_completed(completed);
+            state.sync_parents();
+        }
+        drop(state);
+        self.announce(Some(id));
+    }
+
+    /// Advance a task by `delta` steps. Unknown ids are ignored.
+    pub fn advance(&self, id: TaskId, delta: u64) {
+        let mut state = lock_recover(&self.state);
+        if let Some(task) = state.task_mut(id) {
+            task.set_completed(task.completed.saturating_add(delta));
+            state.sync_parents();
+        }
+        drop(state);
+        self.announce(Some(id));
+    }
+
+    /// Change a task's total. Unknown ids are ignored.
+    pub fn set_total(&self, id: TaskId, total: Option<u64>) {
+        let mut state = lock_recover(&self.state);
+        if let Some(task) = state.task_mut(id) {
+            task.total = total;
+            task.update_finished();
+            state.sync_parents();
+        }
+        drop(state);
+        self.announce(Some(id));
+    }
+
+    /// Set a custom field on a task, shown by [template](ProgressColumn::template)
+    /// columns as `{task.fields[name]}`. Unknown ids are ignored.
+    pub fn set_field(&self, id: TaskId, name: impl Into<String>, value: impl Into<String>) {
+        if let Some(task) = lock_recover(&self.state).task_mut(id) {
+            task.fields.insert(name.into(), value.into());
+        }
+    }
+
+    /// Set a field shown for every task that doesn't set it itself.
+    pub fn set_global_field(&self, name: impl Into<String>, value: impl Into<String>) {
+        lock_recover(&self.state)
+            .fields
+            .insert(name.into(), value.into());
+    }
+
+    /// Change a task's description. Unknown ids are ignored.
+    pub fn set_description(&self, id: TaskId, description: impl Into<Text>) {
+        if let Some(task) = lock_recover(&self.state).task_mut(id) {
+            task.description = description.into();
+        }
+    }
+
+    /// Show or hide a task. Unknown ids are ignored.
+    pub fn set_visible(&self, id: TaskId, visible: bool) {
+        if let Some(task) = lock_recover(&self.state).task_mut(id) {
+            task.visible = visible;
+        }
+    }
+
+    /// A snapshot of a task.
+    #[must_use]
+    pub fn task(&self, id: TaskId) -> Option<ProgressTask> {
+        lock_recover(&self.state)
+            .tasks
+            .iter()
+            .find(|task| task.id == id)
+            .cloned()
+    }
+
+    /// Snapshots of all tasks, in the order they were added.
+    #[must_use]
+    pub fn tasks(&self) -> Vec<ProgressTask> {
+        lock_recover(&self.state).tasks.clone()
+    }
+
+    /// Whether every task with a total has finished.
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        lock_recover(&self.state)
+            .tasks
+            .iter()
+            .all(|task| task.total.is_none() || task.is_finished())
+    }
+
+    /// Print log lines for `id` (or every task) while announcing.
+    fn announce(&self, id: Option<TaskId>) {
+        if !self.announcing.load(Ordering::SeqCst) {
+            return;
+        }
+        let lines: Vec<Text> = lock_recover(&self.state)
+            .tasks
+            .iter_mut()
+            .filter(|task| id.is_none_or(|id| task.id == id))
+            .filter_map(ProgressTask::announcement)
+            .collect();
+        for line in &lines {
+            self.console.print_text(line);
+        }
+    }
+
+    /// Start the live display.
+    ///
+    /// On an accessible console this prints each task's state instead.
+    pub fn start(&self) -> io::Result<()> {
+        if self.console.is_accessible() {
+            self.announcing.store(true, Ordering::SeqCst);
+            self.announce(None);
+            return Ok(());
+        }
+        let mut slot = lock_recover(&self.live);
+        if slot.is_some() {
+            return Ok(());
+        }
+        let live = Live::with_options(Arc::clone(&self.console), self.options.clone()).renderable(
+            ProgressView {
+                state: Arc::clone(&self.state),
+            },
+        );
+        live.start(true)?;
+        *slot = Some(live);
+        Ok(())
+    }
+
+    /// Stop the live display, leaving the final state unless transient.
+    pub fn stop(&self) -> io::Result<()> {
+        self.announcing.store(false, Ordering::SeqCst);
+        match lock_recover(&self.live).take() {
+            Some(live) => live.stop(),
+            None => Ok(()),
+        }
+    }
+
+    /// Redraw the display now instead of waiting for the next refresh.
+    pub fn refresh(&self) -> io::Result<()> {
+        match lock_recover(&self.live).as_ref() {
+            Some(live) => live.refresh(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+impl Renderable for Progress {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        lock_recover(&self.state).render(console, options.max_width)
+    }
+}
+
+/// The renderable a [`Progress`] hands to its [`Live`] display.
+struct ProgressView {
+    state: Arc<Mutex<ProgressState>>,
+}
+
+impl Renderable for ProgressView {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        lock_recover(&self.state).render(console, options.max_width)
+    }
+}
+
+// =============================================================================
+// Iterator tracking
+// =============================================================================
+
+/// Iterate over `iter` while showing its progress (Python Rich's `track()`).
+///
+/// The total is taken from the iterator's size hint when it is exact (as for
+/// any [`ExactSizeIterator`]); otherwise the task is indeterminate until
+/// [`Track::total`] sets one. The display starts with the first item and
+/// stops once the iterator is exhausted or the adapter is dropped.
+///
+/// The display draws to a new [`Console`] unless [`Track::console`] supplies
+/// one; print through that console while iterating so output appears above
+/// the bar instead of through it.
+///
+/// ```rust,ignore
+/// use rich_rust::renderables::progress::track;
+///
+/// for file in track(files, "Processing") {
+///     process(file);
+/// }
+/// ```
+pub fn track<I: IntoIterator>(iter: I, description: impl Into<Text>) -> Track<I::IntoIter> {
+    let iter = iter.into_iter();
+    let total = match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => u64::try_from(lower).ok(),
+        _ => None,
+    };
+    Track {
+        iter,
+        description: description.into(),
+        total,
+        console: None,
+        transient: false,
+        running: None,
+        done: false,
+    }
+}
+
+/// Extension trait adding [`track`] to every iterator.
+///
+/// ```rust,ignore
+/// use rich_rust::prelude::*;
+///
+/// let console = Console::new().shared();
+/// for line in lines.iter().track("Parsing").console(Arc::clone(&console)) {
+///     console.print(&format!("parsed {line}"));
+/// }
+/// ```
+pub trait ProgressIteratorExt: Iterator + Sized {
+    /// Wrap the iterator so it drives a progress display as items are yielded.
+    fn track(self, description: impl Into<Text>) -> Track<Self> {
+        track(self, description)
+    }
+}
+
+impl<I: Iterator> ProgressIteratorExt for I {}
+
+/// Iterator adapter returned by [`track`] and [`ProgressIteratorExt::track`].
+pub struct Track<I> {
+    iter: I,
+    description: Text,
+    total: Option<u64>,
+    console: Option<Arc<Console>>,
+    transient: bool,
+    running: Option<(Progress, TaskId)>,
+    done: bool,
+}
+
+impl<I> Track<I> {
+    /// Draw the display on `console` (and route its prints above the bar).
+    #[must_use]
+    pub fn console(mut self, console: Arc<Console>) -> Self {
+        self.console = Some(console);
+        self
+    }
+
+    /// Set the number of items, for iterators without an exact size hint.
+    #[must_use]
+    pub fn total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// Remove the display once iteration ends instead of leaving the final state.
+    #[must_use]
+    pub fn transient(mut self, transient: bool) -> Self {
+        self.transient = transient;
+        self
+    }
+
+    /// The progress display, once iteration has started.
+    #[must_use]
+    pub fn progress(&self) -> Option<&Progress> {
+        self.running.as_ref().map(|(progress, _)| progress)
+    }
+
+    fn start(&mut self) {
+        let console = self
+            .console
+            .clone()
+            .unwrap_or_else(|| Console::new().shared());
+        let progress = Progress::new(console).transient(self.transient);
+        let task = progress.add_task(self.description.clone(), self.total);
+        // A display that cannot start still counts items; iteration must not fail.
+        let _ = progress.start();
+        self.running = Some((progress, task));
+    }
+
+    fn finish(&mut self) {
+        self.done = true;
+        if let Some((progress, task)) = &self.running {
+            if let Some(snapshot) = progress.task(*task)
+                && snapshot.total().is_none()
+            {
+                progress.set_total(*task, Some(snapshot.completed()));
+            }
+            let _ = progress.stop();
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Track<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return self.iter.next();
+        }
+        // The previous item counts as done once the caller asks for the next one.
+        match &self.running {
+            Some((progress, task)) => progress.advance(*task, 1),
+            None => self.start(),
+        }
+        let item = self.iter.next();
+        if item.is_none() {
+            self.finish();
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Track<I> {}
+
+impl<I: std::fmt::Debug> std::fmt::Debug for Track<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Track")
+            .field("iter", &self.iter)
+            .field("description", &self.description)
+            .field("total", &self.total)
+            .field("transient", &self.transient)
+            .field("started", &self.running.is_some())
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Attributes;
+
+    #[test]
+    fn test_progress_bar_new() {
+        let bar = ProgressBar::new();
+        assert!((bar.progress() - 0.0).abs() < f64::EPSILON);
+        assert!(!bar.is_finished());
+    }
+
+    #[test]
+    fn test_progress_bar_with_total() {
+        let mut bar = ProgressBar::with_total(100);
+        bar.update(50);
+        assert!((bar.progress() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_progress_bar_set_progress() {
+        let mut bar = ProgressBar::new();
+        bar.set_progress(0.75);
+        assert!((bar.progress() - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_progress_bar_advance() {
+        let mut bar = ProgressBar::with_total(10);
+        bar.advance(3);
+        assert!((bar.progress() - 0.3).abs() < f64::EPSILON);
+        bar.advance(2);
+        assert!((bar.progress() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_progress_bar_finish() {
+        let mut bar = ProgressBar::new();
+        bar.finish();
+        assert!(bar.is_finished());
+        assert!((bar.progress() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_progress_bar_render() {
+        let mut bar = ProgressBar::new().width(20).show_brackets(true);
+        bar.set_progress(0.5);
+        let segments = bar.render(80);
+        assert!(!segments.is_empty());
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert!(text.contains('['));
+        assert!(text.contains(']'));
+        assert!(text.contains('%'));
+    }
+
+    #[test]
+    fn test_progress_bar_render_plain() {
+        let mut bar = ProgressBar::new().width(10).show_brackets(false);
+        bar.set_progress(0.5);
+        let plain = bar.render_plain(40);
+        assert!(!plain.is_empty());
+    }
+
+    #[test]
+    fn test_progress_bar_styles() {
+        for style in [
+            BarStyle::Ascii,
+            BarStyle::Block,
+            BarStyle::Line,
+            BarStyle::Dots,
+        ] {
+            let mut bar = ProgressBar::new().bar_style(style).width(10);
+            bar.set_progress(0.5);
+            let segments = bar.render(40);
+            assert!(!segments.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_progress_bar_with_description() {
+        let mut bar = ProgressBar::new().description("Downloading").width(20);
+        bar.set_progress(0.5);
+        let plain = bar.render_plain(80);
+        assert!(plain.contains("Downloading"));
+    }
+
+    #[test]
+    fn test_progress_bar_description_preserves_spans() {
+        let mut desc = Text::new("Download");
+        desc.stylize(0, 8, Style::new().bold());
+        let bar = ProgressBar::new().description(desc).width(20);
         let segments = bar.render(80);
         let has_bold = segments.iter().any(|seg| {
             seg.text.contains("Download")
@@ -1306,6 +2855,55 @@ mod tests {
         assert_eq!(spinner.next_frame(), "|"); // Wraps around
     }
 
+    #[test]
+    fn test_spinner_named() {
+        let line = Spinner::named("line").unwrap();
+        assert_eq!(line.current_frame(), "-");
+        assert_eq!(line.frame_interval(), Duration::from_millis(130));
+        assert_eq!(Spinner::named("dots").unwrap().current_frame(), "⠋");
+        assert_eq!(Spinner::named("simple").unwrap().current_frame(), "|");
+        assert!(Spinner::named("nope").is_none());
+
+        let bar = Spinner::named("bouncingBar").unwrap();
+        assert_eq!(bar.frame_at(Duration::from_millis(80)), "[=   ]");
+        let earth = Spinner::named("earth").unwrap();
+        assert_eq!(earth.frame_at(Duration::from_millis(360)), "🌏");
+    }
+
+    #[test]
+    fn test_spinner_names_cover_table() {
+        let names = Spinner::names();
+        for name in [
+            "dots",
+            "dots12",
+            "moon",
+            "earth",
+            "clock",
+            "bouncingBar",
+            "simple",
+        ] {
+            assert!(names.contains(&name), "missing {name}");
+        }
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+        for name in names {
+            assert!(Spinner::named(name).is_some(), "{name} not found");
+        }
+    }
+
+    #[test]
+    fn test_spinner_frame_intervals() {
+        let spinner = Spinner::custom(vec!["a", "b", "c"])
+            .frame_intervals([Duration::from_millis(100), Duration::from_millis(50)]);
+        // Durations cycle over the frames: a=100, b=50, c=100 (cycle 250ms).
+        assert_eq!(spinner.frame_at(Duration::from_millis(99)), "a");
+        assert_eq!(spinner.frame_at(Duration::from_millis(100)), "b");
+        assert_eq!(spinner.frame_at(Duration::from_millis(150)), "c");
+        assert_eq!(spinner.frame_at(Duration::from_millis(250)), "a");
+
+        let fast = spinner.clone().speed(2.0);
+        assert_eq!(fast.frame_at(Duration::from_millis(75)), "c");
+    }
+
     #[test]
     fn test_spinner_current_frame() {
         let spinner = Spinner::simple();
@@ -1313,6 +2911,36 @@ mod tests {
         assert_eq!(spinner.current_frame(), "|"); // Doesn't advance
     }
 
+    #[test]
+    fn test_spinner_frame_at_uses_spinner_interval() {
+        let spinner = Spinner::simple();
+        assert_eq!(spinner.frame_interval(), Duration::from_millis(100));
+        assert_eq!(spinner.frame_at(Duration::ZERO), "|");
+        assert_eq!(spinner.frame_at(Duration::from_millis(150)), "/");
+        assert_eq!(spinner.frame_at(Duration::from_millis(450)), "|");
+
+        let fast = Spinner::simple().speed(2.0);
+        assert_eq!(fast.frame_interval(), Duration::from_millis(50));
+        assert_eq!(fast.frame_at(Duration::from_millis(150)), "\\");
+    }
+
+    #[test]
+    fn test_spinner_frame_styles_cycle() {
+        let red = Style::new().fg("red");
+        let blue = Style::new().fg("blue");
+        let spinner = Spinner::simple()
+            .style(Style::new().bold())
+            .frame_styles([red, blue]);
+
+        let first = spinner.render_at(Duration::ZERO);
+        assert_eq!(first.style, Some(Style::new().bold().fg("red")));
+        let second = spinner.render_at(Duration::from_millis(100));
+        assert_eq!(second.text, "/");
+        assert_eq!(second.style, Some(Style::new().bold().fg("blue")));
+        let third = spinner.render_at(Duration::from_millis(200));
+        assert_eq!(third.style, Some(Style::new().bold().fg("red")));
+    }
+
     #[test]
     fn test_spinner_render() {
         let spinner = Spinner::dots();
@@ -1498,6 +3126,14 @@ mod tests {
         assert_eq!(column.total(), 10_000_000);
     }
 
+    #[test]
+    fn test_download_column_uses_total_unit() {
+        let column = DownloadColumn::new(500_000, 2_000_000);
+        assert_eq!(column.render_plain(), "0.5/2.0 MB");
+        let column = DownloadColumn::new(12, 500);
+        assert_eq!(column.render_plain(), "12/500 bytes");
+    }
+
     #[test]
     fn test_download_column_binary() {
         let column = DownloadColumn::new(1_048_576, 10_485_760).unit(SizeUnit::Binary);
@@ -1546,4 +3182,382 @@ mod tests {
         assert_eq!(DownloadColumn::default().current(), 0);
         assert!((TransferSpeedColumn::default().speed() - 0.0).abs() < f64::EPSILON);
     }
+
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn progress_lines(progress: &Progress, width: usize) -> Vec<String> {
+        let console = Console::builder().width(width).markup(false).build();
+        let segments = progress.render(&console, &console.options());
+        let text: String = segments
+            .iter()
+            .map(|segment| segment.text.as_ref())
+            .collect();
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_progress_task_lifecycle() {
+        let progress = Progress::new(Console::new().shared());
+        let id = progress.add_task("copy", Some(10));
+        progress.advance(id, 4);
+        let task = progress.task(id).expect("task");
+        assert_eq!(task.completed(), 4);
+        assert!((task.fraction() - 0.4).abs() < f64::EPSILON);
+        assert!(!progress.finished());
+
+        progress.update(id, 10);
+        assert!(progress.task(id).expect("task").is_finished());
+        assert_eq!(
+            progress.task(id).expect("task").time_remaining(),
+            Some(Duration::ZERO)
+        );
+        assert!(progress.finished());
+
+        progress.set_total(id, Some(20));
+        assert!(!progress.task(id).expect("task").is_finished());
+
+        progress.remove_task(id);
+        assert!(progress.task(id).is_none());
+        progress.advance(id, 1);
+        assert!(progress.tasks().is_empty());
+    }
+
+    #[test]
+    fn test_progress_subtasks_sum_into_parent_and_draw_guides() {
+        let progress =
+            Progress::new(Console::new().shared()).columns([ProgressColumn::Description]);
+        let install = progress.add_task("install", None);
+        let download = progress.add_subtask(install, "download", None);
+        let other = progress.add_task("other", Some(1));
+        let verify = progress.add_subtask(install, "verify", Some(10));
+        let fetch = progress.add_subtask(download, "fetch", Some(100));
+
+        progress.advance(fetch, 50);
+        progress.update(verify, 10);
+        assert_eq!(progress.task(download).expect("stage").total(), Some(100));
+        let parent = progress.task(install).expect("parent");
+        assert_eq!(parent.total(), Some(110));
+        assert_eq!(parent.completed(), 60);
+        assert_eq!(progress.task(verify).expect("task").parent(), Some(install));
+
+        let lines: Vec<String> = progress_lines(&progress, 30)
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "install",
+                "├── download",
+                "│   └── fetch",
+                "└── verify",
+                "other"
+            ]
+        );
+
+        progress.update(fetch, 100);
+        assert!(progress.task(install).expect("parent").is_finished());
+
+        progress.remove_task(download);
+        assert!(progress.task(fetch).is_none());
+        assert_eq!(progress.task(install).expect("parent").total(), Some(10));
+        assert!(progress.task(other).is_some());
+    }
+
+    #[test]
+    fn test_progress_template_column_reads_task_and_global_fields() {
+        let progress = Progress::new(Console::new().shared()).columns([
+            ProgressColumn::Description,
+            ProgressColumn::template("{task.fields[server]} {task.completed}/{task.total} {{x}}"),
+        ]);
+        let eu = progress.add_task("sync", Some(8));
+        let us = progress.add_task("sync", Some(8));
+        progress.set_global_field("server", "default");
+        progress.set_field(us, "server", "[us-east]");
+        progress.advance(eu, 3);
+
+        assert_eq!(
+            progress.task(us).expect("task").field("server"),
+            Some("[us-east]")
+        );
+        let lines: Vec<String> = progress_lines(&progress, 40)
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec!["sync default 3/8 {x}", "sync [us-east] 0/8 {x}"]
+        );
+    }
+
+    #[test]
+    fn test_progress_task_ids_are_unique() {
+        let progress = Progress::new(Console::new().shared());
+        let first = progress.add_task("a", None);
+        progress.remove_task(first);
+        let second = progress.add_task("b", None);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_progress_renders_one_row_per_visible_task() {
+        let progress = Progress::new(Console::new().shared());
+        let a = progress.add_task("alpha", Some(100));
+        let b = progress.add_task("beta", Some(100));
+        let hidden = progress.add_task("hidden", Some(100));
+        progress.update(a, 50);
+        progress.update(b, 100);
+        progress.set_visible(hidden, false);
+
+        let lines = progress_lines(&progress, 40);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("alpha "));
+        assert!(lines[0].contains(" 50% "));
+        assert!(lines[1].starts_with("beta "));
+        assert!(lines[1].ends_with("100% 0s"), "{:?}", lines[1]);
+        for line in &lines {
+            assert_eq!(cells::cell_len(line), 40, "row not filled: {line:?}");
+        }
+    }
+
+    #[test]
+    fn test_progress_download_columns() {
+        let progress = Progress::new(Console::new().shared()).columns([
+            ProgressColumn::Description,
+            ProgressColumn::Download(DownloadColumn::default()),
+            ProgressColumn::Text(Text::new("|")),
+            ProgressColumn::TotalFileSize(TotalFileSizeColumn::default()),
+        ]);
+        let id = progress.add_task("file.bin", Some(2_000_000));
+        progress.update(id, 500_000);
+
+        let lines = progress_lines(&progress, 60);
+        assert_eq!(lines, vec!["file.bin 0.5/2.0 MB | 2.0 MB"]);
+    }
+
+    #[test]
+    fn test_progress_task_columns() {
+        #[derive(Debug)]
+        struct Shout;
+
+        impl TaskColumn for Shout {
+            fn render_task(&self, task: &ProgressTask, _: &Console) -> Vec<Segment<'static>> {
+                vec![Segment::new(
+                    task.description().plain().to_uppercase(),
+                    None,
+                )]
+            }
+        }
+
+        let progress = Progress::new(Console::new().shared()).columns([
+            SpinnerColumn::new().finished_text("✓").into(),
+            Shout.into(),
+            MofNCompleteColumn::new().into(),
+            PercentageColumn::new().into(),
+        ]);
+        let running = progress.add_task("copy", Some(120));
+        let done = progress.add_task("sync", Some(3));
+        let open = progress.add_task("scan", None);
+        progress.update(running, 7);
+        progress.update(done, 3);
+        progress.update(open, 5);
+
+        let lines = progress_lines(&progress, 40);
+        assert!(lines[0].ends_with(" COPY   7/120   5%"), "{:?}", lines[0]);
+        assert_eq!(lines[1], "✓ SYNC 3/3 100%");
+        assert!(lines[2].ends_with(" SCAN 5/?   0%"), "{:?}", lines[2]);
+    }
+
+    #[test]
+    fn test_progress_visuals_follow_console_theme() {
+        let theme = crate::theme::Theme::from_style_definitions(
+            [
+                ("bar.complete", "blue"),
+                ("bar.back", "red"),
+                ("bar.pulse", "yellow"),
+                ("progress.percentage", "cyan"),
+                ("progress.spinner", "magenta"),
+            ],
+            true,
+        )
+        .unwrap();
+        let console = Console::builder().width(40).theme(theme).build();
+        let progress = Progress::new(Console::new().shared()).columns([
+            ProgressColumn::Spinner(Spinner::dots()),
+            ProgressColumn::bar(),
+            ProgressColumn::Percentage,
+        ]);
+        let id = progress.add_task("copy", Some(10));
+        progress.update(id, 5);
+
+        let segments = progress.render(&console, &console.options());
+        let styled = |text: &str, style: &str| {
+            segments.iter().any(|segment| {
+                segment.text.contains(text)
+                    && segment.style.as_ref() == Some(&console.get_style(style))
+            })
+        };
+        let bar = BarStyle::default();
+        assert!(styled(bar.completed_char(), "bar.complete"));
+        assert!(styled(bar.pulse_char(), "bar.pulse"));
+        assert!(styled(bar.remaining_char(), "bar.back"));
+        assert!(styled("50%", "progress.percentage"));
+        assert!(styled("", "progress.spinner"));
+
+        // Explicit styles still win over the theme.
+        let mut bar = ProgressBar::new()
+            .completed_style(Style::new().bold())
+            .show_brackets(false)
+            .width(10);
+        bar.set_progress(0.5);
+        let segments = bar.render_themed(40, &console);
+        assert_eq!(segments[0].style, Some(Style::new().bold()));
+        assert_eq!(segments[2].style, Some(console.get_style("bar.back")));
+    }
+
+    #[test]
+    fn test_time_remaining_column_elapsed_when_finished() {
+        let console = Console::new();
+        let mut task = ProgressTask::new(TaskId(0), Text::new("t"), Some(2));
+        let column = TimeRemainingColumn::new().elapsed_when_finished(true);
+        let text = |segments: Vec<Segment<'static>>| segments[0].text.to_string();
+
+        assert_eq!(text(column.render_task(&task, &console)), "-:--");
+        task.set_completed(2);
+        assert_eq!(text(column.render_task(&task, &console)), "0s");
+        assert_eq!(
+            column.render_task(&task, &console)[0].style,
+            Some(console.get_style("progress.elapsed"))
+        );
+        assert_eq!(
+            text(TimeRemainingColumn::new().render_task(&task, &console)),
+            "0s"
+        );
+    }
+
+    #[test]
+    fn test_progress_row_is_cropped_to_width() {
+        let progress = Progress::new(Console::new().shared())
+            .columns([ProgressColumn::Description, ProgressColumn::Percentage]);
+        progress.add_task("a very long task description", Some(1));
+
+        let lines = progress_lines(&progress, 10);
+        assert_eq!(lines, vec!["a very lon"]);
+    }
+
+    #[test]
+    fn test_progress_start_stop_writes_rows() {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let console = Console::builder()
+            .force_terminal(true)
+            .width(40)
+            .file(Box::new(Sink(Arc::clone(&buffer))))
+            .build()
+            .shared();
+        let progress = Progress::new(console);
+        let id = progress.add_task("upload", Some(4));
+        progress.start().expect("start");
+        progress.advance(id, 4);
+        progress.refresh().expect("refresh");
+        progress.stop().expect("stop");
+
+        let output = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(output.contains("upload"), "missing row: {output:?}");
+        assert!(output.contains("100%"), "missing percentage: {output:?}");
+    }
+
+    #[test]
+    fn test_track_counts_items_of_exact_size_iterator() {
+        let console = Console::builder().force_terminal(false).build().shared();
+        let mut tracked = [1, 2, 3].into_iter().track("sum").console(console);
+        assert!(tracked.progress().is_none());
+        assert_eq!(tracked.len(), 3);
+
+        assert_eq!(tracked.next(), Some(1));
+        let progress = tracked.progress().expect("started");
+        let task = progress.tasks()[0].clone();
+        assert_eq!(task.total(), Some(3));
+        assert_eq!(task.completed(), 0);
+
+        assert_eq!(tracked.by_ref().sum::<i32>(), 5);
+        let task = tracked.progress().expect("started").tasks()[0].clone();
+        assert_eq!(task.completed(), 3);
+        assert!(task.is_finished());
+    }
+
+    #[test]
+    fn test_track_sets_total_of_unsized_iterator_when_exhausted() {
+        let console = Console::builder().force_terminal(false).build().shared();
+        let mut tracked = track((0..10).filter(|n| n % 2 == 0), "evens").console(console);
+        tracked.next();
+        let progress = tracked.progress().expect("started");
+        assert_eq!(progress.tasks()[0].total(), None);
+
+        assert_eq!(tracked.by_ref().count(), 4);
+        let task = tracked.progress().expect("started").tasks()[0].clone();
+        assert_eq!(task.total(), Some(5));
+        assert!(task.is_finished());
+    }
+
+    #[test]
+    fn test_track_draws_on_supplied_console() {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let console = Console::builder()
+            .force_terminal(true)
+            .width(40)
+            .file(Box::new(Sink(Arc::clone(&buffer))))
+            .build()
+            .shared();
+        let items: Vec<u32> = vec![10, 20]
+            .into_iter()
+            .track("items")
+            .console(console)
+            .collect();
+        assert_eq!(items, vec![10, 20]);
+
+        let output = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert!(output.contains("items"), "missing row: {output:?}");
+        assert!(output.contains("100%"), "missing percentage: {output:?}");
+    }
+
+    #[test]
+    fn test_accessible_progress_prints_log_lines() {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let console = Console::builder()
+            .force_terminal(true)
+            .accessible(true)
+            .width(40)
+            .file(Box::new(Sink(Arc::clone(&buffer))))
+            .build()
+            .shared();
+        let progress = Progress::new(console);
+        let task = progress.add_task("Copy", Some(100));
+        progress.start().unwrap();
+        let spinner = progress.add_task("Scan", None);
+        for _ in 0..10 {
+            progress.advance(task, 5);
+        }
+        progress.advance(spinner, 3);
+        progress.update(task, 100);
+        progress.stop().unwrap();
+        progress.advance(task, 1);
+
+        let output = String::from_utf8_lossy(&buffer.lock().unwrap()).into_owned();
+        assert_eq!(
+            output,
+            "Copy: 0%\nScan: started\nCopy: 10%\nCopy: 20%\nCopy: 30%\nCopy: 40%\n\
+             Copy: 50%\nCopy: complete\n"
+        );
+    }
 }
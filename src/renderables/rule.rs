@@ -3,24 +3,32 @@
 //! A Rule renders as a horizontal line that spans the console width,
 //! optionally with a centered (or aligned) title.
 
+use crate::r#box::BoxChars;
 use crate::cells;
 use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
+use crate::renderables::align::AlignMethod;
 use crate::segment::Segment;
 use crate::style::Style;
-use crate::text::{JustifyMethod, OverflowMethod, Text};
+use crate::text::{OverflowMethod, Text};
 
 /// A horizontal rule with optional title.
 #[derive(Debug, Clone)]
 pub struct Rule {
     /// Optional title text.
     title: Option<Text>,
-    /// Character to use for the rule line.
+    /// Characters repeated to draw the rule line.
     character: String,
     /// Style for the rule line.
     style: Style,
+    /// Base style for the title, under the title's own styles.
+    title_style: Option<Style>,
     /// Title alignment.
-    align: JustifyMethod,
+    align: AlignMethod,
+    /// Box style whose horizontal line replaces `character`.
+    box_style: Option<&'static BoxChars>,
+    /// Use ASCII-safe characters.
+    safe_box: Option<bool>,
 }
 
 impl Default for Rule {
@@ -29,7 +37,10 @@ impl Default for Rule {
             title: None,
             character: String::from("\u{2500}"), // ─
             style: Style::parse("bright_green").unwrap_or_default(),
-            align: JustifyMethod::Center,
+            title_style: None,
+            align: AlignMethod::Center,
+            box_style: None,
+            safe_box: None,
         }
     }
 }
@@ -57,42 +68,99 @@ impl Rule {
         self
     }
 
-    /// Set the rule style.
+    /// Set the characters repeated to draw the line (Python Rich's `characters`).
+    ///
+    /// Multi-character patterns like `"=-"` are repeated and cut to fill the
+    /// width exactly.
+    #[must_use]
+    pub fn characters(self, characters: impl Into<String>) -> Self {
+        self.character(characters)
+    }
+
+    /// Draw the rule with a box style's horizontal line (e.g. `━` for
+    /// [`HEAVY`](crate::box::HEAVY)), matching a panel or table drawn with it.
+    ///
+    /// Takes precedence over [`Rule::character`].
+    #[must_use]
+    pub fn box_style(mut self, style: &'static BoxChars) -> Self {
+        self.box_style = Some(style);
+        self
+    }
+
+    /// Force ASCII-safe rendering.
+    ///
+    /// Box styles are substituted with [`ASCII`](crate::box::ASCII), and a
+    /// non-ASCII rule character falls back to `-`.
+    #[must_use]
+    pub fn safe_box(mut self, safe: bool) -> Self {
+        self.safe_box = Some(safe);
+        self
+    }
+
+    /// The string repeated to draw the line, after box and safe-box handling.
+    fn line_character(&self) -> String {
+        let safe = self.safe_box.unwrap_or(false);
+        match self.box_style {
+            Some(box_style) => box_style.substitute(safe).head_row[1].to_string(),
+            None if safe && !self.character.is_ascii() => String::from("-"),
+            None => self.character.clone(),
+        }
+    }
+
+    /// Set the style of the rule line.
     #[must_use]
     pub fn style(mut self, style: Style) -> Self {
         self.style = style;
         self
     }
 
+    /// Set the base style of the title (and the spaces around it).
+    ///
+    /// Styles already on the title text are applied on top.
+    #[must_use]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = Some(style);
+        self
+    }
+
     /// Set title alignment.
+    ///
+    /// Accepts an [`AlignMethod`] or a [`JustifyMethod`](crate::text::JustifyMethod).
     #[must_use]
-    pub fn align(mut self, align: JustifyMethod) -> Self {
-        self.align = align;
+    pub fn align(mut self, align: impl Into<AlignMethod>) -> Self {
+        self.align = align.into();
         self
     }
 
     /// Left-align the title.
     #[must_use]
     pub fn align_left(self) -> Self {
-        self.align(JustifyMethod::Left)
+        self.align(AlignMethod::Left)
     }
 
     /// Center the title.
     #[must_use]
     pub fn align_center(self) -> Self {
-        self.align(JustifyMethod::Center)
+        self.align(AlignMethod::Center)
     }
 
     /// Right-align the title.
     #[must_use]
     pub fn align_right(self) -> Self {
-        self.align(JustifyMethod::Right)
+        self.align(AlignMethod::Right)
+    }
+
+    /// Repeat `character` to fill at most `width` cells.
+    fn fill(character: &str, char_width: usize, width: usize) -> String {
+        let repeated = character.repeat(width / char_width + 1);
+        cells::chop_cells(&repeated, width).0.to_string()
     }
 
     /// Render the rule to segments for a given width.
     #[must_use]
     pub fn render(&self, width: usize) -> Vec<Segment<'static>> {
-        let char_width = cells::cell_len(&self.character);
+        let character = self.line_character();
+        let char_width = cells::cell_len(&character);
         if char_width == 0 || width == 0 {
             return vec![Segment::line()];
         }
@@ -106,10 +174,12 @@ impl Rule {
                 let sanitized = title.plain().replace('\n', " ");
                 title = Text::new(sanitized);
             }
+            if let Some(title_style) = &self.title_style {
+                title.set_style(title_style.combine(title.style()));
+            }
 
             if title.plain().is_empty() {
-                let count = width / char_width;
-                let rule_text = self.character.repeat(count);
+                let rule_text = Self::fill(&character, char_width, width);
                 segments.push(Segment::new(rule_text, Some(self.style.clone())));
                 segments.push(Segment::line());
                 return segments;
@@ -117,9 +187,9 @@ impl Rule {
 
             let title_width = cells::cell_len(title.plain());
             let (left_pad, right_pad) = match self.align {
-                JustifyMethod::Left => (0, 1),
-                JustifyMethod::Right => (1, 0),
-                JustifyMethod::Center | JustifyMethod::Full | JustifyMethod::Default => (1, 1),
+                AlignMethod::Left => (0, 1),
+                AlignMethod::Right => (1, 0),
+                AlignMethod::Center => (1, 1),
             };
             let title_total_width = title_width
                 .saturating_add(left_pad)
@@ -127,7 +197,7 @@ impl Rule {
 
             if title_total_width > width {
                 let mut truncated = title.clone();
-                truncated.truncate(width, OverflowMethod::Crop, false);
+                truncated.truncate(width, OverflowMethod::Ellipsis, false);
                 segments.extend(
                     truncated
                         .render("")
@@ -140,9 +210,8 @@ impl Rule {
 
             // Calculate available space for rule characters
             let available = width.saturating_sub(title_total_width);
-            let rule_chars = available / char_width;
 
-            if rule_chars < 1 {
+            if available < char_width {
                 // Not enough space for rule, just show title
                 if left_pad > 0 {
                     segments.push(Segment::new(
@@ -163,24 +232,15 @@ impl Rule {
                     ));
                 }
             } else {
-                let (left_count, right_count) = match self.align {
-                    JustifyMethod::Left => (0, rule_chars),
-                    JustifyMethod::Right => (rule_chars, 0),
-                    JustifyMethod::Center | JustifyMethod::Full => {
-                        let left = rule_chars / 2;
-                        let right = rule_chars - left;
-                        (left, right)
-                    }
-                    JustifyMethod::Default => {
-                        let left = rule_chars / 2;
-                        let right = rule_chars - left;
-                        (left, right)
-                    }
+                let (left_width, right_width) = match self.align {
+                    AlignMethod::Left => (0, available),
+                    AlignMethod::Right => (available, 0),
+                    AlignMethod::Center => (available / 2, available - available / 2),
                 };
 
                 // Left rule section
-                if left_count > 0 {
-                    let left_rule = self.character.repeat(left_count);
+                let left_rule = Self::fill(&character, char_width, left_width);
+                if !left_rule.is_empty() {
                     segments.push(Segment::new(left_rule, Some(self.style.clone())));
                 }
 
@@ -205,15 +265,14 @@ impl Rule {
                 }
 
                 // Right rule section
-                if right_count > 0 {
-                    let right_rule = self.character.repeat(right_count);
+                let right_rule = Self::fill(&character, char_width, right_width);
+                if !right_rule.is_empty() {
                     segments.push(Segment::new(right_rule, Some(self.style.clone())));
                 }
             }
         } else {
             // No title, just a full-width rule
-            let count = width / char_width;
-            let rule_text = self.character.repeat(count);
+            let rule_text = Self::fill(&character, char_width, width);
             segments.push(Segment::new(rule_text, Some(self.style.clone())));
         }
 
@@ -221,6 +280,15 @@ impl Rule {
         segments
     }
 
+    /// Announce the rule in words for an accessible console.
+    fn render_accessible(&self) -> Vec<Segment<'static>> {
+        let text = match &self.title {
+            Some(title) => format!("Section: {}", title.plain()),
+            None => "Section break".to_string(),
+        };
+        vec![Segment::new(text, None), Segment::line()]
+    }
+
     /// Render the rule as a string (for simple output).
     #[must_use]
     pub fn render_plain(&self, width: usize) -> String {
@@ -232,8 +300,17 @@ impl Rule {
 }
 
 impl Renderable for Rule {
-    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
-        self.render(options.max_width).into_iter().collect()
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        if console.is_accessible() {
+            return self.render_accessible();
+        }
+        if self.safe_box.is_some() {
+            return self.render(options.max_width).into_iter().collect();
+        }
+
+        // Inherit the Console's safe_box setting unless explicitly overridden.
+        let effective = self.clone().safe_box(console.safe_box());
+        effective.render(options.max_width).into_iter().collect()
     }
 }
 
@@ -259,6 +336,35 @@ pub fn heavy_rule() -> Rule {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_rule_box_style() {
+        use crate::r#box::{DOUBLE, HEAVY};
+
+        let heavy = Rule::new().box_style(&HEAVY).render_plain(4);
+        assert_eq!(heavy, "\u{2501}\u{2501}\u{2501}\u{2501}\n");
+
+        // Box style wins over the character, and safe_box substitutes ASCII.
+        let rule = Rule::new().character("*").box_style(&DOUBLE);
+        assert_eq!(rule.render_plain(3), "\u{2550}\u{2550}\u{2550}\n");
+        assert_eq!(rule.safe_box(true).render_plain(3), "---\n");
+    }
+
+    #[test]
+    fn test_rule_safe_box_character() {
+        assert_eq!(Rule::new().safe_box(true).render_plain(3), "---\n");
+        assert_eq!(
+            Rule::new().character("=").safe_box(true).render_plain(3),
+            "===\n"
+        );
+
+        let console = Console::builder().safe_box(true).build();
+        let options = console.options().update_width(3);
+        let rule = Rule::new();
+        let segments = Renderable::render(&rule, &console, &options);
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(text, "---\n");
+    }
+
     #[test]
     fn test_rule_no_title() {
         let rule = Rule::new();
@@ -401,6 +507,41 @@ mod tests {
         assert!(text.contains("=-"));
     }
 
+    #[test]
+    fn test_rule_characters_pattern_fills_width() {
+        let rule = Rule::with_title("T").characters("=-");
+        assert_eq!(rule.render_plain(9), "=-= T =-=\n");
+        assert_eq!(Rule::new().characters("=-").render_plain(5), "=-=-=\n");
+    }
+
+    #[test]
+    fn test_rule_align_method() {
+        let left = Rule::with_title("X").align(AlignMethod::Left);
+        assert_eq!(left.render_plain(5), "X \u{2500}\u{2500}\u{2500}\n");
+        let right = Rule::with_title("X").align(crate::text::JustifyMethod::Right);
+        assert_eq!(right.render_plain(5), "\u{2500}\u{2500}\u{2500} X\n");
+    }
+
+    #[test]
+    fn test_rule_title_style() {
+        let title_style = Style::parse("bold").unwrap();
+        let line_style = Style::parse("red").unwrap();
+        let rule = Rule::with_title(Text::styled("T", Style::parse("blue").unwrap()))
+            .style(line_style.clone())
+            .title_style(title_style.clone());
+        let segments = rule.render(7);
+        let title = segments.iter().find(|s| s.text == "T").unwrap();
+        assert_eq!(
+            title.style,
+            Some(title_style.combine(&Style::parse("blue").unwrap()))
+        );
+        let line = segments
+            .iter()
+            .find(|s| s.text.contains('\u{2500}'))
+            .unwrap();
+        assert_eq!(line.style, Some(line_style));
+    }
+
     #[test]
     fn test_rule_fills_width_no_title() {
         let rule = Rule::new();
@@ -48,6 +48,25 @@
 //! let segments = syntax.render(None)?;
 //! ```
 //!
+//! # Console Theme Colors
+//!
+//! With [`Syntax::console_theme`] enabled, tokens are styled from the Console theme
+//! (`syntax.keyword`, `syntax.string`, `syntax.comment`, ...) instead of a syntect
+//! theme, so code blocks match the rest of a themed application. Names the theme
+//! does not define fall back to the Python Rich default palette.
+//!
+//! ```rust,ignore
+//! use rich_rust::prelude::*;
+//! use rich_rust::renderables::syntax::Syntax;
+//!
+//! let console = Console::new();
+//! console.push_theme(
+//!     Theme::from_style_definitions([("syntax.keyword", "bold blue")], true)?,
+//!     true,
+//! );
+//! console.print_renderable(&Syntax::new("fn main() {}", "rust").console_theme(true));
+//! ```
+//!
 //! # Loading from Files
 //!
 //! ```rust,ignore
@@ -95,9 +114,9 @@ use std::path::Path;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
-use syntect::easy::HighlightLines;
+use syntect::easy::{HighlightLines, ScopeRangeIterator};
 use syntect::highlighting::{Theme, ThemeSet};
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxSet};
 use syntect::util::LinesWithEndings;
 
 static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
@@ -164,6 +183,8 @@ pub struct Syntax {
     custom_syntax_set: Option<Arc<SyntaxSet>>,
     /// Optional custom theme set (loaded from user paths).
     custom_theme_set: Option<Arc<ThemeSet>>,
+    /// Whether token styles come from the Console theme instead of syntect.
+    console_theme: bool,
 }
 
 impl Default for Syntax {
@@ -182,6 +203,7 @@ impl Default for Syntax {
             padding: (0, 0),
             custom_syntax_set: None,
             custom_theme_set: None,
+            console_theme: false,
         }
     }
 }
@@ -331,6 +353,24 @@ impl Syntax {
         self
     }
 
+    /// Style tokens from the Console theme instead of the syntect theme.
+    ///
+    /// Looks up `syntax.keyword`, `syntax.function`, `syntax.type`, `syntax.operator`,
+    /// `syntax.number`, `syntax.constant`, `syntax.string`, `syntax.comment` and
+    /// `syntax.text`, plus `syntax.background` for the block background. Names the
+    /// theme does not define use the Python Rich default palette.
+    #[must_use]
+    pub fn console_theme(mut self, enabled: bool) -> Self {
+        self.console_theme = enabled;
+        self
+    }
+
+    /// Check whether tokens are styled from the Console theme.
+    #[must_use]
+    pub const fn uses_console_theme(&self) -> bool {
+        self.console_theme
+    }
+
     /// Override the background color.
     #[must_use]
     pub fn background_color(mut self, color: Color) -> Self {
@@ -399,6 +439,37 @@ impl Syntax {
     ///
     /// Returns an error if the theme or language is not found.
     pub fn render(&self, max_width: Option<usize>) -> Result<Vec<Segment<'_>>, SyntaxError> {
+        if self.console_theme {
+            self.render_with_theme(max_width, |_| None)
+        } else {
+            self.render_highlighted(max_width, None)
+        }
+    }
+
+    /// Render using theme styles looked up through `resolve`.
+    ///
+    /// `resolve` maps a style name such as `syntax.keyword` to a style; names it
+    /// returns `None` for use the Python Rich default palette. This is what the
+    /// [`Renderable`](crate::renderables::Renderable) impl uses when
+    /// [`Self::console_theme`] is enabled, with the Console theme as resolver.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the theme or language is not found.
+    pub fn render_with_theme(
+        &self,
+        max_width: Option<usize>,
+        resolve: impl Fn(&str) -> Option<Style>,
+    ) -> Result<Vec<Segment<'_>>, SyntaxError> {
+        let palette = ThemePalette::resolve(resolve);
+        self.render_highlighted(max_width, Some(&palette))
+    }
+
+    fn render_highlighted(
+        &self,
+        max_width: Option<usize>,
+        palette: Option<&ThemePalette>,
+    ) -> Result<Vec<Segment<'_>>, SyntaxError> {
         let ps: &SyntaxSet = self.custom_syntax_set.as_deref().unwrap_or(&*SYNTAX_SET);
         let ts: &ThemeSet = self.custom_theme_set.as_deref().unwrap_or(&*THEME_SET);
         let use_python_rich_theme = self.uses_python_rich_theme();
@@ -424,6 +495,8 @@ impl Syntax {
             .ok_or_else(|| SyntaxError::UnknownTheme(self.theme_name.clone()))?;
 
         let mut highlighter = HighlightLines::new(syntax, theme);
+        let mut parse_state = ParseState::new(syntax);
+        let mut scope_stack = ScopeStack::new();
         let mut segments: Vec<Segment<'static>> = Vec::new();
 
         // Background used for padding/fill and for styling indent guides.
@@ -438,7 +511,11 @@ impl Syntax {
                 .unwrap_or(syntect::highlighting::Color::BLACK);
             Color::from_rgb(bg_color.r, bg_color.g, bg_color.b)
         };
-        let base_bg_style = Style::new().bgcolor(bg.clone());
+        let base_bg_style = match (palette, &self.background_color) {
+            (Some(_), Some(override_bg)) => Style::new().bgcolor(override_bg.clone()),
+            (Some(palette), None) => palette.background.clone(),
+            (None, _) => Style::new().bgcolor(bg.clone()),
+        };
         let guide_style = base_bg_style.combine(&Style::new().dim());
 
         // Calculate line number width (digits only).
@@ -503,7 +580,22 @@ impl Syntax {
             };
 
             let mut line_text = Text::new("");
-            if use_python_rich_rust {
+            if let Some(palette) = palette {
+                let mut col = 0usize;
+                for (text, token) in
+                    scope_tokens(&line_for_highlight, &mut parse_state, &mut scope_stack, ps)
+                {
+                    let token_style = base_bg_style.combine(palette.style(token));
+                    append_syntax_text(
+                        &mut line_text,
+                        text,
+                        &token_style,
+                        leading_spaces,
+                        &mut col,
+                        &guide_style,
+                    );
+                }
+            } else if use_python_rich_rust {
                 for (text, style) in self.python_rich_rust_highlight(&line_for_highlight, &bg) {
                     line_text.append_styled(&text, style);
                 }
@@ -853,6 +945,160 @@ impl Syntax {
     }
 }
 
+/// Token categories styled from the Console theme.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ThemeToken {
+    Keyword,
+    Function,
+    Type,
+    Operator,
+    Number,
+    Constant,
+    String,
+    Comment,
+    Text,
+}
+
+impl ThemeToken {
+    const ALL: [Self; 9] = [
+        Self::Keyword,
+        Self::Function,
+        Self::Type,
+        Self::Operator,
+        Self::Number,
+        Self::Constant,
+        Self::String,
+        Self::Comment,
+        Self::Text,
+    ];
+
+    const fn style_name(self) -> &'static str {
+        match self {
+            Self::Keyword => "syntax.keyword",
+            Self::Function => "syntax.function",
+            Self::Type => "syntax.type",
+            Self::Operator => "syntax.operator",
+            Self::Number => "syntax.number",
+            Self::Constant => "syntax.constant",
+            Self::String => "syntax.string",
+            Self::Comment => "syntax.comment",
+            Self::Text => "syntax.text",
+        }
+    }
+
+    /// Python Rich default (monokai) foreground for this token.
+    fn default_style(self) -> Style {
+        let (r, g, b) = match self {
+            Self::Keyword => (102, 217, 239),
+            Self::Function | Self::Type => (166, 226, 46),
+            Self::Operator => (255, 70, 137),
+            Self::Number | Self::Constant => (174, 129, 255),
+            Self::String => (230, 219, 116),
+            Self::Comment => (117, 113, 94),
+            Self::Text => (248, 248, 242),
+        };
+        Style::new().color(Color::from_rgb(r, g, b))
+    }
+
+    /// Classify a token by its innermost recognised scope.
+    fn classify(scopes: &[Scope]) -> Self {
+        scopes
+            .iter()
+            .rev()
+            .find_map(|scope| Self::from_scope(&scope.build_string()))
+            .unwrap_or(Self::Text)
+    }
+
+    fn from_scope(scope: &str) -> Option<Self> {
+        let has_prefix = |prefix: &str| {
+            scope == prefix
+                || scope
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        };
+        if has_prefix("comment") {
+            Some(Self::Comment)
+        } else if has_prefix("string") {
+            Some(Self::String)
+        } else if has_prefix("constant.numeric") {
+            Some(Self::Number)
+        } else if has_prefix("constant") {
+            Some(Self::Constant)
+        } else if has_prefix("keyword.operator") {
+            Some(Self::Operator)
+        } else if has_prefix("keyword") || has_prefix("storage") {
+            Some(Self::Keyword)
+        } else if has_prefix("entity.name.function")
+            || has_prefix("support.function")
+            || has_prefix("variable.function")
+        {
+            Some(Self::Function)
+        } else if has_prefix("entity.name")
+            || has_prefix("support.type")
+            || has_prefix("support.class")
+        {
+            Some(Self::Type)
+        } else {
+            None
+        }
+    }
+}
+
+/// Token styles resolved once per render from the Console theme.
+#[derive(Debug)]
+struct ThemePalette {
+    styles: Vec<Style>,
+    background: Style,
+}
+
+impl ThemePalette {
+    fn resolve(resolve: impl Fn(&str) -> Option<Style>) -> Self {
+        let styles = ThemeToken::ALL
+            .iter()
+            .map(|token| resolve(token.style_name()).unwrap_or_else(|| token.default_style()))
+            .collect();
+        let background = resolve("syntax.background").unwrap_or_default();
+        Self { styles, background }
+    }
+
+    fn style(&self, token: ThemeToken) -> &Style {
+        &self.styles[token as usize]
+    }
+}
+
+/// Split a line into text runs tagged with their theme token.
+///
+/// Parser state carries across calls so multi-line strings and comments keep
+/// their scopes. Parse failures yield the whole line as plain text.
+fn scope_tokens<'a>(
+    line: &'a str,
+    parse_state: &mut ParseState,
+    scope_stack: &mut ScopeStack,
+    syntax_set: &SyntaxSet,
+) -> Vec<(&'a str, ThemeToken)> {
+    // The bundled syntaxes expect a trailing newline to close line-scoped regions.
+    let with_newline = format!("{line}\n");
+    let Ok(ops) = parse_state.parse_line(&with_newline, syntax_set) else {
+        return vec![(line, ThemeToken::Text)];
+    };
+
+    let mut out = Vec::new();
+    for (range, op) in ScopeRangeIterator::new(&ops, &with_newline) {
+        if scope_stack.apply(op).is_err() {
+            continue;
+        }
+        let end = range.end.min(line.len());
+        if range.start >= end {
+            continue;
+        }
+        out.push((
+            &line[range.start..end],
+            ThemeToken::classify(scope_stack.as_slice()),
+        ));
+    }
+    out
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum RustTokenKind {
     Keyword,
@@ -1638,4 +1884,72 @@ contexts:
         let plain: String = rendered.iter().map(|s| s.text.as_ref()).collect();
         assert!(plain.contains("hello"));
     }
+
+    #[test]
+    fn test_console_theme_styles_tokens_from_resolver() {
+        let keyword = Style::parse("bold blue").expect("style");
+        let syntax = Syntax::new("let x = \"hi\"; // note", "rust").console_theme(true);
+        let segments = syntax
+            .render_with_theme(None, |name| {
+                (name == "syntax.keyword").then(|| keyword.clone())
+            })
+            .expect("render");
+
+        let style_of = |needle: &str| {
+            segments
+                .iter()
+                .find(|seg| seg.text.contains(needle))
+                .and_then(|seg| seg.style.clone())
+                .expect("segment present")
+        };
+        assert_eq!(style_of("let"), keyword);
+        assert_eq!(style_of("hi"), ThemeToken::String.default_style());
+        assert_eq!(style_of("note"), ThemeToken::Comment.default_style());
+    }
+
+    #[test]
+    fn test_console_theme_background() {
+        let background = Style::parse("on grey11").expect("style");
+        let syntax = Syntax::new("x", "python").console_theme(true).padding(0, 1);
+        let segments = syntax
+            .render_with_theme(Some(5), |name| {
+                (name == "syntax.background").then(|| background.clone())
+            })
+            .expect("render");
+        assert_eq!(segments[0].style.as_ref(), Some(&background));
+
+        let plain = syntax.render(Some(5)).expect("render");
+        assert!(
+            plain[0]
+                .style
+                .as_ref()
+                .is_none_or(|style| style.bgcolor.is_none())
+        );
+    }
+
+    #[test]
+    fn test_theme_token_classification() {
+        assert_eq!(
+            ThemeToken::from_scope("comment.line.double-slash.rust"),
+            Some(ThemeToken::Comment)
+        );
+        assert_eq!(
+            ThemeToken::from_scope("keyword.operator.arithmetic"),
+            Some(ThemeToken::Operator)
+        );
+        assert_eq!(
+            ThemeToken::from_scope("constant.numeric.integer"),
+            Some(ThemeToken::Number)
+        );
+        assert_eq!(
+            ThemeToken::from_scope("entity.name.function.rust"),
+            Some(ThemeToken::Function)
+        );
+        assert_eq!(
+            ThemeToken::from_scope("storage.type.rust"),
+            Some(ThemeToken::Keyword)
+        );
+        assert_eq!(ThemeToken::from_scope("stringly"), None);
+        assert_eq!(ThemeToken::from_scope("source.rust"), None);
+    }
 }
@@ -55,19 +55,31 @@
 //! - `width(n)`: Fixed width in characters
 //! - `min_width(n)`: Minimum width
 //! - `max_width(n)`: Maximum width
+//! - `ratio(n)`: With `Table::expand(true)`, share the width left over by the
+//!   other columns in proportion to `n`, ignoring content width
 //! - `justify(method)`: Left, right, center, or full justification
-//! - `no_wrap`: Disable text wrapping
+//! - `overflow(method)`: Fold (wrap), crop, or ellipsize content wider than the column
+//! - `no_wrap(bool)`: Keep each line of content on one row, cut by `overflow`
 //! - `style(s)`: Apply a style to cell content
+//!
+//! ## Records
+//!
+//! Types implementing [`IntoRow`] can be added with [`Table::add_record`].
+//! With the `json` feature, [`Table::from_serde`] builds a whole table from
+//! a slice of serializable structs, using field names as column headers.
 
-use crate::r#box::{ASCII, BoxChars, HEAVY_HEAD, RowLevel};
+use crate::r#box::{ASCII, BoxChars, HEAVY_HEAD, RowLevel, SIMPLE};
 use crate::cells;
 use crate::console::{Console, ConsoleOptions};
 use crate::markup;
 use crate::renderables::Renderable;
 use crate::segment::{Segment, adjust_line_length};
 use crate::style::Style;
+use crate::sync::lock_recover;
 use crate::text::{JustifyMethod, OverflowMethod, Text};
-use num_rational::Ratio;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
 
 // PaddingDimensions is available but not needed for current implementation
 
@@ -88,10 +100,14 @@ pub enum VerticalAlign {
 pub struct Column {
     /// Header text.
     pub header: Text,
+    /// Second header line shown below the header, e.g. units.
+    pub subheader: Option<Text>,
     /// Footer text.
     pub footer: Text,
     /// Style for header.
     pub header_style: Style,
+    /// Style for the subheader, used instead of the table's header style.
+    pub subheader_style: Style,
     /// Style for footer.
     pub footer_style: Style,
     /// Style for cell content.
@@ -118,8 +134,10 @@ impl Default for Column {
     fn default() -> Self {
         Self {
             header: Text::new(""),
+            subheader: None,
             footer: Text::new(""),
             header_style: Style::new(),
+            subheader_style: Style::new().dim(),
             footer_style: Style::new(),
             style: Style::new(),
             justify: JustifyMethod::Left,
@@ -158,6 +176,23 @@ impl Column {
         self
     }
 
+    /// Set a second header line, rendered below the header (e.g. units).
+    ///
+    /// Tables with any subheader give the header section an extra row; columns
+    /// without one leave it blank.
+    #[must_use]
+    pub fn subheader(mut self, subheader: impl Into<Text>) -> Self {
+        self.subheader = Some(subheader.into());
+        self
+    }
+
+    /// Set the subheader style (default dim).
+    #[must_use]
+    pub fn subheader_style(mut self, style: Style) -> Self {
+        self.subheader_style = style;
+        self
+    }
+
     /// Set footer style.
     #[must_use]
     pub fn footer_style(mut self, style: Style) -> Self {
@@ -180,6 +215,10 @@ impl Column {
     }
 
     /// Set vertical alignment.
+    ///
+    /// Cells with fewer lines than the tallest cell in their row are placed
+    /// at the top, middle, or bottom, and padded with blank lines in the
+    /// cell's style.
     #[must_use]
     pub fn vertical(mut self, align: VerticalAlign) -> Self {
         self.vertical = align;
@@ -207,7 +246,11 @@ impl Column {
         self
     }
 
-    /// Set overflow handling.
+    /// Set how content wider than the column is handled (default
+    /// [`OverflowMethod::Fold`]).
+    ///
+    /// `Crop` and `Ellipsis` cut overflowing lines instead of wrapping them;
+    /// with [`Column::no_wrap`], `Fold` also crops.
     #[must_use]
     pub fn overflow(mut self, overflow: OverflowMethod) -> Self {
         self.overflow = overflow;
@@ -215,25 +258,35 @@ impl Column {
     }
 
     /// Set flex ratio.
+    ///
+    /// When the table expands, columns with a ratio split the width not used
+    /// by the other columns in proportion to their ratios, regardless of their
+    /// content. The fixed `width` (or `min_width`) is the smallest share.
     #[must_use]
     pub fn ratio(mut self, ratio: usize) -> Self {
         self.ratio = Some(ratio);
         self
     }
 
-    /// Disable text wrapping.
+    /// Set whether text wrapping is disabled.
+    ///
+    /// Each line of a `no_wrap` cell stays on one row and is cut to the column
+    /// width by the column's [`overflow`](Column::overflow) method. When the
+    /// table is too wide, wrappable columns are shrunk before `no_wrap` ones.
     #[must_use]
-    pub fn no_wrap(mut self) -> Self {
-        self.no_wrap = true;
+    pub fn no_wrap(mut self, no_wrap: bool) -> Self {
+        self.no_wrap = no_wrap;
         self
     }
 
-    /// Get the header width.
+    /// Get the header width, including the subheader.
     fn header_width(&self) -> usize {
+        let subheader = self.subheader.as_ref().map_or("", Text::plain);
         self.header
             .plain()
             .lines()
-            .map(cells::cell_len)
+            .chain(subheader.lines())
+            .map(cells::cached_cell_len)
             .max()
             .unwrap_or(0)
     }
@@ -243,7 +296,7 @@ impl Column {
         self.footer
             .plain()
             .lines()
-            .map(cells::cell_len)
+            .map(cells::cached_cell_len)
             .max()
             .unwrap_or(0)
     }
@@ -343,6 +396,8 @@ pub struct Row {
     pub style: Style,
     /// Draw separator after this row.
     pub end_section: bool,
+    /// Caller data attached to this row; see [`Table::row_regions`].
+    pub meta: BTreeMap<String, String>,
 }
 
 impl Row {
@@ -356,6 +411,9 @@ impl Row {
     }
 
     /// Set row style.
+    ///
+    /// Applies to every cell in the row, on top of the table's alternating
+    /// [`Table::row_styles`] and under column and cell styles.
     #[must_use]
     pub fn style(mut self, style: Style) -> Self {
         self.style = style;
@@ -368,6 +426,29 @@ impl Row {
         self.end_section = true;
         self
     }
+
+    /// Attach a metadata entry, e.g. the id of the record this row shows.
+    ///
+    /// Metadata does not affect rendering; it is returned with the row's
+    /// position by [`Table::row_regions`].
+    #[must_use]
+    pub fn meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Where a body row landed in a rendered [`Table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowRegion<'a> {
+    /// Index of the row in the table.
+    pub index: usize,
+    /// Rendered lines covered by the row, counted from the table's first line.
+    ///
+    /// Includes the row's vertical padding but not separators or leading.
+    pub lines: Range<usize>,
+    /// The row's [metadata](Row::meta).
+    pub meta: &'a BTreeMap<String, String>,
 }
 
 impl From<Vec<Cell>> for Row {
@@ -376,6 +457,120 @@ impl From<Vec<Cell>> for Row {
     }
 }
 
+/// Conversion of a record into a table [`Row`].
+///
+/// Implement this for your own types to pass them straight to
+/// [`Table::add_record`]:
+///
+/// ```
+/// use rich_rust::renderables::table::{Column, IntoRow, Row, Table};
+///
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// impl IntoRow for &User {
+///     fn into_row(self) -> Row {
+///         Row::new(vec![self.name.clone().into(), self.age.to_string().into()])
+///     }
+/// }
+///
+/// let users = vec![User { name: "Alice".into(), age: 30 }];
+/// let mut table = Table::new().with_columns([Column::new("Name"), Column::new("Age")]);
+/// table.add_records(&users);
+/// ```
+pub trait IntoRow {
+    /// Convert `self` into a row.
+    fn into_row(self) -> Row;
+}
+
+impl IntoRow for Row {
+    fn into_row(self) -> Row {
+        self
+    }
+}
+
+impl<T: Into<Cell>> IntoRow for Vec<T> {
+    fn into_row(self) -> Row {
+        Row::new(self.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Cell>, const N: usize> IntoRow for [T; N] {
+    fn into_row(self) -> Row {
+        Row::new(self.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Row count from which body rows are rendered on several threads.
+const PARALLEL_ROW_THRESHOLD: usize = 1024;
+
+/// Height used for streamed rows when neither the table nor the console gives one.
+const DEFAULT_STREAM_HEIGHT: usize = 24;
+
+/// Lazily pulled rows for [`Table::with_row_source`].
+///
+/// Clones share the same underlying iterator and buffer, so rendering a cloned
+/// table never re-pulls rows.
+#[derive(Clone)]
+struct RowSource {
+    state: Arc<Mutex<RowSourceState>>,
+}
+
+struct RowSourceState {
+    iter: Box<dyn Iterator<Item = Row> + Send>,
+    buffered: Vec<Row>,
+    exhausted: bool,
+}
+
+impl std::fmt::Debug for RowSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = lock_recover(&self.state);
+        f.debug_struct("RowSource")
+            .field("buffered", &state.buffered.len())
+            .field("exhausted", &state.exhausted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RowSource {
+    fn new(iter: impl Iterator<Item = Row> + Send + 'static) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(RowSourceState {
+                iter: Box::new(iter),
+                buffered: Vec::new(),
+                exhausted: false,
+            })),
+        }
+    }
+
+    /// The first `n` rows, pulling from the iterator only as far as needed.
+    ///
+    /// Also returns whether more rows exist and, if known, how many.
+    fn take(&self, n: usize) -> (Vec<Row>, bool, Option<usize>) {
+        let mut state = lock_recover(&self.state);
+        // Pull one extra row to learn whether anything is left.
+        while !state.exhausted && state.buffered.len() <= n {
+            match state.iter.next() {
+                Some(row) => state.buffered.push(row),
+                None => state.exhausted = true,
+            }
+        }
+        let shown = n.min(state.buffered.len());
+        let extra = state.buffered.len() - shown;
+        let remaining = if state.exhausted {
+            Some(extra)
+        } else {
+            match state.iter.size_hint() {
+                (lower, Some(upper)) if lower == upper => Some(extra + lower),
+                _ => None,
+            }
+        };
+        (state.buffered[..shown].to_vec(), extra > 0, remaining)
+    }
+}
+
 /// A data table with columns and rows.
 #[derive(Debug, Clone)]
 pub struct Table {
@@ -391,6 +586,8 @@ pub struct Table {
     width: Option<usize>,
     /// Minimum width.
     min_width: Option<usize>,
+    /// Maximum width.
+    max_width: Option<usize>,
     /// Box style.
     box_style: &'static BoxChars,
     /// Force ASCII boxes.
@@ -431,6 +628,10 @@ pub struct Table {
     title_justify: JustifyMethod,
     /// Caption justification.
     caption_justify: JustifyMethod,
+    /// Lazily pulled rows rendered after `rows`.
+    row_source: Option<RowSource>,
+    /// Maximum rendered height (in lines) when rows come from a row source.
+    max_height: Option<usize>,
 }
 
 impl Default for Table {
@@ -442,6 +643,7 @@ impl Default for Table {
             caption: None,
             width: None,
             min_width: None,
+            max_width: None,
             box_style: &HEAVY_HEAD,
             safe_box: None,
             padding: (1, 0),
@@ -456,12 +658,14 @@ impl Default for Table {
             style: Style::new(),
             row_styles: Vec::new(),
             header_style: Style::new().bold(),
-            footer_style: Style::new(),
+            footer_style: Style::new().bold(),
             border_style: Style::new(),
             title_style: Style::new().italic(),
-            caption_style: Style::new(),
+            caption_style: Style::new().dim().italic(),
             title_justify: JustifyMethod::Center,
             caption_justify: JustifyMethod::Center,
+            row_source: None,
+            max_height: None,
         }
     }
 }
@@ -473,6 +677,35 @@ impl Table {
         Self::default()
     }
 
+    /// Create a borderless grid for laying out content (Python Rich `Table.grid`).
+    ///
+    /// A grid has no header, edges, or cell padding; columns are separated
+    /// by a single space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rich_rust::renderables::table::{Column, Table};
+    ///
+    /// let grid = Table::grid()
+    ///     .with_column(Column::new(""))
+    ///     .with_column(Column::new(""))
+    ///     .with_row_cells(["a", "b"]);
+    /// assert_eq!(grid.render_plain(10), "a b\n");
+    /// ```
+    #[must_use]
+    pub fn grid() -> Self {
+        Self {
+            box_style: &SIMPLE,
+            padding: (0, 0),
+            collapse_padding: true,
+            pad_edge: false,
+            show_header: false,
+            show_edge: false,
+            ..Self::default()
+        }
+    }
+
     /// Add a column to the table.
     pub fn add_column(&mut self, column: Column) {
         self.columns.push(column);
@@ -565,6 +798,96 @@ impl Table {
         self
     }
 
+    /// Add a row from any [`IntoRow`] record.
+    pub fn add_record(&mut self, record: impl IntoRow) {
+        self.rows.push(record.into_row());
+    }
+
+    /// Add a row for each record.
+    pub fn add_records<R: IntoRow>(&mut self, records: impl IntoIterator<Item = R>) {
+        self.rows.extend(records.into_iter().map(IntoRow::into_row));
+    }
+
+    /// Add a row from any [`IntoRow`] record (builder pattern).
+    #[must_use]
+    pub fn with_record(mut self, record: impl IntoRow) -> Self {
+        self.add_record(record);
+        self
+    }
+
+    /// Build a table from serializable records, one row per record.
+    ///
+    /// Each record must serialize as a struct or map. Field names become
+    /// column headers, in the order they are first seen; a record missing a
+    /// field gets an empty cell. Strings are shown without quotes, `null` as
+    /// an empty cell, and nested arrays or objects as compact JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record fails to serialize or is not a struct or
+    /// map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "json")]
+    /// # {
+    /// use rich_rust::renderables::table::Table;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     name: &'static str,
+    ///     age: u32,
+    /// }
+    ///
+    /// let table = Table::from_serde(&[User { name: "Alice", age: 30 }]).unwrap();
+    /// assert!(table.render_plain(40).contains("Alice"));
+    /// # }
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_serde<T: serde::Serialize>(records: &[T]) -> Result<Self, serde_json::Error> {
+        let mut headers: Vec<String> = Vec::new();
+        let mut parsed = Vec::with_capacity(records.len());
+        for record in records {
+            // Round-trip through a JSON string: unlike `serde_json::Value`,
+            // this keeps fields in declaration order.
+            let json = serde_json::to_string(record)?;
+            let SerdeRecord(fields) = serde_json::from_str(&json)?;
+            for (key, _) in &fields {
+                if !headers.contains(key) {
+                    headers.push(key.clone());
+                }
+            }
+            parsed.push(fields);
+        }
+
+        let mut table = Self::new().with_columns(headers.iter().map(|h| Column::new(h.as_str())));
+        for fields in parsed {
+            table.add_record(
+                headers
+                    .iter()
+                    .map(|header| {
+                        fields
+                            .iter()
+                            .find(|(key, _)| key == header)
+                            .map_or_else(String::new, |(_, value)| serde_cell(value))
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        }
+        Ok(table)
+    }
+
+    /// Start a new section: a row separator is drawn before the next row.
+    ///
+    /// Has no effect until the table has at least one row.
+    pub fn add_section(&mut self) {
+        if let Some(row) = self.rows.last_mut() {
+            row.end_section = true;
+        }
+    }
+
     /// Set the title.
     #[must_use]
     pub fn title(mut self, title: impl Into<Text>) -> Self {
@@ -593,6 +916,15 @@ impl Table {
         self
     }
 
+    /// Set maximum width.
+    ///
+    /// The table never renders wider than this; cells wrap to fit.
+    #[must_use]
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
     /// Set the box style.
     #[must_use]
     pub fn box_style(mut self, style: &'static BoxChars) -> Self {
@@ -637,6 +969,9 @@ impl Table {
     }
 
     /// Set whether to expand to fill width.
+    ///
+    /// Extra width goes to `ratio` columns if there are any, otherwise to all
+    /// columns in proportion to their widths.
     #[must_use]
     pub fn expand(mut self, expand: bool) -> Self {
         self.expand = expand;
@@ -692,7 +1027,7 @@ impl Table {
         self
     }
 
-    /// Set caption style.
+    /// Set caption style (default: dim italic).
     #[must_use]
     pub fn caption_style(mut self, style: Style) -> Self {
         self.caption_style = style;
@@ -720,13 +1055,71 @@ impl Table {
         self
     }
 
+    /// Set the style of the footer row (default: bold).
+    ///
+    /// Each column's [`Column::footer_style`] is applied on top of it.
+    #[must_use]
+    pub fn footer_style(mut self, style: Style) -> Self {
+        self.footer_style = style;
+        self
+    }
+
     /// Set alternating row styles.
+    ///
+    /// Row `i` uses `styles[i % styles.len()]`, combined with the row's own
+    /// [`Row::style`] on top.
     #[must_use]
     pub fn row_styles(mut self, styles: Vec<Style>) -> Self {
         self.row_styles = styles;
         self
     }
 
+    /// Set alternating row styles from style definitions, e.g. `["", "dim"]`
+    /// for zebra striping.
+    ///
+    /// Definitions that fail to parse are treated as no style.
+    #[must_use]
+    pub fn row_styles_str<S: AsRef<str>>(self, styles: impl IntoIterator<Item = S>) -> Self {
+        self.row_styles(
+            styles
+                .into_iter()
+                .map(|style| Style::parse(style.as_ref()).unwrap_or_default())
+                .collect(),
+        )
+    }
+
+    /// Stream rows from an iterator, pulling only as many as fit on screen.
+    ///
+    /// Rows are rendered after any rows added directly. The table stops at
+    /// [`max_height`](Self::max_height) lines (or the console height) and ends with
+    /// a `… N more rows` note when rows are left over. `N` is exact once the
+    /// iterator is exhausted or when its `size_hint` is exact; otherwise the note
+    /// reads `… more rows`.
+    ///
+    /// ```
+    /// use rich_rust::renderables::table::{Column, Row, Table};
+    ///
+    /// let rows = (0..1_000_000).map(|i| Row::new(vec![i.to_string().into()]));
+    /// let table = Table::new()
+    ///     .with_column(Column::new("id"))
+    ///     .with_row_source(rows)
+    ///     .max_height(10);
+    /// let plain = table.render_plain(20);
+    /// assert!(plain.contains("more rows"));
+    /// ```
+    #[must_use]
+    pub fn with_row_source(mut self, rows: impl Iterator<Item = Row> + Send + 'static) -> Self {
+        self.row_source = Some(RowSource::new(rows));
+        self
+    }
+
+    /// Limit the rendered height (in lines) of a table with a row source.
+    #[must_use]
+    pub fn max_height(mut self, height: usize) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
     /// Get the effective box characters.
     fn effective_box(&self) -> &'static BoxChars {
         let safe = self.safe_box.unwrap_or(false);
@@ -790,12 +1183,45 @@ impl Table {
             })
             .collect();
 
+        // Flexible columns share whatever the others leave, by ratio (Rich's `flexible` columns).
+        let flexible: Vec<bool> = self
+            .columns
+            .iter()
+            .map(|col| col.ratio.unwrap_or(0) > 0)
+            .collect();
+        if self.expand && flexible.contains(&true) {
+            let fixed_total: usize = widths
+                .iter()
+                .zip(&flexible)
+                .filter(|(_, flex)| !**flex)
+                .map(|(width, _)| *width)
+                .sum();
+            let (ratios, minimums): (Vec<usize>, Vec<usize>) = self
+                .columns
+                .iter()
+                .filter_map(|col| {
+                    let ratio = col.ratio.filter(|ratio| *ratio > 0)?;
+                    Some((ratio, col.width.or(col.min_width).unwrap_or(1).max(1)))
+                })
+                .unzip();
+            let mut flex_widths =
+                ratio_distribute(available.saturating_sub(fixed_total), &ratios, &minimums)
+                    .into_iter();
+            for (width, _) in widths.iter_mut().zip(&flexible).filter(|(_, flex)| **flex) {
+                *width = flex_widths.next().unwrap_or(*width);
+            }
+        }
+
         // Calculate total and adjust if needed
         let mut total: usize = widths.iter().sum();
 
         if total > available {
             // Need to shrink columns
-            widths = self.collapse_widths(&widths, available);
+            // Like Rich, shrink wrappable columns first and cut `no_wrap` ones last.
+            widths = self.collapse_widths(&widths, available, true);
+            if widths.iter().sum::<usize>() > available {
+                widths = self.collapse_widths(&widths, available, false);
+            }
             total = widths.iter().sum();
         }
 
@@ -812,11 +1238,15 @@ impl Table {
         }
 
         if should_expand && total < target_available {
-            // Expand to fill target width
-            if self.columns.iter().any(|col| col.ratio.unwrap_or(0) > 0) {
-                widths = self.expand_widths(&widths, target_available);
-            } else if self.width.is_some() || self.min_width.is_some() {
-                widths = self.expand_widths_by_weights(&widths, target_available);
+            // Pad columns in proportion to their widths to fill the target width
+            let weights: Vec<usize> = if total == 0 {
+                vec![1; widths.len()]
+            } else {
+                widths.clone()
+            };
+            let pads = ratio_distribute(target_available - total, &weights, &[]);
+            for (width, pad) in widths.iter_mut().zip(pads) {
+                *width += pad;
             }
         }
 
@@ -824,7 +1254,14 @@ impl Table {
     }
 
     /// Collapse column widths to fit available space.
-    fn collapse_widths(&self, widths: &[usize], available: usize) -> Vec<usize> {
+    ///
+    /// With `keep_no_wrap`, `no_wrap` columns keep their current width.
+    fn collapse_widths(
+        &self,
+        widths: &[usize],
+        available: usize,
+        keep_no_wrap: bool,
+    ) -> Vec<usize> {
         let total: usize = widths.iter().sum();
         if total <= available {
             return widths.to_vec();
@@ -837,7 +1274,11 @@ impl Table {
         let minimums: Vec<usize> = self
             .columns
             .iter()
-            .map(|col| {
+            .zip(widths)
+            .map(|(col, &width)| {
+                if keep_no_wrap && col.no_wrap {
+                    return width;
+                }
                 let explicit_min = col.min_width.unwrap_or(1);
                 if let Some(fixed) = col.width {
                     fixed.max(explicit_min)
@@ -888,92 +1329,200 @@ impl Table {
         result
     }
 
-    /// Expand column widths to fill available space.
-    fn expand_widths(&self, widths: &[usize], available: usize) -> Vec<usize> {
-        let total: usize = widths.iter().sum();
-        if total >= available {
-            return widths.to_vec();
-        }
-
-        let remaining = available - total;
-        let mut sizes = widths.to_vec();
-
-        let ratios: Vec<usize> = self
-            .columns
-            .iter()
-            .zip(sizes.iter())
-            .map(|(col, &size)| {
-                let ratio = col.ratio.unwrap_or(0);
-                if ratio > 0 && size < available {
-                    ratio
-                } else {
-                    0
-                }
-            })
-            .collect();
+    /// Render the table to segments.
+    #[must_use]
+    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        self.render_within(max_width, None)
+    }
 
-        let total_ratio: usize = ratios.iter().sum();
-        if total_ratio == 0 {
-            return sizes;
+    fn render_within(
+        &self,
+        max_width: usize,
+        available_height: Option<usize>,
+    ) -> Vec<Segment<'static>> {
+        let max_width = self
+            .max_width
+            .map_or(max_width, |limit| limit.min(max_width));
+        match &self.row_source {
+            Some(source) => {
+                let max_height = self
+                    .max_height
+                    .or(available_height)
+                    .unwrap_or(DEFAULT_STREAM_HEIGHT);
+                self.render_streaming(source, max_width, max_height)
+            }
+            None => self.render_rows(max_width),
         }
+    }
 
-        let flexible_count = ratios.iter().filter(|&&r| r > 0).count();
-        let mut distributed = 0;
-        let mut flex_idx = 0;
+    /// Render directly added rows plus as many source rows as fit in `max_height`.
+    fn render_streaming(
+        &self,
+        source: &RowSource,
+        max_width: usize,
+        max_height: usize,
+    ) -> Vec<Segment<'static>> {
+        let mut table = self.clone();
+        table.row_source = None;
+        let fixed_rows = table.rows.len();
+
+        // Reserve a line for the "more rows" note; every row takes at least one line.
+        let budget = max_height.saturating_sub(1);
+        let mut take = budget;
+        loop {
+            let (rows, has_more, remaining) = source.take(take);
+            table.rows.truncate(fixed_rows);
+            table.rows.extend(rows);
+            let mut segments = table.render_rows(max_width);
+            let height: usize = segments.iter().map(|s| s.text.matches('\n').count()).sum();
+            if height > budget && take > 0 {
+                take -= 1;
+                continue;
+            }
 
-        for (i, &ratio) in ratios.iter().enumerate() {
-            if ratio > 0 {
-                flex_idx += 1;
-                let share = Ratio::new(ratio, total_ratio);
-                let extra = if flex_idx == flexible_count {
-                    remaining - distributed
-                } else {
-                    (share * remaining).round().to_integer()
+            if has_more {
+                let note = match remaining {
+                    Some(n) => format!("\u{2026} {n} more rows"),
+                    None => "\u{2026} more rows".to_string(),
                 };
-                sizes[i] = sizes[i].saturating_add(extra);
-                distributed += extra;
+                let note = Text::new(note);
+                let widths = table.calculate_widths(max_width);
+                let width = table
+                    .total_row_width(&widths)
+                    .max(note.cell_len())
+                    .min(max_width);
+                segments.extend(table.render_title_or_caption(
+                    &note,
+                    width,
+                    &Style::new().dim(),
+                    self.caption_justify,
+                ));
+                segments.push(Segment::line());
             }
+            return segments;
         }
-
-        sizes
     }
 
-    /// Expand column widths proportionally to their current sizes.
-    fn expand_widths_by_weights(&self, widths: &[usize], available: usize) -> Vec<usize> {
-        let total: usize = widths.iter().sum();
-        if total >= available {
-            return widths.to_vec();
-        }
+    /// The combined row style and per-cell style overrides for a body row.
+    fn body_row_styles(&self, row_idx: usize, row: &Row) -> (Style, Vec<Option<Style>>) {
+        // Alternating style first, then the row's own style on top (as in Rich).
+        let row_style = if self.row_styles.is_empty() {
+            row.style.clone()
+        } else {
+            self.row_styles[row_idx % self.row_styles.len()].combine(&row.style)
+        };
+        let overrides = (0..self.columns.len())
+            .map(|i| row.cells.get(i).and_then(|cell| cell.style.clone()))
+            .collect();
+        (row_style, overrides)
+    }
 
-        let remaining = available - total;
-        let mut sizes = widths.to_vec();
-        let weights: Vec<usize> = sizes.iter().map(|&size| size.max(1)).collect();
-        let total_weight: usize = weights.iter().sum();
-        if total_weight == 0 {
-            return sizes;
+    /// Render the content lines of one body row, padding missing cells.
+    fn render_body_row(
+        &self,
+        box_chars: &BoxChars,
+        widths: &[usize],
+        row_idx: usize,
+        row: &Row,
+    ) -> Vec<Segment<'static>> {
+        let (row_style, overrides) = self.body_row_styles(row_idx, row);
+        let empty = Text::new("");
+        let cells: Vec<&Text> = (0..self.columns.len())
+            .map(|i| row.cells.get(i).map_or(&empty, |cell| &cell.content))
+            .collect();
+        let col_styles: Vec<&Style> = self.columns.iter().map(|c| &c.style).collect();
+        self.render_row_content(
+            box_chars,
+            widths,
+            &cells,
+            &col_styles,
+            &row_style,
+            &overrides,
+            RowLevel::Row,
+        )
+    }
+
+    /// Render the content lines of every body row.
+    ///
+    /// Rows are independent once column widths are known, so tables with at
+    /// least [`PARALLEL_ROW_THRESHOLD`] rows are split across scoped threads.
+    fn render_body_rows(
+        &self,
+        box_chars: &BoxChars,
+        widths: &[usize],
+    ) -> Vec<Vec<Segment<'static>>> {
+        let threads = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+        if self.rows.len() < PARALLEL_ROW_THRESHOLD || threads < 2 {
+            return self
+                .rows
+                .iter()
+                .enumerate()
+                .map(|(row_idx, row)| self.render_body_row(box_chars, widths, row_idx, row))
+                .collect();
         }
 
-        let mut distributed = 0;
-        let mut weight_idx = 0;
-
-        for (i, &weight) in weights.iter().enumerate() {
-            weight_idx += 1;
-            let share = Ratio::new(weight, total_weight);
-            let extra = if weight_idx == weights.len() {
-                remaining - distributed
-            } else {
-                (share * remaining).round().to_integer()
-            };
-            sizes[i] = sizes[i].saturating_add(extra);
-            distributed += extra;
-        }
+        let chunk_size = self.rows.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .rows
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(chunk_idx, rows)| {
+                    scope.spawn(move || {
+                        rows.iter()
+                            .enumerate()
+                            .map(|(offset, row)| {
+                                let row_idx = chunk_idx * chunk_size + offset;
+                                self.render_body_row(box_chars, widths, row_idx, row)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| match handle.join() {
+                    Ok(rows) => rows,
+                    Err(panic) => std::panic::resume_unwind(panic),
+                })
+                .collect()
+        })
+    }
+
+    /// The lines each directly added body row occupies when rendered at `max_width`.
+    ///
+    /// Use this to map a line of output (e.g. a mouse click) back to the
+    /// row, and through its [metadata](Row::meta) to the record it shows.
+    /// Rows pulled from a [row source](Table::with_row_source) are not included.
+    #[must_use]
+    pub fn row_regions(&self, max_width: usize) -> Vec<RowRegion<'_>> {
+        let max_width = self
+            .max_width
+            .map_or(max_width, |limit| limit.min(max_width));
+        let mut spans = Vec::new();
+        self.layout_rows(max_width, Some(&mut spans));
+        self.rows
+            .iter()
+            .zip(spans)
+            .enumerate()
+            .map(|(index, (row, lines))| RowRegion {
+                index,
+                lines,
+                meta: &row.meta,
+            })
+            .collect()
+    }
 
-        sizes
+    fn render_rows(&self, max_width: usize) -> Vec<Segment<'static>> {
+        self.layout_rows(max_width, None)
     }
 
-    /// Render the table to segments.
-    #[must_use]
-    pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+    /// Render every row, recording each body row's line span into `spans`.
+    fn layout_rows(
+        &self,
+        max_width: usize,
+        mut spans: Option<&mut Vec<Range<usize>>>,
+    ) -> Vec<Segment<'static>> {
         let box_chars = self.effective_box();
         let widths = self.calculate_widths(max_width);
 
@@ -1030,6 +1579,26 @@ impl Table {
                 RowLevel::HeadRow,
             ));
             segments.push(Segment::line());
+            if self.columns.iter().any(|c| c.subheader.is_some()) {
+                let blank = Text::new("");
+                let subheader_cells: Vec<&Text> = self
+                    .columns
+                    .iter()
+                    .map(|c| c.subheader.as_ref().unwrap_or(&blank))
+                    .collect();
+                let subheader_styles: Vec<&Style> =
+                    self.columns.iter().map(|c| &c.subheader_style).collect();
+                segments.extend(self.render_row_content(
+                    box_chars,
+                    &widths,
+                    &subheader_cells,
+                    &subheader_styles,
+                    &Style::new(),
+                    &header_overrides,
+                    RowLevel::HeadRow,
+                ));
+                segments.push(Segment::line());
+            }
             if self.padding.1 > 0 {
                 segments.extend(self.render_leading_lines(
                     box_chars,
@@ -1060,29 +1629,25 @@ impl Table {
             segments.push(Segment::line());
         }
 
-        // Data rows
-        for (row_idx, row) in self.rows.iter().enumerate() {
-            let row_style = if self.row_styles.is_empty() {
-                &row.style
-            } else {
-                &self.row_styles[row_idx % self.row_styles.len()]
-            };
-
-            // Pad cells to match column count
-            let mut cells: Vec<Text> = Vec::with_capacity(self.columns.len());
-            let mut overrides: Vec<Option<Style>> = Vec::with_capacity(self.columns.len());
-            for i in 0..self.columns.len() {
-                if let Some(cell) = row.cells.get(i) {
-                    cells.push(cell.content.clone());
-                    overrides.push(cell.style.clone());
-                } else {
-                    cells.push(Text::new(""));
-                    overrides.push(None);
-                }
-            }
-            let cell_refs: Vec<&Text> = cells.iter().collect();
+        // Data rows: wrap and style every row's cells first (in parallel for
+        // large tables), then lay out separators and padding in order.
+        let col_styles: Vec<&Style> = self.columns.iter().map(|c| &c.style).collect();
+        let row_contents = self.render_body_rows(box_chars, &widths);
+        let mut line = 0;
+        let mut counted = 0;
+        let mut count_lines = |segments: &[Segment<'static>]| {
+            line += segments[counted..]
+                .iter()
+                .map(|s| s.text.matches('\n').count())
+                .sum::<usize>();
+            counted = segments.len();
+            line
+        };
+        for ((row_idx, row), content) in self.rows.iter().enumerate().zip(row_contents) {
+            let (row_style, overrides) = self.body_row_styles(row_idx, row);
+            let row_style = &row_style;
+            let start = count_lines(&segments);
 
-            let col_styles: Vec<&Style> = self.columns.iter().map(|c| &c.style).collect();
             if self.padding.1 > 0 {
                 segments.extend(self.render_leading_lines(
                     box_chars,
@@ -1094,15 +1659,7 @@ impl Table {
                     RowLevel::Row,
                 ));
             }
-            segments.extend(self.render_row_content(
-                box_chars,
-                &widths,
-                &cell_refs,
-                &col_styles,
-                row_style,
-                &overrides,
-                RowLevel::Row,
-            ));
+            segments.extend(content);
             segments.push(Segment::line());
             if self.padding.1 > 0 {
                 segments.extend(self.render_leading_lines(
@@ -1115,6 +1672,9 @@ impl Table {
                     RowLevel::Row,
                 ));
             }
+            if let Some(spans) = spans.as_deref_mut() {
+                spans.push(start..count_lines(&segments));
+            }
 
             let is_last = row_idx == self.rows.len() - 1;
             let has_next_row = row_idx + 1 < self.rows.len() || has_footer;
@@ -1319,10 +1879,10 @@ impl Table {
             let mut cell_text = cell.clone();
             cell_text.set_style(combined_style.clone());
 
-            let overflow = self
+            let (overflow, no_wrap) = self
                 .columns
                 .get(i)
-                .map_or(OverflowMethod::Fold, |c| c.overflow);
+                .map_or((OverflowMethod::Fold, false), |c| (c.overflow, c.no_wrap));
 
             // Handle wrapping/truncation
             cell_text.overflow = overflow;
@@ -1330,7 +1890,20 @@ impl Table {
             // If overflow is Crop/Ellipsis/Ignore, wrap() handles them (returning single line or truncated line)
             // If overflow is Fold, wrap() handles wrapping.
             // Note: wrap() handles explicit newlines via split_lines() internally first.
-            let lines: Vec<Text> = cell_text.wrap(width);
+            let lines: Vec<Text> = if no_wrap {
+                // Keep each line whole, then cut it to the column (Fold crops here).
+                cell_text
+                    .expand_tabs(cell_text.tab_size)
+                    .split_lines()
+                    .into_iter()
+                    .map(|mut line| {
+                        line.truncate(width, overflow, false);
+                        line
+                    })
+                    .collect()
+            } else {
+                cell_text.wrap(width)
+            };
 
             let justify = self
                 .columns
@@ -1364,6 +1937,20 @@ impl Table {
         // But cell_lines_segments usually has at least 1 line (even if empty).
         // Text::wrap always returns at least one line.
 
+        // Blank lines above each cell shorter than the row, from its column's vertical alignment.
+        let top_offsets: Vec<usize> = row_cells_lines
+            .iter()
+            .enumerate()
+            .map(|(i, cell_lines)| {
+                let spare = max_height - cell_lines.len();
+                match self.columns.get(i).map(|c| c.vertical) {
+                    Some(VerticalAlign::Middle) => spare / 2,
+                    Some(VerticalAlign::Bottom) => spare,
+                    Some(VerticalAlign::Top) | None => 0,
+                }
+            })
+            .collect();
+
         for h in 0..max_height {
             // Left edge
             if self.show_edge {
@@ -1395,9 +1982,12 @@ impl Table {
                 }
 
                 // Content
-                if h < cell_lines.len() {
+                if let Some(line) = h
+                    .checked_sub(top_offsets[i])
+                    .and_then(|line| cell_lines.get(line))
+                {
                     // Existing line
-                    segments.extend(cell_lines[h].iter().cloned());
+                    segments.extend(line.iter().cloned());
                 } else {
                     // Empty line (padding for shorter cells)
                     segments.push(Segment::new(
@@ -1489,7 +2079,7 @@ impl Table {
 
         let mut content_text = text.clone();
         if content_text.cell_len() > width {
-            content_text.truncate(width, OverflowMethod::Crop, false);
+            content_text.crop(width);
         }
         if content_text.cell_len() < width {
             content_text.pad(width, justify);
@@ -1553,16 +2143,94 @@ impl Table {
 
 impl Renderable for Table {
     fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        let height = options.height.unwrap_or(options.size.height);
         if self.safe_box.is_some() {
-            return self.render(options.max_width).into_iter().collect();
+            return self
+                .render_within(options.max_width, Some(height))
+                .into_iter()
+                .collect();
         }
 
         // Inherit the Console's safe_box setting unless explicitly overridden.
         let effective = self.clone().safe_box(console.safe_box());
-        effective.render(options.max_width).into_iter().collect()
+        effective
+            .render_within(options.max_width, Some(height))
+            .into_iter()
+            .collect()
+    }
+
+    fn origin(&self) -> Option<&'static str> {
+        Some("table")
+    }
+}
+
+/// One record's fields, in serialization order.
+#[cfg(feature = "json")]
+struct SerdeRecord(Vec<(String, serde_json::Value)>);
+
+#[cfg(feature = "json")]
+impl<'de> serde::Deserialize<'de> for SerdeRecord {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FieldsVisitor {
+            type Value = SerdeRecord;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a struct or map")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut fields = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    fields.push(entry);
+                }
+                Ok(SerdeRecord(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
     }
 }
 
+/// Cell text for a serialized field value.
+#[cfg(feature = "json")]
+fn serde_cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Split `total` into parts proportional to `ratios`, each at least its minimum.
+///
+/// Port of Rich's `ratio_distribute`: shares are rounded up in order, so the
+/// parts sum to `total` unless the minimums exceed it.
+fn ratio_distribute(total: usize, ratios: &[usize], minimums: &[usize]) -> Vec<usize> {
+    let mut total_ratio: usize = ratios.iter().sum();
+    let mut remaining = total;
+    let mut parts = Vec::with_capacity(ratios.len());
+    for (i, &ratio) in ratios.iter().enumerate() {
+        let minimum = minimums.get(i).copied().unwrap_or(0);
+        let part = if total_ratio > 0 {
+            ratio
+                .saturating_mul(remaining)
+                .div_ceil(total_ratio)
+                .max(minimum)
+        } else {
+            remaining
+        };
+        parts.push(part);
+        total_ratio -= ratio;
+        remaining = remaining.saturating_sub(part);
+    }
+    parts
+}
+
 #[cfg(test)]
 #[allow(clippy::similar_names)]
 mod tests {
@@ -1571,6 +2239,17 @@ mod tests {
     use crate::color::Color;
     use crate::style::Attributes;
 
+    #[test]
+    fn test_table_max_width_wraps_cells() {
+        let mut table = Table::new().expand(true).max_width(20);
+        table.add_column(Column::new("Name"));
+        table.add_row_cells(["a rather long cell value"]);
+
+        let plain = table.render_plain(80);
+        assert!(plain.lines().all(|line| cell_len(line) == 20), "{plain}");
+        assert!(plain.contains("a rather"), "{plain}");
+    }
+
     #[test]
     fn test_column_new() {
         let col = Column::new("Name");
@@ -1668,6 +2347,106 @@ mod tests {
         assert_eq!(cell_len(lines[2]), cell_len(lines[1]));
     }
 
+    #[test]
+    fn test_column_subheader_below_header() {
+        let mut table = Table::new()
+            .with_column(Column::new("Latency").subheader("ms"))
+            .with_column(Column::new("Name"))
+            .with_column(Column::new("Load").subheader("requests/s"))
+            .ascii();
+        table.add_row_cells(["12", "api", "300"]);
+
+        let output = table.render_plain(60);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1], "| Latency | Name | Load       |");
+        assert_eq!(lines[2], "| ms      |      | requests/s |");
+        assert!(lines[3].starts_with('|') && lines[3].contains('-'));
+        assert_eq!(lines[4], "| 12      | api  | 300        |");
+
+        let segments = table.render(60);
+        let units = segments
+            .iter()
+            .find(|segment| segment.text.contains("ms"))
+            .expect("subheader segment");
+        assert_eq!(units.style, Some(Style::new().dim()));
+    }
+
+    #[test]
+    fn test_row_style_and_meta_regions() {
+        let error = Style::new().fg("red");
+        let mut table = Table::new()
+            .with_column(Column::new("Job"))
+            .ascii()
+            .show_lines(true);
+        table.add_row(Row::new(vec!["build".into()]).meta("id", "1"));
+        table.add_row(
+            Row::new(vec!["deploy\nfailed".into()])
+                .style(error.clone())
+                .meta("id", "2"),
+        );
+
+        let regions = table.row_regions(40);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].lines, 3..4);
+        assert_eq!(regions[1].lines, 5..7);
+        assert_eq!(regions[1].meta.get("id").map(String::as_str), Some("2"));
+
+        let plain = table.render_plain(40);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[3], "| build  |");
+        assert_eq!(lines[5], "| deploy |");
+        assert_eq!(lines[6], "| failed |");
+
+        let segments = table.render(40);
+        let failed = segments
+            .iter()
+            .find(|segment| segment.text.contains("failed"))
+            .expect("failed cell");
+        assert_eq!(failed.style, Some(error));
+    }
+
+    #[test]
+    fn test_table_footer_inside_frame_and_caption_below() {
+        let mut table = Table::new()
+            .with_column(Column::new("Item").footer("Total"))
+            .with_column(Column::new("Qty").footer("3"))
+            .ascii()
+            .show_footer(true)
+            .caption("Stock");
+        table.add_row_cells(["apple", "3"]);
+
+        let segments = table.render(40);
+        let footer = segments
+            .iter()
+            .find(|segment| segment.text.contains("Total"))
+            .expect("footer segment");
+        assert_eq!(footer.style, Some(Style::new().bold()));
+        let caption = segments
+            .iter()
+            .find(|segment| segment.text.contains("Stock"))
+            .expect("caption segment");
+        assert_eq!(caption.style, Some(Style::new().dim().italic()));
+
+        let output = table.render_plain(40);
+        let lines: Vec<&str> = output.lines().collect();
+        let footer_idx = lines
+            .iter()
+            .position(|line| line.contains("Total"))
+            .unwrap();
+        assert!(lines[footer_idx].starts_with('|'));
+        assert!(lines[footer_idx - 1].starts_with('|') && lines[footer_idx - 1].contains('-'));
+        assert!(lines[footer_idx + 1].starts_with('+'));
+        assert_eq!(lines[footer_idx + 2].trim(), "Stock");
+
+        let plain = table.footer_style(Style::new()).caption_style(Style::new());
+        let segments = plain.render(40);
+        let footer = segments
+            .iter()
+            .find(|segment| segment.text.contains("Total"))
+            .expect("footer segment");
+        assert_eq!(footer.style, Some(Style::new()));
+    }
+
     #[test]
     fn test_table_vertical_padding_header_body_footer() {
         let mut table = Table::new()
@@ -1740,6 +2519,78 @@ mod tests {
         assert!(text.contains("Alice"));
     }
 
+    #[test]
+    fn test_row_source_pulls_only_visible_rows() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&pulled);
+        let rows = (0..1_000_000).map(move |i| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Row::new(vec![Cell::new(format!("row {i}"))])
+        });
+        let table = Table::new()
+            .with_column(Column::new("Name"))
+            .with_row_source(rows)
+            .max_height(10);
+
+        let plain = table.render_plain(30);
+        let lines: Vec<&str> = plain.lines().collect();
+        assert!(lines.len() <= 10, "{plain}");
+        assert!(plain.contains("row 0"));
+        // Top border, header, separator, bottom border and the note leave 5 rows.
+        assert!(
+            plain.contains("row 4") && !plain.contains("row 5"),
+            "{plain}"
+        );
+        assert!(
+            lines.last().unwrap().contains("\u{2026} 999995 more rows"),
+            "{plain}"
+        );
+        assert!(pulled.load(Ordering::Relaxed) < 20);
+    }
+
+    #[test]
+    fn test_row_source_unknown_remaining() {
+        let rows = (0..100)
+            .filter(|i| i % 2 == 0)
+            .map(|i| Row::new(vec![Cell::new(i.to_string())]));
+        let table = Table::new()
+            .with_column(Column::new("N"))
+            .with_row_source(rows)
+            .max_height(6);
+        let plain = table.render_plain(20);
+        assert!(plain.contains("\u{2026} more rows"), "{plain}");
+    }
+
+    #[test]
+    fn test_row_source_fits_without_note() {
+        let rows = (0..3).map(|i| Row::new(vec![Cell::new(i.to_string())]));
+        let table = Table::new()
+            .with_column(Column::new("N"))
+            .with_row_source(rows)
+            .max_height(20);
+        let plain = table.render_plain(20);
+        assert!(!plain.contains("more rows"), "{plain}");
+        assert_eq!(plain.lines().count(), 7);
+        // Rendering again reuses the buffered rows.
+        assert_eq!(table.render_plain(20), plain);
+    }
+
+    #[test]
+    fn test_row_source_uses_console_height() {
+        let rows = (0..100).map(|i| Row::new(vec![Cell::new(i.to_string())]));
+        let table = Table::new()
+            .with_column(Column::new("N"))
+            .with_row_source(rows);
+        let console = Console::builder().width(20).height(8).build();
+        let options = console.options();
+        let segments = Renderable::render(&table, &console, &options);
+        let plain: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert!(plain.lines().count() <= 8, "{plain}");
+        assert!(plain.contains("more rows"));
+    }
+
     #[test]
     fn test_table_with_title() {
         let mut table = Table::new()
@@ -1869,6 +2720,60 @@ mod tests {
         assert_eq!(col.vertical, VerticalAlign::Middle);
     }
 
+    #[test]
+    fn test_vertical_align_positions_short_cells() {
+        let mut table = Table::new()
+            .with_column(Column::new("T"))
+            .with_column(Column::new("M").vertical(VerticalAlign::Middle))
+            .with_column(Column::new("B").vertical(VerticalAlign::Bottom))
+            .with_column(Column::new("Tall"))
+            .show_header(false)
+            .show_edge(false);
+        table.add_row_cells(["t", "m", "b", "1\n2\n3"]);
+
+        let output = table.render_plain(40);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            [" t │   │   │ 1", "   │ m │   │ 2", "   │   │ b │ 3"]
+        );
+    }
+
+    #[test]
+    fn test_vertical_align_pads_with_cell_style() {
+        let style = Style::new().on("blue");
+        let mut table = Table::new()
+            .with_column(Column::new("A").vertical(VerticalAlign::Bottom))
+            .with_column(Column::new("B"))
+            .show_header(false);
+        table.add_row(Row::new(vec![
+            Cell::new("x").style(style.clone()),
+            Cell::new("1\n2"),
+        ]));
+
+        let segments = table.render(20);
+        // Lines: top border, the padded blank line, the content line, bottom border.
+        let lines: Vec<Vec<&Segment<'_>>> = segments
+            .split(|s| s.text == "\n")
+            .map(|line| line.iter().collect())
+            .collect();
+        let plain =
+            |line: &[&Segment<'_>]| line.iter().map(|s| s.text.as_ref()).collect::<String>();
+        assert!(!plain(&lines[1]).contains('x'));
+        assert!(plain(&lines[2]).contains('x'));
+        assert!(
+            lines[1]
+                .iter()
+                .filter(|s| s
+                    .style
+                    .as_ref()
+                    .is_some_and(|st| st.bgcolor == style.bgcolor))
+                .count()
+                >= 3,
+            "padding, blank content, and padding share the cell style"
+        );
+    }
+
     #[test]
     fn test_cell_from_string() {
         let cell: Cell = "Hello".into();
@@ -1881,6 +2786,89 @@ mod tests {
         assert!(row.end_section);
     }
 
+    #[test]
+    fn test_ratio_distribute_matches_rich() {
+        assert_eq!(ratio_distribute(10, &[1, 1], &[]), vec![5, 5]);
+        assert_eq!(ratio_distribute(45, &[1, 1], &[]), vec![23, 22]);
+        assert_eq!(ratio_distribute(96, &[7, 13, 23], &[]), vec![16, 29, 51]);
+        assert_eq!(ratio_distribute(10, &[1, 9], &[4, 1]), vec![4, 6]);
+    }
+
+    #[test]
+    fn test_table_ratio_columns_ignore_content_width() {
+        let mut table = Table::new()
+            .expand(true)
+            .ascii()
+            .padding(0, 0)
+            .with_column(Column::new("Narrow").ratio(1))
+            .with_column(Column::new("W").ratio(1));
+        table.add_row_cells(["a much longer value", "b"]);
+
+        let output = table.render_plain(23);
+        let top = output.lines().next().expect("top border");
+        assert_eq!(top, "+----------+----------+");
+    }
+
+    #[test]
+    fn test_row_style_combines_with_alternating_styles() {
+        let mut table = Table::new()
+            .with_column(Column::new("X").style(Style::new().italic()))
+            .row_styles_str(["", "dim"])
+            .show_header(false);
+        table.add_row_cells(["a"]);
+        table.add_row(Row::new(vec![Cell::new("b")]).style(Style::parse("red").unwrap()));
+        table.add_row(Row::new(vec![Cell::new("c").style(Style::new().bold())]));
+
+        let segments = table.render(20);
+        let style_of = |needle: &str| {
+            segments
+                .iter()
+                .find(|seg| seg.text.contains(needle))
+                .and_then(|seg| seg.style.clone())
+                .expect("cell segment")
+        };
+
+        let a = style_of("a");
+        assert!(!a.attributes.contains(Attributes::DIM));
+        assert!(a.attributes.contains(Attributes::ITALIC));
+
+        let b = style_of("b");
+        assert!(b.attributes.contains(Attributes::DIM));
+        assert!(b.attributes.contains(Attributes::ITALIC));
+        assert_eq!(b.color, Some(Color::parse("red").unwrap()));
+
+        let c = style_of("c");
+        assert!(!c.attributes.contains(Attributes::DIM));
+        assert!(c.attributes.contains(Attributes::BOLD));
+        assert!(c.attributes.contains(Attributes::ITALIC));
+    }
+
+    #[test]
+    fn test_table_add_section() {
+        let mut table = Table::new()
+            .with_column(Column::new("X"))
+            .ascii()
+            .show_header(false);
+
+        table.add_section();
+        table.add_row_cells(["1"]);
+        table.add_row_cells(["2"]);
+        table.add_section();
+        table.add_row_cells(["3"]);
+        table.add_section();
+
+        let output = table.render_plain(20);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 6, "{output}");
+        assert!(lines[1].contains('1'));
+        assert!(lines[2].contains('2'));
+        assert!(lines[3].contains('-'));
+        assert!(lines[4].contains('3'));
+        assert!(!table.rows[0].end_section);
+        assert!(table.rows[1].end_section);
+    }
+
     #[test]
     fn test_table_cell_style_applies_with_column_style() {
         let red = Style::new().color(Color::parse("red").unwrap());
@@ -2306,4 +3294,151 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_add_record_accepts_rows_vecs_and_arrays() {
+        let mut table = Table::new().with_columns([Column::new("A"), Column::new("B")]);
+        table.add_record(["1", "2"]);
+        table.add_record(vec!["3", "4"]);
+        table.add_records([Row::new(vec![Cell::new("5"), Cell::new("6")])]);
+        let table = table.with_record(["7", "8"]);
+
+        let plain: Vec<String> = table
+            .rows
+            .iter()
+            .map(|r| r.cells[1].content.plain().to_string())
+            .collect();
+        assert_eq!(plain, ["2", "4", "6", "8"]);
+    }
+
+    #[test]
+    fn test_add_record_custom_impl() {
+        struct Point(i32, i32);
+        impl IntoRow for &Point {
+            fn into_row(self) -> Row {
+                [self.0.to_string(), self.1.to_string()].into_row()
+            }
+        }
+
+        let points = [Point(1, 2), Point(3, 4)];
+        let mut table = Table::new().with_columns([Column::new("X"), Column::new("Y")]);
+        table.add_records(&points);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[1].cells[0].content.plain(), "3");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_serde_uses_field_order() {
+        #[derive(serde::Serialize)]
+        struct Service {
+            name: &'static str,
+            port: u16,
+            tags: Vec<&'static str>,
+            owner: Option<&'static str>,
+        }
+
+        let table = Table::from_serde(&[
+            Service {
+                name: "web",
+                port: 80,
+                tags: vec!["edge"],
+                owner: None,
+            },
+            Service {
+                name: "db",
+                port: 5432,
+                tags: vec![],
+                owner: Some("ops"),
+            },
+        ])
+        .expect("records serialize");
+
+        let headers: Vec<&str> = table.columns.iter().map(|c| c.header.plain()).collect();
+        assert_eq!(headers, ["name", "port", "tags", "owner"]);
+        let first: Vec<&str> = table.rows[0]
+            .cells
+            .iter()
+            .map(|c| c.content.plain())
+            .collect();
+        assert_eq!(first, ["web", "80", "[\"edge\"]", ""]);
+        assert_eq!(table.rows[1].cells[3].content.plain(), "ops");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_serde_unions_map_keys() {
+        use std::collections::BTreeMap;
+
+        let a = BTreeMap::from([("x", 1)]);
+        let b = BTreeMap::from([("y", 2)]);
+        let table = Table::from_serde(&[a, b]).expect("maps serialize");
+
+        assert_eq!(table.columns.len(), 2);
+        assert_eq!(table.rows[0].cells[1].content.plain(), "");
+        assert_eq!(table.rows[1].cells[1].content.plain(), "2");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_serde_rejects_non_records() {
+        assert!(Table::from_serde(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_column_no_wrap_truncates_with_overflow() {
+        let render = |column: Column| {
+            let mut table = Table::new().with_column(column).ascii();
+            table.add_row_cells(["alpha beta gamma"]);
+            table.render_plain(80)
+        };
+
+        let cropped = render(Column::new("H").width(8).no_wrap(true));
+        assert!(cropped.contains("| alpha be |"), "{cropped}");
+        assert!(!cropped.contains("gamma"), "{cropped}");
+
+        let ellipsis = render(
+            Column::new("H")
+                .width(8)
+                .no_wrap(true)
+                .overflow(OverflowMethod::Ellipsis),
+        );
+        assert!(ellipsis.contains("| alpha... |"), "{ellipsis}");
+
+        let wrapped = render(Column::new("H").width(8));
+        assert!(wrapped.contains("| gamma    |"), "{wrapped}");
+    }
+
+    #[test]
+    fn test_no_wrap_columns_shrink_last() {
+        let mut table = Table::new()
+            .with_column(Column::new("id").no_wrap(true))
+            .with_column(Column::new("description"));
+        table.add_row_cells(["abcdefghij", "some long wrapping description text"]);
+
+        let widths = table.calculate_widths(30);
+        assert_eq!(widths[0], 10);
+        assert!(widths.iter().sum::<usize>() <= 30 - 7);
+    }
+
+    #[test]
+    fn test_parallel_body_rows_match_sequential() {
+        let mut table = Table::new()
+            .with_columns([Column::new("N"), Column::new("Text").max_width(12)])
+            .row_styles(vec![Style::new(), Style::new().dim()]);
+        for i in 0..PARALLEL_ROW_THRESHOLD + 37 {
+            table.add_row_cells([i.to_string(), format!("row {i} wraps over lines")]);
+        }
+
+        let box_chars = table.effective_box();
+        let widths = table.calculate_widths(40);
+        let contents = table.render_body_rows(box_chars, &widths);
+        assert_eq!(contents.len(), table.rows.len());
+        for (i, row) in table.rows.iter().enumerate() {
+            assert_eq!(
+                contents[i],
+                table.render_body_row(box_chars, &widths, i, row)
+            );
+        }
+    }
 }
@@ -19,6 +19,10 @@
 //! let traceback = Traceback::capture("MyError", "something went wrong");
 //! console.print_exception(&traceback);
 //! ```
+//!
+//! Captured tracebacks start with a header naming the thread (and, with the
+//! `tokio` feature, the async task) they were captured on, so crashes in
+//! multi-threaded programs can be attributed at a glance.
 
 use crate::console::{Console, ConsoleOptions};
 use crate::markup;
@@ -118,6 +122,8 @@ pub struct Traceback {
     title: Text,
     extra_lines: usize,
     show_locals: bool,
+    thread: Option<String>,
+    task: Option<String>,
 }
 
 impl Traceback {
@@ -137,6 +143,8 @@ impl Traceback {
             ),
             extra_lines: 0,
             show_locals: false,
+            thread: None,
+            task: None,
         }
     }
 
@@ -159,6 +167,40 @@ impl Traceback {
         self
     }
 
+    /// Name the thread the error happened on, shown in the header line.
+    #[must_use]
+    pub fn thread(mut self, thread: impl Into<String>) -> Self {
+        self.thread = Some(thread.into());
+        self
+    }
+
+    /// Record the calling thread's name and id, e.g. `'worker-2' (ThreadId(3))`.
+    #[must_use]
+    pub fn current_thread(self) -> Self {
+        let current = std::thread::current();
+        let name = current.name().unwrap_or("<unnamed>");
+        self.thread(format!("'{name}' ({:?})", current.id()))
+    }
+
+    /// Identify the async task the error happened in, shown in the header line.
+    #[must_use]
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// Record the id of the Tokio task this is called from, if any.
+    ///
+    /// Requires the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn current_task(self) -> Self {
+        match tokio::task::try_id() {
+            Some(id) => self.task(id.to_string()),
+            None => self,
+        }
+    }
+
     /// Push a frame (builder-style).
     pub fn push_frame(&mut self, frame: TracebackFrame) {
         self.frames.push(frame);
@@ -167,7 +209,8 @@ impl Traceback {
     /// Capture the current call stack and create a traceback.
     ///
     /// This is the primary way to create a Traceback from an actual runtime
-    /// error. It captures the current backtrace and converts it to frames.
+    /// error. It captures the current backtrace and converts it to frames,
+    /// and records the current thread (and Tokio task) for the header.
     ///
     /// # Arguments
     /// * `exception_type` - The type/name of the exception (e.g., `PanicError`)
@@ -190,10 +233,24 @@ impl Traceback {
         Self::from_backtrace(&bt, exception_type, exception_message)
     }
 
+    /// Record the current thread and, with the `tokio` feature, the current task.
+    #[cfg(feature = "backtrace")]
+    fn with_current_context(self) -> Self {
+        #[cfg(feature = "tokio")]
+        {
+            self.current_thread().current_task()
+        }
+        #[cfg(not(feature = "tokio"))]
+        {
+            self.current_thread()
+        }
+    }
+
     /// Create a Traceback from an existing `backtrace::Backtrace`.
     ///
     /// This is useful when you have a backtrace from a panic handler or
-    /// error type that provides its own backtrace.
+    /// error type that provides its own backtrace. The header names the
+    /// thread (and Tokio task) this is called on.
     ///
     /// # Arguments
     /// * `bt` - The backtrace to convert
@@ -209,7 +266,7 @@ impl Traceback {
         exception_message: impl Into<String>,
     ) -> Self {
         let frames = Self::parse_backtrace(bt);
-        Self::new(frames, exception_type, exception_message)
+        Self::new(frames, exception_type, exception_message).with_current_context()
     }
 
     /// Parse a backtrace into `TracebackFrame` list.
@@ -355,6 +412,24 @@ impl Renderable for Traceback {
         let exception_msg_style = None;
 
         let mut content_lines: Vec<Vec<Segment<'static>>> = Vec::new();
+        if self.thread.is_some() || self.task.is_some() {
+            let label_style = Style::parse("dim").ok();
+            let value_style = Style::parse("bold magenta").ok();
+            let mut header = Vec::new();
+            if let Some(thread) = &self.thread {
+                header.push(Segment::new("thread ", label_style.clone()));
+                header.push(Segment::new(thread.clone(), value_style.clone()));
+            }
+            if let Some(task) = &self.task {
+                if !header.is_empty() {
+                    header.push(Segment::new(", ", label_style.clone()));
+                }
+                header.push(Segment::new("task ", label_style));
+                header.push(Segment::new(task.clone(), value_style));
+            }
+            content_lines.push(header);
+            content_lines.push(vec![Segment::new(String::new(), None)]);
+        }
         for frame in &self.frames {
             // Try to get source: first from provided context, then from filesystem
             let source_result = self.get_frame_source(frame);
@@ -1008,6 +1083,41 @@ mod tests {
         assert!(output.contains("quotes"));
     }
 
+    #[test]
+    fn test_render_thread_and_task_header() {
+        let frame = TracebackFrame::new("handler", 7);
+        let traceback = Traceback::new(vec![frame], "Error", "boom")
+            .thread("'worker-1' (ThreadId(4))")
+            .task("12");
+
+        let output = render_to_text(&traceback, 80);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(
+            lines[1].contains("thread 'worker-1' (ThreadId(4)), task 12"),
+            "{output}"
+        );
+        assert!(lines[3].contains("in handler:7"), "{output}");
+
+        let plain = render_to_text(&Traceback::new(Vec::new(), "Error", "boom"), 80);
+        assert!(!plain.contains("thread "));
+    }
+
+    #[test]
+    fn test_current_thread_uses_thread_name() {
+        let traceback = std::thread::Builder::new()
+            .name("crash-site".to_string())
+            .spawn(|| Traceback::new(Vec::new(), "Error", "boom").current_thread())
+            .expect("spawn")
+            .join()
+            .expect("join");
+
+        let output = render_to_text(&traceback, 80);
+        assert!(
+            output.contains("thread 'crash-site' (ThreadId("),
+            "{output}"
+        );
+    }
+
     #[cfg(feature = "backtrace")]
     mod backtrace_tests {
         use super::*;
@@ -1137,5 +1247,17 @@ mod tests {
             assert_eq!(traceback.exception_type, "BTError");
             assert_eq!(traceback.exception_message, "from backtrace");
         }
+
+        #[test]
+        fn capture_records_current_thread() {
+            let traceback = Traceback::capture("Error", "test");
+            let current = std::thread::current();
+            let expected = format!(
+                "'{}' ({:?})",
+                current.name().unwrap_or("<unnamed>"),
+                current.id()
+            );
+            assert_eq!(traceback.thread.as_deref(), Some(expected.as_str()));
+        }
     }
 }
@@ -3,9 +3,17 @@
 //! This module provides tree components for displaying hierarchical data
 //! in the terminal with configurable guide characters and styles.
 
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cells;
 use crate::console::{Console, ConsoleOptions};
+use crate::filesize;
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{self, Segment};
 use crate::style::Style;
 use crate::text::Text;
 
@@ -68,13 +76,24 @@ impl TreeGuides {
     }
 }
 
+/// Builds a node's children on demand (see [`TreeNode::with_children_fn`]).
+type ChildrenFn = Arc<dyn Fn() -> Vec<TreeNode> + Send + Sync>;
+
 /// A node in the tree.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TreeNode {
     /// The label for this node.
     label: Text,
+    /// Renderable shown instead of the label (a table, panel, ...).
+    renderable: Option<Arc<dyn Renderable + Send + Sync>>,
+    /// Style of this node's label, inherited by its children.
+    style: Style,
+    /// Style of the guides to this node's children, inherited by their children.
+    guide_style: Option<Style>,
     /// Child nodes.
     children: Vec<TreeNode>,
+    /// Builds further children each time the expanded node is rendered.
+    children_fn: Option<ChildrenFn>,
     /// Whether this node is expanded (children visible).
     expanded: bool,
     /// Optional icon to display before the label.
@@ -83,6 +102,28 @@ pub struct TreeNode {
     icon_style: Style,
 }
 
+impl std::fmt::Debug for TreeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TreeNode")
+            .field("label", &self.label)
+            .field(
+                "renderable",
+                &self.renderable.as_ref().map(|_| "<dyn Renderable>"),
+            )
+            .field("style", &self.style)
+            .field("guide_style", &self.guide_style)
+            .field("children", &self.children)
+            .field(
+                "children_fn",
+                &self.children_fn.as_ref().map(|_| "<Fn() -> Vec<TreeNode>>"),
+            )
+            .field("expanded", &self.expanded)
+            .field("icon", &self.icon)
+            .field("icon_style", &self.icon_style)
+            .finish()
+    }
+}
+
 impl TreeNode {
     /// Create a new tree node with a label.
     ///
@@ -93,7 +134,11 @@ impl TreeNode {
     pub fn new(label: impl Into<Text>) -> Self {
         Self {
             label: label.into(),
+            renderable: None,
+            style: Style::new(),
+            guide_style: None,
             children: Vec::new(),
+            children_fn: None,
             expanded: true,
             icon: None,
             icon_style: Style::new(),
@@ -107,13 +152,18 @@ impl TreeNode {
     /// [`crate::markup::render_or_plain`]).
     #[must_use]
     pub fn with_icon(icon: impl Into<String>, label: impl Into<Text>) -> Self {
-        Self {
-            label: label.into(),
-            children: Vec::new(),
-            expanded: true,
-            icon: Some(icon.into()),
-            icon_style: Style::new(),
-        }
+        Self::new(label).icon(icon)
+    }
+
+    /// Create a node whose label is any renderable (e.g. a table).
+    ///
+    /// The renderable is laid out in the width left after the guides, and
+    /// every line it produces is indented under the node's branch.
+    #[must_use]
+    pub fn from_renderable(renderable: impl Renderable + Send + Sync + 'static) -> Self {
+        let mut node = Self::new("");
+        node.renderable = Some(Arc::new(renderable));
+        node
     }
 
     /// Add a child node.
@@ -130,6 +180,22 @@ impl TreeNode {
         self
     }
 
+    /// Create a node whose children are built on demand.
+    ///
+    /// `children` is called each time the node is rendered while expanded
+    /// and within the tree's maximum depth, so huge hierarchies (such as a
+    /// file system) are only walked as far as they are shown. Its nodes
+    /// follow any added with [`Self::child`].
+    #[must_use]
+    pub fn with_children_fn<F>(label: impl Into<Text>, children: F) -> Self
+    where
+        F: Fn() -> Vec<TreeNode> + Send + Sync + 'static,
+    {
+        let mut node = Self::new(label);
+        node.children_fn = Some(Arc::new(children));
+        node
+    }
+
     /// Set the icon for this node.
     #[must_use]
     pub fn icon(mut self, icon: impl Into<String>) -> Self {
@@ -144,6 +210,22 @@ impl TreeNode {
         self
     }
 
+    /// Set the style of this node's label; children inherit it.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the guides leading to this node's children.
+    ///
+    /// Combined over the tree's guide style and inherited by descendants.
+    #[must_use]
+    pub fn guide_style(mut self, style: Style) -> Self {
+        self.guide_style = Some(style);
+        self
+    }
+
     /// Set whether this node is expanded.
     #[must_use]
     pub fn expanded(mut self, expanded: bool) -> Self {
@@ -151,7 +233,7 @@ impl TreeNode {
         self
     }
 
-    /// Collapse this node (hide children).
+    /// Collapse this node: its children are hidden and it is marked with `+`.
     #[must_use]
     pub fn collapsed(self) -> Self {
         self.expanded(false)
@@ -163,6 +245,12 @@ impl TreeNode {
         &self.label
     }
 
+    /// Check if this node's label is a renderable rather than text.
+    #[must_use]
+    pub fn has_renderable(&self) -> bool {
+        self.renderable.is_some()
+    }
+
     /// Get the children.
     #[must_use]
     pub fn children_nodes(&self) -> &[TreeNode] {
@@ -170,9 +258,53 @@ impl TreeNode {
     }
 
     /// Check if this node has children.
+    ///
+    /// Always true for nodes with a children callback, which isn't called here.
     #[must_use]
     pub fn has_children(&self) -> bool {
-        !self.children.is_empty()
+        !self.children.is_empty() || self.children_fn.is_some()
+    }
+
+    /// The children to render: the added ones followed by the callback's.
+    fn resolved_children(&self) -> Cow<'_, [TreeNode]> {
+        match &self.children_fn {
+            None => Cow::Borrowed(&self.children),
+            Some(children_fn) => {
+                let mut children = self.children.clone();
+                children.extend(children_fn());
+                Cow::Owned(children)
+            }
+        }
+    }
+
+    /// A copy of this node keeping only descendants that match `predicate`
+    /// or lead to one, or `None` if neither this node nor any descendant matches.
+    ///
+    /// Kept ancestors are expanded; matching labels are styled with `highlight`.
+    fn filtered<F>(&self, predicate: &F, highlight: Option<&Style>) -> Option<TreeNode>
+    where
+        F: Fn(&TreeNode) -> bool,
+    {
+        let children: Vec<TreeNode> = self
+            .resolved_children()
+            .iter()
+            .filter_map(|child| child.filtered(predicate, highlight))
+            .collect();
+        let matched = predicate(self);
+        if !matched && children.is_empty() {
+            return None;
+        }
+
+        let mut node = self.clone();
+        node.children_fn = None;
+        if !children.is_empty() {
+            node.expanded = true;
+        }
+        node.children = children;
+        if matched && let Some(style) = highlight {
+            node.label.stylize_all(style.clone());
+        }
+        Some(node)
     }
 
     /// Check if this node is expanded.
@@ -195,14 +327,18 @@ pub struct Tree {
     root: TreeNode,
     /// Guide style.
     guides: TreeGuides,
-    /// Style for the guide characters.
-    guide_style: Style,
+    /// Style for the guide characters (None = the theme's `tree.line`).
+    guide_style: Option<Style>,
     /// Whether to show the root node.
     show_root: bool,
     /// Style for highlighted nodes.
     highlight_style: Option<Style>,
     /// Maximum depth to display (-1 for unlimited).
     max_depth: isize,
+    /// Fixed width (None = natural width).
+    width: Option<usize>,
+    /// Maximum width (None = unlimited).
+    max_width: Option<usize>,
 }
 
 impl Default for Tree {
@@ -210,10 +346,12 @@ impl Default for Tree {
         Self {
             root: TreeNode::new("root"),
             guides: TreeGuides::default(),
-            guide_style: Style::new(),
+            guide_style: None,
             show_root: true,
             highlight_style: None,
             max_depth: -1,
+            width: None,
+            max_width: None,
         }
     }
 }
@@ -246,9 +384,12 @@ impl Tree {
     }
 
     /// Set the style for guide characters.
+    ///
+    /// Defaults to the console theme's `tree.line` style when rendered
+    /// through a console.
     #[must_use]
     pub fn guide_style(mut self, style: Style) -> Self {
-        self.guide_style = style;
+        self.guide_style = Some(style);
         self
     }
 
@@ -279,6 +420,22 @@ impl Tree {
         self
     }
 
+    /// Set a fixed width.
+    ///
+    /// Labels wrap to fit and every line is padded to `width`.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set a maximum width; labels wrap to fit.
+    #[must_use]
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
     /// Add a child node to the root.
     #[must_use]
     pub fn child(mut self, node: TreeNode) -> Self {
@@ -293,20 +450,141 @@ impl Tree {
         self
     }
 
+    /// A pruned copy keeping the nodes that match `predicate` and their ancestors.
+    ///
+    /// The root is always kept. Ancestors of matches are expanded so every
+    /// match is visible; children built on demand are resolved while searching.
+    ///
+    /// ```
+    /// use rich_rust::renderables::tree::{Tree, TreeNode};
+    ///
+    /// let tree = Tree::with_label("src")
+    ///     .child(TreeNode::new("main.rs"))
+    ///     .child(TreeNode::new("renderables").child(TreeNode::new("tree.rs")));
+    /// let found = tree.filter(|node| node.label().plain().contains("tree"));
+    /// assert!(!found.render_plain().contains("main.rs"));
+    /// ```
+    #[must_use]
+    pub fn filter<F>(&self, predicate: F) -> Self
+    where
+        F: Fn(&TreeNode) -> bool,
+    {
+        self.filter_inner(&predicate, None)
+    }
+
+    /// Like [`Self::filter`], also styling the labels of matching nodes with `style`.
+    #[must_use]
+    pub fn filter_highlighted<F>(&self, predicate: F, style: &Style) -> Self
+    where
+        F: Fn(&TreeNode) -> bool,
+    {
+        self.filter_inner(&predicate, Some(style))
+    }
+
+    fn filter_inner<F>(&self, predicate: &F, highlight: Option<&Style>) -> Self
+    where
+        F: Fn(&TreeNode) -> bool,
+    {
+        let root = self.root.filtered(predicate, highlight).unwrap_or_else(|| {
+            let mut root = self.root.clone();
+            root.children = Vec::new();
+            root.children_fn = None;
+            root
+        });
+        Self {
+            root,
+            guides: self.guides,
+            guide_style: self.guide_style.clone(),
+            show_root: self.show_root,
+            highlight_style: self.highlight_style.clone(),
+            max_depth: self.max_depth,
+            width: self.width,
+            max_width: self.max_width,
+        }
+    }
+
+    /// Build a tree of the files under the directory `path`.
+    ///
+    /// Directories are bold and files are annotated with their size; see
+    /// [`FileTreeOptions`] for filtering, depth, and sorting. Subdirectories
+    /// that can't be read are shown without children.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` itself can't be read as a directory.
+    pub fn from_path(path: impl AsRef<Path>, options: &FileTreeOptions) -> io::Result<Self> {
+        let path = path.as_ref();
+        let name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        let root = directory_node(name, options).children(directory_children(path, options, 1)?);
+        Ok(Self::new(root))
+    }
+
     /// Render the tree to segments.
+    ///
+    /// Only [`Self::width`] and [`Self::max_width`] limit the width here;
+    /// renderable labels are laid out with a default console.
     #[must_use]
     pub fn render(&self) -> Vec<Segment<'_>> {
+        let console = Console::new();
+        let style = TreeStyle {
+            label: Style::new(),
+            guide: self.guide_style.clone().unwrap_or_default(),
+        };
+        self.render_within(&console, &console.options(), None, &style)
+    }
+
+    /// Render the tree within `available` cells (None = unlimited).
+    fn render_within(
+        &self,
+        console: &Console,
+        options: &ConsoleOptions,
+        available: Option<usize>,
+        style: &TreeStyle,
+    ) -> Vec<Segment<'_>> {
+        let limit = [self.width, self.max_width, available]
+            .into_iter()
+            .flatten()
+            .min();
+        let layout = Layout {
+            limit,
+            pad: self.width.and(limit),
+        };
+        let context = RenderContext {
+            console,
+            options,
+            layout,
+        };
         let mut segments = Vec::new();
-        let prefix_stack: Vec<bool> = Vec::new();
+        let prefix_stack: Vec<(bool, Style)> = Vec::new();
 
         if self.show_root {
-            self.render_node(&self.root, &mut segments, &prefix_stack, true, 0);
+            self.render_node(
+                &self.root,
+                &mut segments,
+                &prefix_stack,
+                true,
+                0,
+                style,
+                &context,
+            );
         } else {
             // Render children directly
-            let children = &self.root.children;
+            let style = style.descend(&self.root);
+            let children = self.root.resolved_children();
             for (i, child) in children.iter().enumerate() {
                 let is_last = i == children.len() - 1;
-                self.render_node(child, &mut segments, &prefix_stack, is_last, 0);
+                self.render_node(
+                    child,
+                    &mut segments,
+                    &prefix_stack,
+                    is_last,
+                    0,
+                    &style,
+                    &context,
+                );
             }
         }
 
@@ -331,18 +609,65 @@ impl Tree {
         sanitized
     }
 
+    /// Lay out a node's label as lines within `width` cells (None = unlimited).
+    fn label_lines(
+        node: &TreeNode,
+        width: Option<usize>,
+        context: &RenderContext<'_>,
+    ) -> Vec<Vec<Segment<'static>>> {
+        if let Some(renderable) = &node.renderable {
+            let width = width.unwrap_or(context.options.max_width).max(1);
+            let options = context.options.update_width(width);
+            let mut lines = segment::split_lines(
+                renderable
+                    .render(context.console, &options)
+                    .into_iter()
+                    .map(Segment::into_owned),
+            );
+            if lines.len() > 1 && lines.last().is_some_and(Vec::is_empty) {
+                lines.pop();
+            }
+            return lines;
+        }
+
+        // Sanitize label newlines to avoid broken tree line structure.
+        let label_text = Self::sanitize_label(&node.label);
+        let texts = match width {
+            Some(width) => label_text.wrap(width),
+            None => vec![label_text],
+        };
+        texts
+            .into_iter()
+            .map(|line| {
+                line.render("")
+                    .into_iter()
+                    .map(Segment::into_owned)
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Render a single node and its children recursively.
+    ///
+    /// `style` holds the label style inherited from the ancestors and the
+    /// guide style of this node's own branch.
     #[expect(
         clippy::cast_possible_wrap,
         reason = "tree depth will never exceed isize::MAX"
     )]
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "recursive layout state; grouping it would only add indirection"
+    )]
     fn render_node<'a>(
         &'a self,
-        node: &'a TreeNode,
+        node: &TreeNode,
         segments: &mut Vec<Segment<'a>>,
-        prefix_stack: &[bool],
+        prefix_stack: &[(bool, Style)],
         is_last: bool,
         depth: usize,
+        style: &TreeStyle,
+        context: &RenderContext<'_>,
     ) {
         // Check depth limit
         if self.max_depth >= 0 && depth as isize > self.max_depth {
@@ -350,73 +675,110 @@ impl Tree {
         }
 
         // Build the prefix (guides from ancestors)
-        for &has_more_siblings in prefix_stack {
-            let guide = if has_more_siblings {
+        let mut prefix = Vec::new();
+        for (has_more_siblings, guide_style) in prefix_stack {
+            let guide = if *has_more_siblings {
                 self.guides.vertical()
             } else {
                 self.guides.space()
             };
-            segments.push(Segment::new(guide, Some(self.guide_style.clone())));
+            prefix.push(Segment::new(guide, Some(guide_style.clone())));
         }
+        // Wrapped label lines continue under the branch guide.
+        let mut continuation = prefix.clone();
 
         // Add the branch guide for this node (if not root at depth 0)
         if depth > 0 || !self.show_root {
-            let guide = if is_last {
-                self.guides.last()
+            let (guide, continue_guide) = if is_last {
+                (self.guides.last(), self.guides.space())
             } else {
-                self.guides.branch()
+                (self.guides.branch(), self.guides.vertical())
             };
-            segments.push(Segment::new(guide, Some(self.guide_style.clone())));
+            prefix.push(Segment::new(guide, Some(style.guide.clone())));
+            continuation.push(Segment::new(continue_guide, Some(style.guide.clone())));
         }
 
         // Add icon if present
         if let Some(icon) = node.get_icon() {
-            segments.push(Segment::new(
-                format!("{icon} "),
-                Some(node.icon_style.clone()),
-            ));
+            let icon = format!("{icon} ");
+            continuation.push(Segment::new(" ".repeat(cells::cell_len(&icon)), None));
+            prefix.push(Segment::new(icon, Some(node.icon_style.clone())));
         }
 
-        // Sanitize label newlines to avoid broken tree line structure.
-        let label_text = Self::sanitize_label(&node.label);
+        let collapsed = node.has_children() && !node.is_expanded();
+        if collapsed {
+            continuation.push(Segment::new("  ", None));
+            prefix.push(Segment::new("+ ", Some(style.guide.clone())));
+        }
 
-        let mut label_segments: Vec<Segment<'static>> = label_text
-            .render("")
-            .into_iter()
-            .map(Segment::into_owned)
-            .collect();
-        if let Some(ref highlight) = self.highlight_style {
-            for segment in &mut label_segments {
+        let label_width = context
+            .layout
+            .limit
+            .map(|limit| limit.saturating_sub(segment::line_length(&prefix)).max(1));
+        let label_lines = Self::label_lines(node, label_width, context);
+        let label_style = style.label.combine(&node.style);
+
+        let line_count = label_lines.len();
+        for (index, line) in label_lines.into_iter().enumerate() {
+            let start = segments.len();
+            if index == 0 {
+                segments.append(&mut prefix);
+            } else {
+                segments.extend(continuation.iter().cloned());
+            }
+
+            for mut segment in line {
                 if !segment.is_control() {
-                    segment.style = Some(match segment.style.take() {
-                        Some(existing) => existing.combine(highlight),
-                        None => highlight.clone(),
-                    });
+                    if !label_style.is_null() {
+                        segment.style = Some(match segment.style.take() {
+                            Some(existing) => label_style.combine(&existing),
+                            None => label_style.clone(),
+                        });
+                    }
+                    if let Some(ref highlight) = self.highlight_style {
+                        segment.style = Some(match segment.style.take() {
+                            Some(existing) => existing.combine(highlight),
+                            None => highlight.clone(),
+                        });
+                    }
                 }
+                segments.push(segment);
             }
-        }
-        for segment in label_segments {
-            segments.push(segment);
-        }
 
-        // Add collapse indicator if has children but collapsed
-        if node.has_children() && !node.is_expanded() {
-            segments.push(Segment::new(" [...]", Some(self.guide_style.clone())));
-        }
+            // Add collapse indicator if has children but collapsed
+            if index + 1 == line_count && collapsed {
+                segments.push(Segment::new(" [...]", Some(style.guide.clone())));
+            }
 
-        segments.push(Segment::line());
+            if let Some(width) = context.layout.pad {
+                let used = segment::line_length(&segments[start..]);
+                if used < width {
+                    segments.push(Segment::new(" ".repeat(width - used), None));
+                }
+            }
+            segments.push(Segment::line());
+        }
 
-        // Render children if expanded
-        if node.is_expanded() {
-            let children = &node.children;
+        // Render children if expanded (and not cut off by the depth limit)
+        if node.is_expanded() && (self.max_depth < 0 || (depth as isize) < self.max_depth) {
+            let children = node.resolved_children();
             let mut new_prefix_stack = prefix_stack.to_vec();
             if !(self.show_root && depth == 0) {
-                new_prefix_stack.push(!is_last);
+                new_prefix_stack.push((!is_last, style.guide.clone()));
             }
 
+            let child_style = style.descend(node);
             for (i, child) in children.iter().enumerate() {
                 let child_is_last = i == children.len() - 1;
-                self.render_node(child, segments, &new_prefix_stack, child_is_last, depth + 1);
+                self.render_node(
+                    child,
+                    segments,
+                    &new_prefix_stack,
+                    child_is_last,
+                    depth + 1,
+                    &child_style,
+                    context,
+                );
             }
         }
     }
@@ -431,9 +793,54 @@ impl Tree {
     }
 }
 
+/// Width constraints applied while rendering a [`Tree`].
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    /// Width labels wrap within, including guides.
+    limit: Option<usize>,
+    /// Width every line is padded to.
+    pad: Option<usize>,
+}
+
+/// Everything shared by every node while rendering a [`Tree`].
+struct RenderContext<'c> {
+    console: &'c Console,
+    options: &'c ConsoleOptions,
+    layout: Layout,
+}
+
+/// Styles inherited down the tree.
+#[derive(Debug, Clone)]
+struct TreeStyle {
+    /// Base style of labels.
+    label: Style,
+    /// Style of the guides to the current node.
+    guide: Style,
+}
+
+impl TreeStyle {
+    /// The styles `node` hands down to its children.
+    fn descend(&self, node: &TreeNode) -> Self {
+        Self {
+            label: self.label.combine(&node.style),
+            guide: match &node.guide_style {
+                Some(guide) => self.guide.combine(guide),
+                None => self.guide.clone(),
+            },
+        }
+    }
+}
+
 impl Renderable for Tree {
-    fn render<'a>(&'a self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment<'a>> {
-        self.render()
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        let style = TreeStyle {
+            label: console.get_style("tree"),
+            guide: self
+                .guide_style
+                .clone()
+                .unwrap_or_else(|| console.get_style("tree.line")),
+        };
+        self.render_within(console, options, Some(options.max_width), &style)
     }
 }
 
@@ -452,6 +859,119 @@ pub fn file_tree(root: &str, entries: &[(&str, bool)]) -> Tree {
     Tree::new(root_node)
 }
 
+/// How [`Tree::from_path`] orders the entries of each directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FileTreeSort {
+    /// Directories before files, each by case-insensitive name (default).
+    #[default]
+    DirsFirst,
+    /// Case-insensitive name only.
+    Name,
+    /// The order the file system returns.
+    Unsorted,
+}
+
+/// Options for [`Tree::from_path`].
+#[derive(Debug, Clone)]
+pub struct FileTreeOptions {
+    /// Include entries whose name starts with `.` (default `false`).
+    pub show_hidden: bool,
+    /// Deepest level of entries to include, 1 being the directory's own
+    /// entries (None = unlimited).
+    pub max_depth: Option<usize>,
+    /// Annotate files with their size in decimal units (default `true`).
+    pub show_size: bool,
+    /// Prefix entries with folder and file icons (default `true`).
+    pub icons: bool,
+    /// Entry order within each directory.
+    pub sort: FileTreeSort,
+}
+
+impl Default for FileTreeOptions {
+    fn default() -> Self {
+        Self {
+            show_hidden: false,
+            max_depth: None,
+            show_size: true,
+            icons: true,
+            sort: FileTreeSort::default(),
+        }
+    }
+}
+
+/// A directory entry collected by [`directory_children`].
+struct FileEntry {
+    name: String,
+    is_dir: bool,
+    size: u64,
+}
+
+fn directory_node(name: String, options: &FileTreeOptions) -> TreeNode {
+    let node = TreeNode::new(Text::styled(name, Style::new().bold()));
+    if options.icons {
+        node.icon("📂")
+    } else {
+        node
+    }
+}
+
+/// Nodes for the entries of `path`, which sit at `depth` below the root.
+fn directory_children(
+    path: &Path,
+    options: &FileTreeOptions,
+    depth: usize,
+) -> io::Result<Vec<TreeNode>> {
+    if options.max_depth.is_some_and(|max| depth > max) {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !options.show_hidden && name.starts_with('.') {
+            continue;
+        }
+        let is_dir = entry.file_type()?.is_dir();
+        let size = if is_dir { 0 } else { entry.metadata()?.len() };
+        entries.push(FileEntry { name, is_dir, size });
+    }
+
+    match options.sort {
+        FileTreeSort::DirsFirst => entries.sort_by(|a, b| {
+            b.is_dir
+                .cmp(&a.is_dir)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        }),
+        FileTreeSort::Name => entries.sort_by_key(|entry| entry.name.to_lowercase()),
+        FileTreeSort::Unsorted => {}
+    }
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| {
+            if entry.is_dir {
+                let children = directory_children(&path.join(&entry.name), options, depth + 1)
+                    .unwrap_or_default();
+                return directory_node(entry.name, options).children(children);
+            }
+            let mut label = Text::new(entry.name);
+            if options.show_size {
+                label.append_styled(
+                    &format!(" ({})", filesize::decimal(entry.size)),
+                    Style::new().dim(),
+                );
+            }
+            let node = TreeNode::new(label);
+            if options.icons {
+                node.icon("📄")
+            } else {
+                node
+            }
+        })
+        .collect())
+}
+
 /// Create an ASCII-style tree.
 #[must_use]
 pub fn ascii_tree(root: TreeNode) -> Tree {
@@ -474,6 +994,103 @@ pub fn bold_tree(root: TreeNode) -> Tree {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tree_max_width_wraps_labels() {
+        let tree = Tree::with_label("root")
+            .child(TreeNode::new("alpha beta gamma"))
+            .child(TreeNode::new("delta"))
+            .max_width(12);
+        assert_eq!(
+            tree.render_plain(),
+            "root\n├── alpha \n│   beta \n│   gamma\n└── delta\n"
+        );
+    }
+
+    #[test]
+    fn test_tree_width_pads_lines() {
+        let tree = Tree::with_label("root")
+            .child(TreeNode::new("leaf"))
+            .width(10);
+        for line in tree.render_plain().lines() {
+            assert_eq!(cells::cell_len(line), 10, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_tree_guide_style_from_theme() {
+        use crate::theme::Theme;
+
+        let console = Console::builder().width(20).build();
+        console.push_theme(
+            Theme::from_style_definitions([("tree.line", "red")], true).unwrap(),
+            true,
+        );
+        let red = Style::parse("red").unwrap();
+        let tree = Tree::with_label("root").child(TreeNode::new("leaf"));
+        let segments = Renderable::render(&tree, &console, &console.options());
+        let guide = segments.iter().find(|seg| seg.text.contains('\u{2514}'));
+        assert_eq!(guide.and_then(|seg| seg.style.as_ref()), Some(&red));
+
+        let blue = Style::parse("blue").unwrap();
+        let tree = tree.guide_style(blue.clone());
+        let segments = Renderable::render(&tree, &console, &console.options());
+        let guide = segments.iter().find(|seg| seg.text.contains('\u{2514}'));
+        assert_eq!(guide.and_then(|seg| seg.style.as_ref()), Some(&blue));
+    }
+
+    #[test]
+    fn test_tree_node_style_and_guide_style_inherit() {
+        use crate::style::Attributes;
+
+        let green = Style::parse("green").unwrap();
+        let tree = Tree::with_label("root").child(
+            TreeNode::new("branch")
+                .style(Style::new().bold())
+                .guide_style(green.clone())
+                .child(TreeNode::new("leaf")),
+        );
+        let segments = tree.render();
+        let style_of = |text: &str| {
+            segments
+                .iter()
+                .find(|seg| seg.text.contains(text))
+                .and_then(|seg| seg.style.clone())
+                .unwrap_or_default()
+        };
+        assert!(style_of("branch").attributes.contains(Attributes::BOLD));
+        assert!(style_of("leaf").attributes.contains(Attributes::BOLD));
+        assert!(!style_of("root").attributes.contains(Attributes::BOLD));
+        // The branch's guide style draws the guides to its children only.
+        assert_eq!(style_of("\u{2514}\u{2500}\u{2500} ").color, None);
+        let leaf_guide = segments
+            .iter()
+            .filter(|seg| seg.text.contains('\u{2514}'))
+            .nth(1)
+            .and_then(|seg| seg.style.clone());
+        assert_eq!(leaf_guide, Some(green));
+    }
+
+    #[test]
+    fn test_tree_renderable_label() {
+        use crate::renderables::Panel;
+
+        let tree = Tree::with_label("root")
+            .child(TreeNode::from_renderable(
+                Panel::from_text("hi").expand(false),
+            ))
+            .child(TreeNode::new("after"));
+        let console = Console::builder().width(30).build();
+        let plain: String = Renderable::render(&tree, &console, &console.options())
+            .iter()
+            .map(|seg| seg.text.as_ref())
+            .collect();
+        assert_eq!(
+            plain,
+            "root\n├── ╭────╮\n│   │ hi │\n│   ╰────╯\n└── after\n"
+        );
+        assert!(tree.root.children_nodes()[0].has_renderable());
+    }
+
     #[test]
     fn test_tree_node_new() {
         let node = TreeNode::new("test");
@@ -623,6 +1240,50 @@ mod tests {
         assert!(!plain.contains("hidden"));
     }
 
+    #[test]
+    fn test_tree_collapsed_marker() {
+        let tree = Tree::with_label("root")
+            .child(TreeNode::new("open").child(TreeNode::new("a")))
+            .child(
+                TreeNode::new("shut")
+                    .expanded(false)
+                    .child(TreeNode::new("b")),
+            );
+        assert_eq!(
+            tree.render_plain(),
+            "root\n├── open\n│   └── a\n└── + shut [...]\n"
+        );
+    }
+
+    #[test]
+    fn test_tree_lazy_children() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let lazy = move |name: &str| {
+            let counter = Arc::clone(&counter);
+            TreeNode::with_children_fn(name.to_string(), move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                vec![TreeNode::new("x"), TreeNode::new("y")]
+            })
+        };
+
+        let tree = Tree::with_label("root")
+            .child(lazy("dir").child(TreeNode::new("static")))
+            .child(lazy("closed").collapsed());
+        assert_eq!(
+            tree.render_plain(),
+            "root\n├── dir\n│   ├── static\n│   ├── x\n│   └── y\n└── + closed [...]\n"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Nodes beyond the depth limit never build their children.
+        let shallow = Tree::with_label("root").max_depth(1).child(lazy("dir"));
+        assert_eq!(shallow.render_plain(), "root\n└── dir\n");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_tree_max_depth() {
         let tree = Tree::with_label("root")
@@ -656,6 +1317,49 @@ mod tests {
         assert!(plain.contains("README.md"));
     }
 
+    #[test]
+    fn test_tree_from_path() {
+        let root = std::env::temp_dir().join(format!("rich_rust_tree_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("src/bin/tool.rs"), "").unwrap();
+        fs::write(root.join("README.md"), "x".repeat(1500)).unwrap();
+        fs::write(root.join("build.rs"), "").unwrap();
+        fs::write(root.join(".env"), "").unwrap();
+
+        let options = FileTreeOptions {
+            icons: false,
+            ..FileTreeOptions::default()
+        };
+        let name = root.file_name().unwrap().to_string_lossy().into_owned();
+        let plain = Tree::from_path(&root, &options).unwrap().render_plain();
+        assert_eq!(
+            plain,
+            format!(
+                "{name}\n├── src\n│   ├── bin\n│   │   └── tool.rs (0 bytes)\n\
+                 │   └── main.rs (12 bytes)\n├── build.rs (0 bytes)\n\
+                 └── README.md (1.5 kB)\n"
+            )
+        );
+
+        let options = FileTreeOptions {
+            show_hidden: true,
+            max_depth: Some(1),
+            show_size: false,
+            icons: false,
+            sort: FileTreeSort::Name,
+        };
+        let plain = Tree::from_path(&root, &options).unwrap().render_plain();
+        assert_eq!(
+            plain,
+            format!("{name}\n├── .env\n├── build.rs\n├── README.md\n└── src\n")
+        );
+
+        assert!(Tree::from_path(root.join("missing"), &options).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
     #[test]
     fn test_file_tree() {
         let tree = file_tree("project", &[("src", true), ("Cargo.toml", false)]);
@@ -713,6 +1417,52 @@ mod tests {
         assert!(!plain.contains("└──"));
     }
 
+    #[test]
+    fn test_tree_filter_keeps_matches_and_ancestors() {
+        let tree = Tree::with_label("project")
+            .guides(TreeGuides::Ascii)
+            .child(
+                TreeNode::new("src")
+                    .collapsed()
+                    .child(TreeNode::new("main.rs"))
+                    .child(TreeNode::new("lib.rs")),
+            )
+            .child(TreeNode::new("docs").child(TreeNode::new("guide.md")))
+            .child(TreeNode::with_children_fn("tests", || {
+                vec![TreeNode::new("lib_tests.rs"), TreeNode::new("smoke.rs")]
+            }));
+
+        let found = tree.filter(|node| node.label().plain().starts_with("lib"));
+        let plain = found.render_plain();
+        assert!(plain.contains("src"), "{plain}");
+        assert!(plain.contains("lib.rs"), "{plain}");
+        assert!(plain.contains("lib_tests.rs"), "{plain}");
+        assert!(!plain.contains("main.rs"), "{plain}");
+        assert!(!plain.contains("docs"), "{plain}");
+        assert!(!plain.contains("smoke.rs"), "{plain}");
+        assert!(!plain.contains("[...]"), "ancestors are expanded: {plain}");
+
+        let none = tree.filter(|_| false).render_plain();
+        assert_eq!(none.trim_end(), "project");
+    }
+
+    #[test]
+    fn test_tree_filter_highlighted_styles_matches() {
+        let mark = Style::new().reverse();
+        let tree = Tree::with_label("root")
+            .child(TreeNode::new("alpha"))
+            .child(TreeNode::new("beta"))
+            .filter_highlighted(|node| node.label().plain() == "alpha", &mark);
+
+        let segments = tree.render();
+        let alpha = segments
+            .iter()
+            .find(|s| s.text.contains("alpha"))
+            .expect("alpha");
+        assert!(alpha.style.as_ref().is_some_and(|s| s.combine(&mark) == *s));
+        assert!(!segments.iter().any(|s| s.text.contains("beta")));
+    }
+
     #[test]
     fn test_tree_wide_unicode_labels() {
         // Test with CJK characters (each is 2 cells wide)
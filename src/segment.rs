@@ -3,7 +3,7 @@
 //! A `Segment` is a piece of text with a single style applied. The rendering
 //! pipeline produces streams of segments that are then written to the terminal.
 
-use crate::cells::cell_len;
+use crate::cells::{cell_len, get_character_cell_size};
 use crate::style::Style;
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -111,6 +111,14 @@ impl ControlCode {
 /// A segment represents a piece of text with a single, consistent style.
 /// The rendering pipeline breaks down complex renderables into segments
 /// for output.
+///
+/// `text` is a public `Cow<'a, str>`, so renderables can borrow from their
+/// input and callers can match on and mutate it directly. Owned segments
+/// (`Segment<'static>`) therefore own their text, and cloning one copies it.
+/// Console recording keeps its text shared instead, so exports borrow from
+/// the recording; only [`Console::end_capture`] copies it out.
+///
+/// [`Console::end_capture`]: crate::console::Console::end_capture
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Segment<'a> {
     /// The text content.
@@ -433,6 +441,25 @@ pub fn simplify<'a>(segments: impl Iterator<Item = Segment<'a>>) -> Vec<Segment<
     result
 }
 
+/// Append styled characters as segments, merging runs that share a style.
+pub(crate) fn push_runs(
+    segments: &mut Vec<Segment<'static>>,
+    cells: impl IntoIterator<Item = (char, Option<Style>)>,
+) {
+    let mut run = String::new();
+    let mut run_style: Option<Style> = None;
+    for (ch, style) in cells {
+        if style != run_style && !run.is_empty() {
+            segments.push(Segment::new(std::mem::take(&mut run), run_style.take()));
+        }
+        run_style = style;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        segments.push(Segment::new(run, run_style));
+    }
+}
+
 /// Divide segments at specified cell positions.
 #[must_use]
 pub fn divide<'a>(segments: Vec<Segment<'a>>, cuts: &[usize]) -> Vec<Vec<Segment<'a>>> {
@@ -612,12 +639,255 @@ pub fn line_length(line: &[Segment]) -> usize {
     line.iter().map(Segment::cell_length).sum()
 }
 
+/// One cell of the virtual screen used by [`apply_control_codes`].
+#[derive(Debug, Clone)]
+enum ScreenCell {
+    /// Never written, or erased.
+    Blank,
+    /// A character (plus any zero-width characters after it).
+    Char(String, Option<Style>),
+    /// The right half of a double-width character.
+    Wide,
+}
+
+/// A grid of cells with a cursor, enough to replay recorded terminal output.
+#[derive(Debug, Default)]
+struct Screen {
+    lines: Vec<Vec<ScreenCell>>,
+    row: usize,
+    col: usize,
+}
+
+impl Screen {
+    fn line_mut(&mut self) -> &mut Vec<ScreenCell> {
+        if self.lines.len() <= self.row {
+            self.lines.resize_with(self.row + 1, Vec::new);
+        }
+        &mut self.lines[self.row]
+    }
+
+    /// Blank one cell, along with the other half of a wide character it belongs to.
+    fn clear_cell(line: &mut [ScreenCell], index: usize) {
+        match line.get(index) {
+            Some(ScreenCell::Wide) if index > 0 => line[index - 1] = ScreenCell::Blank,
+            Some(ScreenCell::Char(..)) if matches!(line.get(index + 1), Some(ScreenCell::Wide)) => {
+                line[index + 1] = ScreenCell::Blank;
+            }
+            _ => {}
+        }
+        if let Some(cell) = line.get_mut(index) {
+            *cell = ScreenCell::Blank;
+        }
+    }
+
+    fn put(&mut self, c: char, style: Option<&Style>) {
+        let width = get_character_cell_size(c);
+        let col = self.col;
+        let line = self.line_mut();
+        if width == 0 {
+            // Combining marks join the character before the cursor.
+            let end = col.min(line.len());
+            let previous = line[..end]
+                .iter_mut()
+                .rev()
+                .find(|cell| !matches!(cell, ScreenCell::Wide));
+            if let Some(ScreenCell::Char(text, _)) = previous {
+                text.push(c);
+            }
+            return;
+        }
+        if line.len() < col + width {
+            line.resize(col + width, ScreenCell::Blank);
+        }
+        for index in col..col + width {
+            Self::clear_cell(line, index);
+        }
+        line[col] = ScreenCell::Char(c.to_string(), style.cloned());
+        if width == 2 {
+            line[col + 1] = ScreenCell::Wide;
+        }
+        self.col += width;
+    }
+
+    fn erase_in_line(&mut self, mode: i32) {
+        let col = self.col;
+        let line = self.line_mut();
+        match mode {
+            0 => {
+                for index in col..line.len() {
+                    Self::clear_cell(line, index);
+                }
+            }
+            1 => {
+                for index in 0..=col.min(line.len().saturating_sub(1)) {
+                    Self::clear_cell(line, index);
+                }
+            }
+            _ => line.clear(),
+        }
+    }
+
+    fn apply(&mut self, control: &ControlCode) {
+        let param = |index: usize, default: usize| {
+            control
+                .params
+                .get(index)
+                .and_then(|value| usize::try_from(*value).ok())
+                .filter(|value| *value > 0)
+                .unwrap_or(default)
+        };
+        match control.control_type {
+            ControlType::CarriageReturn => self.col = 0,
+            ControlType::Home => (self.row, self.col) = (0, 0),
+            ControlType::Clear => self.lines.clear(),
+            ControlType::CursorUp => self.row = self.row.saturating_sub(param(0, 1)),
+            ControlType::CursorDown => self.row += param(0, 1),
+            ControlType::CursorForward => self.col += param(0, 1),
+            ControlType::CursorBackward => self.col = self.col.saturating_sub(param(0, 1)),
+            ControlType::CursorMoveToColumn => self.col = param(0, 0),
+            ControlType::CursorMoveTo => (self.col, self.row) = (param(0, 0), param(1, 0)),
+            ControlType::EraseInLine => {
+                let mode = control.params.first().copied().unwrap_or(2);
+                self.erase_in_line(mode);
+            }
+            ControlType::Bell
+            | ControlType::ShowCursor
+            | ControlType::HideCursor
+            | ControlType::EnableAltScreen
+            | ControlType::DisableAltScreen
+            | ControlType::SetWindowTitle => {}
+        }
+    }
+
+    fn into_segments(self) -> Vec<Segment<'static>> {
+        let rows = self.lines.len().max(self.row);
+        let mut segments: Vec<Segment<'static>> = Vec::new();
+        for index in 0..rows {
+            let line = self.lines.get(index).map_or(&[][..], Vec::as_slice);
+            let end = line
+                .iter()
+                .rposition(|cell| !matches!(cell, ScreenCell::Blank))
+                .map_or(0, |last| last + 1);
+            for cell in &line[..end] {
+                let (text, style) = match cell {
+                    ScreenCell::Blank => (" ", None),
+                    ScreenCell::Char(text, style) => (text.as_str(), style.as_ref()),
+                    ScreenCell::Wide => continue,
+                };
+                match segments.last_mut() {
+                    Some(last) if !last.text.ends_with('\n') && last.style.as_ref() == style => {
+                        last.text.to_mut().push_str(text);
+                    }
+                    _ => segments.push(Segment::new(text.to_string(), style.cloned())),
+                }
+            }
+            // Lines above the cursor or above other output were ended by the terminal.
+            if index + 1 < self.lines.len() || index < self.row {
+                segments.push(Segment::line());
+            }
+        }
+        segments
+    }
+}
+
+/// Replay carriage returns, cursor movement and erase codes as a terminal would.
+///
+/// Output that was later overwritten (progress bars redrawn with `\r`, Live
+/// displays moving the cursor up and erasing lines) is dropped, leaving the
+/// final screen contents as plain segments joined by newlines. Other control
+/// codes (bell, cursor visibility, titles) have no visible effect and are
+/// removed.
+#[must_use]
+pub fn apply_control_codes<'a>(
+    segments: impl IntoIterator<Item = Segment<'a>>,
+) -> Vec<Segment<'static>> {
+    let mut screen = Screen::default();
+    for segment in segments {
+        if let Some(controls) = &segment.control {
+            for control in controls {
+                screen.apply(control);
+            }
+            continue;
+        }
+        for c in segment.text.chars() {
+            match c {
+                '\n' => (screen.row, screen.col) = (screen.row + 1, 0),
+                '\r' => screen.col = 0,
+                c if c.is_control() => {}
+                c => screen.put(c, segment.style.as_ref()),
+            }
+        }
+    }
+    screen.into_segments()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::color::Color;
     use crate::style::Attributes;
 
+    fn screen_text(segments: Vec<Segment<'_>>) -> String {
+        apply_control_codes(segments)
+            .iter()
+            .map(|s| s.text.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_control_codes_carriage_return_overwrites() {
+        let erase = ControlCode::with_params_vec(ControlType::EraseInLine, vec![2]);
+        let segments = vec![
+            Segment::new("loading 10%", None),
+            Segment::control(vec![ControlCode::new(ControlType::CarriageReturn), erase]),
+            Segment::new("done", None),
+            Segment::line(),
+        ];
+        assert_eq!(screen_text(segments), "done\n");
+
+        // A bare `\r` without erasing only overwrites the cells written over.
+        assert_eq!(
+            screen_text(vec![Segment::new("abcd\rxy\n", None)]),
+            "xycd\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_control_codes_live_redraw() {
+        let erase = || ControlCode::with_params_vec(ControlType::EraseInLine, vec![2]);
+        let segments = vec![
+            Segment::new("old 1\nold 2", None),
+            Segment::control(vec![
+                ControlCode::new(ControlType::CarriageReturn),
+                erase(),
+                ControlCode::with_params_vec(ControlType::CursorUp, vec![1]),
+                erase(),
+            ]),
+            Segment::new("new 1\nnew 2\n", None),
+            Segment::control(vec![ControlCode::new(ControlType::HideCursor)]),
+        ];
+        assert_eq!(screen_text(segments), "new 1\nnew 2\n");
+    }
+
+    #[test]
+    fn test_apply_control_codes_keeps_styles_and_wide_chars() {
+        let red = Style::new().color(Color::parse("red").unwrap());
+        let segments = vec![
+            Segment::new("\u{4f60}\u{597d}", None),
+            Segment::control(vec![ControlCode::with_params_vec(
+                ControlType::CursorMoveToColumn,
+                vec![1],
+            )]),
+            Segment::new("ab", Some(red.clone())),
+            Segment::new("c", Some(red.clone())),
+        ];
+        let screen = apply_control_codes(segments);
+        // Writing into the right half of the first wide char blanks it.
+        assert_eq!(screen[0], Segment::new(" ", None));
+        assert_eq!(screen[1], Segment::new("abc", Some(red)));
+        assert_eq!(screen.len(), 2);
+    }
+
     #[test]
     fn test_segment_new() {
         let seg = Segment::new("hello", None);
@@ -62,6 +62,7 @@ use smallvec::SmallVec;
 use std::fmt::{self, Write as _};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 
 use crate::color::{Color, ColorParseError, ColorSystem, ColorTriplet, TerminalTheme, blend_rgb};
@@ -320,6 +321,42 @@ impl Style {
         Ok(self.bgcolor(c))
     }
 
+    /// Set the foreground color from a color definition, for inline chaining.
+    ///
+    /// Unlike [`color_str`](Self::color_str) this never fails: an unparseable
+    /// definition leaves the foreground unchanged.
+    ///
+    /// ```
+    /// use rich_rust::style::Style;
+    ///
+    /// let style = Style::new().fg("red").on("grey15").bold();
+    /// assert_eq!(style, Style::parse("bold red on grey15").unwrap());
+    /// ```
+    #[must_use]
+    pub fn fg(self, color: &str) -> Self {
+        match Color::parse(color) {
+            Ok(c) => self.color(c),
+            Err(_) => self,
+        }
+    }
+
+    /// Set the background color from a color definition, for inline chaining.
+    ///
+    /// An unparseable definition leaves the background unchanged.
+    #[must_use]
+    pub fn bg(self, color: &str) -> Self {
+        match Color::parse(color) {
+            Ok(c) => self.bgcolor(c),
+            Err(_) => self,
+        }
+    }
+
+    /// Background shorthand mirroring the `"fg on bg"` style syntax; same as [`bg`](Self::bg).
+    #[must_use]
+    pub fn on(self, color: &str) -> Self {
+        self.bg(color)
+    }
+
     /// Enable bold text.
     #[must_use]
     pub fn bold(mut self) -> Self {
@@ -420,6 +457,17 @@ impl Style {
         self
     }
 
+    /// Set a hyperlink URL with a freshly generated, process-unique ID.
+    ///
+    /// Some terminals merge adjacent OSC 8 links that share a URL and have no ID;
+    /// a unique ID keeps each link a separate hover/click target.
+    #[must_use]
+    pub fn link_unique(self, url: impl Into<String>) -> Self {
+        static NEXT_LINK_ID: AtomicU64 = AtomicU64::new(1);
+        let id = NEXT_LINK_ID.fetch_add(1, Ordering::Relaxed);
+        self.link_with_id(url, format!("rich-{id}"))
+    }
+
     /// Set the hyperlink ID for OSC 8 tracking.
     ///
     /// This is useful when you want to set the ID separately from the URL.
@@ -472,6 +520,36 @@ impl Style {
         }
     }
 
+    /// The parts of this style that differ from `base`.
+    ///
+    /// Combining `base` with the result gives the same style as combining
+    /// `base` with `self`, so the result is the minimal override to layer on
+    /// top of `base`. Returns a null style when nothing differs.
+    #[must_use]
+    pub fn diff(&self, base: &Style) -> Style {
+        fn changed<T: Clone + PartialEq>(value: Option<&T>, base: Option<&T>) -> Option<T> {
+            value.filter(|value| Some(*value) != base).cloned()
+        }
+
+        let set_attributes =
+            self.set_attributes & (!base.set_attributes | (self.attributes ^ base.attributes));
+        let style = Style {
+            color: changed(self.color.as_ref(), base.color.as_ref()),
+            bgcolor: changed(self.bgcolor.as_ref(), base.bgcolor.as_ref()),
+            attributes: self.attributes & set_attributes,
+            set_attributes,
+            link: changed(self.link.as_ref(), base.link.as_ref()),
+            link_id: changed(self.link_id.as_ref(), base.link_id.as_ref()),
+            meta: changed(self.meta.as_ref(), base.meta.as_ref()),
+            null: false,
+        };
+        if style == Style::new() {
+            Style::null()
+        } else {
+            style
+        }
+    }
+
     /// Generate ANSI escape codes for this style.
     #[must_use]
     pub fn make_ansi_codes(&self, color_system: ColorSystem) -> String {
@@ -930,6 +1008,73 @@ impl From<[u8; 3]> for Style {
 }
 
 /// Parse an attribute name to its flag.
+/// Attribute names accepted by [`Style::parse`] (long forms only).
+const ATTRIBUTE_NAMES: &[&str] = &[
+    "bold",
+    "dim",
+    "italic",
+    "underline",
+    "blink",
+    "blink2",
+    "reverse",
+    "conceal",
+    "strike",
+    "underline2",
+    "frame",
+    "encircle",
+    "overline",
+];
+
+/// The attribute or color name closest to an unknown style word.
+pub(crate) fn suggest_token(word: &str) -> Option<&'static str> {
+    let candidates = ATTRIBUTE_NAMES
+        .iter()
+        .copied()
+        .chain(crate::color::named_colors());
+    closest_match(word, candidates)
+}
+
+/// Find the candidate closest to `word`, if `word` is plausibly a typo of it.
+pub(crate) fn closest_match<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = (word.chars().count() / 3).clamp(1, 3);
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != word)
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Edit distance counting insertions, deletions, substitutions and adjacent swaps.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
 fn parse_attribute(name: &str) -> Option<Attributes> {
     match name {
         "bold" | "b" => Some(Attributes::BOLD),
@@ -1011,9 +1156,42 @@ impl Default for StyleStack {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StyleParseError {
     InvalidFormat(String),
+    /// The word after `not` is not an attribute.
     UnknownAttribute(String),
+    /// A word is neither an attribute, a color nor a keyword.
     UnknownToken(String),
     ColorError(ColorParseError),
+    /// A word that is not a style but is close to one of the theme's style
+    /// names; returned by [`Console::try_get_style`](crate::console::Console::try_get_style).
+    UnknownStyle {
+        token: String,
+        suggestion: String,
+    },
+}
+
+impl StyleParseError {
+    /// The closest valid name for the offending word, if it looks like a typo.
+    ///
+    /// Attribute and color names are suggested for unknown words, attribute
+    /// names for the word after `not`.
+    ///
+    /// ```
+    /// use rich_rust::style::Style;
+    ///
+    /// let err = Style::parse("blod red").unwrap_err();
+    /// assert_eq!(err.suggestion(), Some("bold"));
+    /// ```
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::UnknownAttribute(attribute) => {
+                closest_match(attribute, ATTRIBUTE_NAMES.iter().copied())
+            }
+            Self::UnknownToken(token) => suggest_token(token),
+            Self::UnknownStyle { suggestion, .. } => Some(suggestion),
+            Self::InvalidFormat(_) | Self::ColorError(_) => None,
+        }
+    }
 }
 
 impl fmt::Display for StyleParseError {
@@ -1023,6 +1201,11 @@ impl fmt::Display for StyleParseError {
             Self::UnknownAttribute(s) => write!(f, "Unknown attribute: {s}"),
             Self::UnknownToken(s) => write!(f, "Unknown token: {s}"),
             Self::ColorError(e) => write!(f, "Color error: {e}"),
+            Self::UnknownStyle { token, .. } => write!(f, "Unknown style: {token}"),
+        }?;
+        match self.suggestion() {
+            Some(suggestion) => write!(f, " (did you mean '{suggestion}'?)"),
+            None => Ok(()),
         }
     }
 }
@@ -1062,6 +1245,22 @@ mod tests {
         assert!(style.color.is_some());
     }
 
+    #[test]
+    fn test_style_fg_on_chaining() {
+        let style = Style::new().fg("red").on("grey15");
+        assert_eq!(style.color, Some(Color::parse("red").unwrap()));
+        assert_eq!(style.bgcolor, Some(Color::parse("grey15").unwrap()));
+        assert_eq!(Style::new().bg("blue"), Style::new().on("blue"));
+    }
+
+    #[test]
+    fn test_style_fg_invalid_color_is_ignored() {
+        let style = Style::new().fg("red").fg("not-a-color").on("nope");
+        assert_eq!(style.color, Some(Color::parse("red").unwrap()));
+        assert!(style.bgcolor.is_none());
+        assert_eq!(Style::new().fg("???"), Style::new());
+    }
+
     #[test]
     fn test_style_combine() {
         let style1 = Style::new().bold().color(Color::from_ansi(1));
@@ -1075,6 +1274,30 @@ mod tests {
         assert_eq!(combined.color.unwrap().number, Some(2));
     }
 
+    #[test]
+    fn test_style_diff() {
+        let base = Style::parse("bold red on black").unwrap();
+        let style = Style::parse("bold green on black not italic underline").unwrap();
+
+        let diff = style.diff(&base);
+        assert_eq!(diff, Style::parse("green not italic underline").unwrap());
+        assert_eq!(base.combine(&diff), base.combine(&style));
+
+        assert!(base.diff(&base).is_null());
+        assert!(Style::null().diff(&base).is_null());
+        assert_eq!(base.diff(&Style::null()), base);
+    }
+
+    #[test]
+    fn test_style_diff_keeps_turned_off_attributes() {
+        let base = Style::new().bold();
+        let style = Style::new().not(Attributes::BOLD);
+
+        let diff = style.diff(&base);
+        assert_eq!(diff, style);
+        assert!(!base.combine(&diff).attributes.contains(Attributes::BOLD));
+    }
+
     #[test]
     fn test_style_combine_null() {
         let style = Style::new().bold();
@@ -1682,10 +1905,28 @@ mod tests {
         assert!(err1.to_string().contains("Invalid style format"));
 
         let err2 = StyleParseError::UnknownAttribute("xyz".to_string());
-        assert!(err2.to_string().contains("Unknown attribute"));
+        assert_eq!(err2.to_string(), "Unknown attribute: xyz");
+
+        let err3 = StyleParseError::UnknownToken("blod".to_string());
+        assert_eq!(
+            err3.to_string(),
+            "Unknown token: blod (did you mean 'bold'?)"
+        );
+    }
 
-        let err3 = StyleParseError::UnknownToken("abc".to_string());
-        assert!(err3.to_string().contains("Unknown token"));
+    #[test]
+    fn test_style_parse_suggests_closest_match() {
+        let suggestion = |style: &str| {
+            Style::parse(style)
+                .unwrap_err()
+                .suggestion()
+                .map(str::to_string)
+        };
+        assert_eq!(suggestion("blod red").as_deref(), Some("bold"));
+        assert_eq!(suggestion("itlaic").as_deref(), Some("italic"));
+        assert_eq!(suggestion("bold gren").as_deref(), Some("green"));
+        assert_eq!(suggestion("not undrline").as_deref(), Some("underline"));
+        assert_eq!(suggestion("zzzzzz"), None);
     }
 
     #[test]
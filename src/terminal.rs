@@ -1,7 +1,8 @@
 //! Terminal detection and manipulation.
 //!
 //! This module provides functionality to detect terminal capabilities
-//! and query terminal dimensions.
+//! and query terminal dimensions, plus a small raw-mode key reader
+//! ([`RawMode`], [`read_key`], [`read_input`]) for interactive prompts.
 
 use std::io::IsTerminal;
 
@@ -87,6 +88,49 @@ pub fn is_dumb_terminal() -> bool {
     })
 }
 
+/// Check whether OSC 8 hyperlinks should be emitted.
+///
+/// `FORCE_HYPERLINK` overrides detection (`0` disables, any other non-empty value
+/// enables). Otherwise hyperlinks are assumed to be supported unless `TERM` is
+/// `dumb` or `unknown`; terminals without OSC 8 support ignore the sequences.
+#[must_use]
+pub fn supports_hyperlinks() -> bool {
+    hyperlinks_supported_with(
+        std::env::var("FORCE_HYPERLINK").ok().as_deref(),
+        is_dumb_terminal(),
+    )
+}
+
+fn hyperlinks_supported_with(force_hyperlink: Option<&str>, is_dumb: bool) -> bool {
+    match force_hyperlink.map(str::trim) {
+        Some("0") => false,
+        Some(value) if !value.is_empty() => true,
+        _ => !is_dumb,
+    }
+}
+
+/// Check whether the terminal shows blinking text (SGR 5 and 6).
+///
+/// Dumb terminals don't, and on Windows only Windows Terminal (which sets
+/// `WT_SESSION`) does; the legacy console ignores the attribute.
+#[must_use]
+pub fn supports_blink() -> bool {
+    extended_attributes_supported_with(is_dumb_terminal(), std::env::var_os("WT_SESSION").is_some())
+}
+
+/// Check whether the terminal hides concealed text (SGR 8).
+///
+/// Detected like [`supports_blink`]; terminals without support would show
+/// concealed text in the clear.
+#[must_use]
+pub fn supports_conceal() -> bool {
+    extended_attributes_supported_with(is_dumb_terminal(), std::env::var_os("WT_SESSION").is_some())
+}
+
+fn extended_attributes_supported_with(is_dumb: bool, windows_terminal: bool) -> bool {
+    !is_dumb && (cfg!(not(windows)) || windows_terminal)
+}
+
 /// Detect the color system supported by the terminal.
 ///
 /// Checks environment variables to determine color capabilities:
@@ -178,6 +222,206 @@ pub fn disable_raw_mode() -> std::io::Result<()> {
     crossterm::terminal::disable_raw_mode()
 }
 
+/// Raw mode that is disabled again when dropped.
+///
+/// ```rust,ignore
+/// use rich_rust::terminal::{Key, RawMode, read_key};
+///
+/// let _raw = RawMode::enable()?;
+/// while read_key()? != Key::Enter {}
+/// ```
+#[derive(Debug)]
+pub struct RawMode {
+    bracketed_paste: bool,
+}
+
+impl RawMode {
+    /// Enable raw mode until the returned guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the terminal doesn't support raw mode (for example
+    /// when stdin is not a terminal).
+    pub fn enable() -> std::io::Result<Self> {
+        enable_raw_mode()?;
+        Ok(Self {
+            bracketed_paste: false,
+        })
+    }
+
+    /// Like [`enable`](Self::enable), but also turn on bracketed paste so
+    /// pasted text arrives as a single [`Input::Paste`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if raw mode or bracketed paste can't be enabled.
+    pub fn with_bracketed_paste() -> std::io::Result<Self> {
+        let mut raw = Self::enable()?;
+        control::enable_bracketed_paste(&mut std::io::stdout())?;
+        raw.bracketed_paste = true;
+        Ok(raw)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if self.bracketed_paste {
+            let _ = control::disable_bracketed_paste(&mut std::io::stdout());
+        }
+        let _ = disable_raw_mode();
+    }
+}
+
+/// A key press read by [`read_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// ↑
+    Up,
+    /// ↓
+    Down,
+    /// ←
+    Left,
+    /// →
+    Right,
+    /// Home
+    Home,
+    /// End
+    End,
+    /// Page Up
+    PageUp,
+    /// Page Down
+    PageDown,
+    /// Enter / Return
+    Enter,
+    /// Escape
+    Escape,
+    /// Tab
+    Tab,
+    /// Backspace
+    Backspace,
+    /// Ctrl-C, which raw mode delivers as a key instead of a signal.
+    CtrlC,
+    /// Any other Ctrl+character combination, e.g. `Ctrl('d')`.
+    Ctrl(char),
+    /// A printable character.
+    Char(char),
+}
+
+/// A key press or bracketed paste read by [`read_input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A key press.
+    Key(Key),
+    /// Text pasted while bracketed paste is on (see [`RawMode::with_bracketed_paste`]).
+    Paste(String),
+}
+
+impl Key {
+    /// Translate a crossterm key event; `None` for releases and other keys.
+    pub(crate) fn from_event(event: crossterm::event::KeyEvent) -> Option<Self> {
+        use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
+
+        if event.kind != KeyEventKind::Press {
+            return None;
+        }
+        let control = event.modifiers.contains(KeyModifiers::CONTROL);
+        Some(match event.code {
+            KeyCode::Up => Self::Up,
+            KeyCode::Down => Self::Down,
+            KeyCode::Left => Self::Left,
+            KeyCode::Right => Self::Right,
+            KeyCode::Home => Self::Home,
+            KeyCode::End => Self::End,
+            KeyCode::PageUp => Self::PageUp,
+            KeyCode::PageDown => Self::PageDown,
+            KeyCode::Enter => Self::Enter,
+            KeyCode::Esc => Self::Escape,
+            KeyCode::Tab => Self::Tab,
+            KeyCode::Backspace => Self::Backspace,
+            KeyCode::Char('c') if control => Self::CtrlC,
+            KeyCode::Char(c) if control => Self::Ctrl(c),
+            KeyCode::Char(c) => Self::Char(c),
+            _ => return None,
+        })
+    }
+}
+
+/// Block until the next supported key press and return it.
+///
+/// Call this while [`RawMode`] is enabled; otherwise the terminal buffers
+/// input by line and echoes it.
+///
+/// # Errors
+///
+/// Returns an error if reading terminal events fails.
+pub fn read_key() -> std::io::Result<Key> {
+    loop {
+        if let Some(Input::Key(key)) = next_input(None)? {
+            return Ok(key);
+        }
+    }
+}
+
+/// Like [`read_key`], but gives up after `timeout` and returns `None`.
+///
+/// # Errors
+///
+/// Returns an error if polling or reading terminal events fails.
+pub fn read_key_timeout(timeout: std::time::Duration) -> std::io::Result<Option<Key>> {
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        match next_input(Some(deadline))? {
+            Some(Input::Key(key)) => return Ok(Some(key)),
+            Some(Input::Paste(_)) => {}
+            None => return Ok(None),
+        }
+    }
+}
+
+/// Block until the next key press or bracketed paste and return it.
+///
+/// # Errors
+///
+/// Returns an error if reading terminal events fails.
+pub fn read_input() -> std::io::Result<Input> {
+    loop {
+        if let Some(input) = next_input(None)? {
+            return Ok(input);
+        }
+    }
+}
+
+/// Like [`read_input`], but gives up after `timeout` and returns `None`.
+///
+/// # Errors
+///
+/// Returns an error if polling or reading terminal events fails.
+pub fn read_input_timeout(timeout: std::time::Duration) -> std::io::Result<Option<Input>> {
+    next_input(Some(std::time::Instant::now() + timeout))
+}
+
+/// Read terminal events until one maps to an [`Input`] or `deadline` passes.
+fn next_input(deadline: Option<std::time::Instant>) -> std::io::Result<Option<Input>> {
+    loop {
+        if let Some(deadline) = deadline
+            && !crossterm::event::poll(
+                deadline.saturating_duration_since(std::time::Instant::now()),
+            )?
+        {
+            return Ok(None);
+        }
+        match crossterm::event::read()? {
+            crossterm::event::Event::Key(event) => {
+                if let Some(key) = Key::from_event(event) {
+                    return Ok(Some(Input::Key(key)));
+                }
+            }
+            crossterm::event::Event::Paste(text) => return Ok(Some(Input::Paste(text))),
+            _ => {}
+        }
+    }
+}
+
 /// Terminal control sequences.
 pub mod control {
     use std::io::Write;
@@ -272,6 +516,20 @@ pub mod control {
         Ok(())
     }
 
+    /// Enable bracketed paste, so pasted text is delivered as a single event.
+    pub fn enable_bracketed_paste<W: Write>(writer: &mut W) -> std::io::Result<()> {
+        use crossterm::{ExecutableCommand, event::EnableBracketedPaste};
+        writer.execute(EnableBracketedPaste)?;
+        Ok(())
+    }
+
+    /// Disable bracketed paste.
+    pub fn disable_bracketed_paste<W: Write>(writer: &mut W) -> std::io::Result<()> {
+        use crossterm::{ExecutableCommand, event::DisableBracketedPaste};
+        writer.execute(DisableBracketedPaste)?;
+        Ok(())
+    }
+
     /// Set the terminal window title.
     pub fn set_title<W: Write>(writer: &mut W, title: &str) -> std::io::Result<()> {
         use crossterm::{ExecutableCommand, terminal::SetTitle};
@@ -305,6 +563,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_key_from_event() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+
+        let event = |code, modifiers, kind| KeyEvent {
+            code,
+            modifiers,
+            kind,
+            state: KeyEventState::NONE,
+        };
+        let press = |code| event(code, KeyModifiers::NONE, KeyEventKind::Press);
+
+        assert_eq!(Key::from_event(press(KeyCode::Up)), Some(Key::Up));
+        assert_eq!(Key::from_event(press(KeyCode::Esc)), Some(Key::Escape));
+        assert_eq!(
+            Key::from_event(press(KeyCode::Char('x'))),
+            Some(Key::Char('x'))
+        );
+        assert_eq!(
+            Key::from_event(event(
+                KeyCode::Char('c'),
+                KeyModifiers::CONTROL,
+                KeyEventKind::Press
+            )),
+            Some(Key::CtrlC)
+        );
+        assert_eq!(
+            Key::from_event(event(
+                KeyCode::Char('d'),
+                KeyModifiers::CONTROL,
+                KeyEventKind::Press
+            )),
+            Some(Key::Ctrl('d'))
+        );
+        assert_eq!(
+            Key::from_event(event(
+                KeyCode::Down,
+                KeyModifiers::NONE,
+                KeyEventKind::Release
+            )),
+            None
+        );
+        assert_eq!(Key::from_event(press(KeyCode::F(1))), None);
+    }
+
     #[test]
     fn test_detect_color_system() {
         // Just ensure it doesn't panic
@@ -317,6 +620,25 @@ mod tests {
         let _ = is_terminal();
     }
 
+    #[test]
+    fn test_extended_attributes_supported_with() {
+        assert!(!extended_attributes_supported_with(true, true));
+        assert!(extended_attributes_supported_with(false, true));
+        assert_eq!(
+            extended_attributes_supported_with(false, false),
+            cfg!(not(windows))
+        );
+    }
+
+    #[test]
+    fn test_hyperlinks_supported_with() {
+        assert!(hyperlinks_supported_with(None, false));
+        assert!(!hyperlinks_supported_with(None, true));
+        assert!(!hyperlinks_supported_with(Some("0"), false));
+        assert!(hyperlinks_supported_with(Some("1"), true));
+        assert!(!hyperlinks_supported_with(Some(""), true));
+    }
+
     #[test]
     fn test_force_color_forces_terminal() {
         assert!(!force_color_forces_terminal(None));
@@ -44,6 +44,79 @@ pub enum OverflowMethod {
     Ellipsis,
     /// No overflow handling.
     Ignore,
+    /// Split at the width regardless of word boundaries, prefixing each
+    /// continuation line with a marker (see [`WrapOptions::continuation_marker`]).
+    ///
+    /// Unlike [`OverflowMethod::Fold`], this keeps whitespace intact and makes
+    /// it visible that the lines belong to one logical line, as log viewers need.
+    Continue,
+}
+
+/// Word-wrapping options for [`Text::wrap_with`].
+///
+/// The defaults match [`Text::wrap`]: lines break at whitespace, and words longer
+/// than the width are split wherever they overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrapOptions {
+    /// Split words that don't fit on a line by themselves (default `true`).
+    ///
+    /// When `false`, long words (URLs, identifiers) are kept whole and overflow the
+    /// width instead.
+    pub break_long_words: bool,
+    /// Character appended where a long word is split (default none).
+    pub hyphenation: Option<char>,
+    /// Allow breaking after a `-` inside a word (default `false`).
+    pub break_on_hyphens: bool,
+    /// Marker prefixed to continuation lines with [`OverflowMethod::Continue`]
+    /// (default `"↳ "`).
+    pub continuation_marker: &'static str,
+}
+
+impl Default for WrapOptions {
+    fn default() -> Self {
+        Self {
+            break_long_words: true,
+            hyphenation: None,
+            break_on_hyphens: false,
+            continuation_marker: "\u{21b3} ",
+        }
+    }
+}
+
+impl WrapOptions {
+    /// Create wrap options with defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether words longer than the width are split.
+    #[must_use]
+    pub const fn break_long_words(mut self, break_long_words: bool) -> Self {
+        self.break_long_words = break_long_words;
+        self
+    }
+
+    /// Set the character appended where a long word is split.
+    #[must_use]
+    pub const fn hyphenation(mut self, hyphen: char) -> Self {
+        self.hyphenation = Some(hyphen);
+        self
+    }
+
+    /// Set whether lines may break after hyphens inside words.
+    #[must_use]
+    pub const fn break_on_hyphens(mut self, break_on_hyphens: bool) -> Self {
+        self.break_on_hyphens = break_on_hyphens;
+        self
+    }
+
+    /// Set the marker prefixed to continuation lines with [`OverflowMethod::Continue`].
+    #[must_use]
+    pub const fn continuation_marker(mut self, marker: &'static str) -> Self {
+        self.continuation_marker = marker;
+        self
+    }
 }
 
 /// A span of styled text.
@@ -59,6 +132,11 @@ pub struct Span {
     pub end: usize,
     /// Style to apply to this span.
     pub style: Style,
+    /// Theme style name resolved against the console theme at render time.
+    ///
+    /// When set, `style` is only the fallback used if the text is rendered without
+    /// a console (see [`Text::stylize_named`]).
+    pub style_name: Option<String>,
 }
 
 impl Span {
@@ -69,6 +147,25 @@ impl Span {
             start: start.min(end),
             end: end.max(start),
             style,
+            style_name: None,
+        }
+    }
+
+    /// Create a span whose style is looked up by theme name at render time.
+    #[must_use]
+    pub fn named(start: usize, end: usize, name: impl Into<String>) -> Self {
+        Self {
+            style_name: Some(name.into()),
+            ..Self::new(start, end, Style::null())
+        }
+    }
+
+    /// Copy of this span (style and style name) covering a different range.
+    #[must_use]
+    pub fn with_range(&self, start: usize, end: usize) -> Self {
+        Self {
+            style_name: self.style_name.clone(),
+            ..Self::new(start, end, self.style.clone())
         }
     }
 
@@ -91,6 +188,7 @@ impl Span {
             start: (self.start + offset).min(max),
             end: (self.end + offset).min(max),
             style: self.style.clone(),
+            style_name: self.style_name.clone(),
         }
     }
 
@@ -106,11 +204,13 @@ impl Span {
                 start: self.start,
                 end: split_point.min(self.end),
                 style: self.style.clone(),
+                style_name: self.style_name.clone(),
             },
             Self {
                 start: split_point.min(self.end),
                 end: self.end,
                 style: self.style.clone(),
+                style_name: self.style_name.clone(),
             },
         )
     }
@@ -122,6 +222,7 @@ impl Span {
             start: self.start.saturating_sub(offset),
             end: self.end.saturating_sub(offset),
             style: self.style.clone(),
+            style_name: self.style_name.clone(),
         }
     }
 }
@@ -338,6 +439,15 @@ impl Text {
         }
     }
 
+    /// Append text as an OSC 8 hyperlink to `url`.
+    ///
+    /// Each call gets a unique link ID (see [`Style::link_unique`]) so two adjacent
+    /// links to the same URL stay distinct. Consoles without hyperlink support render
+    /// the text without the link.
+    pub fn append_link(&mut self, text: &str, url: &str) {
+        self.append_styled(text, Style::new().link_unique(url));
+    }
+
     /// Append another Text object, merging spans.
     pub fn append_text(&mut self, other: &Text) {
         let offset = self.length;
@@ -360,6 +470,43 @@ impl Text {
         }
     }
 
+    /// Apply a theme style by name to a range.
+    ///
+    /// The name (e.g. `"warning"`, `"repr.number"`) is resolved against the console's
+    /// theme when the text is printed or rendered, not now, so the same `Text` picks up
+    /// a different theme after [`Console::push_theme`](crate::console::Console::push_theme).
+    pub fn stylize_named(&mut self, start: usize, end: usize, name: impl Into<String>) {
+        let clamped_start = start.min(self.length);
+        let clamped_end = end.min(self.length);
+        if clamped_start < clamped_end {
+            self.spans
+                .push(Span::named(clamped_start, clamped_end, name));
+        }
+    }
+
+    /// True if any span refers to a theme style by name.
+    #[must_use]
+    pub fn has_named_styles(&self) -> bool {
+        self.spans.iter().any(|span| span.style_name.is_some())
+    }
+
+    /// Resolve named spans against the console theme.
+    ///
+    /// Returns the text unchanged (borrowed) when there is nothing to resolve.
+    #[must_use]
+    pub fn resolve_styles(&self, console: &Console) -> std::borrow::Cow<'_, Self> {
+        if !self.has_named_styles() {
+            return std::borrow::Cow::Borrowed(self);
+        }
+        let mut resolved = self.clone();
+        for span in &mut resolved.spans {
+            if let Some(name) = span.style_name.take() {
+                span.style = span.style.combine(&console.get_style(&name));
+            }
+        }
+        std::borrow::Cow::Owned(resolved)
+    }
+
     /// Apply style to entire text.
     pub fn stylize_all(&mut self, style: Style) {
         if self.length > 0 {
@@ -537,7 +684,7 @@ impl Text {
             let new_end = span.end.min(clamped_end) - clamped_start;
 
             if new_start < new_end {
-                spans.push(Span::new(new_start, new_end, span.style.clone()));
+                spans.push(span.with_range(new_start, new_end));
             }
         }
 
@@ -612,7 +759,7 @@ impl Text {
                     let new_end = span.end.min(char_idx) - start_char;
 
                     if new_start < new_end {
-                        spans.push(Span::new(new_start, new_end, span.style.clone()));
+                        spans.push(span.with_range(new_start, new_end));
                     }
                 }
 
@@ -647,7 +794,7 @@ impl Text {
                 let new_start = span.start.max(start_char) - start_char;
                 let new_end = span.end.min(self.length) - start_char;
                 if new_start < new_end {
-                    spans.push(Span::new(new_start, new_end, span.style.clone()));
+                    spans.push(span.with_range(new_start, new_end));
                 }
             }
 
@@ -697,6 +844,24 @@ impl Text {
         result
     }
 
+    /// Hard-fold text into lines of at most `width` cells, breaking anywhere.
+    ///
+    /// A character wider than `width` still gets a line of its own.
+    #[must_use]
+    pub(crate) fn fold(&self, width: usize) -> Vec<Self> {
+        let mut offsets = Vec::new();
+        let mut column = 0;
+        for (index, c) in self.plain.chars().enumerate() {
+            let cell = crate::cells::get_character_cell_size(c);
+            if column > 0 && column + cell > width {
+                offsets.push(index);
+                column = 0;
+            }
+            column += cell;
+        }
+        self.divide(&offsets)
+    }
+
     /// Expand tabs to spaces.
     #[must_use]
     pub fn expand_tabs(&self, tab_size: usize) -> Self {
@@ -741,7 +906,7 @@ impl Text {
             let new_end = char_map.partition_point(|&old| old < span.end);
 
             if new_start < new_end {
-                new_spans.push(Span::new(new_start, new_end, span.style.clone()));
+                new_spans.push(span.with_range(new_start, new_end));
             }
         }
 
@@ -771,7 +936,7 @@ impl Text {
         }
 
         match overflow {
-            OverflowMethod::Crop | OverflowMethod::Fold => {
+            OverflowMethod::Crop | OverflowMethod::Fold | OverflowMethod::Continue => {
                 // Find character position that fits - iterate directly without collecting
                 let (cut_pos, width) = self.find_truncation_point(max_width);
                 *self = self.slice(0, cut_pos);
@@ -859,6 +1024,33 @@ impl Text {
         }
     }
 
+    /// Crop text to at most `width` cells.
+    ///
+    /// A double-width character straddling the boundary is replaced by a
+    /// space (keeping its style), so the result is exactly `width` cells
+    /// whenever the text was at least that wide.
+    pub fn crop(&mut self, width: usize) {
+        if self.cell_len() <= width {
+            return;
+        }
+        let (cut_pos, cut_width) = self.find_truncation_point(width);
+        if cut_width < width {
+            // The next character is wide and only half of it fits.
+            let mut cropped = self.slice(0, cut_pos + 1);
+            let keep: String = cropped.plain.chars().take(cut_pos).collect();
+            cropped.plain = keep + " ";
+            *self = cropped;
+        } else {
+            *self = self.slice(0, cut_pos);
+        }
+    }
+
+    /// Crop or pad text to exactly `width` cells.
+    pub fn pad_to(&mut self, width: usize, align: JustifyMethod) {
+        self.crop(width);
+        self.pad(width, align);
+    }
+
     /// Strip leading and trailing whitespace.
     #[must_use]
     pub fn strip(&self) -> Self {
@@ -923,7 +1115,7 @@ impl Text {
             let new_end = positions[end];
 
             if new_start < new_end {
-                new_spans.push(Span::new(new_start, new_end, span.style.clone()));
+                new_spans.push(span.with_range(new_start, new_end));
             }
         }
 
@@ -1052,13 +1244,28 @@ impl Text {
     /// Word wrap text to fit within a width.
     #[must_use]
     pub fn wrap(&self, width: usize) -> Vec<Self> {
+        self.wrap_with(width, WrapOptions::default())
+    }
+
+    /// Word wrap text to fit within a width, with explicit [`WrapOptions`].
+    ///
+    /// ```
+    /// use rich_rust::text::{Text, WrapOptions};
+    ///
+    /// let text = Text::new("see https://example.com/a/long/path");
+    /// let lines = text.wrap_with(12, WrapOptions::new().break_long_words(false));
+    /// assert_eq!(lines[1].plain(), "https://example.com/a/long/path");
+    /// ```
+    #[must_use]
+    pub fn wrap_with(&self, width: usize, options: WrapOptions) -> Vec<Self> {
         if width == 0 {
             return vec![Self::new("")];
         }
 
         let expanded = self.expand_tabs(self.tab_size);
 
-        if expanded.no_wrap || expanded.cell_len() <= width {
+        // Text that fits is returned whole, unless it has line breaks to split at.
+        if expanded.no_wrap || (expanded.cell_len() <= width && !expanded.plain.contains('\n')) {
             return vec![expanded];
         }
 
@@ -1068,7 +1275,7 @@ impl Text {
             if line.cell_len() <= width {
                 lines.push(line);
             } else {
-                lines.extend(self.wrap_line(&line, width));
+                lines.extend(self.wrap_line(&line, width, options));
             }
         }
 
@@ -1076,7 +1283,7 @@ impl Text {
     }
 
     /// Wrap a single line of text.
-    fn wrap_line(&self, line: &Text, width: usize) -> Vec<Self> {
+    fn wrap_line(&self, line: &Text, width: usize, options: WrapOptions) -> Vec<Self> {
         let mut result = Vec::new();
         let chars: Vec<char> = line.plain.chars().collect();
 
@@ -1086,56 +1293,93 @@ impl Text {
 
         match line.overflow {
             OverflowMethod::Fold => {
+                let cell = |c: char| crate::cells::get_character_cell_size(c);
+                // A break opportunity *after* position `i`: whitespace, or (optionally) a hyphen.
+                let breaks_after = |i: usize| {
+                    let c = chars[i];
+                    (c.is_whitespace() && c != '\n') || (options.break_on_hyphens && c == '-')
+                };
+
                 // Wrap at word boundaries when possible
                 let mut current_line_start = 0;
                 let mut current_width = 0;
-                let mut last_space = None;
+                // Start of the next line if we break at the last opportunity seen.
+                let mut last_break = None;
 
                 for (i, c) in chars.iter().enumerate() {
-                    let char_width = crate::cells::get_character_cell_size(*c);
+                    let char_width = cell(*c);
 
-                    if c.is_whitespace() && *c != '\n' {
-                        last_space = Some(i);
+                    if breaks_after(i) {
+                        last_break = Some(i + 1);
                     }
 
-                    if current_width + char_width > width {
-                        // Need to wrap
-                        let (wrap_at, next_start) = if let Some(space_pos) = last_space {
-                            if space_pos > current_line_start && space_pos < i {
-                                // Preserve the whitespace we wrapped at on the previous line.
-                                // This matches Python Rich's wrapping behavior and matters for
-                                // renderables that include significant trailing spaces (e.g. `": "`).
-                                (space_pos + 1, space_pos + 1)
-                            } else {
-                                (i, i)
-                            }
-                        } else {
-                            (i, i)
-                        };
+                    if current_width + char_width <= width {
+                        current_width += char_width;
+                        continue;
+                    }
 
-                        if wrap_at > current_line_start {
-                            result.push(line.slice(current_line_start, wrap_at));
+                    // Need to wrap. Prefer the last break opportunity on this line; the
+                    // whitespace we wrapped at stays on the previous line, which matches
+                    // Python Rich and matters for renderables with significant trailing
+                    // spaces (e.g. `": "`).
+                    let mut hyphenate = false;
+                    let wrap_at = match last_break {
+                        Some(pos) if pos > current_line_start + 1 && pos <= i => pos,
+                        _ if c.is_whitespace() => i,
+                        _ if !options.break_long_words => {
+                            // Keep the word whole and let it overflow.
+                            current_width += char_width;
+                            continue;
                         }
+                        _ => match options.hyphenation {
+                            Some(hyphen) => {
+                                let available = width.saturating_sub(cell(hyphen));
+                                let mut end = current_line_start;
+                                let mut used = 0;
+                                while end < i && used + cell(chars[end]) <= available {
+                                    used += cell(chars[end]);
+                                    end += 1;
+                                }
+                                if end > current_line_start && chars[end - 1] != hyphen {
+                                    hyphenate = true;
+                                    end
+                                } else {
+                                    i
+                                }
+                            }
+                            None => i,
+                        },
+                    };
 
-                        // Skip whitespace at wrap point (but keep the first break-space above if we chose it)
-                        current_line_start = next_start;
-                        while current_line_start < chars.len()
-                            && chars[current_line_start].is_whitespace()
-                        {
-                            current_line_start += 1;
+                    if wrap_at > current_line_start {
+                        let mut wrapped = line.slice(current_line_start, wrap_at);
+                        if hyphenate && let Some(hyphen) = options.hyphenation {
+                            wrapped.append(hyphen.encode_utf8(&mut [0; 4]));
                         }
+                        result.push(wrapped);
+                    }
 
-                        current_width = 0;
-                        last_space = None;
+                    // Skip whitespace at the wrap point
+                    current_line_start = wrap_at;
+                    while current_line_start < chars.len()
+                        && chars[current_line_start].is_whitespace()
+                    {
+                        current_line_start += 1;
+                    }
 
-                        // Recalculate width from new start
-                        for j in current_line_start..=i {
-                            if j < chars.len() {
-                                current_width += crate::cells::get_character_cell_size(chars[j]);
-                            }
+                    // Recalculate width and break opportunities from the new start
+                    current_width = 0;
+                    last_break = None;
+                    for (j, &carried) in chars
+                        .iter()
+                        .enumerate()
+                        .take(i + 1)
+                        .skip(current_line_start)
+                    {
+                        current_width += cell(carried);
+                        if breaks_after(j) {
+                            last_break = Some(j + 1);
                         }
-                    } else {
-                        current_width += char_width;
                     }
                 }
 
@@ -1159,6 +1403,42 @@ impl Text {
             OverflowMethod::Ignore => {
                 result.push(line.clone());
             }
+            OverflowMethod::Continue => {
+                let marker = options.continuation_marker;
+                let marker_width = crate::cells::cell_len(marker);
+                // Drop the marker rather than loop forever if it leaves no room.
+                let (marker, marker_width) = if marker_width < width {
+                    (marker, marker_width)
+                } else {
+                    ("", 0)
+                };
+
+                let mut start = 0;
+                let mut available = width;
+                while start < chars.len() {
+                    let mut end = start;
+                    let mut used = 0;
+                    while end < chars.len() {
+                        let char_width = crate::cells::get_character_cell_size(chars[end]);
+                        if used + char_width > available && end > start {
+                            break;
+                        }
+                        used += char_width;
+                        end += 1;
+                    }
+                    let piece = line.slice(start, end);
+                    if start == 0 {
+                        result.push(piece);
+                    } else {
+                        let mut continued = piece.slice(0, 0);
+                        continued.append_styled(marker, Style::new().dim());
+                        continued.append_text(&piece);
+                        result.push(continued);
+                    }
+                    start = end;
+                    available = width - marker_width;
+                }
+            }
         }
 
         if result.is_empty() {
@@ -1183,8 +1463,15 @@ impl Text {
 }
 
 impl Renderable for Text {
-    fn render<'a>(&'a self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment<'a>> {
-        self.render("")
+    fn render<'a>(&'a self, console: &Console, _options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        match self.resolve_styles(console) {
+            std::borrow::Cow::Borrowed(text) => text.render(""),
+            std::borrow::Cow::Owned(text) => text
+                .render("")
+                .into_iter()
+                .map(Segment::into_owned)
+                .collect(),
+        }
     }
 }
 
@@ -1535,6 +1822,21 @@ mod tests {
         assert_eq!(parts[1].spans()[0].end, 6);
     }
 
+    #[test]
+    fn test_fold_breaks_at_cell_width() {
+        let parts = Text::new("abcdefg").fold(3);
+        let plain: Vec<&str> = parts.iter().map(Text::plain).collect();
+        assert_eq!(plain, ["abc", "def", "g"]);
+
+        // Wide characters never straddle a break, and one wider than the
+        // width still gets its own line.
+        let parts = Text::new("a日本b").fold(2);
+        let plain: Vec<&str> = parts.iter().map(Text::plain).collect();
+        assert_eq!(plain, ["a", "日", "本", "b"]);
+        let parts = Text::new("日本").fold(1);
+        assert_eq!(parts.len(), 2);
+    }
+
     #[test]
     fn test_divide_span_ends_at_cut() {
         let mut text = Text::new("hello world");
@@ -1628,6 +1930,13 @@ mod tests {
         assert_eq!(lines[1].plain(), "b");
     }
 
+    #[test]
+    fn test_wrap_splits_short_text_at_newlines() {
+        let lines = Text::new("1\n2\n3").wrap(10);
+        let plain: Vec<&str> = lines.iter().map(Text::plain).collect();
+        assert_eq!(plain, ["1", "2", "3"]);
+    }
+
     #[test]
     fn test_wrap_long_word() {
         let text = Text::new("supercalifragilistic");
@@ -1639,6 +1948,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wrap_with_keeps_long_words_whole() {
+        let text = Text::new("go to https://example.com/path now");
+        let options = WrapOptions::new().break_long_words(false);
+        let plains: Vec<String> = text
+            .wrap_with(10, options)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(plains, ["go to ", "https://example.com/path", "now"]);
+    }
+
+    #[test]
+    fn test_wrap_with_hyphenation_on_forced_breaks() {
+        let text = Text::new("supercalifragilistic");
+        let options = WrapOptions::new().hyphenation('-');
+        let lines = text.wrap_with(8, options);
+        let plains: Vec<&str> = lines.iter().map(Text::plain).collect();
+        assert_eq!(plains, ["superca-", "lifragi-", "listic"]);
+        for line in &lines {
+            assert!(line.cell_len() <= 8);
+        }
+    }
+
+    #[test]
+    fn test_wrap_with_break_on_hyphens() {
+        let text = Text::new("well-known-identifier");
+        let default: Vec<String> = text
+            .wrap(12)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(default, ["well-known-i", "dentifier"]);
+
+        let options = WrapOptions::new().break_on_hyphens(true);
+        let plains: Vec<String> = text
+            .wrap_with(12, options)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(plains, ["well-known-", "identifier"]);
+    }
+
+    #[test]
+    fn test_wrap_with_default_matches_wrap() {
+        let text = Text::new("the quick brown fox jumps over the lazy dog");
+        assert_eq!(text.wrap(9), text.wrap_with(9, WrapOptions::default()));
+    }
+
     #[test]
     fn test_wrap_zero_width() {
         let text = Text::new("hello");
@@ -1929,6 +2287,53 @@ mod tests {
         assert_eq!(upper.spans()[0].end, 3);
     }
 
+    #[test]
+    fn test_stylize_named_resolves_at_render_time() {
+        use crate::theme::Theme;
+
+        let console = Console::builder().build();
+        let mut text = Text::new("careful now");
+        text.stylize_named(0, 7, "warning");
+        assert!(text.has_named_styles());
+
+        console.push_theme(
+            Theme::from_style_definitions([("warning", "bold")], true).unwrap(),
+            true,
+        );
+        let bold = text.resolve_styles(&console).into_owned();
+        assert_eq!(bold.spans()[0].style, Style::new().bold());
+
+        console.pop_theme().unwrap();
+        console.push_theme(
+            Theme::from_style_definitions([("warning", "italic")], true).unwrap(),
+            true,
+        );
+        let segments = Renderable::render(&text, &console, &console.options());
+        assert_eq!(segments[0].text, "careful");
+        assert_eq!(segments[0].style, Some(Style::new().italic()));
+    }
+
+    #[test]
+    fn test_stylize_named_survives_slicing_and_wrapping() {
+        let mut text = Text::new("alpha beta");
+        text.stylize_named(6, 10, "warning");
+        let lines = text.wrap(6);
+        assert_eq!(lines[1].plain(), "beta");
+        assert_eq!(lines[1].spans()[0].style_name.as_deref(), Some("warning"));
+    }
+
+    #[test]
+    fn test_append_link_uses_unique_ids() {
+        let mut text = Text::new("");
+        text.append_link("a", "https://example.com");
+        text.append_link("b", "https://example.com");
+        let spans = text.spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].style.link.as_deref(), Some("https://example.com"));
+        assert!(spans[0].style.link_id.is_some());
+        assert_ne!(spans[0].style.link_id, spans[1].style.link_id);
+    }
+
     #[test]
     fn test_append_text_merges_spans() {
         let mut a = Text::new("hello");
@@ -2008,6 +2413,84 @@ mod tests {
         assert_eq!(text.plain(), "hi   ");
     }
 
+    #[test]
+    fn test_wrap_overflow_continue() {
+        let mut text = Text::new("error: connection reset by peer");
+        text.overflow = OverflowMethod::Continue;
+        let lines: Vec<String> = text
+            .wrap(12)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec!["error: conne", "\u{21b3} ction rese", "\u{21b3} t by peer"]
+        );
+        let marker = text.wrap(12)[1].spans()[0].clone();
+        assert_eq!((marker.start, marker.end), (0, 2));
+        assert_eq!(marker.style, Style::new().dim());
+    }
+
+    #[test]
+    fn test_wrap_overflow_continue_custom_marker() {
+        let mut text = Text::new("abcdefgh");
+        text.overflow = OverflowMethod::Continue;
+        let options = WrapOptions::new().continuation_marker("> ");
+        let lines: Vec<String> = text
+            .wrap_with(4, options)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(lines, vec!["abcd", "> ef", "> gh"]);
+
+        // A marker that leaves no room is dropped.
+        let options = WrapOptions::new().continuation_marker(">>>>");
+        let lines: Vec<String> = text
+            .wrap_with(4, options)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(lines, vec!["abcd", "efgh"]);
+    }
+
+    #[test]
+    fn test_crop() {
+        let mut text = Text::new("hello world");
+        text.crop(5);
+        assert_eq!(text.plain(), "hello");
+
+        let mut short = Text::new("hi");
+        short.crop(5);
+        assert_eq!(short.plain(), "hi");
+    }
+
+    #[test]
+    fn test_crop_wide_boundary_substitutes_space() {
+        let mut text = Text::new("ab日本");
+        text.stylize(2, 4, Style::new().bold());
+        text.crop(5);
+        assert_eq!(text.plain(), "ab日 ");
+        assert_eq!(text.cell_len(), 5);
+        assert_eq!(text.len(), 4);
+        assert_eq!(text.spans().len(), 1);
+        assert_eq!((text.spans()[0].start, text.spans()[0].end), (2, 4));
+    }
+
+    #[test]
+    fn test_pad_to() {
+        let mut text = Text::new("日本語");
+        text.pad_to(5, JustifyMethod::Left);
+        assert_eq!(text.plain(), "日本 ");
+
+        let mut text = Text::new("ab");
+        text.pad_to(5, JustifyMethod::Right);
+        assert_eq!(text.plain(), "   ab");
+
+        let mut text = Text::new("abc");
+        text.pad_to(3, JustifyMethod::Center);
+        assert_eq!(text.plain(), "abc");
+    }
+
     #[test]
     fn test_join_basic() {
         let separator = Text::new(", ");
@@ -42,6 +42,13 @@ static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     styles
 });
 
+/// A built-in style by name, or an empty style for unknown names.
+///
+/// Used by renderables that can be rendered without a [`crate::console::Console`].
+pub(crate) fn default_style(name: &str) -> Style {
+    DEFAULT_STYLES.get(name).cloned().unwrap_or_default()
+}
+
 /// A container for style information used by [`crate::console::Console`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Theme {
@@ -259,6 +266,9 @@ impl std::error::Error for ThemeStackError {}
 #[derive(Debug, Clone)]
 pub struct ThemeStack {
     entries: Vec<HashMap<String, Style>>,
+    /// Identifies each entry, so a theme can be found again after pushes and pops.
+    ids: Vec<u64>,
+    next_id: u64,
 }
 
 impl ThemeStack {
@@ -267,6 +277,8 @@ impl ThemeStack {
     pub fn new(theme: Theme) -> Self {
         Self {
             entries: vec![theme.styles],
+            ids: vec![0],
+            next_id: 1,
         }
     }
 
@@ -286,6 +298,17 @@ impl ThemeStack {
             theme.styles
         };
         self.entries.push(styles);
+        self.ids.push(self.next_id);
+        self.next_id += 1;
+    }
+
+    /// Names of the styles in the top-most theme.
+    pub(crate) fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .last()
+            .into_iter()
+            .flat_map(HashMap::keys)
+            .map(String::as_str)
     }
 
     /// Pop (and discard) the top-most theme.
@@ -294,8 +317,36 @@ impl ThemeStack {
             return Err(ThemeStackError);
         }
         self.entries.pop();
+        self.ids.pop();
         Ok(())
     }
+
+    /// Identifier of the top-most theme, for [`replace_theme`](Self::replace_theme).
+    #[cfg(feature = "watch")]
+    pub(crate) fn top_id(&self) -> u64 {
+        self.ids.last().copied().unwrap_or_default()
+    }
+
+    /// Replace the theme with identifier `id` in place.
+    ///
+    /// Themes pushed above it keep the styles they were merged with. Returns
+    /// `false` if the theme has been popped.
+    #[cfg(feature = "watch")]
+    pub(crate) fn replace_theme(&mut self, id: u64, theme: Theme, inherit: bool) -> bool {
+        let Some(index) = self.ids.iter().position(|&entry| entry == id) else {
+            return false;
+        };
+        let styles = match index.checked_sub(1).filter(|_| inherit) {
+            Some(below) => {
+                let mut merged = self.entries[below].clone();
+                merged.extend(theme.styles);
+                merged
+            }
+            None => theme.styles,
+        };
+        self.entries[index] = styles;
+        true
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +96,7 @@ mod tests {
             redirect_stdout: false,
             redirect_stderr: false,
             vertical_overflow: VerticalOverflowMethod::Crop,
+            ..LiveOptions::default()
         };
         let live = Live::with_options(console, options);
         drop(live);
@@ -742,6 +743,7 @@ mod tests {
             redirect_stdout: false,
             redirect_stderr: false,
             vertical_overflow: VerticalOverflowMethod::Crop,
+            ..LiveOptions::default()
         };
 
         let cloned = options.clone();
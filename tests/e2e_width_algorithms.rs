@@ -5,8 +5,9 @@
 //!
 //! ## Validation Status
 //!
-//! - `expand_widths()`: **MATCHES SPEC** - Distributes space by column ratio
-//!   per Section 14.4
+//! - Expansion: **MATCHES RICH** - Ratio columns split the space left by the
+//!   other columns (`ratio_distribute`); without ratios, columns are padded
+//!   in proportion to their widths
 //!
 //! - `collapse_widths()`: **MATCHES SPEC** - Includes rounding error correction
 //!   loop per Section 9.3 (lines 1680-1694)
@@ -63,11 +64,11 @@ fn test_expand_widths_with_ratios() {
     tracing::info!("Test Vector 1: expand_widths with ratios");
 
     // Create a table with three columns having different ratios
-    // All columns have fixed width 20 initially (via content)
-    // Ratios are 1:2:1, so with 40 extra space:
-    // - Column 0 should get 10 extra (1/4 of 40)
-    // - Column 1 should get 20 extra (2/4 of 40)
-    // - Column 2 should get 10 extra (1/4 of 40)
+    // All columns are 20 wide by content, but ratio columns ignore content
+    // width (as in Rich), so the 100 available cells split 1:2:1:
+    // - Column 0 should get 25 (1/4 of 100)
+    // - Column 1 should get 50 (2/4 of 100)
+    // - Column 2 should get 25 (1/4 of 100)
     let mut table = Table::new()
         .expand(true)
         .box_style(&SQUARE)
@@ -85,7 +86,7 @@ fn test_expand_widths_with_ratios() {
     ]);
 
     // Calculate available width for column content
-    // Width 104: available=100 (3*20 base + 40 extra), overhead=4 with padding=0
+    // Width 104: available=100, overhead=4 with padding=0
     let output = table.render_plain(104);
     tracing::debug!(output = %output, "Rendered table with ratios");
 
@@ -93,7 +94,7 @@ fn test_expand_widths_with_ratios() {
     let widths = column_widths_from_top_border(top_border, 0);
     assert_eq!(
         widths,
-        vec![30, 40, 30],
+        vec![25, 50, 25],
         "ratio expansion should follow 1:2:1"
     );
 }
@@ -193,16 +194,16 @@ fn test_column_ratio_field_exists() {
 }
 
 // =============================================================================
-// Test Vector 4: Zero ratios - no expansion
+// Test Vector 4: Zero ratios - expand by width
 // =============================================================================
 //
-// SPEC (Section 14.4): Columns with ratio=0 get no extra space.
-// Columns without explicit ratio default to None (treated as 0).
+// Without ratio columns, an expanding table pads every column in proportion
+// to its width (Rich's `ratio_distribute(extra, widths)`).
 
 #[test]
 fn test_expand_widths_zero_ratios() {
     init_test_logging();
-    tracing::info!("Test Vector 4: Zero ratios - no expansion");
+    tracing::info!("Test Vector 4: Zero ratios - expand by width");
 
     // Create table with no ratios set (all columns default to ratio=None)
     let mut table = Table::new()
@@ -214,18 +215,18 @@ fn test_expand_widths_zero_ratios() {
 
     table.add_row_cells(["x", "y"]);
 
-    // With no ratios, columns should NOT expand even with expand=true
+    // With no ratios, the 45 extra cells are shared by width (1:1)
     let output = table.render_plain(50);
     tracing::debug!(output = %output, "Zero ratio table");
 
     let top_border = output.lines().next().expect("top border line");
     let widths = column_widths_from_top_border(top_border, 0);
 
-    // Both columns should stay at minimum width (1 char each for content)
+    // Available = 50 - 3 (overhead) = 47; extra 45 rounds up to the first column
     assert_eq!(
         widths,
-        vec![1, 1],
-        "columns without ratio should not expand"
+        vec![24, 23],
+        "columns without ratio should expand by width"
     );
 }
 